@@ -176,6 +176,44 @@ fn generate_shader_headers(shader_dir: &Path, generated_root: &Path) {
     }
 }
 
+// Text shaping dependencies for `WITH_RIVE_TEXT` (`runtime-text` feature):
+// HarfBuzz and SheenBidi checkouts, overridable through the environment.
+fn dependency_dir(var: &str, default: &str) -> PathBuf {
+    println!("cargo:rerun-if-env-changed={var}");
+    let dir = env::var_os(var).map_or_else(|| PathBuf::from(default), PathBuf::from);
+    if !dir.is_dir() {
+        panic!(
+            "{} not found; check out the dependency there or set {var}",
+            dir.display()
+        );
+    }
+    dir
+}
+
+fn compile_text_dependencies(harfbuzz: &Path, sheenbidi: &Path) {
+    cc::Build::new()
+        .cpp(true)
+        .include(harfbuzz.join("src"))
+        .define("HAVE_OT", None)
+        .define("HB_NO_FALLBACK_SHAPE", None)
+        .define("HB_NO_WIN1256", None)
+        .define("HB_NO_EXTERN_HELPERS", None)
+        .define("HB_DISABLE_DEPRECATED", None)
+        .define("HB_NO_BUFFER_SERIALIZE", None)
+        .define("HB_NO_SETLOCALE", None)
+        .flag_if_supported("-std=c++17")
+        .warnings(false)
+        .file(harfbuzz.join("src/harfbuzz.cc"))
+        .compile("rive_rs_harfbuzz");
+
+    cc::Build::new()
+        .include(sheenbidi.join("Headers"))
+        .define("SB_CONFIG_UNITY", None)
+        .warnings(false)
+        .file(sheenbidi.join("Source/SheenBidi.c"))
+        .compile("rive_rs_sheenbidi");
+}
+
 fn main() {
     println!("cargo:rerun-if-changed=include/rive_rs_abi.h");
     println!("cargo:rerun-if-changed=cpp/provider_core.cpp");
@@ -192,6 +230,14 @@ fn main() {
     let runtime_src = runtime_root.join("src");
     let target = env::var("TARGET").unwrap_or_default();
     let is_emscripten = target.contains("emscripten");
+    let text = env::var_os("CARGO_FEATURE_RUNTIME_TEXT")
+        .is_some()
+        .then(|| {
+            (
+                dependency_dir("RIVE_RS_HARFBUZZ_DIR", "submodules/harfbuzz"),
+                dependency_dir("RIVE_RS_SHEENBIDI_DIR", "submodules/SheenBidi"),
+            )
+        });
 
     let mut runtime_sources = Vec::new();
     collect_cpp_files(&runtime_src, &mut runtime_sources);
@@ -211,6 +257,14 @@ fn main() {
 
     build.files(runtime_sources);
 
+    if let Some((harfbuzz, sheenbidi)) = &text {
+        // Font decoding, shaping and fallback need the text engine.
+        build
+            .include(harfbuzz.join("src"))
+            .include(sheenbidi.join("Headers"))
+            .define("WITH_RIVE_TEXT", None);
+    }
+
     if is_emscripten {
         println!("cargo:rerun-if-changed=submodules/rive-runtime/renderer/include");
         println!("cargo:rerun-if-changed=submodules/rive-runtime/renderer/src");
@@ -257,4 +311,8 @@ fn main() {
     }
 
     build.compile("rive_rs_provider");
+    // After the provider, so static linking resolves its references.
+    if let Some((harfbuzz, sheenbidi)) = &text {
+        compile_text_dependencies(harfbuzz, sheenbidi);
+    }
 }
@@ -2,43 +2,97 @@
 
 #include "rive/animation/animation_state.hpp"
 #include "rive/animation/any_state.hpp"
+#include "rive/animation/blend_state.hpp"
 #include "rive/animation/entry_state.hpp"
 #include "rive/animation/exit_state.hpp"
+#include "rive/animation/keyed_callback_reporter.hpp"
+#include "rive/animation/layer_state.hpp"
 #include "rive/animation/linear_animation.hpp"
 #include "rive/animation/linear_animation_instance.hpp"
 #include "rive/animation/nested_state_machine.hpp"
 #include "rive/animation/state_machine.hpp"
 #include "rive/animation/state_machine_bool.hpp"
+#include "rive/animation/state_machine_input.hpp"
 #include "rive/animation/state_machine_input_instance.hpp"
 #include "rive/animation/state_machine_instance.hpp"
+#include "rive/animation/state_machine_layer.hpp"
+#include "rive/animation/state_machine_listener.hpp"
 #include "rive/animation/state_machine_number.hpp"
 #include "rive/animation/state_machine_trigger.hpp"
+#include "rive/animation/state_transition.hpp"
+#include "rive/animation/state_transition_flags.hpp"
+#include "rive/animation/transition_bool_condition.hpp"
+#include "rive/animation/transition_condition.hpp"
+#include "rive/animation/transition_condition_op.hpp"
+#include "rive/animation/transition_input_condition.hpp"
+#include "rive/animation/transition_number_condition.hpp"
+#include "rive/animation/transition_trigger_condition.hpp"
+#include "rive/animation/transition_value_condition.hpp"
 #include "rive/artboard.hpp"
+#include "rive/audio_event.hpp"
 #include "rive/assets/audio_asset.hpp"
 #include "rive/assets/file_asset.hpp"
 #include "rive/assets/font_asset.hpp"
 #include "rive/assets/image_asset.hpp"
 #include "rive/bindable_artboard.hpp"
 #include "rive/bones/bone.hpp"
+#include "rive/bones/cubic_weight.hpp"
 #include "rive/bones/root_bone.hpp"
+#include "rive/bones/skin.hpp"
+#include "rive/bones/tendon.hpp"
+#include "rive/bones/weight.hpp"
 #include "rive/constraints/constraint.hpp"
+#include "rive/constraints/distance_constraint.hpp"
+#include "rive/constraints/follow_path_constraint.hpp"
+#include "rive/constraints/ik_constraint.hpp"
+#include "rive/constraints/rotation_constraint.hpp"
+#include "rive/constraints/scale_constraint.hpp"
+#include "rive/constraints/transform_constraint.hpp"
+#include "rive/constraints/translation_constraint.hpp"
 #include "rive/custom_property.hpp"
 #include "rive/custom_property_boolean.hpp"
 #include "rive/custom_property_number.hpp"
 #include "rive/custom_property_string.hpp"
+#include "rive/draw_rules.hpp"
+#include "rive/draw_target.hpp"
 #include "rive/event.hpp"
 #include "rive/factory.hpp"
 #include "rive/file.hpp"
 #include "rive/file_asset_loader.hpp"
+#include "rive/joystick.hpp"
+#include "rive/layout_component.hpp"
+#include "rive/listener_type.hpp"
 #include "rive/math/mat2d.hpp"
 #include "rive/math/vec2d.hpp"
 #include "rive/nested_artboard.hpp"
 #include "rive/node.hpp"
 #include "rive/open_url_event.hpp"
 #include "rive/renderer.hpp"
+#include "rive/shapes/clipping_shape.hpp"
+#include "rive/shapes/cubic_asymmetric_vertex.hpp"
+#include "rive/shapes/cubic_detached_vertex.hpp"
+#include "rive/shapes/cubic_mirrored_vertex.hpp"
 #include "rive/shapes/cubic_vertex.hpp"
+#include "rive/shapes/ellipse.hpp"
+#include "rive/shapes/image.hpp"
+#include "rive/shapes/paint/fill.hpp"
+#include "rive/shapes/paint/gradient_stop.hpp"
+#include "rive/shapes/paint/linear_gradient.hpp"
+#include "rive/shapes/paint/radial_gradient.hpp"
+#include "rive/shapes/paint/solid_color.hpp"
+#include "rive/shapes/paint/stroke.hpp"
+#include "rive/shapes/paint/trim_path.hpp"
 #include "rive/shapes/path.hpp"
+#include "rive/shapes/points_path.hpp"
+#include "rive/shapes/polygon.hpp"
+#include "rive/shapes/rectangle.hpp"
+#include "rive/shapes/shape.hpp"
+#include "rive/shapes/star.hpp"
+#include "rive/shapes/straight_vertex.hpp"
+#include "rive/shapes/triangle.hpp"
 #include "rive/simple_array.hpp"
+#include "rive/solo.hpp"
+#include "rive/text/text.hpp"
 #include "rive/text/text_value_run.hpp"
 #include "rive/text_engine.hpp"
 #include "rive/transform_component.hpp"
@@ -75,6 +129,7 @@
 #include <limits>
 #include <map>
 #include <memory>
+#include <mutex>
 #include <new>
 #include <set>
 #include <string>
@@ -85,6 +140,7 @@
 namespace webgl2_provider
 {
 class Renderer;
+class OffscreenTarget;
 }
 #endif
 
@@ -93,6 +149,26 @@ struct rive_rs_factory
     std::atomic_uint32_t refs;
     rive::Factory* factory;
     bool owns_factory = true;
+    // False for the no-op factory, whose render objects and images hold
+    // nothing to draw.
+    bool draws = true;
+    // Wraps unpremultiplied RGBA8 pixels; set for factories that support it.
+    rive::rcp<rive::RenderImage> (*make_image)(rive::Factory* factory,
+                                               uint32_t width,
+                                               uint32_t height,
+                                               const uint8_t* rgba) = nullptr;
+};
+
+struct rive_rs_render_target
+{
+    std::atomic_uint32_t refs;
+    int32_t width = 0;
+    int32_t height = 0;
+    // Unpremultiplied RGBA8 written back by CPU renderers.
+    std::vector<uint8_t> pixels;
+#ifdef __EMSCRIPTEN__
+    webgl2_provider::OffscreenTarget* offscreen = nullptr;
+#endif
 };
 
 struct rive_rs_artboard
@@ -101,6 +177,28 @@ struct rive_rs_artboard
     std::unique_ptr<rive::ArtboardInstance> artboard;
 };
 
+// What the runtime's private layer instance holds for one layer, rebuilt from
+// the public changed-state list after every advance.
+struct TrackedLayer
+{
+    // Null until the layer first changes state, i.e. still in its entry state.
+    const rive::LayerState* state = nullptr;
+    const rive::LayerState* state_from = nullptr;
+    const rive::StateTransition* transition = nullptr;
+    float elapsed_seconds = 0.0f;
+};
+
+struct rive_rs_state_machine_instance
+{
+    rive_rs_state_machine_instance(const rive::StateMachine* machine,
+                                   rive::ArtboardInstance* artboard) :
+        instance(machine, artboard), layers(machine->layerCount())
+    {}
+
+    rive::StateMachineInstance instance;
+    std::vector<TrackedLayer> layers;
+};
+
 struct rive_rs_webgl2_renderer
 {
     int32_t width = 0;
@@ -109,6 +207,7 @@ struct rive_rs_webgl2_renderer
     uint32_t save_depth = 0;
     uint32_t clip_depth = 0;
     float opacity = 1.0f;
+    rive_rs_render_target* target = nullptr;
 #ifdef __EMSCRIPTEN__
     webgl2_provider::Renderer* renderer = nullptr;
 #else
@@ -124,6 +223,7 @@ struct rive_rs_webgpu_renderer
     uint32_t save_depth = 0;
     uint32_t clip_depth = 0;
     float opacity = 1.0f;
+    rive_rs_render_target* target = nullptr;
     rive::NoOpRenderer renderer;
 };
 
@@ -136,6 +236,7 @@ using namespace rive::gpu;
 class Renderer;
 class WebGl2RenderImage;
 class WebGl2RenderBuffer;
+class OffscreenTarget;
 
 using PLSResourceID = uint64_t;
 static std::atomic<PLSResourceID> s_next_webgl2_buffer_id;
@@ -253,6 +354,21 @@ public:
                              static_cast<int>(encoded_bytes.size()));
     }
 
+    // Wraps a texture that already lives in `context`, e.g. an offscreen
+    // target's.
+    WebGl2RenderImage(rive::rcp<rive::RiveRenderImage> render_image,
+                      EMSCRIPTEN_WEBGL_CONTEXT_HANDLE context,
+                      int width,
+                      int height) :
+        m_width(width),
+        m_height(height),
+        m_context_gl(context),
+        m_render_image(std::move(render_image))
+    {
+        m_Width = width;
+        m_Height = height;
+    }
+
     ~WebGl2RenderImage()
     {
         ScopedGLContextMakeCurrent make_current(m_context_gl);
@@ -358,6 +474,33 @@ private:
     PLSResourceID m_mutation_id = 0;
 };
 
+class OffscreenTarget
+{
+public:
+    OffscreenTarget(int32_t width, int32_t height) : m_width(width), m_height(height) {}
+
+    ~OffscreenTarget() { release(); }
+
+    // Allocates the backing texture in `renderer`'s context on first use.
+    rive::rcp<rive::gpu::RenderTargetGL> prep(Renderer* renderer);
+
+    WebGl2RenderImage* image() const { return m_image.get(); }
+
+private:
+    void release()
+    {
+        ScopedGLContextMakeCurrent make_current(m_context_gl);
+        m_render_target = nullptr;
+        m_image = nullptr;
+    }
+
+    int32_t m_width = 0;
+    int32_t m_height = 0;
+    EMSCRIPTEN_WEBGL_CONTEXT_HANDLE m_context_gl = 0;
+    rive::rcp<rive::gpu::TextureRenderTargetGL> m_render_target;
+    rive::rcp<WebGl2RenderImage> m_image;
+};
+
 class Renderer final : public rive::RiveRenderer
 {
 public:
@@ -374,6 +517,7 @@ public:
     {
         ScopedGLContextMakeCurrent make_current(m_context_gl);
         m_pls_synchronized_buffers.clear();
+        m_bound_target = nullptr;
         m_render_target = nullptr;
         m_render_context = nullptr;
     }
@@ -395,15 +539,22 @@ public:
             rive::make_rcp<rive::gpu::FramebufferRenderTargetGL>(width, height, 0, sample_count);
     }
 
+    // Draws into `target`, or the canvas for nullptr, from the next clear().
+    void bind_target(OffscreenTarget* target)
+    {
+        m_bound_target = target == nullptr ? nullptr : target->prep(this);
+    }
+
     void clear()
     {
+        rive::gpu::RenderTargetGL* target = current_target();
         rive::gpu::RenderContext::FrameDescriptor descriptor = {
-            .renderTargetWidth = m_render_target->width(),
-            .renderTargetHeight = m_render_target->height(),
+            .renderTargetWidth = target->width(),
+            .renderTargetHeight = target->height(),
             .loadAction = rive::gpu::LoadAction::clear,
             .clearColor = 0,
         };
-        if (m_render_target->sampleCount() > 1)
+        if (m_bound_target == nullptr && m_render_target->sampleCount() > 1)
         {
             descriptor.msaaSampleCount = m_render_target->sampleCount();
         }
@@ -476,7 +627,7 @@ public:
     void flush()
     {
         ScopedGLContextMakeCurrent make_current(m_context_gl);
-        m_render_context->flush({.renderTarget = m_render_target.get()});
+        m_render_context->flush({.renderTarget = current_target()});
     }
 
     void on_webgl2_buffer_deleted(PLSResourceID webgl_buffer_id)
@@ -485,6 +636,11 @@ public:
     }
 
 private:
+    rive::gpu::RenderTargetGL* current_target() const
+    {
+        return m_bound_target != nullptr ? m_bound_target.get() : m_render_target.get();
+    }
+
     rive::rcp<rive::RenderBuffer> ref_pls_buffer(WebGl2RenderBuffer* webgl_buffer)
     {
         PLSSynchronizedBuffer& synchronized_buffer =
@@ -497,6 +653,7 @@ private:
     EMSCRIPTEN_WEBGL_CONTEXT_HANDLE m_context_gl = emscripten_webgl_get_current_context();
     std::unique_ptr<rive::gpu::RenderContext> m_render_context;
     rive::rcp<rive::gpu::FramebufferRenderTargetGL> m_render_target;
+    rive::rcp<rive::gpu::RenderTargetGL> m_bound_target;
     std::map<PLSResourceID, PLSSynchronizedBuffer> m_pls_synchronized_buffers;
 };
 
@@ -575,6 +732,37 @@ rive::RenderImage* WebGl2RenderImage::prep(Renderer* renderer,
     return m_render_image.get();
 }
 
+rive::rcp<rive::gpu::RenderTargetGL> OffscreenTarget::prep(Renderer* renderer)
+{
+    if (m_render_target != nullptr && renderer->context_gl() == m_context_gl)
+    {
+        return m_render_target;
+    }
+    // Images handed out earlier keep their texture alive in the old context.
+    release();
+
+    ScopedGLContextMakeCurrent make_current(m_context_gl = renderer->context_gl());
+    GLuint texture_id = 0;
+    glGenTextures(1, &texture_id);
+    glActiveTexture(GL_TEXTURE0);
+    glBindTexture(GL_TEXTURE_2D, texture_id);
+    glTexStorage2D(GL_TEXTURE_2D, 1, GL_RGBA8, m_width, m_height);
+    glTexParameteri(GL_TEXTURE_2D, GL_TEXTURE_MIN_FILTER, GL_LINEAR);
+    glTexParameteri(GL_TEXTURE_2D, GL_TEXTURE_MAG_FILTER, GL_LINEAR);
+    glTexParameteri(GL_TEXTURE_2D, GL_TEXTURE_WRAP_S, GL_CLAMP_TO_EDGE);
+    glTexParameteri(GL_TEXTURE_2D, GL_TEXTURE_WRAP_T, GL_CLAMP_TO_EDGE);
+    m_render_target = rive::make_rcp<rive::gpu::TextureRenderTargetGL>(m_width, m_height);
+    m_render_target->setTargetTexture(texture_id);
+    // The image adopts the texture, so it outlives the target if still in use.
+    m_image = rive::make_rcp<WebGl2RenderImage>(
+        rive::make_rcp<rive::RiveRenderImage>(
+            renderer->render_context_gl()->adoptImageTexture(m_width, m_height, texture_id)),
+        m_context_gl,
+        m_width,
+        m_height);
+    return m_render_target;
+}
+
 PLSSynchronizedBuffer::PLSSynchronizedBuffer(Renderer* renderer,
                                              WebGl2RenderBuffer* webgl_buffer) :
     m_context_gl(renderer->context_gl()),
@@ -724,6 +912,29 @@ inline void factory_unref_internal(rive_rs_factory* factory)
     }
 }
 
+inline void render_target_ref_internal(rive_rs_render_target* target)
+{
+    if (target != nullptr)
+    {
+        (void)target->refs.fetch_add(1, std::memory_order_relaxed);
+    }
+}
+
+inline void render_target_unref_internal(rive_rs_render_target* target)
+{
+    if (target == nullptr)
+    {
+        return;
+    }
+    if (target->refs.fetch_sub(1, std::memory_order_acq_rel) == 1)
+    {
+#ifdef __EMSCRIPTEN__
+        delete target->offscreen;
+#endif
+        delete target;
+    }
+}
+
 inline rive::Factory* as_factory(rive_rs_factory* factory)
 {
     return factory == nullptr ? nullptr : factory->factory;
@@ -863,16 +1074,64 @@ inline const rive::LinearAnimation* as_linear_animation(
     return reinterpret_cast<const rive::LinearAnimation*>(animation);
 }
 
+// Linear animation instance that keeps the audio events its timeline fired
+// during the last advance. The runtime only hands keyed events to a
+// `KeyedCallbackReporter`, so the instance reports to itself.
+class AudioReportingAnimationInstance : public rive::LinearAnimationInstance,
+                                        public rive::KeyedCallbackReporter
+{
+public:
+    AudioReportingAnimationInstance(const rive::LinearAnimation* animation,
+                                    rive::ArtboardInstance* artboard) :
+        rive::LinearAnimationInstance(animation, artboard), m_artboard(artboard)
+    {}
+
+    void reportKeyedCallback(uint32_t objectId,
+                             uint32_t propertyKey,
+                             float elapsedSeconds) override
+    {
+        (void)propertyKey;
+        (void)elapsedSeconds;
+        auto* object = m_artboard->resolve(objectId);
+        if (object == nullptr || !object->is<rive::AudioEvent>())
+        {
+            return;
+        }
+        auto* asset = object->as<rive::AudioEvent>()->asset();
+        if (asset != nullptr)
+        {
+            reportedAudio.push_back(asset);
+        }
+    }
+
+    std::vector<rive::AudioAsset*> reportedAudio;
+
+private:
+    rive::ArtboardInstance* m_artboard;
+};
+
+inline AudioReportingAnimationInstance* as_reporting_animation_instance(
+    rive_rs_linear_animation_instance* instance)
+{
+    return reinterpret_cast<AudioReportingAnimationInstance*>(instance);
+}
+
+inline const AudioReportingAnimationInstance* as_reporting_animation_instance(
+    const rive_rs_linear_animation_instance* instance)
+{
+    return reinterpret_cast<const AudioReportingAnimationInstance*>(instance);
+}
+
 inline rive::LinearAnimationInstance* as_linear_animation_instance(
     rive_rs_linear_animation_instance* instance)
 {
-    return reinterpret_cast<rive::LinearAnimationInstance*>(instance);
+    return as_reporting_animation_instance(instance);
 }
 
 inline const rive::LinearAnimationInstance* as_linear_animation_instance(
     const rive_rs_linear_animation_instance* instance)
 {
-    return reinterpret_cast<const rive::LinearAnimationInstance*>(instance);
+    return as_reporting_animation_instance(instance);
 }
 
 inline rive::StateMachine* as_state_machine(rive_rs_state_machine* state_machine)
@@ -888,13 +1147,13 @@ inline const rive::StateMachine* as_state_machine(const rive_rs_state_machine* s
 inline rive::StateMachineInstance* as_state_machine_instance(
     rive_rs_state_machine_instance* instance)
 {
-    return reinterpret_cast<rive::StateMachineInstance*>(instance);
+    return &instance->instance;
 }
 
 inline const rive::StateMachineInstance* as_state_machine_instance(
     const rive_rs_state_machine_instance* instance)
 {
-    return reinterpret_cast<const rive::StateMachineInstance*>(instance);
+    return &instance->instance;
 }
 
 inline rive::SMIInput* as_smi_input(rive_rs_smi_input* input)
@@ -1223,6 +1482,14 @@ inline rive_rs_status renderer_clear_impl(RendererHandle* renderer)
     return RIVE_RS_STATUS_OK;
 }
 
+template <typename RendererHandle>
+inline void renderer_bind_target_impl(RendererHandle* renderer, rive_rs_render_target* target)
+{
+    render_target_ref_internal(target);
+    render_target_unref_internal(renderer->target);
+    renderer->target = target;
+}
+
 template <typename RendererHandle>
 inline rive_rs_status renderer_flush_impl(RendererHandle* renderer)
 {
@@ -1372,6 +1639,10 @@ inline rive_rs_status renderer_restore_clip_rect_impl(RendererHandle* renderer)
     return renderer_restore_impl(renderer);
 }
 
+// `inspect_header` in src/riv.rs checks headers against its own copy of the
+// supported major version.
+static_assert(rive::File::majorVersion == 7, "update RIV_MAJOR_VERSION in src/riv.rs");
+
 inline rive_rs_status import_result_to_status(rive::ImportResult result)
 {
     switch (result)
@@ -1545,155 +1816,1308 @@ inline rive_rs_status fill_event_property_info(const rive::CustomProperty* prope
     }
 }
 
-inline bool has_any_listener(rive::StateMachineInstance* instance)
+// Component types the provider knows by name and size. Objects of any other
+// type get an empty `type_name` and count as a plain component.
+#define RIVE_RS_COMPONENT_TYPES(X)                                             \
+    X(Artboard)                                                                \
+    X(Node)                                                                    \
+    X(Shape)                                                                   \
+    X(Ellipse)                                                                 \
+    X(Rectangle)                                                               \
+    X(Triangle)                                                                \
+    X(Polygon)                                                                 \
+    X(Star)                                                                    \
+    X(PointsPath)                                                              \
+    X(StraightVertex)                                                          \
+    X(CubicMirroredVertex)                                                     \
+    X(CubicAsymmetricVertex)                                                   \
+    X(CubicDetachedVertex)                                                     \
+    X(ClippingShape)                                                           \
+    X(Image)                                                                   \
+    X(Fill)                                                                    \
+    X(Stroke)                                                                  \
+    X(SolidColor)                                                              \
+    X(LinearGradient)                                                          \
+    X(RadialGradient)                                                          \
+    X(GradientStop)                                                            \
+    X(TrimPath)                                                                \
+    X(Bone)                                                                    \
+    X(RootBone)                                                                \
+    X(Skin)                                                                    \
+    X(Tendon)                                                                  \
+    X(Weight)                                                                  \
+    X(CubicWeight)                                                             \
+    X(IKConstraint)                                                            \
+    X(DistanceConstraint)                                                      \
+    X(TransformConstraint)                                                     \
+    X(TranslationConstraint)                                                   \
+    X(ScaleConstraint)                                                         \
+    X(RotationConstraint)                                                      \
+    X(FollowPathConstraint)                                                    \
+    X(DrawTarget)                                                              \
+    X(DrawRules)                                                               \
+    X(NestedArtboard)                                                          \
+    X(Text)                                                                    \
+    X(TextValueRun)                                                            \
+    X(Event)                                                                   \
+    X(OpenUrlEvent)                                                            \
+    X(AudioEvent)                                                              \
+    X(CustomPropertyBoolean)                                                   \
+    X(CustomPropertyNumber)                                                    \
+    X(CustomPropertyString)                                                    \
+    X(Joystick)                                                                \
+    X(LayoutComponent)                                                         \
+    X(Solo)
+
+inline const char* component_type_name(uint16_t type_key)
+{
+    switch (type_key)
+    {
+#define RIVE_RS_COMPONENT_TYPE_NAME(type)                                      \
+    case rive::type::typeKey:                                                  \
+        return #type;
+        RIVE_RS_COMPONENT_TYPES(RIVE_RS_COMPONENT_TYPE_NAME)
+#undef RIVE_RS_COMPONENT_TYPE_NAME
+        default:
+            return "";
+    }
+}
+
+// Estimated bytes an object occupies: its own size, not what it owns on the
+// heap.
+inline size_t core_object_size(const rive::Core* object)
 {
-    if (instance == nullptr)
+    switch (object->coreType())
     {
-        return false;
+#define RIVE_RS_COMPONENT_TYPE_SIZE(type)                                      \
+    case rive::type::typeKey:                                                  \
+        return sizeof(rive::type);
+        RIVE_RS_COMPONENT_TYPES(RIVE_RS_COMPONENT_TYPE_SIZE)
+#undef RIVE_RS_COMPONENT_TYPE_SIZE
+        default:
+            return sizeof(rive::Component);
     }
-    if (instance->hasListeners())
+}
+
+inline void fill_component_info(const rive::Core* object, rive_rs_component_info* out_component)
+{
+    out_component->name = kEmptyStrView;
+    out_component->type_name = kEmptyStrView;
+    out_component->type_key = 0;
+    out_component->has_parent = false;
+    out_component->parent_index = 0;
+    out_component->is_transform_component = false;
+    out_component->is_node = false;
+    out_component->is_bone = false;
+    out_component->is_root_bone = false;
+    if (object == nullptr)
     {
-        return true;
+        return;
     }
-    for (auto* nested_artboard : instance->artboard()->nestedArtboards())
+
+    out_component->type_key = object->coreType();
+    out_component->type_name = to_str_view(component_type_name(object->coreType()));
+    out_component->is_transform_component = object->is<rive::TransformComponent>();
+    out_component->is_node = object->is<rive::Node>();
+    out_component->is_bone = object->is<rive::Bone>();
+    out_component->is_root_bone = object->is<rive::RootBone>();
+    if (object->is<rive::Component>())
     {
-        for (auto* nested_animation : nested_artboard->nestedAnimations())
+        const auto* component = object->as<rive::Component>();
+        out_component->name = to_str_view(component->name());
+        if (component->parent() != nullptr)
         {
-            if (nested_animation->is<rive::NestedStateMachine>())
-            {
-                auto* nested_state_machine = nested_animation->as<rive::NestedStateMachine>();
-                if (has_any_listener(nested_state_machine->stateMachineInstance()))
-                {
-                    return true;
-                }
-            }
+            out_component->has_parent = true;
+            out_component->parent_index = component->parentId();
         }
     }
-    return false;
 }
 
-class AbiFileAssetLoader final : public rive::FileAssetLoader
+// Looks up the object at `index` as a `T`, reporting a type mismatch
+// separately from a missing index.
+template <typename T, typename Out>
+rive_rs_status typed_component_at(rive_rs_artboard* artboard, size_t index, Out** out)
 {
-public:
-    AbiFileAssetLoader(const rive_rs_file_asset_loader_callbacks& callbacks,
-                       rive_rs_factory* factory_handle) :
-        m_callbacks(callbacks), m_factory(factory_handle)
+    if (artboard == nullptr || out == nullptr)
     {
-        factory_ref_internal(m_factory);
+        return RIVE_RS_STATUS_NULL;
     }
 
-    ~AbiFileAssetLoader() override { factory_unref_internal(m_factory); }
-
-    bool loadContents(rive::FileAsset& asset,
-                      rive::Span<const uint8_t> in_band_bytes,
-                      rive::Factory*) override
+    *out = nullptr;
+    const auto& objects = as_artboard(artboard)->objects();
+    if (index >= objects.size())
     {
-        if (m_callbacks.load_contents == nullptr)
-        {
-            return false;
-        }
+        return RIVE_RS_STATUS_OUT_OF_RANGE;
+    }
 
-        rive_rs_bytes_view bytes{in_band_bytes.data(), in_band_bytes.size()};
-        return m_callbacks.load_contents(m_callbacks.user_data,
-                                         reinterpret_cast<rive_rs_file_asset*>(&asset),
-                                         bytes,
-                                         m_factory);
+    auto* object = objects[index];
+    if (object == nullptr || !object->is<T>())
+    {
+        return RIVE_RS_STATUS_INVALID_ARGUMENT;
     }
 
-private:
-    rive_rs_file_asset_loader_callbacks m_callbacks{};
-    rive_rs_factory* m_factory = nullptr;
-};
-} // namespace
+    *out = reinterpret_cast<Out*>(object->as<T>());
+    return RIVE_RS_STATUS_OK;
+}
 
-extern "C"
-{
-#ifdef __EMSCRIPTEN__
-EMSCRIPTEN_KEEPALIVE void set_webgl2_image_size(
-    webgl2_provider::WebGl2RenderImage* render_image,
-    int width,
-    int height)
+inline const rive::StateMachineLayer* state_machine_layer_at(
+    const rive_rs_state_machine* state_machine,
+    size_t layer_index)
 {
-    webgl2_provider::set_web_image_size(render_image, width, height);
+    const auto* machine = as_state_machine(state_machine);
+    return layer_index < machine->layerCount() ? machine->layer(layer_index) : nullptr;
 }
-#endif
-
-uint32_t rive_rs_abi_version(void) { return 1; }
 
-rive_rs_factory* rive_rs_factory_default(void)
+inline const rive::LayerState* layer_state_at(const rive::StateMachineLayer* layer,
+                                              size_t state_index)
 {
-    auto* handle = new (std::nothrow) rive_rs_factory();
-    if (handle == nullptr)
+    if (layer == nullptr || state_index >= layer->stateCount())
     {
         return nullptr;
     }
+    return layer->state(state_index);
+}
 
-    auto* factory = new (std::nothrow) NoOpFactory();
-    if (factory == nullptr)
+inline const rive::StateTransition* state_transition_at(const rive::LayerState* state,
+                                                        size_t transition_index)
+{
+    if (state == nullptr || transition_index >= state->transitionCount())
     {
-        delete handle;
         return nullptr;
     }
+    return state->transition(transition_index);
+}
 
-    handle->refs.store(1, std::memory_order_relaxed);
-    handle->factory = factory;
-    handle->owns_factory = true;
-    return handle;
+inline rive_rs_layer_state_type layer_state_type(const rive::LayerState* state)
+{
+    if (state->is<rive::AnimationState>())
+    {
+        return RIVE_RS_LAYER_STATE_ANIMATION;
+    }
+    if (state->is<rive::BlendState>())
+    {
+        return RIVE_RS_LAYER_STATE_BLEND;
+    }
+    switch (state->coreType())
+    {
+        case rive::EntryState::typeKey:
+            return RIVE_RS_LAYER_STATE_ENTRY;
+        case rive::ExitState::typeKey:
+            return RIVE_RS_LAYER_STATE_EXIT;
+        case rive::AnyState::typeKey:
+            return RIVE_RS_LAYER_STATE_ANY;
+        default:
+            return RIVE_RS_LAYER_STATE_OTHER;
+    }
 }
 
-rive_rs_factory* rive_rs_factory_webgl2(void)
+// Animation states are named after their animation; the other states only
+// have a type.
+inline rive_rs_str_view layer_state_name(const rive::LayerState* state)
 {
-#ifdef __EMSCRIPTEN__
-    auto* handle = new (std::nothrow) rive_rs_factory();
-    if (handle == nullptr)
+    if (!state->is<rive::AnimationState>())
     {
-        return nullptr;
+        return kEmptyStrView;
     }
-    handle->refs.store(1, std::memory_order_relaxed);
-    handle->factory = webgl2_provider::factory_instance();
-    handle->owns_factory = false;
-    return handle;
-#else
-    return rive_rs_factory_default();
-#endif
+    const auto* animation = state->as<rive::AnimationState>()->animation();
+    return animation == nullptr ? kEmptyStrView : to_str_view(animation->name());
 }
 
-rive_rs_factory* rive_rs_factory_webgpu(void)
+// Position of `state` in `layer`, or false when it belongs elsewhere.
+inline bool layer_state_index(const rive::StateMachineLayer* layer,
+                              const rive::LayerState* state,
+                              size_t* out_index)
 {
-    return rive_rs_factory_default();
+    for (size_t index = 0; index < layer->stateCount(); ++index)
+    {
+        if (layer->state(index) == state)
+        {
+            *out_index = index;
+            return true;
+        }
+    }
+    return false;
 }
 
-void rive_rs_factory_ref(rive_rs_factory* factory) { factory_ref_internal(factory); }
+// Index path of `transition` as (owning state, transition) in `layer`.
+inline bool layer_transition_index(const rive::StateMachineLayer* layer,
+                                   const rive::StateTransition* transition,
+                                   size_t* out_state_index,
+                                   size_t* out_transition_index)
+{
+    for (size_t state_index = 0; state_index < layer->stateCount(); ++state_index)
+    {
+        const auto* state = layer->state(state_index);
+        for (size_t index = 0; index < state->transitionCount(); ++index)
+        {
+            if (state->transition(index) == transition)
+            {
+                *out_state_index = state_index;
+                *out_transition_index = index;
+                return true;
+            }
+        }
+    }
+    return false;
+}
 
-void rive_rs_factory_unref(rive_rs_factory* factory)
+inline bool has_transition_flag(const rive::StateTransition* transition,
+                                rive::StateTransitionFlags flag)
 {
-    factory_unref_internal(factory);
+    return (transition->flags() & static_cast<uint32_t>(flag)) != 0;
 }
 
-rive_rs_status rive_rs_load_file(rive_rs_factory* factory,
-                                 rive_rs_bytes_view bytes,
-                                 rive_rs_file** out_file)
+// The enabled transition from `from`, or else from the layer's any state,
+// that targets `to`. The runtime does not say which transition it took, so
+// this is the first candidate whose conditions may have passed.
+inline const rive::StateTransition* taken_transition(const rive::StateMachineLayer* layer,
+                                                     const rive::LayerState* from,
+                                                     const rive::LayerState* to)
 {
-    if (out_file == nullptr)
+    const rive::LayerState* sources[] = {from, nullptr};
+    for (size_t index = 0; index < layer->stateCount(); ++index)
     {
-        return RIVE_RS_STATUS_NULL;
+        if (layer_state_type(layer->state(index)) == RIVE_RS_LAYER_STATE_ANY)
+        {
+            sources[1] = layer->state(index);
+        }
     }
-    *out_file = nullptr;
-
-    if (factory == nullptr || as_factory(factory) == nullptr)
+    for (const auto* source : sources)
     {
-        return RIVE_RS_STATUS_NULL;
+        if (source == nullptr)
+        {
+            continue;
+        }
+        for (size_t index = 0; index < source->transitionCount(); ++index)
+        {
+            const auto* transition = source->transition(index);
+            if (!transition->isDisabled() && transition->stateTo() == to)
+            {
+                return transition;
+            }
+        }
     }
-    if (invalid_bytes(bytes))
+    return nullptr;
+}
+
+// Counts `seconds` into every layer, then moves layers whose state changed
+// during the advance into the new state with a fresh clock.
+void track_state_changes(rive_rs_state_machine_instance* tracked, float seconds)
+{
+    for (auto& layer : tracked->layers)
     {
-        return RIVE_RS_STATUS_INVALID_ARGUMENT;
+        layer.elapsed_seconds += seconds;
     }
 
-    rive::ImportResult import_result = rive::ImportResult::malformed;
-    auto file = rive::File::import(to_span(bytes), as_factory(factory), &import_result, nullptr);
-    if (file == nullptr)
+    const auto& instance = tracked->instance;
+    const auto* machine = instance.stateMachine();
+    for (size_t change = 0; change < instance.stateChangedCount(); ++change)
     {
-        return import_result_to_status(import_result);
+        const auto* state = instance.stateChangedByIndex(change);
+        for (size_t layer_index = 0; layer_index < machine->layerCount(); ++layer_index)
+        {
+            const auto* layer = machine->layer(layer_index);
+            size_t state_index = 0;
+            if (!layer_state_index(layer, state, &state_index))
+            {
+                continue;
+            }
+            auto& tracked_layer = tracked->layers[layer_index];
+            const auto* from =
+                tracked_layer.state == nullptr ? layer->entryState() : tracked_layer.state;
+            tracked_layer.state_from = from;
+            tracked_layer.transition = taken_transition(layer, from, state);
+            tracked_layer.state = state;
+            tracked_layer.elapsed_seconds = 0.0f;
+            break;
+        }
+    }
+}
+
+inline rive_rs_condition_op condition_op(rive::TransitionConditionOp op)
+{
+    switch (op)
+    {
+        case rive::TransitionConditionOp::notEqual:
+            return RIVE_RS_CONDITION_OP_NOT_EQUAL;
+        case rive::TransitionConditionOp::lessThanOrEqual:
+            return RIVE_RS_CONDITION_OP_LESS_THAN_OR_EQUAL;
+        case rive::TransitionConditionOp::greaterThanOrEqual:
+            return RIVE_RS_CONDITION_OP_GREATER_THAN_OR_EQUAL;
+        case rive::TransitionConditionOp::lessThan:
+            return RIVE_RS_CONDITION_OP_LESS_THAN;
+        case rive::TransitionConditionOp::greaterThan:
+            return RIVE_RS_CONDITION_OP_GREATER_THAN;
+        case rive::TransitionConditionOp::equal:
+        default:
+            return RIVE_RS_CONDITION_OP_EQUAL;
+    }
+}
+
+inline rive_rs_listener_type listener_type(rive::ListenerType type)
+{
+    switch (type)
+    {
+        case rive::ListenerType::enter:
+            return RIVE_RS_LISTENER_ENTER;
+        case rive::ListenerType::exit:
+            return RIVE_RS_LISTENER_EXIT;
+        case rive::ListenerType::down:
+            return RIVE_RS_LISTENER_DOWN;
+        case rive::ListenerType::up:
+            return RIVE_RS_LISTENER_UP;
+        case rive::ListenerType::move:
+            return RIVE_RS_LISTENER_MOVE;
+        case rive::ListenerType::event:
+            return RIVE_RS_LISTENER_EVENT;
+        default:
+            return RIVE_RS_LISTENER_OTHER;
+    }
+}
+
+inline bool has_any_listener(rive::StateMachineInstance* instance)
+{
+    if (instance == nullptr)
+    {
+        return false;
+    }
+    if (instance->hasListeners())
+    {
+        return true;
+    }
+    for (auto* nested_artboard : instance->artboard()->nestedArtboards())
+    {
+        for (auto* nested_animation : nested_artboard->nestedAnimations())
+        {
+            if (nested_animation->is<rive::NestedStateMachine>())
+            {
+                auto* nested_state_machine = nested_animation->as<rive::NestedStateMachine>();
+                if (has_any_listener(nested_state_machine->stateMachineInstance()))
+                {
+                    return true;
+                }
+            }
+        }
+    }
+    return false;
+}
+
+inline rive_rs_fill_rule to_abi_fill_rule(rive::FillRule fill_rule)
+{
+    switch (fill_rule)
+    {
+        case rive::FillRule::evenOdd:
+            return RIVE_RS_FILL_RULE_EVEN_ODD;
+        case rive::FillRule::clockwise:
+            return RIVE_RS_FILL_RULE_CLOCKWISE;
+        case rive::FillRule::nonZero:
+        default:
+            return RIVE_RS_FILL_RULE_NON_ZERO;
+    }
+}
+
+inline rive_rs_paint_style to_abi_paint_style(rive::RenderPaintStyle style)
+{
+    return style == rive::RenderPaintStyle::stroke ? RIVE_RS_PAINT_STYLE_STROKE
+                                                   : RIVE_RS_PAINT_STYLE_FILL;
+}
+
+inline rive_rs_stroke_join to_abi_stroke_join(rive::StrokeJoin join)
+{
+    switch (join)
+    {
+        case rive::StrokeJoin::round:
+            return RIVE_RS_STROKE_JOIN_ROUND;
+        case rive::StrokeJoin::bevel:
+            return RIVE_RS_STROKE_JOIN_BEVEL;
+        case rive::StrokeJoin::miter:
+        default:
+            return RIVE_RS_STROKE_JOIN_MITER;
+    }
+}
+
+inline rive_rs_stroke_cap to_abi_stroke_cap(rive::StrokeCap cap)
+{
+    switch (cap)
+    {
+        case rive::StrokeCap::round:
+            return RIVE_RS_STROKE_CAP_ROUND;
+        case rive::StrokeCap::square:
+            return RIVE_RS_STROKE_CAP_SQUARE;
+        case rive::StrokeCap::butt:
+        default:
+            return RIVE_RS_STROKE_CAP_BUTT;
+    }
+}
+
+inline rive_rs_blend_mode to_abi_blend_mode(rive::BlendMode blend_mode)
+{
+    switch (blend_mode)
+    {
+        case rive::BlendMode::screen:
+            return RIVE_RS_BLEND_MODE_SCREEN;
+        case rive::BlendMode::overlay:
+            return RIVE_RS_BLEND_MODE_OVERLAY;
+        case rive::BlendMode::darken:
+            return RIVE_RS_BLEND_MODE_DARKEN;
+        case rive::BlendMode::lighten:
+            return RIVE_RS_BLEND_MODE_LIGHTEN;
+        case rive::BlendMode::colorDodge:
+            return RIVE_RS_BLEND_MODE_COLOR_DODGE;
+        case rive::BlendMode::colorBurn:
+            return RIVE_RS_BLEND_MODE_COLOR_BURN;
+        case rive::BlendMode::hardLight:
+            return RIVE_RS_BLEND_MODE_HARD_LIGHT;
+        case rive::BlendMode::softLight:
+            return RIVE_RS_BLEND_MODE_SOFT_LIGHT;
+        case rive::BlendMode::difference:
+            return RIVE_RS_BLEND_MODE_DIFFERENCE;
+        case rive::BlendMode::exclusion:
+            return RIVE_RS_BLEND_MODE_EXCLUSION;
+        case rive::BlendMode::multiply:
+            return RIVE_RS_BLEND_MODE_MULTIPLY;
+        case rive::BlendMode::hue:
+            return RIVE_RS_BLEND_MODE_HUE;
+        case rive::BlendMode::saturation:
+            return RIVE_RS_BLEND_MODE_SATURATION;
+        case rive::BlendMode::color:
+            return RIVE_RS_BLEND_MODE_COLOR;
+        case rive::BlendMode::luminosity:
+            return RIVE_RS_BLEND_MODE_LUMINOSITY;
+        case rive::BlendMode::srcOver:
+        default:
+            return RIVE_RS_BLEND_MODE_SRC_OVER;
+    }
+}
+
+inline rive_rs_image_wrap to_abi_image_wrap(rive::ImageWrap wrap)
+{
+    switch (wrap)
+    {
+        case rive::ImageWrap::repeat:
+            return RIVE_RS_IMAGE_WRAP_REPEAT;
+        case rive::ImageWrap::mirror:
+            return RIVE_RS_IMAGE_WRAP_MIRROR;
+        case rive::ImageWrap::clamp:
+        default:
+            return RIVE_RS_IMAGE_WRAP_CLAMP;
+    }
+}
+
+inline rive_rs_image_sampler to_abi_image_sampler(const rive::ImageSampler& sampler)
+{
+    rive_rs_image_sampler out{};
+    out.wrap_x = to_abi_image_wrap(sampler.wrapX);
+    out.wrap_y = to_abi_image_wrap(sampler.wrapY);
+    out.filter = sampler.filter == rive::ImageFilter::nearest ? RIVE_RS_IMAGE_FILTER_NEAREST
+                                                              : RIVE_RS_IMAGE_FILTER_BILINEAR;
+    return out;
+}
+
+inline bool backend_vtable_complete(const rive_rs_render_backend& backend)
+{
+    return backend.release != nullptr && backend.make_path != nullptr &&
+           backend.make_paint != nullptr && backend.make_linear_gradient != nullptr &&
+           backend.make_radial_gradient != nullptr && backend.decode_image != nullptr &&
+           backend.make_buffer != nullptr && backend.release_path != nullptr &&
+           backend.release_paint != nullptr && backend.release_shader != nullptr &&
+           backend.release_image != nullptr && backend.release_buffer != nullptr &&
+           backend.path_rewind != nullptr && backend.path_fill_rule != nullptr &&
+           backend.path_move_to != nullptr && backend.path_line_to != nullptr &&
+           backend.path_cubic_to != nullptr && backend.path_close != nullptr &&
+           backend.path_add_path != nullptr && backend.paint_color != nullptr &&
+           backend.paint_style != nullptr && backend.paint_thickness != nullptr &&
+           backend.paint_join != nullptr && backend.paint_cap != nullptr &&
+           backend.paint_blend_mode != nullptr && backend.paint_shader != nullptr &&
+           backend.paint_feather != nullptr && backend.image_size != nullptr &&
+           backend.image_pixels != nullptr && backend.buffer_map != nullptr && backend.buffer_unmap != nullptr;
+}
+
+inline bool renderer_callbacks_complete(const rive_rs_renderer_callbacks& callbacks)
+{
+    return callbacks.save != nullptr && callbacks.restore != nullptr &&
+           callbacks.transform != nullptr && callbacks.modulate_opacity != nullptr &&
+           callbacks.draw_path != nullptr && callbacks.clip_path != nullptr &&
+           callbacks.draw_image != nullptr && callbacks.draw_image_mesh != nullptr;
+}
+
+class BackendState final : public rive::RefCnt<BackendState>
+{
+public:
+    explicit BackendState(const rive_rs_render_backend& vtable) : m_vtable(vtable) {}
+
+    ~BackendState()
+    {
+        if (m_vtable.release != nullptr)
+        {
+            m_vtable.release(m_vtable.user_data);
+        }
+    }
+
+    // Drops ownership of the user data without calling `release`, used when
+    // factory construction fails and the caller keeps ownership.
+    void detach() { m_vtable.release = nullptr; }
+
+    const rive_rs_render_backend& vtable() const { return m_vtable; }
+    void* user_data() const { return m_vtable.user_data; }
+    uint64_t key() const { return m_vtable.backend_key; }
+
+private:
+    rive_rs_render_backend m_vtable;
+};
+
+class BackendRenderShader final
+    : public LITE_RTTI_OVERRIDE(rive::RenderShader, BackendRenderShader)
+{
+public:
+    BackendRenderShader(rive::rcp<BackendState> backend, void* shader) :
+        m_backend(std::move(backend)), m_shader(shader)
+    {}
+
+    ~BackendRenderShader() override
+    {
+        m_backend->vtable().release_shader(m_backend->user_data(), m_shader);
+    }
+
+    const void* handle() const { return m_shader; }
+    uint64_t backend_key() const { return m_backend->key(); }
+
+private:
+    rive::rcp<BackendState> m_backend;
+    void* m_shader = nullptr;
+};
+
+class BackendRenderPaint final
+    : public LITE_RTTI_OVERRIDE(rive::RenderPaint, BackendRenderPaint)
+{
+public:
+    BackendRenderPaint(rive::rcp<BackendState> backend, void* paint) :
+        m_backend(std::move(backend)), m_paint(paint)
+    {}
+
+    ~BackendRenderPaint() override
+    {
+        // Release the shader before the paint so backends never observe a
+        // paint referencing a released shader.
+        m_shader.reset();
+        m_backend->vtable().release_paint(m_backend->user_data(), m_paint);
+    }
+
+    const void* handle() const { return m_paint; }
+    uint64_t backend_key() const { return m_backend->key(); }
+
+    void color(unsigned int value) override
+    {
+        m_backend->vtable().paint_color(m_backend->user_data(), m_paint, value);
+    }
+
+    void style(rive::RenderPaintStyle value) override
+    {
+        m_backend->vtable().paint_style(m_backend->user_data(),
+                                        m_paint,
+                                        to_abi_paint_style(value));
+    }
+
+    void thickness(float value) override
+    {
+        m_backend->vtable().paint_thickness(m_backend->user_data(), m_paint, value);
+    }
+
+    void join(rive::StrokeJoin value) override
+    {
+        m_backend->vtable().paint_join(m_backend->user_data(), m_paint, to_abi_stroke_join(value));
+    }
+
+    void cap(rive::StrokeCap value) override
+    {
+        m_backend->vtable().paint_cap(m_backend->user_data(), m_paint, to_abi_stroke_cap(value));
+    }
+
+    void blendMode(rive::BlendMode value) override
+    {
+        m_backend->vtable().paint_blend_mode(m_backend->user_data(),
+                                             m_paint,
+                                             to_abi_blend_mode(value));
+    }
+
+    void shader(rive::rcp<rive::RenderShader> value) override
+    {
+        const void* shader_handle = nullptr;
+        auto* backend_shader = value == nullptr
+                                   ? nullptr
+                                   : rive::lite_rtti_cast<BackendRenderShader*>(value.get());
+        if (backend_shader != nullptr && backend_shader->backend_key() == backend_key())
+        {
+            shader_handle = backend_shader->handle();
+        }
+        m_backend->vtable().paint_shader(m_backend->user_data(), m_paint, shader_handle);
+        if (shader_handle == nullptr)
+        {
+            m_shader = nullptr;
+        }
+        else
+        {
+            m_shader = std::move(value);
+        }
+    }
+
+    void invalidateStroke() override {}
+
+    void feather(float value) override
+    {
+        m_backend->vtable().paint_feather(m_backend->user_data(), m_paint, value);
+    }
+
+private:
+    rive::rcp<BackendState> m_backend;
+    void* m_paint = nullptr;
+    rive::rcp<rive::RenderShader> m_shader;
+};
+
+class BackendRenderPath final : public LITE_RTTI_OVERRIDE(rive::RenderPath, BackendRenderPath)
+{
+public:
+    BackendRenderPath(rive::rcp<BackendState> backend, void* path) :
+        m_backend(std::move(backend)), m_path(path)
+    {}
+
+    ~BackendRenderPath() override
+    {
+        m_backend->vtable().release_path(m_backend->user_data(), m_path);
+    }
+
+    const void* handle() const { return m_path; }
+    uint64_t backend_key() const { return m_backend->key(); }
+
+    void rewind() override { m_backend->vtable().path_rewind(m_backend->user_data(), m_path); }
+
+    void fillRule(rive::FillRule value) override
+    {
+        m_backend->vtable().path_fill_rule(m_backend->user_data(),
+                                           m_path,
+                                           to_abi_fill_rule(value));
+    }
+
+    void addPath(rive::CommandPath* path, const rive::Mat2D& transform) override
+    {
+        addRenderPath(static_cast<rive::RenderPath*>(path), transform);
+    }
+
+    void addRenderPath(rive::RenderPath* path, const rive::Mat2D& transform) override
+    {
+        LITE_RTTI_CAST_OR_RETURN(other, BackendRenderPath*, path);
+        if (other->backend_key() != backend_key())
+        {
+            return;
+        }
+        rive_rs_mat2d matrix{};
+        copy_mat2d(transform, &matrix);
+        m_backend->vtable().path_add_path(m_backend->user_data(),
+                                          m_path,
+                                          other->handle(),
+                                          &matrix);
+    }
+
+    void moveTo(float x, float y) override
+    {
+        m_backend->vtable().path_move_to(m_backend->user_data(), m_path, x, y);
+    }
+
+    void lineTo(float x, float y) override
+    {
+        m_backend->vtable().path_line_to(m_backend->user_data(), m_path, x, y);
+    }
+
+    void cubicTo(float ox, float oy, float ix, float iy, float x, float y) override
+    {
+        m_backend->vtable().path_cubic_to(m_backend->user_data(), m_path, ox, oy, ix, iy, x, y);
+    }
+
+    void close() override { m_backend->vtable().path_close(m_backend->user_data(), m_path); }
+
+    void addRawPath(const rive::RawPath& path) override
+    {
+        for (auto [verb, pts] : path)
+        {
+            switch (verb)
+            {
+                case rive::PathVerb::move:
+                    moveTo(pts[0].x, pts[0].y);
+                    break;
+                case rive::PathVerb::line:
+                    lineTo(pts[1].x, pts[1].y);
+                    break;
+                case rive::PathVerb::quad:
+                {
+                    // Elevate to a cubic; backends only see line and cubic
+                    // segments.
+                    const rive::Vec2D c1 = pts[0] + (pts[1] - pts[0]) * (2.0f / 3.0f);
+                    const rive::Vec2D c2 = pts[2] + (pts[1] - pts[2]) * (2.0f / 3.0f);
+                    cubicTo(c1.x, c1.y, c2.x, c2.y, pts[2].x, pts[2].y);
+                    break;
+                }
+                case rive::PathVerb::cubic:
+                    cubicTo(pts[1].x, pts[1].y, pts[2].x, pts[2].y, pts[3].x, pts[3].y);
+                    break;
+                case rive::PathVerb::close:
+                    close();
+                    break;
+                default:
+                    break;
+            }
+        }
+    }
+
+private:
+    rive::rcp<BackendState> m_backend;
+    void* m_path = nullptr;
+};
+
+class BackendRenderImage final
+    : public LITE_RTTI_OVERRIDE(rive::RenderImage, BackendRenderImage)
+{
+public:
+    BackendRenderImage(rive::rcp<BackendState> backend, void* image) :
+        m_backend(std::move(backend)), m_image(image)
+    {
+        uint32_t width = 0;
+        uint32_t height = 0;
+        m_backend->vtable().image_size(m_backend->user_data(), m_image, &width, &height);
+        m_Width = static_cast<int>(width);
+        m_Height = static_cast<int>(height);
+    }
+
+    ~BackendRenderImage() override
+    {
+        m_backend->vtable().release_image(m_backend->user_data(), m_image);
+    }
+
+    const void* handle() const { return m_image; }
+    uint64_t backend_key() const { return m_backend->key(); }
+
+    const uint8_t* pixels() const
+    {
+        return m_backend->vtable().image_pixels(m_backend->user_data(), m_image);
+    }
+
+private:
+    rive::rcp<BackendState> m_backend;
+    void* m_image = nullptr;
+};
+
+class BackendRenderBuffer final
+    : public LITE_RTTI_OVERRIDE(rive::RenderBuffer, BackendRenderBuffer)
+{
+public:
+    BackendRenderBuffer(rive::rcp<BackendState> backend,
+                        void* buffer,
+                        rive::RenderBufferType type,
+                        rive::RenderBufferFlags flags,
+                        size_t size_in_bytes) :
+        lite_rtti_override(type, flags, size_in_bytes),
+        m_backend(std::move(backend)),
+        m_buffer(buffer)
+    {}
+
+    ~BackendRenderBuffer() override
+    {
+        m_backend->vtable().release_buffer(m_backend->user_data(), m_buffer);
+    }
+
+    const void* handle() const { return m_buffer; }
+    uint64_t backend_key() const { return m_backend->key(); }
+
+    void* onMap() override
+    {
+        void* mapped = m_backend->vtable().buffer_map(m_backend->user_data(), m_buffer);
+        if (mapped != nullptr)
+        {
+            return mapped;
+        }
+        // The runtime writes through the mapping unchecked.
+        if (m_scratch == nullptr)
+        {
+            m_scratch.reset(new uint8_t[sizeInBytes()]);
+        }
+        return m_scratch.get();
+    }
+
+    void onUnmap() override { m_backend->vtable().buffer_unmap(m_backend->user_data(), m_buffer); }
+
+private:
+    rive::rcp<BackendState> m_backend;
+    void* m_buffer = nullptr;
+    std::unique_ptr<uint8_t[]> m_scratch;
+};
+
+class BackendFactory final : public rive::Factory
+{
+public:
+    explicit BackendFactory(rive::rcp<BackendState> backend) : m_backend(std::move(backend)) {}
+
+    rive::rcp<rive::RenderBuffer> makeRenderBuffer(rive::RenderBufferType type,
+                                                    rive::RenderBufferFlags flags,
+                                                    size_t size_in_bytes) override
+    {
+        void* buffer = m_backend->vtable().make_buffer(
+            m_backend->user_data(),
+            type == rive::RenderBufferType::index ? RIVE_RS_RENDER_BUFFER_TYPE_INDEX
+                                                  : RIVE_RS_RENDER_BUFFER_TYPE_VERTEX,
+            static_cast<uint32_t>(flags),
+            size_in_bytes);
+        if (buffer == nullptr)
+        {
+            return nullptr;
+        }
+        return rive::make_rcp<BackendRenderBuffer>(m_backend, buffer, type, flags, size_in_bytes);
+    }
+
+    rive::rcp<rive::RenderShader> makeLinearGradient(float sx,
+                                                     float sy,
+                                                     float ex,
+                                                     float ey,
+                                                     const rive::ColorInt colors[],
+                                                     const float stops[],
+                                                     size_t count) override
+    {
+        void* shader = m_backend->vtable().make_linear_gradient(m_backend->user_data(),
+                                                                sx,
+                                                                sy,
+                                                                ex,
+                                                                ey,
+                                                                colors,
+                                                                stops,
+                                                                count);
+        if (shader == nullptr)
+        {
+            return nullptr;
+        }
+        return rive::make_rcp<BackendRenderShader>(m_backend, shader);
+    }
+
+    rive::rcp<rive::RenderShader> makeRadialGradient(float cx,
+                                                     float cy,
+                                                     float radius,
+                                                     const rive::ColorInt colors[],
+                                                     const float stops[],
+                                                     size_t count) override
+    {
+        void* shader = m_backend->vtable().make_radial_gradient(m_backend->user_data(),
+                                                                cx,
+                                                                cy,
+                                                                radius,
+                                                                colors,
+                                                                stops,
+                                                                count);
+        if (shader == nullptr)
+        {
+            return nullptr;
+        }
+        return rive::make_rcp<BackendRenderShader>(m_backend, shader);
+    }
+
+    rive::rcp<rive::RenderPath> makeRenderPath(rive::RawPath& raw_path,
+                                               rive::FillRule fill_rule) override
+    {
+        auto path = make_path(fill_rule);
+        if (path != nullptr)
+        {
+            path->addRawPath(raw_path);
+        }
+        return path;
+    }
+
+    rive::rcp<rive::RenderPath> makeEmptyRenderPath() override
+    {
+        return make_path(rive::FillRule::nonZero);
+    }
+
+    rive::rcp<rive::RenderPaint> makeRenderPaint() override
+    {
+        void* paint = m_backend->vtable().make_paint(m_backend->user_data());
+        if (paint == nullptr)
+        {
+            return nullptr;
+        }
+        return rive::make_rcp<BackendRenderPaint>(m_backend, paint);
+    }
+
+    rive::rcp<rive::RenderImage> decodeImage(rive::Span<const uint8_t> bytes) override
+    {
+        void* image = m_backend->vtable().decode_image(m_backend->user_data(),
+                                                       rive_rs_bytes_view{bytes.data(),
+                                                                          bytes.size()});
+        if (image == nullptr)
+        {
+            return nullptr;
+        }
+        return rive::make_rcp<BackendRenderImage>(m_backend, image);
+    }
+
+    rive::rcp<rive::RenderImage> make_image(uint32_t width, uint32_t height, const uint8_t* rgba)
+    {
+        void* image = m_backend->vtable().make_image(m_backend->user_data(), width, height, rgba);
+        if (image == nullptr)
+        {
+            return nullptr;
+        }
+        return rive::make_rcp<BackendRenderImage>(m_backend, image);
+    }
+
+private:
+    rive::rcp<rive::RenderPath> make_path(rive::FillRule fill_rule)
+    {
+        void* path = m_backend->vtable().make_path(m_backend->user_data(),
+                                                   to_abi_fill_rule(fill_rule));
+        if (path == nullptr)
+        {
+            return nullptr;
+        }
+        return rive::make_rcp<BackendRenderPath>(m_backend, path);
+    }
+
+    rive::rcp<BackendState> m_backend;
+};
+
+rive::rcp<rive::RenderImage> backend_make_image(rive::Factory* factory,
+                                                uint32_t width,
+                                                uint32_t height,
+                                                const uint8_t* rgba)
+{
+    return static_cast<BackendFactory*>(factory)->make_image(width, height, rgba);
+}
+
+class BackendRenderer final : public rive::Renderer
+{
+public:
+    explicit BackendRenderer(const rive_rs_renderer_callbacks& callbacks) :
+        m_callbacks(callbacks)
+    {}
+
+    void save() override { m_callbacks.save(m_callbacks.user_data); }
+
+    void restore() override { m_callbacks.restore(m_callbacks.user_data); }
+
+    void transform(const rive::Mat2D& transform) override
+    {
+        rive_rs_mat2d matrix{};
+        copy_mat2d(transform, &matrix);
+        m_callbacks.transform(m_callbacks.user_data, &matrix);
+    }
+
+    void modulateOpacity(float opacity) override
+    {
+        m_callbacks.modulate_opacity(m_callbacks.user_data, opacity);
+    }
+
+    void drawPath(rive::RenderPath* path, rive::RenderPaint* paint) override
+    {
+        LITE_RTTI_CAST_OR_RETURN(backend_path, BackendRenderPath*, path);
+        LITE_RTTI_CAST_OR_RETURN(backend_paint, BackendRenderPaint*, paint);
+        if (!accepts(backend_path->backend_key()) || !accepts(backend_paint->backend_key()))
+        {
+            return;
+        }
+        m_callbacks.draw_path(m_callbacks.user_data,
+                              backend_path->handle(),
+                              backend_paint->handle());
+    }
+
+    void clipPath(rive::RenderPath* path) override
+    {
+        LITE_RTTI_CAST_OR_RETURN(backend_path, BackendRenderPath*, path);
+        if (!accepts(backend_path->backend_key()))
+        {
+            return;
+        }
+        m_callbacks.clip_path(m_callbacks.user_data, backend_path->handle());
+    }
+
+    void drawImage(const rive::RenderImage* image,
+                   const rive::ImageSampler sampler,
+                   rive::BlendMode blend_mode,
+                   float opacity) override
+    {
+        LITE_RTTI_CAST_OR_RETURN(backend_image, const BackendRenderImage*, image);
+        if (!accepts(backend_image->backend_key()))
+        {
+            return;
+        }
+        m_callbacks.draw_image(m_callbacks.user_data,
+                               backend_image->handle(),
+                               to_abi_image_sampler(sampler),
+                               to_abi_blend_mode(blend_mode),
+                               opacity);
+    }
+
+    void drawImageMesh(const rive::RenderImage* image,
+                       const rive::ImageSampler sampler,
+                       rive::rcp<rive::RenderBuffer> vertices_f32,
+                       rive::rcp<rive::RenderBuffer> uv_coords_f32,
+                       rive::rcp<rive::RenderBuffer> indices_u16,
+                       uint32_t vertex_count,
+                       uint32_t index_count,
+                       rive::BlendMode blend_mode,
+                       float opacity) override
+    {
+        LITE_RTTI_CAST_OR_RETURN(backend_image, const BackendRenderImage*, image);
+        LITE_RTTI_CAST_OR_RETURN(vertices, BackendRenderBuffer*, vertices_f32.get());
+        LITE_RTTI_CAST_OR_RETURN(uv_coords, BackendRenderBuffer*, uv_coords_f32.get());
+        LITE_RTTI_CAST_OR_RETURN(indices, BackendRenderBuffer*, indices_u16.get());
+        if (!accepts(backend_image->backend_key()) || !accepts(vertices->backend_key()) ||
+            !accepts(uv_coords->backend_key()) || !accepts(indices->backend_key()))
+        {
+            return;
+        }
+        m_callbacks.draw_image_mesh(m_callbacks.user_data,
+                                    backend_image->handle(),
+                                    to_abi_image_sampler(sampler),
+                                    vertices->handle(),
+                                    uv_coords->handle(),
+                                    indices->handle(),
+                                    vertex_count,
+                                    index_count,
+                                    to_abi_blend_mode(blend_mode),
+                                    opacity);
+    }
+
+private:
+    bool accepts(uint64_t backend_key) const { return backend_key == m_callbacks.backend_key; }
+
+    rive_rs_renderer_callbacks m_callbacks{};
+};
+
+class AbiFileAssetLoader final : public rive::FileAssetLoader
+{
+public:
+    AbiFileAssetLoader(const rive_rs_file_asset_loader_callbacks& callbacks,
+                       rive_rs_factory* factory_handle) :
+        m_callbacks(callbacks), m_factory(factory_handle)
+    {
+        factory_ref_internal(m_factory);
+    }
+
+    ~AbiFileAssetLoader() override
+    {
+        if (m_callbacks.release != nullptr)
+        {
+            m_callbacks.release(m_callbacks.user_data);
+        }
+        factory_unref_internal(m_factory);
+    }
+
+    bool has_load_contents() const { return m_callbacks.load_contents != nullptr; }
+
+    bool loadContents(rive::FileAsset& asset,
+                      rive::Span<const uint8_t> in_band_bytes,
+                      rive::Factory*) override
+    {
+        if (m_callbacks.load_contents == nullptr)
+        {
+            return false;
+        }
+
+        rive_rs_bytes_view bytes{in_band_bytes.data(), in_band_bytes.size()};
+        return m_callbacks.load_contents(m_callbacks.user_data,
+                                         reinterpret_cast<rive_rs_file_asset*>(&asset),
+                                         bytes,
+                                         m_factory);
+    }
+
+private:
+    rive_rs_file_asset_loader_callbacks m_callbacks{};
+    rive_rs_factory* m_factory = nullptr;
+};
+
+// `File::assets()` holds raw pointers in older runtimes and `rcp`s in newer
+// ones.
+inline rive::FileAsset* file_asset_ptr(rive::FileAsset* asset) { return asset; }
+
+inline rive::FileAsset* file_asset_ptr(const rive::rcp<rive::FileAsset>& asset)
+{
+    return asset.get();
+}
+
+#ifdef WITH_RIVE_TEXT
+// `rive::Font::gFallbackProc` is global, so the chain is too: one per process,
+// shared by every factory.
+std::mutex& font_fallback_mutex()
+{
+    static std::mutex mutex;
+    return mutex;
+}
+
+std::vector<rive::rcp<rive::Font>>& font_fallback_chain()
+{
+    static std::vector<rive::rcp<rive::Font>> chain;
+    return chain;
+}
+
+// Returns the `fallback_index`-th font in the chain that covers `missing`, so
+// the shaper can keep asking until it runs out of candidates.
+rive::rcp<rive::Font> font_fallback(const rive::Unichar missing,
+                                    const uint32_t fallback_index,
+                                    const rive::Font*)
+{
+    std::lock_guard<std::mutex> lock(font_fallback_mutex());
+    uint32_t index = 0;
+    for (const auto& font : font_fallback_chain())
+    {
+        if (!font->hasGlyph(rive::Span<const rive::Unichar>(&missing, 1)))
+        {
+            continue;
+        }
+        if (index++ == fallback_index)
+        {
+            return font;
+        }
+    }
+    return nullptr;
+}
+#endif
+} // namespace
+
+extern "C"
+{
+#ifdef __EMSCRIPTEN__
+EMSCRIPTEN_KEEPALIVE void set_webgl2_image_size(
+    webgl2_provider::WebGl2RenderImage* render_image,
+    int width,
+    int height)
+{
+    webgl2_provider::set_web_image_size(render_image, width, height);
+}
+#endif
+
+uint32_t rive_rs_abi_version(void) { return 2; }
+
+rive_rs_factory* rive_rs_factory_default(void)
+{
+    auto* handle = new (std::nothrow) rive_rs_factory();
+    if (handle == nullptr)
+    {
+        return nullptr;
+    }
+
+    auto* factory = new (std::nothrow) NoOpFactory();
+    if (factory == nullptr)
+    {
+        delete handle;
+        return nullptr;
+    }
+
+    handle->refs.store(1, std::memory_order_relaxed);
+    handle->factory = factory;
+    handle->owns_factory = true;
+    handle->draws = false;
+    return handle;
+}
+
+rive_rs_factory* rive_rs_factory_webgl2(void)
+{
+#ifdef __EMSCRIPTEN__
+    auto* handle = new (std::nothrow) rive_rs_factory();
+    if (handle == nullptr)
+    {
+        return nullptr;
+    }
+    handle->refs.store(1, std::memory_order_relaxed);
+    handle->factory = webgl2_provider::factory_instance();
+    handle->owns_factory = false;
+    return handle;
+#else
+    return rive_rs_factory_default();
+#endif
+}
+
+rive_rs_factory* rive_rs_factory_webgpu(void)
+{
+    return rive_rs_factory_default();
+}
+
+rive_rs_status rive_rs_factory_from_backend(const rive_rs_render_backend* backend,
+                                           rive_rs_factory** out_factory)
+{
+    if (out_factory == nullptr)
+    {
+        return RIVE_RS_STATUS_NULL;
+    }
+    *out_factory = nullptr;
+    if (backend == nullptr)
+    {
+        return RIVE_RS_STATUS_NULL;
+    }
+    if (!backend_vtable_complete(*backend))
+    {
+        return RIVE_RS_STATUS_INVALID_ARGUMENT;
+    }
+
+    auto* handle = new (std::nothrow) rive_rs_factory();
+    if (handle == nullptr)
+    {
+        return RIVE_RS_STATUS_RUNTIME_ERROR;
+    }
+
+    auto* state = new (std::nothrow) BackendState(*backend);
+    if (state == nullptr)
+    {
+        delete handle;
+        return RIVE_RS_STATUS_RUNTIME_ERROR;
+    }
+    rive::rcp<BackendState> backend_state(state);
+
+    auto* factory = new (std::nothrow) BackendFactory(backend_state);
+    if (factory == nullptr)
+    {
+        backend_state->detach();
+        delete handle;
+        return RIVE_RS_STATUS_RUNTIME_ERROR;
+    }
+
+    handle->refs.store(1, std::memory_order_relaxed);
+    handle->factory = factory;
+    handle->owns_factory = true;
+    handle->make_image = backend->make_image != nullptr ? backend_make_image : nullptr;
+    *out_factory = handle;
+    return RIVE_RS_STATUS_OK;
+}
+
+void rive_rs_factory_ref(rive_rs_factory* factory) { factory_ref_internal(factory); }
+
+void rive_rs_factory_unref(rive_rs_factory* factory)
+{
+    factory_unref_internal(factory);
+}
+
+bool rive_rs_factory_draws(const rive_rs_factory* factory)
+{
+    return factory != nullptr && factory->draws;
+}
+
+rive_rs_status rive_rs_load_file(rive_rs_factory* factory,
+                                 rive_rs_bytes_view bytes,
+                                 rive_rs_file** out_file)
+{
+    if (out_file == nullptr)
+    {
+        return RIVE_RS_STATUS_NULL;
+    }
+    *out_file = nullptr;
+
+    if (factory == nullptr || as_factory(factory) == nullptr)
+    {
+        return RIVE_RS_STATUS_NULL;
+    }
+    if (invalid_bytes(bytes))
+    {
+        return RIVE_RS_STATUS_INVALID_ARGUMENT;
+    }
+
+    rive::ImportResult import_result = rive::ImportResult::malformed;
+    auto file = rive::File::import(to_span(bytes), as_factory(factory), &import_result, nullptr);
+    if (file == nullptr)
+    {
+        return import_result_to_status(import_result);
     }
 
     *out_file = reinterpret_cast<rive_rs_file*>(file.release());
@@ -1706,6 +3130,14 @@ rive_rs_status rive_rs_load_file_with_asset_loader(
     const rive_rs_file_asset_loader_callbacks* callbacks,
     rive_rs_file** out_file)
 {
+    // Owns the callbacks' user data from here on so `release` runs exactly
+    // once, whichever way this returns.
+    rive::rcp<AbiFileAssetLoader> loader;
+    if (callbacks != nullptr)
+    {
+        loader = rive::make_rcp<AbiFileAssetLoader>(*callbacks, factory);
+    }
+
     if (out_file == nullptr)
     {
         return RIVE_RS_STATUS_NULL;
@@ -1722,22 +3154,10 @@ rive_rs_status rive_rs_load_file_with_asset_loader(
     }
 
     rive::ImportResult import_result = rive::ImportResult::malformed;
-
-    if (callbacks == nullptr || callbacks->load_contents == nullptr)
-    {
-        auto file =
-            rive::File::import(to_span(bytes), as_factory(factory), &import_result, nullptr);
-        if (file == nullptr)
-        {
-            return import_result_to_status(import_result);
-        }
-        *out_file = reinterpret_cast<rive_rs_file*>(file.release());
-        return RIVE_RS_STATUS_OK;
-    }
-
-    auto loader = rive::make_rcp<AbiFileAssetLoader>(*callbacks, factory);
+    rive::FileAssetLoader* asset_loader =
+        loader != nullptr && loader->has_load_contents() ? loader.get() : nullptr;
     auto file =
-        rive::File::import(to_span(bytes), as_factory(factory), &import_result, loader.get());
+        rive::File::import(to_span(bytes), as_factory(factory), &import_result, asset_loader);
     if (file == nullptr)
     {
         return import_result_to_status(import_result);
@@ -2039,6 +3459,76 @@ bool rive_rs_file_has_audio(const rive_rs_file* file)
     return file == nullptr ? false : as_file(file)->hasAudio();
 }
 
+size_t rive_rs_file_asset_count(const rive_rs_file* file)
+{
+    return file == nullptr ? 0 : as_file(file)->assets().size();
+}
+
+rive_rs_status rive_rs_file_asset_at(const rive_rs_file* file,
+                                     size_t index,
+                                     rive_rs_file_asset** out_asset)
+{
+    if (file == nullptr || out_asset == nullptr)
+    {
+        return RIVE_RS_STATUS_NULL;
+    }
+
+    *out_asset = nullptr;
+    const auto& assets = as_file(file)->assets();
+    if (index >= assets.size())
+    {
+        return RIVE_RS_STATUS_OUT_OF_RANGE;
+    }
+
+    *out_asset = reinterpret_cast<rive_rs_file_asset*>(file_asset_ptr(assets[index]));
+    return RIVE_RS_STATUS_OK;
+}
+
+size_t rive_rs_file_object_count(const rive_rs_file* file)
+{
+    if (file == nullptr)
+    {
+        return 0;
+    }
+
+    size_t count = 0;
+    for (size_t index = 0; index < as_file(file)->artboardCount(); ++index)
+    {
+        if (auto* artboard = as_file(file)->artboard(index))
+        {
+            count += artboard->objects().size();
+        }
+    }
+    return count;
+}
+
+size_t rive_rs_file_object_bytes(const rive_rs_file* file)
+{
+    if (file == nullptr)
+    {
+        return 0;
+    }
+
+    size_t bytes = 0;
+    for (size_t index = 0; index < as_file(file)->artboardCount(); ++index)
+    {
+        auto* artboard = as_file(file)->artboard(index);
+        if (artboard == nullptr)
+        {
+            continue;
+        }
+        bytes += artboard->objects().capacity() * sizeof(rive::Core*);
+        for (const auto* object : artboard->objects())
+        {
+            if (object != nullptr)
+            {
+                bytes += core_object_size(object);
+            }
+        }
+    }
+    return bytes;
+}
+
 size_t rive_rs_file_enum_count(const rive_rs_file* file)
 {
     if (file == nullptr)
@@ -2265,6 +3755,15 @@ void rive_rs_artboard_set_frame_origin(rive_rs_artboard* artboard, bool frame_or
     }
 }
 
+rive_rs_vec2 rive_rs_artboard_origin(const rive_rs_artboard* artboard)
+{
+    if (artboard == nullptr)
+    {
+        return rive_rs_vec2{0.0f, 0.0f};
+    }
+    return rive_rs_vec2{as_artboard(artboard)->originX(), as_artboard(artboard)->originY()};
+}
+
 bool rive_rs_artboard_has_audio(const rive_rs_artboard* artboard)
 {
     return artboard == nullptr ? false : as_artboard(artboard)->hasAudio();
@@ -2648,6 +4147,78 @@ rive_rs_status rive_rs_artboard_root_bone_by_name(rive_rs_artboard* artboard,
     return RIVE_RS_STATUS_OK;
 }
 
+size_t rive_rs_artboard_object_count(const rive_rs_artboard* artboard)
+{
+    return artboard == nullptr ? 0 : as_artboard(artboard)->objects().size();
+}
+
+bool rive_rs_artboard_uses_image_asset(const rive_rs_artboard* artboard,
+                                       const rive_rs_file_asset* asset)
+{
+    if (artboard == nullptr || asset == nullptr)
+    {
+        return false;
+    }
+
+    for (const auto* object : as_artboard(artboard)->objects())
+    {
+        if (object != nullptr && object->is<rive::Image>() &&
+            object->as<rive::Image>()->imageAsset() == as_file_asset(asset))
+        {
+            return true;
+        }
+    }
+    return false;
+}
+
+rive_rs_status rive_rs_artboard_component_at(const rive_rs_artboard* artboard,
+                                             size_t index,
+                                             rive_rs_component_info* out_component)
+{
+    if (artboard == nullptr || out_component == nullptr)
+    {
+        return RIVE_RS_STATUS_NULL;
+    }
+
+    const auto& objects = as_artboard(artboard)->objects();
+    if (index >= objects.size())
+    {
+        return RIVE_RS_STATUS_OUT_OF_RANGE;
+    }
+
+    fill_component_info(objects[index], out_component);
+    return RIVE_RS_STATUS_OK;
+}
+
+rive_rs_status rive_rs_artboard_transform_component_at(
+    rive_rs_artboard* artboard,
+    size_t index,
+    rive_rs_transform_component** out_component)
+{
+    return typed_component_at<rive::TransformComponent>(artboard, index, out_component);
+}
+
+rive_rs_status rive_rs_artboard_node_at(rive_rs_artboard* artboard,
+                                        size_t index,
+                                        rive_rs_node** out_node)
+{
+    return typed_component_at<rive::Node>(artboard, index, out_node);
+}
+
+rive_rs_status rive_rs_artboard_bone_at(rive_rs_artboard* artboard,
+                                        size_t index,
+                                        rive_rs_bone** out_bone)
+{
+    return typed_component_at<rive::Bone>(artboard, index, out_bone);
+}
+
+rive_rs_status rive_rs_artboard_root_bone_at(rive_rs_artboard* artboard,
+                                             size_t index,
+                                             rive_rs_root_bone** out_root_bone)
+{
+    return typed_component_at<rive::RootBone>(artboard, index, out_root_bone);
+}
+
 rive_rs_status rive_rs_artboard_text_value_run_by_name(
     rive_rs_artboard* artboard,
     rive_rs_str_view name,
@@ -2752,6 +4323,38 @@ rive_rs_status rive_rs_artboard_bind_view_model_instance(
     return RIVE_RS_STATUS_OK;
 }
 
+rive_rs_status rive_rs_renderer_new(const rive_rs_renderer_callbacks* callbacks,
+                                    rive_rs_renderer** out_renderer)
+{
+    if (out_renderer == nullptr)
+    {
+        return RIVE_RS_STATUS_NULL;
+    }
+    *out_renderer = nullptr;
+    if (callbacks == nullptr)
+    {
+        return RIVE_RS_STATUS_NULL;
+    }
+    if (!renderer_callbacks_complete(*callbacks))
+    {
+        return RIVE_RS_STATUS_INVALID_ARGUMENT;
+    }
+
+    auto* renderer = new (std::nothrow) BackendRenderer(*callbacks);
+    if (renderer == nullptr)
+    {
+        return RIVE_RS_STATUS_RUNTIME_ERROR;
+    }
+    *out_renderer =
+        reinterpret_cast<rive_rs_renderer*>(static_cast<rive::Renderer*>(renderer));
+    return RIVE_RS_STATUS_OK;
+}
+
+void rive_rs_renderer_delete(rive_rs_renderer* renderer)
+{
+    delete reinterpret_cast<rive::Renderer*>(renderer);
+}
+
 rive_rs_status rive_rs_webgl2_renderer_new(int32_t width,
                                            int32_t height,
                                            rive_rs_webgl2_renderer** out_renderer)
@@ -2796,9 +4399,34 @@ void rive_rs_webgl2_renderer_delete(rive_rs_webgl2_renderer* renderer)
     webgl2_provider::delete_renderer(renderer->renderer);
     renderer->renderer = nullptr;
 #endif
+    render_target_unref_internal(renderer->target);
     delete renderer;
 }
 
+rive_rs_status rive_rs_webgl2_renderer_bind_target(rive_rs_webgl2_renderer* renderer,
+                                                   rive_rs_render_target* target)
+{
+    if (renderer == nullptr)
+    {
+        return RIVE_RS_STATUS_NULL;
+    }
+#ifdef __EMSCRIPTEN__
+    if (renderer->renderer == nullptr)
+    {
+        return RIVE_RS_STATUS_RUNTIME_ERROR;
+    }
+    renderer->renderer->bind_target(target == nullptr ? nullptr : target->offscreen);
+#else
+    // Offscreen targets need the WebGL2 renderer.
+    if (target != nullptr)
+    {
+        return RIVE_RS_STATUS_UNSUPPORTED;
+    }
+#endif
+    renderer_bind_target_impl(renderer, target);
+    return RIVE_RS_STATUS_OK;
+}
+
 rive_rs_status rive_rs_webgl2_renderer_clear(rive_rs_webgl2_renderer* renderer)
 {
     if (renderer == nullptr)
@@ -3034,9 +4662,31 @@ rive_rs_status rive_rs_webgpu_renderer_new(int32_t width,
 
 void rive_rs_webgpu_renderer_delete(rive_rs_webgpu_renderer* renderer)
 {
+    if (renderer == nullptr)
+    {
+        return;
+    }
+    render_target_unref_internal(renderer->target);
     delete renderer;
 }
 
+rive_rs_status rive_rs_webgpu_renderer_bind_target(rive_rs_webgpu_renderer* renderer,
+                                                   rive_rs_render_target* target)
+{
+    if (renderer == nullptr)
+    {
+        return RIVE_RS_STATUS_NULL;
+    }
+    // The WebGPU renderer draws through a no-op renderer, so a target would
+    // never be filled.
+    if (target != nullptr)
+    {
+        return RIVE_RS_STATUS_UNSUPPORTED;
+    }
+    renderer_bind_target_impl(renderer, target);
+    return RIVE_RS_STATUS_OK;
+}
+
 rive_rs_status rive_rs_webgpu_renderer_clear(rive_rs_webgpu_renderer* renderer)
 {
     return renderer_clear_impl(renderer);
@@ -3100,6 +4750,126 @@ rive_rs_status rive_rs_webgpu_renderer_restore_clip_rect(rive_rs_webgpu_renderer
     return renderer_restore_clip_rect_impl(renderer);
 }
 
+rive_rs_status rive_rs_render_target_new(int32_t width,
+                                         int32_t height,
+                                         rive_rs_render_target** out_target)
+{
+    if (out_target == nullptr)
+    {
+        return RIVE_RS_STATUS_NULL;
+    }
+    *out_target = nullptr;
+    if (width <= 0 || height <= 0)
+    {
+        return RIVE_RS_STATUS_INVALID_ARGUMENT;
+    }
+
+    auto* target = new (std::nothrow) rive_rs_render_target();
+    if (target == nullptr)
+    {
+        return RIVE_RS_STATUS_RUNTIME_ERROR;
+    }
+    target->refs.store(1, std::memory_order_relaxed);
+    target->width = width;
+    target->height = height;
+#ifdef __EMSCRIPTEN__
+    target->offscreen = new (std::nothrow) webgl2_provider::OffscreenTarget(width, height);
+    if (target->offscreen == nullptr)
+    {
+        delete target;
+        return RIVE_RS_STATUS_RUNTIME_ERROR;
+    }
+#endif
+    *out_target = target;
+    return RIVE_RS_STATUS_OK;
+}
+
+void rive_rs_render_target_ref(rive_rs_render_target* target)
+{
+    render_target_ref_internal(target);
+}
+
+void rive_rs_render_target_unref(rive_rs_render_target* target)
+{
+    render_target_unref_internal(target);
+}
+
+int32_t rive_rs_render_target_width(const rive_rs_render_target* target)
+{
+    return target == nullptr ? 0 : target->width;
+}
+
+int32_t rive_rs_render_target_height(const rive_rs_render_target* target)
+{
+    return target == nullptr ? 0 : target->height;
+}
+
+rive_rs_status rive_rs_render_target_write_pixels(rive_rs_render_target* target,
+                                                  rive_rs_bytes_view rgba)
+{
+    if (target == nullptr)
+    {
+        return RIVE_RS_STATUS_NULL;
+    }
+    size_t size = static_cast<size_t>(target->width) * static_cast<size_t>(target->height) * 4;
+    if (invalid_bytes(rgba) || rgba.len != size)
+    {
+        return RIVE_RS_STATUS_INVALID_ARGUMENT;
+    }
+    target->pixels.assign(rgba.ptr, rgba.ptr + rgba.len);
+    return RIVE_RS_STATUS_OK;
+}
+
+rive_rs_status rive_rs_render_target_image(rive_rs_factory* factory,
+                                           rive_rs_render_target* target,
+                                           rive_rs_render_image** out_image)
+{
+    if (factory == nullptr || target == nullptr || out_image == nullptr)
+    {
+        return RIVE_RS_STATUS_NULL;
+    }
+
+    *out_image = nullptr;
+    if (as_factory(factory) == nullptr)
+    {
+        return RIVE_RS_STATUS_NULL;
+    }
+#ifdef __EMSCRIPTEN__
+    // Targets a WebGL2 renderer has drawn into share their texture.
+    if (target->offscreen != nullptr && target->offscreen->image() != nullptr)
+    {
+        rive::RenderImage* image = target->offscreen->image();
+        image->ref();
+        *out_image = reinterpret_cast<rive_rs_render_image*>(image);
+        return RIVE_RS_STATUS_OK;
+    }
+#endif
+    if (factory->make_image == nullptr)
+    {
+        return RIVE_RS_STATUS_UNSUPPORTED;
+    }
+
+    // Never-flushed targets are transparent.
+    std::vector<uint8_t> blank;
+    const uint8_t* pixels = target->pixels.data();
+    if (target->pixels.empty())
+    {
+        blank.resize(static_cast<size_t>(target->width) * static_cast<size_t>(target->height) * 4);
+        pixels = blank.data();
+    }
+    auto image = factory->make_image(as_factory(factory),
+                                     static_cast<uint32_t>(target->width),
+                                     static_cast<uint32_t>(target->height),
+                                     pixels);
+    if (image == nullptr)
+    {
+        return RIVE_RS_STATUS_RUNTIME_ERROR;
+    }
+
+    *out_image = reinterpret_cast<rive_rs_render_image*>(image.release());
+    return RIVE_RS_STATUS_OK;
+}
+
 void rive_rs_bindable_artboard_ref(rive_rs_bindable_artboard* bindable_artboard)
 {
     if (bindable_artboard != nullptr)
@@ -3502,7 +5272,7 @@ rive_rs_status rive_rs_linear_animation_instance_new(
 
     *out_instance = nullptr;
     auto* instance = new (std::nothrow)
-        rive::LinearAnimationInstance(as_linear_animation(animation), as_artboard(artboard));
+        AudioReportingAnimationInstance(as_linear_animation(animation), as_artboard(artboard));
     if (instance == nullptr)
     {
         return RIVE_RS_STATUS_RUNTIME_ERROR;
@@ -3558,32 +5328,63 @@ rive_rs_status rive_rs_linear_animation_apply(const rive_rs_linear_animation* an
                                               float time,
                                               float mix)
 {
-    if (animation == nullptr || artboard == nullptr)
+    if (animation == nullptr || artboard == nullptr)
+    {
+        return RIVE_RS_STATUS_NULL;
+    }
+
+    as_linear_animation(animation)->apply(as_artboard(artboard), time, mix);
+    return RIVE_RS_STATUS_OK;
+}
+
+void rive_rs_linear_animation_instance_delete(rive_rs_linear_animation_instance* instance)
+{
+    delete as_reporting_animation_instance(instance);
+}
+
+rive_rs_status rive_rs_linear_animation_instance_advance(
+    rive_rs_linear_animation_instance* instance,
+    float seconds,
+    bool* out_looped)
+{
+    if (instance == nullptr || out_looped == nullptr)
     {
         return RIVE_RS_STATUS_NULL;
     }
 
-    as_linear_animation(animation)->apply(as_artboard(artboard), time, mix);
+    auto* reporting = as_reporting_animation_instance(instance);
+    reporting->reportedAudio.clear();
+    (void)reporting->advance(seconds, reporting);
+    *out_looped = reporting->didLoop();
     return RIVE_RS_STATUS_OK;
 }
 
-void rive_rs_linear_animation_instance_delete(rive_rs_linear_animation_instance* instance)
+size_t rive_rs_linear_animation_instance_reported_audio_count(
+    const rive_rs_linear_animation_instance* instance)
 {
-    delete as_linear_animation_instance(instance);
+    return instance == nullptr ? 0
+                               : as_reporting_animation_instance(instance)->reportedAudio.size();
 }
 
-rive_rs_status rive_rs_linear_animation_instance_advance(
-    rive_rs_linear_animation_instance* instance,
-    float seconds,
-    bool* out_looped)
+rive_rs_status rive_rs_linear_animation_instance_reported_audio_asset(
+    const rive_rs_linear_animation_instance* instance,
+    size_t index,
+    rive_rs_file_asset** out_asset)
 {
-    if (instance == nullptr || out_looped == nullptr)
+    if (instance == nullptr || out_asset == nullptr)
     {
         return RIVE_RS_STATUS_NULL;
     }
 
-    (void)as_linear_animation_instance(instance)->advance(seconds);
-    *out_looped = as_linear_animation_instance(instance)->didLoop();
+    *out_asset = nullptr;
+    const auto& reported = as_reporting_animation_instance(instance)->reportedAudio;
+    if (index >= reported.size())
+    {
+        return RIVE_RS_STATUS_OUT_OF_RANGE;
+    }
+
+    *out_asset =
+        reinterpret_cast<rive_rs_file_asset*>(static_cast<rive::FileAsset*>(reported[index]));
     return RIVE_RS_STATUS_OK;
 }
 
@@ -3633,26 +5434,205 @@ rive_rs_status rive_rs_state_machine_instance_new(
     }
 
     *out_instance = nullptr;
-    auto* instance = new (std::nothrow)
-        rive::StateMachineInstance(as_state_machine(state_machine), as_artboard(artboard));
-    if (instance == nullptr)
+    *out_instance = new (std::nothrow)
+        rive_rs_state_machine_instance(as_state_machine(state_machine), as_artboard(artboard));
+    return *out_instance == nullptr ? RIVE_RS_STATUS_RUNTIME_ERROR : RIVE_RS_STATUS_OK;
+}
+
+rive_rs_str_view rive_rs_state_machine_name(const rive_rs_state_machine* state_machine)
+{
+    return state_machine == nullptr ? kEmptyStrView
+                                    : to_str_view(as_state_machine(state_machine)->name());
+}
+
+size_t rive_rs_state_machine_layer_count(const rive_rs_state_machine* state_machine)
+{
+    return state_machine == nullptr ? 0 : as_state_machine(state_machine)->layerCount();
+}
+
+rive_rs_status rive_rs_state_machine_layer_at(const rive_rs_state_machine* state_machine,
+                                              size_t layer_index,
+                                              rive_rs_state_machine_layer_info* out_layer)
+{
+    if (state_machine == nullptr || out_layer == nullptr)
     {
-        return RIVE_RS_STATUS_RUNTIME_ERROR;
+        return RIVE_RS_STATUS_NULL;
     }
 
-    *out_instance = reinterpret_cast<rive_rs_state_machine_instance*>(instance);
+    const auto* layer = state_machine_layer_at(state_machine, layer_index);
+    if (layer == nullptr)
+    {
+        return RIVE_RS_STATUS_OUT_OF_RANGE;
+    }
+
+    out_layer->name = to_str_view(layer->name());
+    out_layer->state_count = layer->stateCount();
     return RIVE_RS_STATUS_OK;
 }
 
-rive_rs_str_view rive_rs_state_machine_name(const rive_rs_state_machine* state_machine)
+rive_rs_status rive_rs_state_machine_state_at(const rive_rs_state_machine* state_machine,
+                                              size_t layer_index,
+                                              size_t state_index,
+                                              rive_rs_layer_state_info* out_state)
 {
-    return state_machine == nullptr ? kEmptyStrView
-                                    : to_str_view(as_state_machine(state_machine)->name());
+    if (state_machine == nullptr || out_state == nullptr)
+    {
+        return RIVE_RS_STATUS_NULL;
+    }
+
+    const auto* state =
+        layer_state_at(state_machine_layer_at(state_machine, layer_index), state_index);
+    if (state == nullptr)
+    {
+        return RIVE_RS_STATUS_OUT_OF_RANGE;
+    }
+
+    out_state->type = layer_state_type(state);
+    out_state->name = layer_state_name(state);
+    out_state->transition_count = state->transitionCount();
+    return RIVE_RS_STATUS_OK;
+}
+
+rive_rs_status rive_rs_state_machine_transition_at(
+    const rive_rs_state_machine* state_machine,
+    size_t layer_index,
+    size_t state_index,
+    size_t transition_index,
+    rive_rs_state_transition_info* out_transition)
+{
+    if (state_machine == nullptr || out_transition == nullptr)
+    {
+        return RIVE_RS_STATUS_NULL;
+    }
+
+    const auto* layer = state_machine_layer_at(state_machine, layer_index);
+    const auto* transition =
+        state_transition_at(layer_state_at(layer, state_index), transition_index);
+    if (transition == nullptr)
+    {
+        return RIVE_RS_STATUS_OUT_OF_RANGE;
+    }
+
+    out_transition->has_target = false;
+    out_transition->target_state = 0;
+    for (size_t index = 0; index < layer->stateCount(); ++index)
+    {
+        if (layer->state(index) == transition->stateTo())
+        {
+            out_transition->has_target = true;
+            out_transition->target_state = index;
+            break;
+        }
+    }
+    out_transition->duration = transition->duration();
+    out_transition->duration_is_percentage =
+        has_transition_flag(transition, rive::StateTransitionFlags::DurationIsPercentage);
+    out_transition->enable_exit_time = transition->enableExitTime();
+    out_transition->exit_time = transition->exitTime();
+    out_transition->exit_time_is_percentage =
+        has_transition_flag(transition, rive::StateTransitionFlags::ExitTimeIsPercentage);
+    out_transition->disabled = transition->isDisabled();
+    out_transition->condition_count = transition->conditionCount();
+    return RIVE_RS_STATUS_OK;
+}
+
+rive_rs_status rive_rs_state_machine_condition_at(
+    const rive_rs_state_machine* state_machine,
+    size_t layer_index,
+    size_t state_index,
+    size_t transition_index,
+    size_t condition_index,
+    rive_rs_transition_condition_info* out_condition)
+{
+    if (state_machine == nullptr || out_condition == nullptr)
+    {
+        return RIVE_RS_STATUS_NULL;
+    }
+
+    const auto* transition = state_transition_at(
+        layer_state_at(state_machine_layer_at(state_machine, layer_index), state_index),
+        transition_index);
+    if (transition == nullptr || condition_index >= transition->conditionCount())
+    {
+        return RIVE_RS_STATUS_OUT_OF_RANGE;
+    }
+
+    const auto* condition = transition->condition(condition_index);
+    out_condition->type = RIVE_RS_CONDITION_OTHER;
+    out_condition->has_input = false;
+    out_condition->input_index = 0;
+    out_condition->input_name = kEmptyStrView;
+    out_condition->op = RIVE_RS_CONDITION_OP_EQUAL;
+    out_condition->value = 0.0f;
+    if (condition == nullptr)
+    {
+        return RIVE_RS_STATUS_OK;
+    }
+
+    if (condition->is<rive::TransitionInputCondition>())
+    {
+        const auto* machine = as_state_machine(state_machine);
+        const auto input_id = condition->as<rive::TransitionInputCondition>()->inputId();
+        if (input_id < machine->inputCount())
+        {
+            out_condition->has_input = true;
+            out_condition->input_index = input_id;
+            out_condition->input_name = to_str_view(machine->input(input_id)->name());
+        }
+    }
+    if (condition->is<rive::TransitionValueCondition>())
+    {
+        out_condition->op = condition_op(condition->as<rive::TransitionValueCondition>()->op());
+    }
+
+    if (condition->is<rive::TransitionTriggerCondition>())
+    {
+        out_condition->type = RIVE_RS_CONDITION_TRIGGER;
+    }
+    else if (condition->is<rive::TransitionBoolCondition>())
+    {
+        out_condition->type = RIVE_RS_CONDITION_BOOL;
+    }
+    else if (condition->is<rive::TransitionNumberCondition>())
+    {
+        out_condition->type = RIVE_RS_CONDITION_NUMBER;
+        out_condition->value = condition->as<rive::TransitionNumberCondition>()->value();
+    }
+    return RIVE_RS_STATUS_OK;
+}
+
+size_t rive_rs_state_machine_listener_count(const rive_rs_state_machine* state_machine)
+{
+    return state_machine == nullptr ? 0 : as_state_machine(state_machine)->listenerCount();
+}
+
+rive_rs_status rive_rs_state_machine_listener_at(
+    const rive_rs_state_machine* state_machine,
+    size_t index,
+    rive_rs_state_machine_listener_info* out_listener)
+{
+    if (state_machine == nullptr || out_listener == nullptr)
+    {
+        return RIVE_RS_STATUS_NULL;
+    }
+
+    const auto* machine = as_state_machine(state_machine);
+    if (index >= machine->listenerCount())
+    {
+        return RIVE_RS_STATUS_OUT_OF_RANGE;
+    }
+
+    const auto* listener = machine->listener(index);
+    out_listener->name = to_str_view(listener->name());
+    out_listener->type = listener_type(listener->listenerType());
+    out_listener->target_index = listener->targetId();
+    out_listener->action_count = listener->actionCount();
+    return RIVE_RS_STATUS_OK;
 }
 
 void rive_rs_state_machine_instance_delete(rive_rs_state_machine_instance* instance)
 {
-    delete as_state_machine_instance(instance);
+    delete instance;
 }
 
 rive_rs_status rive_rs_state_machine_instance_advance(
@@ -3666,6 +5646,7 @@ rive_rs_status rive_rs_state_machine_instance_advance(
     }
 
     *out_changed = as_state_machine_instance(instance)->advance(seconds);
+    track_state_changes(instance, seconds);
     return RIVE_RS_STATUS_OK;
 }
 
@@ -3680,6 +5661,7 @@ rive_rs_status rive_rs_state_machine_instance_advance_and_apply(
     }
 
     *out_changed = as_state_machine_instance(instance)->advanceAndApply(seconds);
+    track_state_changes(instance, seconds);
     return RIVE_RS_STATUS_OK;
 }
 
@@ -3963,6 +5945,39 @@ rive_rs_status rive_rs_state_machine_reported_event_property_at(
     return fill_event_property_info(property, out_property);
 }
 
+rive_rs_status rive_rs_state_machine_reported_event_audio_asset(
+    const rive_rs_state_machine_instance* instance,
+    size_t index,
+    rive_rs_file_asset** out_asset)
+{
+    if (instance == nullptr || out_asset == nullptr)
+    {
+        return RIVE_RS_STATUS_NULL;
+    }
+
+    *out_asset = nullptr;
+    if (index >= as_state_machine_instance(instance)->reportedEventCount())
+    {
+        return RIVE_RS_STATUS_OUT_OF_RANGE;
+    }
+
+    const auto report = as_state_machine_instance(instance)->reportedEventAt(index);
+    auto* event = report.event();
+    if (event == nullptr || !event->is<rive::AudioEvent>())
+    {
+        return RIVE_RS_STATUS_NOT_FOUND;
+    }
+
+    auto* asset = event->as<rive::AudioEvent>()->asset();
+    if (asset == nullptr)
+    {
+        return RIVE_RS_STATUS_NOT_FOUND;
+    }
+
+    *out_asset = reinterpret_cast<rive_rs_file_asset*>(static_cast<rive::FileAsset*>(asset));
+    return RIVE_RS_STATUS_OK;
+}
+
 size_t rive_rs_state_machine_state_changed_count(
     const rive_rs_state_machine_instance* instance)
 {
@@ -4014,6 +6029,64 @@ rive_rs_status rive_rs_state_machine_state_changed_name_at(
     }
 }
 
+size_t rive_rs_state_machine_instance_layer_count(const rive_rs_state_machine_instance* instance)
+{
+    return instance == nullptr ? 0
+                               : as_state_machine_instance(instance)->stateMachine()->layerCount();
+}
+
+rive_rs_status rive_rs_state_machine_instance_current_state_at(
+    const rive_rs_state_machine_instance* instance,
+    size_t layer_index,
+    rive_rs_layer_current_state_info* out_state)
+{
+    if (instance == nullptr || out_state == nullptr)
+    {
+        return RIVE_RS_STATUS_NULL;
+    }
+
+    const auto* machine = as_state_machine_instance(instance)->stateMachine();
+    if (layer_index >= machine->layerCount())
+    {
+        return RIVE_RS_STATUS_OUT_OF_RANGE;
+    }
+
+    const auto* layer = machine->layer(layer_index);
+    const auto& tracked = instance->layers[layer_index];
+    const auto* state = tracked.state == nullptr ? layer->entryState() : tracked.state;
+    *out_state = {};
+    out_state->layer_name = to_str_view(layer->name());
+    if (!layer_state_index(layer, state, &out_state->state_index))
+    {
+        return RIVE_RS_STATUS_NOT_FOUND;
+    }
+    out_state->state_type = layer_state_type(state);
+    out_state->state_name = layer_state_name(state);
+    out_state->elapsed_seconds = tracked.elapsed_seconds;
+    out_state->mix = 1.0f;
+    out_state->has_state_from =
+        tracked.state_from != nullptr &&
+        layer_state_index(layer, tracked.state_from, &out_state->state_from_index);
+    if (tracked.transition == nullptr || tracked.state_from == nullptr)
+    {
+        return RIVE_RS_STATUS_OK;
+    }
+    // Same clock as the runtime's mix, which starts at 0 when the transition
+    // is taken and reaches 1 after `mixTime` seconds.
+    const float mix_seconds = tracked.transition->mixTime(tracked.state_from);
+    if (mix_seconds > 0.0f && tracked.elapsed_seconds < mix_seconds &&
+        layer_transition_index(layer,
+                               tracked.transition,
+                               &out_state->transition_state_index,
+                               &out_state->transition_index))
+    {
+        out_state->is_transitioning = true;
+        out_state->mix = tracked.elapsed_seconds / mix_seconds;
+        out_state->mix_seconds = mix_seconds;
+    }
+    return RIVE_RS_STATUS_OK;
+}
+
 rive_rs_status rive_rs_state_machine_instance_bind_view_model_instance(
     rive_rs_state_machine_instance* instance,
     rive_rs_view_model_instance* view_model_instance)
@@ -4974,6 +7047,14 @@ void rive_rs_audio_source_unref(rive_rs_audio_source* audio)
     }
 }
 
+void rive_rs_font_ref(rive_rs_font* font)
+{
+    if (font != nullptr)
+    {
+        as_font(font)->ref();
+    }
+}
+
 void rive_rs_font_unref(rive_rs_font* font)
 {
     if (font != nullptr)
@@ -4982,6 +7063,100 @@ void rive_rs_font_unref(rive_rs_font* font)
     }
 }
 
+rive_rs_status rive_rs_set_font_fallbacks(rive_rs_font* const* fonts, size_t count)
+{
+    if (fonts == nullptr && count != 0)
+    {
+        return RIVE_RS_STATUS_NULL;
+    }
+
+    std::vector<rive::rcp<rive::Font>> chain;
+    chain.reserve(count);
+    for (size_t i = 0; i < count; ++i)
+    {
+        if (fonts[i] == nullptr)
+        {
+            return RIVE_RS_STATUS_INVALID_ARGUMENT;
+        }
+        chain.push_back(rive::ref_rcp(as_font(fonts[i])));
+    }
+
+#ifdef WITH_RIVE_TEXT
+    std::lock_guard<std::mutex> lock(font_fallback_mutex());
+    font_fallback_chain().swap(chain);
+    rive::Font::gFallbackProc = font_fallback_chain().empty() ? nullptr : font_fallback;
+    return RIVE_RS_STATUS_OK;
+#else
+    // Without the text engine there is no shaper to consult the chain.
+    return chain.empty() ? RIVE_RS_STATUS_OK : RIVE_RS_STATUS_UNSUPPORTED;
+#endif
+}
+
+rive_rs_status rive_rs_decode_render_image(rive_rs_factory* factory,
+                                           rive_rs_bytes_view bytes,
+                                           rive_rs_render_image** out_image)
+{
+    if (factory == nullptr || out_image == nullptr)
+    {
+        return RIVE_RS_STATUS_NULL;
+    }
+
+    *out_image = nullptr;
+    if (as_factory(factory) == nullptr)
+    {
+        return RIVE_RS_STATUS_NULL;
+    }
+    if (!factory->draws)
+    {
+        return RIVE_RS_STATUS_UNSUPPORTED;
+    }
+    if (invalid_bytes(bytes) || bytes.len == 0)
+    {
+        return RIVE_RS_STATUS_INVALID_ARGUMENT;
+    }
+
+    auto image = as_factory(factory)->decodeImage(to_span(bytes));
+    if (image == nullptr)
+    {
+        return RIVE_RS_STATUS_DECODE_ERROR;
+    }
+
+    *out_image = reinterpret_cast<rive_rs_render_image*>(image.release());
+    return RIVE_RS_STATUS_OK;
+}
+
+uint32_t rive_rs_render_image_width(const rive_rs_render_image* image)
+{
+    return image == nullptr ? 0 : static_cast<uint32_t>(as_render_image(image)->width());
+}
+
+uint32_t rive_rs_render_image_height(const rive_rs_render_image* image)
+{
+    return image == nullptr ? 0 : static_cast<uint32_t>(as_render_image(image)->height());
+}
+
+rive_rs_status rive_rs_render_image_pixels(const rive_rs_render_image* image,
+                                           rive_rs_bytes_view* out_pixels)
+{
+    if (image == nullptr || out_pixels == nullptr)
+    {
+        return RIVE_RS_STATUS_NULL;
+    }
+
+    *out_pixels = rive_rs_bytes_view{nullptr, 0};
+    auto backend_image = rive::lite_rtti_cast<const BackendRenderImage*>(as_render_image(image));
+    const uint8_t* pixels = backend_image == nullptr ? nullptr : backend_image->pixels();
+    if (pixels == nullptr)
+    {
+        return RIVE_RS_STATUS_UNSUPPORTED;
+    }
+
+    *out_pixels = rive_rs_bytes_view{pixels,
+                                     static_cast<size_t>(backend_image->width()) *
+                                         static_cast<size_t>(backend_image->height()) * 4};
+    return RIVE_RS_STATUS_OK;
+}
+
 void rive_rs_render_image_ref(rive_rs_render_image* image)
 {
     if (image != nullptr)
@@ -5060,6 +7235,53 @@ rive_rs_str_view rive_rs_file_asset_cdn_uuid(const rive_rs_file_asset* asset)
     return asset == nullptr ? kEmptyStrView : to_scratch_view(as_file_asset(asset)->cdnUuidStr());
 }
 
+bool rive_rs_file_asset_is_loaded(const rive_rs_file_asset* asset)
+{
+    if (asset == nullptr)
+    {
+        return false;
+    }
+
+    auto* file_asset = const_cast<rive::FileAsset*>(as_file_asset(asset));
+    if (file_asset->is<rive::ImageAsset>())
+    {
+        return file_asset->as<rive::ImageAsset>()->renderImage() != nullptr;
+    }
+    if (file_asset->is<rive::FontAsset>())
+    {
+        return file_asset->as<rive::FontAsset>()->font() != nullptr;
+    }
+    if (file_asset->is<rive::AudioAsset>())
+    {
+        return file_asset->as<rive::AudioAsset>()->audioSource() != nullptr;
+    }
+    return false;
+}
+
+rive_rs_status rive_rs_image_asset_render_image(const rive_rs_file_asset* asset,
+                                                rive_rs_render_image** out_image)
+{
+    if (asset == nullptr || out_image == nullptr)
+    {
+        return RIVE_RS_STATUS_NULL;
+    }
+
+    *out_image = nullptr;
+    auto* file_asset = const_cast<rive::FileAsset*>(as_file_asset(asset));
+    if (!file_asset->is<rive::ImageAsset>())
+    {
+        return RIVE_RS_STATUS_INVALID_ARGUMENT;
+    }
+
+    auto* image = file_asset->as<rive::ImageAsset>()->renderImage();
+    if (image != nullptr)
+    {
+        image->ref();
+        *out_image = reinterpret_cast<rive_rs_render_image*>(image);
+    }
+    return RIVE_RS_STATUS_OK;
+}
+
 rive_rs_status rive_rs_file_asset_decode(rive_rs_factory* factory,
                                          rive_rs_file_asset* asset,
                                          rive_rs_bytes_view bytes)
@@ -37,6 +37,7 @@ typedef struct rive_rs_flattened_path rive_rs_flattened_path;
 typedef struct rive_rs_renderer rive_rs_renderer;
 typedef struct rive_rs_webgl2_renderer rive_rs_webgl2_renderer;
 typedef struct rive_rs_webgpu_renderer rive_rs_webgpu_renderer;
+typedef struct rive_rs_render_target rive_rs_render_target;
 
 typedef struct rive_rs_linear_animation rive_rs_linear_animation;
 typedef struct rive_rs_linear_animation_instance rive_rs_linear_animation_instance;
@@ -114,6 +115,99 @@ typedef enum rive_rs_event_property_type {
   RIVE_RS_EVENT_PROPERTY_STRING = 3,
 } rive_rs_event_property_type;
 
+typedef enum rive_rs_layer_state_type {
+  RIVE_RS_LAYER_STATE_ANIMATION = 0,
+  RIVE_RS_LAYER_STATE_BLEND = 1,
+  RIVE_RS_LAYER_STATE_ENTRY = 2,
+  RIVE_RS_LAYER_STATE_EXIT = 3,
+  RIVE_RS_LAYER_STATE_ANY = 4,
+  RIVE_RS_LAYER_STATE_OTHER = 5,
+} rive_rs_layer_state_type;
+
+typedef enum rive_rs_condition_type {
+  RIVE_RS_CONDITION_BOOL = 0,
+  RIVE_RS_CONDITION_NUMBER = 1,
+  RIVE_RS_CONDITION_TRIGGER = 2,
+  RIVE_RS_CONDITION_OTHER = 3,
+} rive_rs_condition_type;
+
+typedef enum rive_rs_condition_op {
+  RIVE_RS_CONDITION_OP_EQUAL = 0,
+  RIVE_RS_CONDITION_OP_NOT_EQUAL = 1,
+  RIVE_RS_CONDITION_OP_LESS_THAN_OR_EQUAL = 2,
+  RIVE_RS_CONDITION_OP_GREATER_THAN_OR_EQUAL = 3,
+  RIVE_RS_CONDITION_OP_LESS_THAN = 4,
+  RIVE_RS_CONDITION_OP_GREATER_THAN = 5,
+} rive_rs_condition_op;
+
+typedef enum rive_rs_listener_type {
+  RIVE_RS_LISTENER_ENTER = 0,
+  RIVE_RS_LISTENER_EXIT = 1,
+  RIVE_RS_LISTENER_DOWN = 2,
+  RIVE_RS_LISTENER_UP = 3,
+  RIVE_RS_LISTENER_MOVE = 4,
+  RIVE_RS_LISTENER_EVENT = 5,
+  RIVE_RS_LISTENER_OTHER = 6,
+} rive_rs_listener_type;
+
+typedef enum rive_rs_fill_rule {
+  RIVE_RS_FILL_RULE_NON_ZERO = 0,
+  RIVE_RS_FILL_RULE_EVEN_ODD = 1,
+  RIVE_RS_FILL_RULE_CLOCKWISE = 2,
+} rive_rs_fill_rule;
+
+typedef enum rive_rs_paint_style {
+  RIVE_RS_PAINT_STYLE_STROKE = 0,
+  RIVE_RS_PAINT_STYLE_FILL = 1,
+} rive_rs_paint_style;
+
+typedef enum rive_rs_stroke_join {
+  RIVE_RS_STROKE_JOIN_MITER = 0,
+  RIVE_RS_STROKE_JOIN_ROUND = 1,
+  RIVE_RS_STROKE_JOIN_BEVEL = 2,
+} rive_rs_stroke_join;
+
+typedef enum rive_rs_stroke_cap {
+  RIVE_RS_STROKE_CAP_BUTT = 0,
+  RIVE_RS_STROKE_CAP_ROUND = 1,
+  RIVE_RS_STROKE_CAP_SQUARE = 2,
+} rive_rs_stroke_cap;
+
+typedef enum rive_rs_blend_mode {
+  RIVE_RS_BLEND_MODE_SRC_OVER = 3,
+  RIVE_RS_BLEND_MODE_SCREEN = 14,
+  RIVE_RS_BLEND_MODE_OVERLAY = 15,
+  RIVE_RS_BLEND_MODE_DARKEN = 16,
+  RIVE_RS_BLEND_MODE_LIGHTEN = 17,
+  RIVE_RS_BLEND_MODE_COLOR_DODGE = 18,
+  RIVE_RS_BLEND_MODE_COLOR_BURN = 19,
+  RIVE_RS_BLEND_MODE_HARD_LIGHT = 20,
+  RIVE_RS_BLEND_MODE_SOFT_LIGHT = 21,
+  RIVE_RS_BLEND_MODE_DIFFERENCE = 22,
+  RIVE_RS_BLEND_MODE_EXCLUSION = 23,
+  RIVE_RS_BLEND_MODE_MULTIPLY = 24,
+  RIVE_RS_BLEND_MODE_HUE = 25,
+  RIVE_RS_BLEND_MODE_SATURATION = 26,
+  RIVE_RS_BLEND_MODE_COLOR = 27,
+  RIVE_RS_BLEND_MODE_LUMINOSITY = 28,
+} rive_rs_blend_mode;
+
+typedef enum rive_rs_image_wrap {
+  RIVE_RS_IMAGE_WRAP_CLAMP = 0,
+  RIVE_RS_IMAGE_WRAP_REPEAT = 1,
+  RIVE_RS_IMAGE_WRAP_MIRROR = 2,
+} rive_rs_image_wrap;
+
+typedef enum rive_rs_image_filter {
+  RIVE_RS_IMAGE_FILTER_BILINEAR = 0,
+  RIVE_RS_IMAGE_FILTER_NEAREST = 1,
+} rive_rs_image_filter;
+
+typedef enum rive_rs_render_buffer_type {
+  RIVE_RS_RENDER_BUFFER_TYPE_INDEX = 0,
+  RIVE_RS_RENDER_BUFFER_TYPE_VERTEX = 1,
+} rive_rs_render_buffer_type;
+
 typedef struct rive_rs_vec2 {
   float x;
   float y;
@@ -168,24 +262,239 @@ typedef struct rive_rs_event_property_info {
   rive_rs_str_view string_value;
 } rive_rs_event_property_info;
 
+typedef struct rive_rs_component_info {
+  rive_rs_str_view name;
+  rive_rs_str_view type_name;
+  uint16_t type_key;
+  bool has_parent;
+  size_t parent_index;
+  bool is_transform_component;
+  bool is_node;
+  bool is_bone;
+  bool is_root_bone;
+} rive_rs_component_info;
+
+typedef struct rive_rs_state_machine_layer_info {
+  rive_rs_str_view name;
+  size_t state_count;
+} rive_rs_state_machine_layer_info;
+
+typedef struct rive_rs_layer_state_info {
+  rive_rs_layer_state_type type;
+  rive_rs_str_view name;
+  size_t transition_count;
+} rive_rs_layer_state_info;
+
+typedef struct rive_rs_state_transition_info {
+  bool has_target;
+  size_t target_state;
+  uint32_t duration;
+  bool duration_is_percentage;
+  bool enable_exit_time;
+  uint32_t exit_time;
+  bool exit_time_is_percentage;
+  bool disabled;
+  size_t condition_count;
+} rive_rs_state_transition_info;
+
+typedef struct rive_rs_transition_condition_info {
+  rive_rs_condition_type type;
+  bool has_input;
+  size_t input_index;
+  rive_rs_str_view input_name;
+  rive_rs_condition_op op;
+  float value;
+} rive_rs_transition_condition_info;
+
+typedef struct rive_rs_state_machine_listener_info {
+  rive_rs_str_view name;
+  rive_rs_listener_type type;
+  size_t target_index;
+  size_t action_count;
+} rive_rs_state_machine_listener_info;
+
+typedef struct rive_rs_layer_current_state_info {
+  rive_rs_str_view layer_name;
+  size_t state_index;
+  rive_rs_layer_state_type state_type;
+  rive_rs_str_view state_name;
+  float elapsed_seconds;
+  bool is_transitioning;
+  size_t transition_state_index;
+  size_t transition_index;
+  bool has_state_from;
+  size_t state_from_index;
+  float mix;
+  float mix_seconds;
+} rive_rs_layer_current_state_info;
+
+typedef struct rive_rs_image_sampler {
+  rive_rs_image_wrap wrap_x;
+  rive_rs_image_wrap wrap_y;
+  rive_rs_image_filter filter;
+} rive_rs_image_sampler;
+
+/*
+ * Render backend vtable used by `rive_rs_factory_from_backend`.
+ *
+ * Every path, paint, shader, image and buffer created through this vtable is
+ * an opaque `void*` owned by the backend and released through the matching
+ * `release_*` callback. All function pointers are required unless marked
+ * optional.
+ */
+typedef struct rive_rs_render_backend {
+  void* user_data;
+  uint64_t backend_key;
+  void (*release)(void* user_data);
+
+  void* (*make_path)(void* user_data, rive_rs_fill_rule fill_rule);
+  void* (*make_paint)(void* user_data);
+  void* (*make_linear_gradient)(
+      void* user_data,
+      float start_x,
+      float start_y,
+      float end_x,
+      float end_y,
+      const uint32_t* colors,
+      const float* stops,
+      size_t count);
+  void* (*make_radial_gradient)(
+      void* user_data,
+      float center_x,
+      float center_y,
+      float radius,
+      const uint32_t* colors,
+      const float* stops,
+      size_t count);
+  void* (*decode_image)(void* user_data, rive_rs_bytes_view bytes);
+  void* (*make_buffer)(
+      void* user_data,
+      rive_rs_render_buffer_type buffer_type,
+      uint32_t flags,
+      size_t size_in_bytes);
+
+  void (*release_path)(void* user_data, void* path);
+  void (*release_paint)(void* user_data, void* paint);
+  void (*release_shader)(void* user_data, void* shader);
+  void (*release_image)(void* user_data, void* image);
+  void (*release_buffer)(void* user_data, void* buffer);
+
+  void (*path_rewind)(void* user_data, void* path);
+  void (*path_fill_rule)(void* user_data, void* path, rive_rs_fill_rule fill_rule);
+  void (*path_move_to)(void* user_data, void* path, float x, float y);
+  void (*path_line_to)(void* user_data, void* path, float x, float y);
+  void (*path_cubic_to)(
+      void* user_data,
+      void* path,
+      float out_x,
+      float out_y,
+      float in_x,
+      float in_y,
+      float x,
+      float y);
+  void (*path_close)(void* user_data, void* path);
+  void (*path_add_path)(
+      void* user_data,
+      void* path,
+      const void* other,
+      const rive_rs_mat2d* transform);
+
+  void (*paint_color)(void* user_data, void* paint, uint32_t argb);
+  void (*paint_style)(void* user_data, void* paint, rive_rs_paint_style style);
+  void (*paint_thickness)(void* user_data, void* paint, float thickness);
+  void (*paint_join)(void* user_data, void* paint, rive_rs_stroke_join join);
+  void (*paint_cap)(void* user_data, void* paint, rive_rs_stroke_cap cap);
+  void (*paint_blend_mode)(void* user_data, void* paint, rive_rs_blend_mode blend_mode);
+  void (*paint_shader)(void* user_data, void* paint, const void* shader);
+  void (*paint_feather)(void* user_data, void* paint, float feather);
+
+  void (*image_size)(
+      void* user_data,
+      const void* image,
+      uint32_t* out_width,
+      uint32_t* out_height);
+  /* Unpremultiplied RGBA8, `width * height * 4` bytes, or NULL if unknown. */
+  const uint8_t* (*image_pixels)(void* user_data, const void* image);
+  /* At least the buffer's `size_in_bytes` writable bytes, or NULL; the
+     provider then maps scratch memory and drops what the runtime writes. */
+  uint8_t* (*buffer_map)(void* user_data, void* buffer);
+  void (*buffer_unmap)(void* user_data, void* buffer);
+
+  /* Added in ABI version 2. Optional; NULL makes render-target conversion
+   * (`rive_rs_render_target_image`) unsupported for this factory.
+   * `rgba` is unpremultiplied RGBA8, `width * height * 4` bytes. */
+  void* (*make_image)(
+      void* user_data,
+      uint32_t width,
+      uint32_t height,
+      const uint8_t* rgba);
+} rive_rs_render_backend;
+
+/*
+ * Draw callbacks used by `rive_rs_renderer_new`.
+ *
+ * Objects are only forwarded when they were created by a backend whose
+ * `backend_key` matches this renderer's `backend_key`. All function pointers
+ * are required.
+ */
+typedef struct rive_rs_renderer_callbacks {
+  void* user_data;
+  uint64_t backend_key;
+  void (*save)(void* user_data);
+  void (*restore)(void* user_data);
+  void (*transform)(void* user_data, const rive_rs_mat2d* matrix);
+  void (*modulate_opacity)(void* user_data, float opacity);
+  void (*draw_path)(void* user_data, const void* path, const void* paint);
+  void (*clip_path)(void* user_data, const void* path);
+  void (*draw_image)(
+      void* user_data,
+      const void* image,
+      rive_rs_image_sampler sampler,
+      rive_rs_blend_mode blend_mode,
+      float opacity);
+  void (*draw_image_mesh)(
+      void* user_data,
+      const void* image,
+      rive_rs_image_sampler sampler,
+      const void* vertices,
+      const void* uv_coords,
+      const void* indices,
+      uint32_t vertex_count,
+      uint32_t index_count,
+      rive_rs_blend_mode blend_mode,
+      float opacity);
+} rive_rs_renderer_callbacks;
+
 typedef bool (*rive_rs_file_asset_loader_load_contents_fn)(
     void* user_data,
     rive_rs_file_asset* asset,
     rive_rs_bytes_view in_band_bytes,
     rive_rs_factory* factory);
 
+typedef void (*rive_rs_file_asset_loader_release_fn)(void* user_data);
+
 typedef struct rive_rs_file_asset_loader_callbacks {
   rive_rs_file_asset_loader_load_contents_fn load_contents;
   void* user_data;
+  /* Added in ABI version 2. Optional; called once when the provider no
+   * longer uses `user_data`, which may be after the load call returns if the
+   * file keeps its loader. */
+  rive_rs_file_asset_loader_release_fn release;
 } rive_rs_file_asset_loader_callbacks;
 
+// Bumped whenever a struct passed across the ABI changes layout.
 RIVE_RS_API uint32_t rive_rs_abi_version(void);
 
 RIVE_RS_API rive_rs_factory* rive_rs_factory_default(void);
 RIVE_RS_API rive_rs_factory* rive_rs_factory_webgl2(void);
 RIVE_RS_API rive_rs_factory* rive_rs_factory_webgpu(void);
+RIVE_RS_API rive_rs_status rive_rs_factory_from_backend(
+    const rive_rs_render_backend* backend,
+    rive_rs_factory** out_factory);
 RIVE_RS_API void rive_rs_factory_ref(rive_rs_factory* factory);
 RIVE_RS_API void rive_rs_factory_unref(rive_rs_factory* factory);
+// False for `rive_rs_factory_default`, whose render objects draw nothing.
+RIVE_RS_API bool rive_rs_factory_draws(const rive_rs_factory* factory);
 
 RIVE_RS_API rive_rs_status rive_rs_load_file(
     rive_rs_factory* factory,
@@ -236,6 +545,15 @@ RIVE_RS_API rive_rs_status rive_rs_file_bindable_artboard_from_artboard(
     rive_rs_artboard* artboard,
     rive_rs_bindable_artboard** out_bindable_artboard);
 RIVE_RS_API bool rive_rs_file_has_audio(const rive_rs_file* file);
+RIVE_RS_API size_t rive_rs_file_asset_count(const rive_rs_file* file);
+RIVE_RS_API rive_rs_status rive_rs_file_asset_at(
+    const rive_rs_file* file,
+    size_t index,
+    rive_rs_file_asset** out_asset);
+// Core objects across the file's source artboards.
+RIVE_RS_API size_t rive_rs_file_object_count(const rive_rs_file* file);
+// Estimated bytes those objects occupy, excluding the buffers they own.
+RIVE_RS_API size_t rive_rs_file_object_bytes(const rive_rs_file* file);
 RIVE_RS_API size_t rive_rs_file_enum_count(const rive_rs_file* file);
 RIVE_RS_API rive_rs_status rive_rs_file_enum_name_at(
     const rive_rs_file* file,
@@ -276,6 +594,7 @@ RIVE_RS_API bool rive_rs_artboard_frame_origin(const rive_rs_artboard* artboard)
 RIVE_RS_API void rive_rs_artboard_set_frame_origin(
     rive_rs_artboard* artboard,
     bool frame_origin);
+RIVE_RS_API rive_rs_vec2 rive_rs_artboard_origin(const rive_rs_artboard* artboard);
 RIVE_RS_API bool rive_rs_artboard_has_audio(const rive_rs_artboard* artboard);
 RIVE_RS_API float rive_rs_artboard_volume(const rive_rs_artboard* artboard);
 RIVE_RS_API void rive_rs_artboard_set_volume(rive_rs_artboard* artboard, float volume);
@@ -353,6 +672,32 @@ RIVE_RS_API rive_rs_status rive_rs_artboard_root_bone_by_name(
     rive_rs_artboard* artboard,
     rive_rs_str_view name,
     rive_rs_root_bone** out_root_bone);
+RIVE_RS_API size_t rive_rs_artboard_object_count(
+    const rive_rs_artboard* artboard);
+// Whether an image component of the artboard draws `asset`.
+RIVE_RS_API bool rive_rs_artboard_uses_image_asset(
+    const rive_rs_artboard* artboard,
+    const rive_rs_file_asset* asset);
+RIVE_RS_API rive_rs_status rive_rs_artboard_component_at(
+    const rive_rs_artboard* artboard,
+    size_t index,
+    rive_rs_component_info* out_component);
+RIVE_RS_API rive_rs_status rive_rs_artboard_transform_component_at(
+    rive_rs_artboard* artboard,
+    size_t index,
+    rive_rs_transform_component** out_component);
+RIVE_RS_API rive_rs_status rive_rs_artboard_node_at(
+    rive_rs_artboard* artboard,
+    size_t index,
+    rive_rs_node** out_node);
+RIVE_RS_API rive_rs_status rive_rs_artboard_bone_at(
+    rive_rs_artboard* artboard,
+    size_t index,
+    rive_rs_bone** out_bone);
+RIVE_RS_API rive_rs_status rive_rs_artboard_root_bone_at(
+    rive_rs_artboard* artboard,
+    size_t index,
+    rive_rs_root_bone** out_root_bone);
 RIVE_RS_API rive_rs_status rive_rs_artboard_text_value_run_by_name(
     rive_rs_artboard* artboard,
     rive_rs_str_view name,
@@ -370,12 +715,20 @@ RIVE_RS_API rive_rs_status rive_rs_artboard_bind_view_model_instance(
     rive_rs_artboard* artboard,
     rive_rs_view_model_instance* instance);
 
+RIVE_RS_API rive_rs_status rive_rs_renderer_new(
+    const rive_rs_renderer_callbacks* callbacks,
+    rive_rs_renderer** out_renderer);
+RIVE_RS_API void rive_rs_renderer_delete(rive_rs_renderer* renderer);
+
 RIVE_RS_API rive_rs_status rive_rs_webgl2_renderer_new(
     int32_t width,
     int32_t height,
     rive_rs_webgl2_renderer** out_renderer);
 RIVE_RS_API void rive_rs_webgl2_renderer_delete(
     rive_rs_webgl2_renderer* renderer);
+RIVE_RS_API rive_rs_status rive_rs_webgl2_renderer_bind_target(
+    rive_rs_webgl2_renderer* renderer,
+    rive_rs_render_target* target);
 RIVE_RS_API rive_rs_status rive_rs_webgl2_renderer_clear(
     rive_rs_webgl2_renderer* renderer);
 RIVE_RS_API rive_rs_status rive_rs_webgl2_renderer_flush(
@@ -416,6 +769,9 @@ RIVE_RS_API rive_rs_status rive_rs_webgpu_renderer_new(
     rive_rs_webgpu_renderer** out_renderer);
 RIVE_RS_API void rive_rs_webgpu_renderer_delete(
     rive_rs_webgpu_renderer* renderer);
+RIVE_RS_API rive_rs_status rive_rs_webgpu_renderer_bind_target(
+    rive_rs_webgpu_renderer* renderer,
+    rive_rs_render_target* target);
 RIVE_RS_API rive_rs_status rive_rs_webgpu_renderer_clear(
     rive_rs_webgpu_renderer* renderer);
 RIVE_RS_API rive_rs_status rive_rs_webgpu_renderer_flush(
@@ -449,6 +805,26 @@ RIVE_RS_API rive_rs_status rive_rs_webgpu_renderer_save_clip_rect(
     float bottom);
 RIVE_RS_API rive_rs_status rive_rs_webgpu_renderer_restore_clip_rect(
     rive_rs_webgpu_renderer* renderer);
+
+/* Offscreen targets renderers can bind instead of their canvas. A NULL target
+ * passed to `*_bind_target` rebinds the canvas. */
+RIVE_RS_API rive_rs_status rive_rs_render_target_new(
+    int32_t width,
+    int32_t height,
+    rive_rs_render_target** out_target);
+RIVE_RS_API void rive_rs_render_target_ref(rive_rs_render_target* target);
+RIVE_RS_API void rive_rs_render_target_unref(rive_rs_render_target* target);
+RIVE_RS_API int32_t rive_rs_render_target_width(const rive_rs_render_target* target);
+RIVE_RS_API int32_t rive_rs_render_target_height(const rive_rs_render_target* target);
+/* Stores CPU-rendered contents as unpremultiplied RGBA8, `width * height * 4`
+ * bytes. */
+RIVE_RS_API rive_rs_status rive_rs_render_target_write_pixels(
+    rive_rs_render_target* target,
+    rive_rs_bytes_view rgba);
+RIVE_RS_API rive_rs_status rive_rs_render_target_image(
+    rive_rs_factory* factory,
+    rive_rs_render_target* target,
+    rive_rs_render_image** out_image);
 RIVE_RS_API void rive_rs_bindable_artboard_ref(rive_rs_bindable_artboard* bindable_artboard);
 RIVE_RS_API void rive_rs_bindable_artboard_unref(rive_rs_bindable_artboard* bindable_artboard);
 
@@ -566,6 +942,14 @@ RIVE_RS_API void rive_rs_linear_animation_instance_set_time(
     float seconds);
 RIVE_RS_API bool rive_rs_linear_animation_instance_did_loop(
     const rive_rs_linear_animation_instance* instance);
+// Audio assets played by `AudioEvent`s the timeline crossed during the last
+// advance, in firing order. The assets are owned by their file.
+RIVE_RS_API size_t rive_rs_linear_animation_instance_reported_audio_count(
+    const rive_rs_linear_animation_instance* instance);
+RIVE_RS_API rive_rs_status rive_rs_linear_animation_instance_reported_audio_asset(
+    const rive_rs_linear_animation_instance* instance,
+    size_t index,
+    rive_rs_file_asset** out_asset);
 
 RIVE_RS_API rive_rs_status rive_rs_state_machine_instance_new(
     rive_rs_state_machine* state_machine,
@@ -573,6 +957,36 @@ RIVE_RS_API rive_rs_status rive_rs_state_machine_instance_new(
     rive_rs_state_machine_instance** out_instance);
 RIVE_RS_API rive_rs_str_view rive_rs_state_machine_name(
     const rive_rs_state_machine* state_machine);
+RIVE_RS_API size_t rive_rs_state_machine_layer_count(
+    const rive_rs_state_machine* state_machine);
+RIVE_RS_API rive_rs_status rive_rs_state_machine_layer_at(
+    const rive_rs_state_machine* state_machine,
+    size_t layer_index,
+    rive_rs_state_machine_layer_info* out_layer);
+RIVE_RS_API rive_rs_status rive_rs_state_machine_state_at(
+    const rive_rs_state_machine* state_machine,
+    size_t layer_index,
+    size_t state_index,
+    rive_rs_layer_state_info* out_state);
+RIVE_RS_API rive_rs_status rive_rs_state_machine_transition_at(
+    const rive_rs_state_machine* state_machine,
+    size_t layer_index,
+    size_t state_index,
+    size_t transition_index,
+    rive_rs_state_transition_info* out_transition);
+RIVE_RS_API rive_rs_status rive_rs_state_machine_condition_at(
+    const rive_rs_state_machine* state_machine,
+    size_t layer_index,
+    size_t state_index,
+    size_t transition_index,
+    size_t condition_index,
+    rive_rs_transition_condition_info* out_condition);
+RIVE_RS_API size_t rive_rs_state_machine_listener_count(
+    const rive_rs_state_machine* state_machine);
+RIVE_RS_API rive_rs_status rive_rs_state_machine_listener_at(
+    const rive_rs_state_machine* state_machine,
+    size_t index,
+    rive_rs_state_machine_listener_info* out_listener);
 RIVE_RS_API void rive_rs_state_machine_instance_delete(
     rive_rs_state_machine_instance* instance);
 RIVE_RS_API rive_rs_status rive_rs_state_machine_instance_advance(
@@ -637,12 +1051,28 @@ RIVE_RS_API rive_rs_status rive_rs_state_machine_reported_event_property_at(
     size_t reported_event_index,
     size_t property_index,
     rive_rs_event_property_info* out_property);
+// Audio asset played by a reported `AudioEvent`; NOT_FOUND for other events.
+// The asset is owned by its file.
+RIVE_RS_API rive_rs_status rive_rs_state_machine_reported_event_audio_asset(
+    const rive_rs_state_machine_instance* instance,
+    size_t index,
+    rive_rs_file_asset** out_asset);
 RIVE_RS_API size_t rive_rs_state_machine_state_changed_count(
     const rive_rs_state_machine_instance* instance);
 RIVE_RS_API rive_rs_status rive_rs_state_machine_state_changed_name_at(
     const rive_rs_state_machine_instance* instance,
     size_t index,
     rive_rs_str_view* out_name);
+RIVE_RS_API size_t rive_rs_state_machine_instance_layer_count(
+    const rive_rs_state_machine_instance* instance);
+// Live state of a layer, tracked from the states each advance reports as
+// changed; `elapsed_seconds` counts the seconds advanced since the advance
+// that entered the state. State and transition indices follow
+// `rive_rs_state_machine_state_at` and `rive_rs_state_machine_transition_at`.
+RIVE_RS_API rive_rs_status rive_rs_state_machine_instance_current_state_at(
+    const rive_rs_state_machine_instance* instance,
+    size_t layer_index,
+    rive_rs_layer_current_state_info* out_state);
 RIVE_RS_API rive_rs_status rive_rs_state_machine_instance_bind_view_model_instance(
     rive_rs_state_machine_instance* instance,
     rive_rs_view_model_instance* view_model_instance);
@@ -821,8 +1251,27 @@ RIVE_RS_API rive_rs_status rive_rs_decode_font(
 RIVE_RS_API rive_rs_status rive_rs_decode_webgl2_image(
     rive_rs_bytes_view bytes,
     rive_rs_render_image** out_image);
+/* Fails with RIVE_RS_STATUS_UNSUPPORTED on the default factory, whose
+ * images hold no pixels; backend and WebGL2/WebGPU factories decode. */
+RIVE_RS_API rive_rs_status rive_rs_decode_render_image(
+    rive_rs_factory* factory,
+    rive_rs_bytes_view bytes,
+    rive_rs_render_image** out_image);
+RIVE_RS_API uint32_t rive_rs_render_image_width(const rive_rs_render_image* image);
+RIVE_RS_API uint32_t rive_rs_render_image_height(const rive_rs_render_image* image);
+RIVE_RS_API rive_rs_status rive_rs_render_image_pixels(
+    const rive_rs_render_image* image,
+    rive_rs_bytes_view* out_pixels);
 RIVE_RS_API void rive_rs_audio_source_unref(rive_rs_audio_source* audio);
+RIVE_RS_API void rive_rs_font_ref(rive_rs_font* font);
 RIVE_RS_API void rive_rs_font_unref(rive_rs_font* font);
+/* Process-wide fallback chain, consulted in order for glyphs a text run's font
+ * lacks. The provider retains the fonts; an empty chain disables fallback.
+ * It sets the runtime's global `rive::Font::gFallbackProc`, so it is shared
+ * by every factory and file and replaces any chain installed before. */
+RIVE_RS_API rive_rs_status rive_rs_set_font_fallbacks(
+    rive_rs_font* const* fonts,
+    size_t count);
 RIVE_RS_API void rive_rs_render_image_ref(rive_rs_render_image* image);
 RIVE_RS_API void rive_rs_render_image_unref(rive_rs_render_image* image);
 
@@ -842,6 +1291,13 @@ RIVE_RS_API bool rive_rs_file_asset_is_image(const rive_rs_file_asset* asset);
 RIVE_RS_API bool rive_rs_file_asset_is_font(const rive_rs_file_asset* asset);
 RIVE_RS_API rive_rs_str_view rive_rs_file_asset_cdn_uuid(
     const rive_rs_file_asset* asset);
+// Whether an image, font or audio asset has decoded contents attached.
+RIVE_RS_API bool rive_rs_file_asset_is_loaded(const rive_rs_file_asset* asset);
+// Referenced render image of an image asset; `*out_image` is null when none
+// is attached.
+RIVE_RS_API rive_rs_status rive_rs_image_asset_render_image(
+    const rive_rs_file_asset* asset,
+    rive_rs_render_image** out_image);
 
 RIVE_RS_API rive_rs_status rive_rs_file_asset_decode(
     rive_rs_factory* factory,
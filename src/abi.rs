@@ -68,6 +68,11 @@ pub struct rive_rs_webgpu_renderer {
     _private: [u8; 0],
 }
 
+#[repr(C)]
+pub struct rive_rs_render_target {
+    _private: [u8; 0],
+}
+
 #[repr(C)]
 pub struct rive_rs_linear_animation {
     _private: [u8; 0],
@@ -192,6 +197,123 @@ pub enum rive_rs_event_property_type {
     RIVE_RS_EVENT_PROPERTY_STRING = 3,
 }
 
+#[repr(i32)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum rive_rs_layer_state_type {
+    RIVE_RS_LAYER_STATE_ANIMATION = 0,
+    RIVE_RS_LAYER_STATE_BLEND = 1,
+    RIVE_RS_LAYER_STATE_ENTRY = 2,
+    RIVE_RS_LAYER_STATE_EXIT = 3,
+    RIVE_RS_LAYER_STATE_ANY = 4,
+    RIVE_RS_LAYER_STATE_OTHER = 5,
+}
+
+#[repr(i32)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum rive_rs_condition_type {
+    RIVE_RS_CONDITION_BOOL = 0,
+    RIVE_RS_CONDITION_NUMBER = 1,
+    RIVE_RS_CONDITION_TRIGGER = 2,
+    RIVE_RS_CONDITION_OTHER = 3,
+}
+
+#[repr(i32)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum rive_rs_condition_op {
+    RIVE_RS_CONDITION_OP_EQUAL = 0,
+    RIVE_RS_CONDITION_OP_NOT_EQUAL = 1,
+    RIVE_RS_CONDITION_OP_LESS_THAN_OR_EQUAL = 2,
+    RIVE_RS_CONDITION_OP_GREATER_THAN_OR_EQUAL = 3,
+    RIVE_RS_CONDITION_OP_LESS_THAN = 4,
+    RIVE_RS_CONDITION_OP_GREATER_THAN = 5,
+}
+
+#[repr(i32)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum rive_rs_listener_type {
+    RIVE_RS_LISTENER_ENTER = 0,
+    RIVE_RS_LISTENER_EXIT = 1,
+    RIVE_RS_LISTENER_DOWN = 2,
+    RIVE_RS_LISTENER_UP = 3,
+    RIVE_RS_LISTENER_MOVE = 4,
+    RIVE_RS_LISTENER_EVENT = 5,
+    RIVE_RS_LISTENER_OTHER = 6,
+}
+
+#[repr(i32)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum rive_rs_fill_rule {
+    RIVE_RS_FILL_RULE_NON_ZERO = 0,
+    RIVE_RS_FILL_RULE_EVEN_ODD = 1,
+    RIVE_RS_FILL_RULE_CLOCKWISE = 2,
+}
+
+#[repr(i32)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum rive_rs_paint_style {
+    RIVE_RS_PAINT_STYLE_STROKE = 0,
+    RIVE_RS_PAINT_STYLE_FILL = 1,
+}
+
+#[repr(i32)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum rive_rs_stroke_join {
+    RIVE_RS_STROKE_JOIN_MITER = 0,
+    RIVE_RS_STROKE_JOIN_ROUND = 1,
+    RIVE_RS_STROKE_JOIN_BEVEL = 2,
+}
+
+#[repr(i32)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum rive_rs_stroke_cap {
+    RIVE_RS_STROKE_CAP_BUTT = 0,
+    RIVE_RS_STROKE_CAP_ROUND = 1,
+    RIVE_RS_STROKE_CAP_SQUARE = 2,
+}
+
+#[repr(i32)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum rive_rs_blend_mode {
+    RIVE_RS_BLEND_MODE_SRC_OVER = 3,
+    RIVE_RS_BLEND_MODE_SCREEN = 14,
+    RIVE_RS_BLEND_MODE_OVERLAY = 15,
+    RIVE_RS_BLEND_MODE_DARKEN = 16,
+    RIVE_RS_BLEND_MODE_LIGHTEN = 17,
+    RIVE_RS_BLEND_MODE_COLOR_DODGE = 18,
+    RIVE_RS_BLEND_MODE_COLOR_BURN = 19,
+    RIVE_RS_BLEND_MODE_HARD_LIGHT = 20,
+    RIVE_RS_BLEND_MODE_SOFT_LIGHT = 21,
+    RIVE_RS_BLEND_MODE_DIFFERENCE = 22,
+    RIVE_RS_BLEND_MODE_EXCLUSION = 23,
+    RIVE_RS_BLEND_MODE_MULTIPLY = 24,
+    RIVE_RS_BLEND_MODE_HUE = 25,
+    RIVE_RS_BLEND_MODE_SATURATION = 26,
+    RIVE_RS_BLEND_MODE_COLOR = 27,
+    RIVE_RS_BLEND_MODE_LUMINOSITY = 28,
+}
+
+#[repr(i32)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum rive_rs_image_wrap {
+    RIVE_RS_IMAGE_WRAP_CLAMP = 0,
+    RIVE_RS_IMAGE_WRAP_REPEAT = 1,
+    RIVE_RS_IMAGE_WRAP_MIRROR = 2,
+}
+
+#[repr(i32)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum rive_rs_image_filter {
+    RIVE_RS_IMAGE_FILTER_BILINEAR = 0,
+    RIVE_RS_IMAGE_FILTER_NEAREST = 1,
+}
+
+#[repr(i32)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum rive_rs_render_buffer_type {
+    RIVE_RS_RENDER_BUFFER_TYPE_INDEX = 0,
+    RIVE_RS_RENDER_BUFFER_TYPE_VERTEX = 1,
+}
+
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct rive_rs_vec2 {
@@ -200,7 +322,7 @@ pub struct rive_rs_vec2 {
 }
 
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Copy, Clone, PartialEq)]
 pub struct rive_rs_mat2d {
     pub xx: f32,
     pub xy: f32,
@@ -262,6 +384,272 @@ pub struct rive_rs_event_property_info {
     pub string_value: rive_rs_str_view,
 }
 
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct rive_rs_component_info {
+    pub name: rive_rs_str_view,
+    pub type_name: rive_rs_str_view,
+    pub type_key: u16,
+    pub has_parent: bool,
+    pub parent_index: usize,
+    pub is_transform_component: bool,
+    pub is_node: bool,
+    pub is_bone: bool,
+    pub is_root_bone: bool,
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct rive_rs_state_machine_layer_info {
+    pub name: rive_rs_str_view,
+    pub state_count: usize,
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct rive_rs_layer_state_info {
+    pub r#type: rive_rs_layer_state_type,
+    pub name: rive_rs_str_view,
+    pub transition_count: usize,
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct rive_rs_state_transition_info {
+    pub has_target: bool,
+    pub target_state: usize,
+    pub duration: u32,
+    pub duration_is_percentage: bool,
+    pub enable_exit_time: bool,
+    pub exit_time: u32,
+    pub exit_time_is_percentage: bool,
+    pub disabled: bool,
+    pub condition_count: usize,
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct rive_rs_transition_condition_info {
+    pub r#type: rive_rs_condition_type,
+    pub has_input: bool,
+    pub input_index: usize,
+    pub input_name: rive_rs_str_view,
+    pub op: rive_rs_condition_op,
+    pub value: f32,
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct rive_rs_state_machine_listener_info {
+    pub name: rive_rs_str_view,
+    pub r#type: rive_rs_listener_type,
+    pub target_index: usize,
+    pub action_count: usize,
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct rive_rs_layer_current_state_info {
+    pub layer_name: rive_rs_str_view,
+    pub state_index: usize,
+    pub state_type: rive_rs_layer_state_type,
+    pub state_name: rive_rs_str_view,
+    pub elapsed_seconds: f32,
+    pub is_transitioning: bool,
+    pub transition_state_index: usize,
+    pub transition_index: usize,
+    pub has_state_from: bool,
+    pub state_from_index: usize,
+    pub mix: f32,
+    pub mix_seconds: f32,
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct rive_rs_image_sampler {
+    pub wrap_x: rive_rs_image_wrap,
+    pub wrap_y: rive_rs_image_wrap,
+    pub filter: rive_rs_image_filter,
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct rive_rs_render_backend {
+    pub user_data: *mut c_void,
+    pub backend_key: u64,
+    pub release: Option<unsafe extern "C" fn(user_data: *mut c_void)>,
+
+    pub make_path: Option<
+        unsafe extern "C" fn(user_data: *mut c_void, fill_rule: rive_rs_fill_rule) -> *mut c_void,
+    >,
+    pub make_paint: Option<unsafe extern "C" fn(user_data: *mut c_void) -> *mut c_void>,
+    pub make_linear_gradient: Option<
+        unsafe extern "C" fn(
+            user_data: *mut c_void,
+            start_x: f32,
+            start_y: f32,
+            end_x: f32,
+            end_y: f32,
+            colors: *const u32,
+            stops: *const f32,
+            count: usize,
+        ) -> *mut c_void,
+    >,
+    pub make_radial_gradient: Option<
+        unsafe extern "C" fn(
+            user_data: *mut c_void,
+            center_x: f32,
+            center_y: f32,
+            radius: f32,
+            colors: *const u32,
+            stops: *const f32,
+            count: usize,
+        ) -> *mut c_void,
+    >,
+    pub decode_image: Option<
+        unsafe extern "C" fn(user_data: *mut c_void, bytes: rive_rs_bytes_view) -> *mut c_void,
+    >,
+    pub make_buffer: Option<
+        unsafe extern "C" fn(
+            user_data: *mut c_void,
+            buffer_type: rive_rs_render_buffer_type,
+            flags: u32,
+            size_in_bytes: usize,
+        ) -> *mut c_void,
+    >,
+
+    pub release_path: Option<unsafe extern "C" fn(user_data: *mut c_void, path: *mut c_void)>,
+    pub release_paint: Option<unsafe extern "C" fn(user_data: *mut c_void, paint: *mut c_void)>,
+    pub release_shader: Option<unsafe extern "C" fn(user_data: *mut c_void, shader: *mut c_void)>,
+    pub release_image: Option<unsafe extern "C" fn(user_data: *mut c_void, image: *mut c_void)>,
+    pub release_buffer: Option<unsafe extern "C" fn(user_data: *mut c_void, buffer: *mut c_void)>,
+
+    pub path_rewind: Option<unsafe extern "C" fn(user_data: *mut c_void, path: *mut c_void)>,
+    pub path_fill_rule: Option<
+        unsafe extern "C" fn(
+            user_data: *mut c_void,
+            path: *mut c_void,
+            fill_rule: rive_rs_fill_rule,
+        ),
+    >,
+    pub path_move_to:
+        Option<unsafe extern "C" fn(user_data: *mut c_void, path: *mut c_void, x: f32, y: f32)>,
+    pub path_line_to:
+        Option<unsafe extern "C" fn(user_data: *mut c_void, path: *mut c_void, x: f32, y: f32)>,
+    pub path_cubic_to: Option<
+        unsafe extern "C" fn(
+            user_data: *mut c_void,
+            path: *mut c_void,
+            out_x: f32,
+            out_y: f32,
+            in_x: f32,
+            in_y: f32,
+            x: f32,
+            y: f32,
+        ),
+    >,
+    pub path_close: Option<unsafe extern "C" fn(user_data: *mut c_void, path: *mut c_void)>,
+    pub path_add_path: Option<
+        unsafe extern "C" fn(
+            user_data: *mut c_void,
+            path: *mut c_void,
+            other: *const c_void,
+            transform: *const rive_rs_mat2d,
+        ),
+    >,
+
+    pub paint_color:
+        Option<unsafe extern "C" fn(user_data: *mut c_void, paint: *mut c_void, argb: u32)>,
+    pub paint_style: Option<
+        unsafe extern "C" fn(
+            user_data: *mut c_void,
+            paint: *mut c_void,
+            style: rive_rs_paint_style,
+        ),
+    >,
+    pub paint_thickness:
+        Option<unsafe extern "C" fn(user_data: *mut c_void, paint: *mut c_void, thickness: f32)>,
+    pub paint_join: Option<
+        unsafe extern "C" fn(user_data: *mut c_void, paint: *mut c_void, join: rive_rs_stroke_join),
+    >,
+    pub paint_cap: Option<
+        unsafe extern "C" fn(user_data: *mut c_void, paint: *mut c_void, cap: rive_rs_stroke_cap),
+    >,
+    pub paint_blend_mode: Option<
+        unsafe extern "C" fn(
+            user_data: *mut c_void,
+            paint: *mut c_void,
+            blend_mode: rive_rs_blend_mode,
+        ),
+    >,
+    pub paint_shader: Option<
+        unsafe extern "C" fn(user_data: *mut c_void, paint: *mut c_void, shader: *const c_void),
+    >,
+    pub paint_feather:
+        Option<unsafe extern "C" fn(user_data: *mut c_void, paint: *mut c_void, feather: f32)>,
+
+    pub image_size: Option<
+        unsafe extern "C" fn(
+            user_data: *mut c_void,
+            image: *const c_void,
+            out_width: *mut u32,
+            out_height: *mut u32,
+        ),
+    >,
+    pub image_pixels:
+        Option<unsafe extern "C" fn(user_data: *mut c_void, image: *const c_void) -> *const u8>,
+    pub buffer_map:
+        Option<unsafe extern "C" fn(user_data: *mut c_void, buffer: *mut c_void) -> *mut u8>,
+    pub buffer_unmap: Option<unsafe extern "C" fn(user_data: *mut c_void, buffer: *mut c_void)>,
+    pub make_image: Option<
+        unsafe extern "C" fn(
+            user_data: *mut c_void,
+            width: u32,
+            height: u32,
+            rgba: *const u8,
+        ) -> *mut c_void,
+    >,
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct rive_rs_renderer_callbacks {
+    pub user_data: *mut c_void,
+    pub backend_key: u64,
+    pub save: Option<unsafe extern "C" fn(user_data: *mut c_void)>,
+    pub restore: Option<unsafe extern "C" fn(user_data: *mut c_void)>,
+    pub transform:
+        Option<unsafe extern "C" fn(user_data: *mut c_void, matrix: *const rive_rs_mat2d)>,
+    pub modulate_opacity: Option<unsafe extern "C" fn(user_data: *mut c_void, opacity: f32)>,
+    pub draw_path: Option<
+        unsafe extern "C" fn(user_data: *mut c_void, path: *const c_void, paint: *const c_void),
+    >,
+    pub clip_path: Option<unsafe extern "C" fn(user_data: *mut c_void, path: *const c_void)>,
+    pub draw_image: Option<
+        unsafe extern "C" fn(
+            user_data: *mut c_void,
+            image: *const c_void,
+            sampler: rive_rs_image_sampler,
+            blend_mode: rive_rs_blend_mode,
+            opacity: f32,
+        ),
+    >,
+    pub draw_image_mesh: Option<
+        unsafe extern "C" fn(
+            user_data: *mut c_void,
+            image: *const c_void,
+            sampler: rive_rs_image_sampler,
+            vertices: *const c_void,
+            uv_coords: *const c_void,
+            indices: *const c_void,
+            vertex_count: u32,
+            index_count: u32,
+            blend_mode: rive_rs_blend_mode,
+            opacity: f32,
+        ),
+    >,
+}
+
 pub type rive_rs_file_asset_loader_load_contents_fn = Option<
     unsafe extern "C" fn(
         user_data: *mut c_void,
@@ -271,11 +659,15 @@ pub type rive_rs_file_asset_loader_load_contents_fn = Option<
     ) -> bool,
 >;
 
+pub type rive_rs_file_asset_loader_release_fn =
+    Option<unsafe extern "C" fn(user_data: *mut c_void)>;
+
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct rive_rs_file_asset_loader_callbacks {
     pub load_contents: rive_rs_file_asset_loader_load_contents_fn,
     pub user_data: *mut c_void,
+    pub release: rive_rs_file_asset_loader_release_fn,
 }
 
 unsafe extern "C" {
@@ -284,8 +676,13 @@ unsafe extern "C" {
     pub fn rive_rs_factory_default() -> *mut rive_rs_factory;
     pub fn rive_rs_factory_webgl2() -> *mut rive_rs_factory;
     pub fn rive_rs_factory_webgpu() -> *mut rive_rs_factory;
+    pub fn rive_rs_factory_from_backend(
+        backend: *const rive_rs_render_backend,
+        out_factory: *mut *mut rive_rs_factory,
+    ) -> rive_rs_status;
     pub fn rive_rs_factory_ref(factory: *mut rive_rs_factory);
     pub fn rive_rs_factory_unref(factory: *mut rive_rs_factory);
+    pub fn rive_rs_factory_draws(factory: *const rive_rs_factory) -> bool;
 
     pub fn rive_rs_load_file(
         factory: *mut rive_rs_factory,
@@ -347,6 +744,14 @@ unsafe extern "C" {
         out_bindable_artboard: *mut *mut rive_rs_bindable_artboard,
     ) -> rive_rs_status;
     pub fn rive_rs_file_has_audio(file: *const rive_rs_file) -> bool;
+    pub fn rive_rs_file_asset_count(file: *const rive_rs_file) -> usize;
+    pub fn rive_rs_file_asset_at(
+        file: *const rive_rs_file,
+        index: usize,
+        out_asset: *mut *mut rive_rs_file_asset,
+    ) -> rive_rs_status;
+    pub fn rive_rs_file_object_count(file: *const rive_rs_file) -> usize;
+    pub fn rive_rs_file_object_bytes(file: *const rive_rs_file) -> usize;
     pub fn rive_rs_file_enum_count(file: *const rive_rs_file) -> usize;
     pub fn rive_rs_file_enum_name_at(
         file: *const rive_rs_file,
@@ -389,6 +794,7 @@ unsafe extern "C" {
     pub fn rive_rs_artboard_set_height(artboard: *mut rive_rs_artboard, height: f32);
     pub fn rive_rs_artboard_frame_origin(artboard: *const rive_rs_artboard) -> bool;
     pub fn rive_rs_artboard_set_frame_origin(artboard: *mut rive_rs_artboard, frame_origin: bool);
+    pub fn rive_rs_artboard_origin(artboard: *const rive_rs_artboard) -> rive_rs_vec2;
     pub fn rive_rs_artboard_has_audio(artboard: *const rive_rs_artboard) -> bool;
     pub fn rive_rs_artboard_volume(artboard: *const rive_rs_artboard) -> f32;
     pub fn rive_rs_artboard_set_volume(artboard: *mut rive_rs_artboard, volume: f32);
@@ -482,6 +888,36 @@ unsafe extern "C" {
         name: rive_rs_str_view,
         out_root_bone: *mut *mut rive_rs_root_bone,
     ) -> rive_rs_status;
+    pub fn rive_rs_artboard_object_count(artboard: *const rive_rs_artboard) -> usize;
+    pub fn rive_rs_artboard_uses_image_asset(
+        artboard: *const rive_rs_artboard,
+        asset: *const rive_rs_file_asset,
+    ) -> bool;
+    pub fn rive_rs_artboard_component_at(
+        artboard: *const rive_rs_artboard,
+        index: usize,
+        out_component: *mut rive_rs_component_info,
+    ) -> rive_rs_status;
+    pub fn rive_rs_artboard_transform_component_at(
+        artboard: *mut rive_rs_artboard,
+        index: usize,
+        out_component: *mut *mut rive_rs_transform_component,
+    ) -> rive_rs_status;
+    pub fn rive_rs_artboard_node_at(
+        artboard: *mut rive_rs_artboard,
+        index: usize,
+        out_node: *mut *mut rive_rs_node,
+    ) -> rive_rs_status;
+    pub fn rive_rs_artboard_bone_at(
+        artboard: *mut rive_rs_artboard,
+        index: usize,
+        out_bone: *mut *mut rive_rs_bone,
+    ) -> rive_rs_status;
+    pub fn rive_rs_artboard_root_bone_at(
+        artboard: *mut rive_rs_artboard,
+        index: usize,
+        out_root_bone: *mut *mut rive_rs_root_bone,
+    ) -> rive_rs_status;
     pub fn rive_rs_artboard_text_value_run_by_name(
         artboard: *mut rive_rs_artboard,
         name: rive_rs_str_view,
@@ -503,12 +939,22 @@ unsafe extern "C" {
         instance: *mut rive_rs_view_model_instance,
     ) -> rive_rs_status;
 
+    pub fn rive_rs_renderer_new(
+        callbacks: *const rive_rs_renderer_callbacks,
+        out_renderer: *mut *mut rive_rs_renderer,
+    ) -> rive_rs_status;
+    pub fn rive_rs_renderer_delete(renderer: *mut rive_rs_renderer);
+
     pub fn rive_rs_webgl2_renderer_new(
         width: i32,
         height: i32,
         out_renderer: *mut *mut rive_rs_webgl2_renderer,
     ) -> rive_rs_status;
     pub fn rive_rs_webgl2_renderer_delete(renderer: *mut rive_rs_webgl2_renderer);
+    pub fn rive_rs_webgl2_renderer_bind_target(
+        renderer: *mut rive_rs_webgl2_renderer,
+        target: *mut rive_rs_render_target,
+    ) -> rive_rs_status;
     pub fn rive_rs_webgl2_renderer_clear(renderer: *mut rive_rs_webgl2_renderer) -> rive_rs_status;
     pub fn rive_rs_webgl2_renderer_flush(renderer: *mut rive_rs_webgl2_renderer) -> rive_rs_status;
     pub fn rive_rs_webgl2_renderer_resize(
@@ -553,6 +999,10 @@ unsafe extern "C" {
         out_renderer: *mut *mut rive_rs_webgpu_renderer,
     ) -> rive_rs_status;
     pub fn rive_rs_webgpu_renderer_delete(renderer: *mut rive_rs_webgpu_renderer);
+    pub fn rive_rs_webgpu_renderer_bind_target(
+        renderer: *mut rive_rs_webgpu_renderer,
+        target: *mut rive_rs_render_target,
+    ) -> rive_rs_status;
     pub fn rive_rs_webgpu_renderer_clear(renderer: *mut rive_rs_webgpu_renderer) -> rive_rs_status;
     pub fn rive_rs_webgpu_renderer_flush(renderer: *mut rive_rs_webgpu_renderer) -> rive_rs_status;
     pub fn rive_rs_webgpu_renderer_resize(
@@ -590,6 +1040,25 @@ unsafe extern "C" {
     pub fn rive_rs_webgpu_renderer_restore_clip_rect(
         renderer: *mut rive_rs_webgpu_renderer,
     ) -> rive_rs_status;
+
+    pub fn rive_rs_render_target_new(
+        width: i32,
+        height: i32,
+        out_target: *mut *mut rive_rs_render_target,
+    ) -> rive_rs_status;
+    pub fn rive_rs_render_target_ref(target: *mut rive_rs_render_target);
+    pub fn rive_rs_render_target_unref(target: *mut rive_rs_render_target);
+    pub fn rive_rs_render_target_width(target: *const rive_rs_render_target) -> i32;
+    pub fn rive_rs_render_target_height(target: *const rive_rs_render_target) -> i32;
+    pub fn rive_rs_render_target_write_pixels(
+        target: *mut rive_rs_render_target,
+        rgba: rive_rs_bytes_view,
+    ) -> rive_rs_status;
+    pub fn rive_rs_render_target_image(
+        factory: *mut rive_rs_factory,
+        target: *mut rive_rs_render_target,
+        out_image: *mut *mut rive_rs_render_image,
+    ) -> rive_rs_status;
     pub fn rive_rs_bindable_artboard_ref(bindable_artboard: *mut rive_rs_bindable_artboard);
     pub fn rive_rs_bindable_artboard_unref(bindable_artboard: *mut rive_rs_bindable_artboard);
 
@@ -731,6 +1200,14 @@ unsafe extern "C" {
     pub fn rive_rs_linear_animation_instance_did_loop(
         instance: *const rive_rs_linear_animation_instance,
     ) -> bool;
+    pub fn rive_rs_linear_animation_instance_reported_audio_count(
+        instance: *const rive_rs_linear_animation_instance,
+    ) -> usize;
+    pub fn rive_rs_linear_animation_instance_reported_audio_asset(
+        instance: *const rive_rs_linear_animation_instance,
+        index: usize,
+        out_asset: *mut *mut rive_rs_file_asset,
+    ) -> rive_rs_status;
 
     pub fn rive_rs_state_machine_instance_new(
         state_machine: *mut rive_rs_state_machine,
@@ -740,6 +1217,41 @@ unsafe extern "C" {
     pub fn rive_rs_state_machine_name(
         state_machine: *const rive_rs_state_machine,
     ) -> rive_rs_str_view;
+    pub fn rive_rs_state_machine_layer_count(state_machine: *const rive_rs_state_machine) -> usize;
+    pub fn rive_rs_state_machine_layer_at(
+        state_machine: *const rive_rs_state_machine,
+        layer_index: usize,
+        out_layer: *mut rive_rs_state_machine_layer_info,
+    ) -> rive_rs_status;
+    pub fn rive_rs_state_machine_state_at(
+        state_machine: *const rive_rs_state_machine,
+        layer_index: usize,
+        state_index: usize,
+        out_state: *mut rive_rs_layer_state_info,
+    ) -> rive_rs_status;
+    pub fn rive_rs_state_machine_transition_at(
+        state_machine: *const rive_rs_state_machine,
+        layer_index: usize,
+        state_index: usize,
+        transition_index: usize,
+        out_transition: *mut rive_rs_state_transition_info,
+    ) -> rive_rs_status;
+    pub fn rive_rs_state_machine_condition_at(
+        state_machine: *const rive_rs_state_machine,
+        layer_index: usize,
+        state_index: usize,
+        transition_index: usize,
+        condition_index: usize,
+        out_condition: *mut rive_rs_transition_condition_info,
+    ) -> rive_rs_status;
+    pub fn rive_rs_state_machine_listener_count(
+        state_machine: *const rive_rs_state_machine,
+    ) -> usize;
+    pub fn rive_rs_state_machine_listener_at(
+        state_machine: *const rive_rs_state_machine,
+        index: usize,
+        out_listener: *mut rive_rs_state_machine_listener_info,
+    ) -> rive_rs_status;
     pub fn rive_rs_state_machine_instance_delete(instance: *mut rive_rs_state_machine_instance);
     pub fn rive_rs_state_machine_instance_advance(
         instance: *mut rive_rs_state_machine_instance,
@@ -819,6 +1331,11 @@ unsafe extern "C" {
         property_index: usize,
         out_property: *mut rive_rs_event_property_info,
     ) -> rive_rs_status;
+    pub fn rive_rs_state_machine_reported_event_audio_asset(
+        instance: *const rive_rs_state_machine_instance,
+        index: usize,
+        out_asset: *mut *mut rive_rs_file_asset,
+    ) -> rive_rs_status;
     pub fn rive_rs_state_machine_state_changed_count(
         instance: *const rive_rs_state_machine_instance,
     ) -> usize;
@@ -827,6 +1344,14 @@ unsafe extern "C" {
         index: usize,
         out_name: *mut rive_rs_str_view,
     ) -> rive_rs_status;
+    pub fn rive_rs_state_machine_instance_layer_count(
+        instance: *const rive_rs_state_machine_instance,
+    ) -> usize;
+    pub fn rive_rs_state_machine_instance_current_state_at(
+        instance: *const rive_rs_state_machine_instance,
+        layer_index: usize,
+        out_state: *mut rive_rs_layer_current_state_info,
+    ) -> rive_rs_status;
     pub fn rive_rs_state_machine_instance_bind_view_model_instance(
         instance: *mut rive_rs_state_machine_instance,
         view_model_instance: *mut rive_rs_view_model_instance,
@@ -1047,8 +1572,24 @@ unsafe extern "C" {
         bytes: rive_rs_bytes_view,
         out_image: *mut *mut rive_rs_render_image,
     ) -> rive_rs_status;
+    pub fn rive_rs_decode_render_image(
+        factory: *mut rive_rs_factory,
+        bytes: rive_rs_bytes_view,
+        out_image: *mut *mut rive_rs_render_image,
+    ) -> rive_rs_status;
+    pub fn rive_rs_render_image_width(image: *const rive_rs_render_image) -> u32;
+    pub fn rive_rs_render_image_height(image: *const rive_rs_render_image) -> u32;
+    pub fn rive_rs_render_image_pixels(
+        image: *const rive_rs_render_image,
+        out_pixels: *mut rive_rs_bytes_view,
+    ) -> rive_rs_status;
     pub fn rive_rs_audio_source_unref(audio: *mut rive_rs_audio_source);
+    pub fn rive_rs_font_ref(font: *mut rive_rs_font);
     pub fn rive_rs_font_unref(font: *mut rive_rs_font);
+    pub fn rive_rs_set_font_fallbacks(
+        fonts: *const *mut rive_rs_font,
+        count: usize,
+    ) -> rive_rs_status;
     pub fn rive_rs_render_image_ref(image: *mut rive_rs_render_image);
     pub fn rive_rs_render_image_unref(image: *mut rive_rs_render_image);
 
@@ -1065,6 +1606,11 @@ unsafe extern "C" {
     pub fn rive_rs_file_asset_is_image(asset: *const rive_rs_file_asset) -> bool;
     pub fn rive_rs_file_asset_is_font(asset: *const rive_rs_file_asset) -> bool;
     pub fn rive_rs_file_asset_cdn_uuid(asset: *const rive_rs_file_asset) -> rive_rs_str_view;
+    pub fn rive_rs_file_asset_is_loaded(asset: *const rive_rs_file_asset) -> bool;
+    pub fn rive_rs_image_asset_render_image(
+        asset: *const rive_rs_file_asset,
+        out_image: *mut *mut rive_rs_render_image,
+    ) -> rive_rs_status;
 
     pub fn rive_rs_file_asset_decode(
         factory: *mut rive_rs_factory,
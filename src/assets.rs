@@ -0,0 +1,152 @@
+use core::ffi::c_void;
+use core::slice;
+use std::cell::RefCell;
+use std::ptr::NonNull;
+use std::rc::Rc;
+
+use crate::Error;
+use crate::abi;
+use crate::backend::{guard, handle_mut, into_handle, release_handle};
+use crate::runtime::{Factory, FileAsset};
+
+/// What an [`AssetLoader`] did with an asset.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum LoadOutcome {
+    /// The loader attached the asset's contents.
+    Loaded,
+    /// The runtime falls back to the asset's in-band bytes, if any.
+    Fallback,
+}
+
+/// Resolves file assets while a file loads; install one with
+/// [`Factory::load_file_with_loader`].
+///
+/// The file keeps its loader alive for as long as the runtime may ask for
+/// assets. A `load` that panics counts as [`LoadOutcome::Fallback`].
+pub trait AssetLoader: 'static {
+    fn load(&mut self, asset: &mut FileAsset, in_band: &[u8], factory: &Factory) -> LoadOutcome;
+}
+
+impl<F> AssetLoader for F
+where
+    F: FnMut(&mut FileAsset, &[u8], &Factory) -> LoadOutcome + 'static,
+{
+    fn load(&mut self, asset: &mut FileAsset, in_band: &[u8], factory: &Factory) -> LoadOutcome {
+        self(asset, in_band, factory)
+    }
+}
+
+/// The kind of a file asset.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum AssetKind {
+    Image,
+    Font,
+    Audio,
+    Other,
+}
+
+impl AssetKind {
+    pub fn of(asset: &FileAsset) -> Self {
+        if asset.is_image() {
+            Self::Image
+        } else if asset.is_font() {
+            Self::Font
+        } else if asset.is_audio() {
+            Self::Audio
+        } else {
+            Self::Other
+        }
+    }
+}
+
+/// An asset referenced by a file, as seen while the file loads.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AssetInfo {
+    pub kind: AssetKind,
+    pub name: String,
+    pub unique_filename: String,
+    pub file_extension: String,
+    pub cdn_uuid: String,
+    /// Whether the file carries the asset's bytes in-band.
+    pub embedded: bool,
+    /// Size of the in-band bytes; zero for referenced and hosted assets.
+    pub embedded_size: usize,
+}
+
+impl AssetInfo {
+    pub fn new(asset: &FileAsset, in_band: &[u8]) -> Self {
+        Self {
+            kind: AssetKind::of(asset),
+            name: asset.name(),
+            unique_filename: asset.unique_filename(),
+            file_extension: asset.file_extension(),
+            cdn_uuid: asset.cdn_uuid(),
+            embedded: !in_band.is_empty(),
+            embedded_size: in_band.len(),
+        }
+    }
+}
+
+/// Lists the assets a `.riv` file references, in file order.
+///
+/// The file is imported but no artboard is instanced and no asset is
+/// decoded.
+pub fn inspect_assets(bytes: &[u8]) -> Result<Vec<AssetInfo>, Error> {
+    let assets = Rc::new(RefCell::new(Vec::new()));
+    let seen = assets.clone();
+    let file = Factory::new()?.load_file_with_loader(
+        bytes,
+        move |asset: &mut FileAsset, in_band: &[u8], _: &Factory| {
+            seen.borrow_mut().push(AssetInfo::new(asset, in_band));
+            // Claiming the asset keeps the runtime from decoding its bytes.
+            LoadOutcome::Loaded
+        },
+    )?;
+    drop(file);
+    Ok(assets.take())
+}
+
+/// Moves `loader` behind loader callbacks; the provider frees it through
+/// `release`.
+pub(crate) fn loader_callbacks<L: AssetLoader>(
+    loader: L,
+) -> abi::rive_rs_file_asset_loader_callbacks {
+    abi::rive_rs_file_asset_loader_callbacks {
+        load_contents: Some(load_contents::<L>),
+        user_data: into_handle(loader),
+        release: Some(release_loader::<L>),
+    }
+}
+
+unsafe extern "C" fn load_contents<L: AssetLoader>(
+    user_data: *mut c_void,
+    asset: *mut abi::rive_rs_file_asset,
+    in_band_bytes: abi::rive_rs_bytes_view,
+    factory: *mut abi::rive_rs_factory,
+) -> bool {
+    let Some(factory) = NonNull::new(factory) else {
+        return false;
+    };
+    guard(false, || {
+        // SAFETY: the provider passes an asset that is live for the call.
+        let Ok(mut asset) = (unsafe { FileAsset::from_raw(asset) }) else {
+            return false;
+        };
+        // SAFETY: the provider passes a factory that is live for the call.
+        let factory = unsafe { Factory::borrow_raw(factory) };
+        let in_band = if in_band_bytes.ptr.is_null() || in_band_bytes.len == 0 {
+            &[][..]
+        } else {
+            // SAFETY: provider passes a byte view valid for the call.
+            unsafe { slice::from_raw_parts(in_band_bytes.ptr, in_band_bytes.len) }
+        };
+        // SAFETY: `user_data` is the loader installed by `loader_callbacks`.
+        let loader = unsafe { handle_mut::<L>(user_data) };
+        loader.load(&mut asset, in_band, &factory) == LoadOutcome::Loaded
+    })
+}
+
+unsafe extern "C" fn release_loader<L: AssetLoader>(user_data: *mut c_void) {
+    // SAFETY: the provider releases the loader exactly once.
+    guard((), || unsafe { release_handle::<L>(user_data) });
+}
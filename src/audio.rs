@@ -0,0 +1,291 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+use std::sync::Arc;
+
+use crate::assets::{AssetKind, AssetLoader};
+use crate::runtime::{
+    Artboard, AudioSource, Factory, File, FileAsset, LinearAnimationInstance, StateMachineInstance,
+};
+use crate::wav::{self, Pcm};
+use crate::{Error, LoadOutcome, Status};
+
+// Sources attached to audio assets while an engine's loader runs, keyed by
+// asset pointer.
+type Attached = Vec<(usize, Option<Arc<Pcm>>)>;
+
+thread_local! {
+    // `None` outside an engine's load.
+    static ATTACHED: RefCell<Option<Attached>> = const { RefCell::new(None) };
+}
+
+/// Notes `source` being attached to `asset` for the engine loading the file,
+/// if any.
+pub(crate) fn note_attached(asset: &FileAsset, source: &AudioSource) {
+    ATTACHED.with_borrow_mut(|attached| {
+        if let Some(attached) = attached {
+            attached.push((asset.as_raw() as usize, source.shared_pcm()));
+        }
+    });
+}
+
+// A sound found while a file loaded: its samples, or `None` if the engine
+// cannot decode its format.
+type FoundSound = (String, Option<Arc<Pcm>>);
+
+/// Wraps a loader to pick up the sound of every audio asset: the source the
+/// loader attached, or else the in-band bytes.
+struct SoundLoader<L> {
+    inner: L,
+    found: Rc<RefCell<Vec<FoundSound>>>,
+}
+
+impl<L: AssetLoader> AssetLoader for SoundLoader<L> {
+    fn load(&mut self, asset: &mut FileAsset, in_band: &[u8], factory: &Factory) -> LoadOutcome {
+        if AssetKind::of(asset) != AssetKind::Audio {
+            return self.inner.load(asset, in_band, factory);
+        }
+        let outer = ATTACHED.replace(Some(Vec::new()));
+        let outcome = self.inner.load(asset, in_band, factory);
+        let attached = ATTACHED.replace(outer).unwrap_or_default();
+        let key = asset.as_raw() as usize;
+        let pcm = match attached.into_iter().rfind(|(asset, _)| *asset == key) {
+            Some((_, pcm)) if outcome == LoadOutcome::Loaded => pcm,
+            _ if in_band.is_empty() => return outcome,
+            _ => wav::decode(in_band).map(Arc::new),
+        };
+        self.found.borrow_mut().push((asset.unique_filename(), pcm));
+        outcome
+    }
+}
+
+struct Voice {
+    pcm: Arc<Pcm>,
+    // Position in source frames; advances by the resampling ratio.
+    position: f64,
+    gain: f32,
+}
+
+/// Mixes the sounds of audio events into interleaved `f32` PCM.
+///
+/// Sounds are WAV files keyed by their asset's unique file name; audio
+/// assets in other formats are listed by [`AudioEngine::unsupported_sounds`].
+/// Audio events reach the engine through [`AudioEngine::collect`], which
+/// reads the events a state machine reported during its last advance, and
+/// [`AudioEngine::collect_animation`], which does the same for a linear
+/// animation played on its own. Voices are resampled to the engine's rate,
+/// scaled by the artboard volume and the engine volume, and summed.
+pub struct AudioEngine {
+    sample_rate: u32,
+    channels: u16,
+    volume: f32,
+    sounds: HashMap<String, Arc<Pcm>>,
+    unsupported: Vec<String>,
+    voices: Vec<Voice>,
+}
+
+impl AudioEngine {
+    pub fn new(sample_rate: u32, channels: u16) -> Result<Self, Error> {
+        if sample_rate == 0 || channels == 0 {
+            return Err(Error::from_status(Status::RIVE_RS_STATUS_INVALID_ARGUMENT));
+        }
+        Ok(Self {
+            sample_rate,
+            channels,
+            volume: 1.0,
+            sounds: HashMap::new(),
+            unsupported: Vec::new(),
+            voices: Vec::new(),
+        })
+    }
+
+    pub fn sample_rate(&self) -> u32 {
+        self.sample_rate
+    }
+
+    pub fn channels(&self) -> u16 {
+        self.channels
+    }
+
+    pub fn volume(&self) -> f32 {
+        self.volume
+    }
+
+    /// Master gain, applied to voices already playing as well.
+    pub fn set_volume(&mut self, volume: f32) {
+        self.volume = volume.max(0.0);
+    }
+
+    /// Registers the sound for an audio asset. Fails with a decode error
+    /// unless `bytes` is a supported WAV file.
+    pub fn insert_sound(&mut self, unique_filename: &str, bytes: &[u8]) -> Result<(), Error> {
+        let pcm = wav::decode(bytes)
+            .ok_or_else(|| Error::from_status(Status::RIVE_RS_STATUS_DECODE_ERROR))?;
+        self.register(unique_filename, Some(Arc::new(pcm)));
+        Ok(())
+    }
+
+    /// Registers a decoded source as the sound for an audio asset, such as
+    /// one attached after its file loaded. Fails as unsupported unless the
+    /// source was a WAV file.
+    pub fn insert_source(
+        &mut self,
+        unique_filename: &str,
+        source: &AudioSource,
+    ) -> Result<(), Error> {
+        let pcm = source
+            .shared_pcm()
+            .ok_or_else(|| Error::from_status(Status::RIVE_RS_STATUS_UNSUPPORTED))?;
+        self.register(unique_filename, Some(pcm));
+        Ok(())
+    }
+
+    pub fn has_sound(&self, unique_filename: &str) -> bool {
+        self.sounds.contains_key(unique_filename)
+    }
+
+    /// Unique file names of the audio assets met while loading files whose
+    /// format the engine cannot mix. Their events stay silent.
+    pub fn unsupported_sounds(&self) -> &[String] {
+        &self.unsupported
+    }
+
+    /// Loads a file, registering every in-band audio asset.
+    pub fn load_file(&mut self, factory: &Factory, bytes: &[u8]) -> Result<File, Error> {
+        self.load_file_with_loader(
+            factory,
+            bytes,
+            |_: &mut FileAsset, _: &[u8], _: &Factory| LoadOutcome::Fallback,
+        )
+    }
+
+    /// Loads a file through `loader`, registering the source it attaches to
+    /// each audio asset, or the in-band bytes of assets it leaves to the
+    /// runtime.
+    pub fn load_file_with_loader(
+        &mut self,
+        factory: &Factory,
+        bytes: &[u8],
+        loader: impl AssetLoader,
+    ) -> Result<File, Error> {
+        let found = Rc::new(RefCell::new(Vec::new()));
+        let file = factory.load_file_with_loader(
+            bytes,
+            SoundLoader {
+                inner: loader,
+                found: found.clone(),
+            },
+        )?;
+        for (unique_filename, pcm) in found.take() {
+            self.register(&unique_filename, pcm);
+        }
+        Ok(file)
+    }
+
+    fn register(&mut self, unique_filename: &str, pcm: Option<Arc<Pcm>>) {
+        self.unsupported.retain(|name| name != unique_filename);
+        match pcm {
+            Some(pcm) => {
+                self.sounds.insert(unique_filename.to_owned(), pcm);
+            }
+            None => {
+                self.sounds.remove(unique_filename);
+                self.unsupported.push(unique_filename.to_owned());
+            }
+        }
+    }
+
+    /// Starts a registered sound at `volume`; `false` if there is none.
+    pub fn play(&mut self, unique_filename: &str, volume: f32) -> bool {
+        let Some(pcm) = self.sounds.get(unique_filename) else {
+            return false;
+        };
+        if pcm.frames() == 0 {
+            return false;
+        }
+        self.voices.push(Voice {
+            pcm: pcm.clone(),
+            position: 0.0,
+            gain: volume.max(0.0),
+        });
+        true
+    }
+
+    /// Starts the sounds of the audio events `machine` reported during its
+    /// last advance, at `artboard`'s volume. Call it after every advance;
+    /// returns how many voices started.
+    pub fn collect(&mut self, machine: &StateMachineInstance, artboard: &Artboard) -> usize {
+        let volume = artboard.volume();
+        (0..machine.reported_event_count())
+            .filter_map(|index| machine.reported_event_audio_asset(index).ok())
+            .filter(|asset| self.play(&asset.unique_filename(), volume))
+            .count()
+    }
+
+    /// Starts the sounds of the audio events `animation` crossed during its
+    /// last advance, at `artboard`'s volume; the counterpart of
+    /// [`AudioEngine::collect`] for animations played without a state
+    /// machine.
+    pub fn collect_animation(
+        &mut self,
+        animation: &LinearAnimationInstance,
+        artboard: &Artboard,
+    ) -> usize {
+        let volume = artboard.volume();
+        (0..animation.reported_audio_count())
+            .filter_map(|index| animation.reported_audio_asset(index).ok())
+            .filter(|asset| self.play(&asset.unique_filename(), volume))
+            .count()
+    }
+
+    pub fn active_voices(&self) -> usize {
+        self.voices.len()
+    }
+
+    pub fn stop_all(&mut self) {
+        self.voices.clear();
+    }
+
+    /// Renders the next `out.len() / channels` frames of interleaved PCM,
+    /// clipped to `[-1, 1]`. Finished voices are dropped.
+    pub fn render(&mut self, out: &mut [f32]) {
+        out.fill(0.0);
+        let channels = self.channels as usize;
+        for voice in &mut self.voices {
+            let pcm = &*voice.pcm;
+            let frames = pcm.frames();
+            let source_channels = pcm.channels as usize;
+            let step = pcm.sample_rate as f64 / self.sample_rate as f64;
+            let gain = voice.gain * self.volume;
+            // Mono output downmixes; otherwise output channels wrap onto
+            // the source's.
+            let value = |frame: usize, channel: usize| {
+                let samples = &pcm.samples[frame * source_channels..][..source_channels];
+                if channels == 1 {
+                    samples.iter().sum::<f32>() / source_channels as f32
+                } else {
+                    samples[channel % source_channels]
+                }
+            };
+            for out in out.chunks_exact_mut(channels) {
+                let index = voice.position as usize;
+                if index >= frames {
+                    break;
+                }
+                let next = (index + 1).min(frames - 1);
+                let t = (voice.position - index as f64) as f32;
+                for (channel, out) in out.iter_mut().enumerate() {
+                    let from = value(index, channel);
+                    let to = value(next, channel);
+                    *out += (from + (to - from) * t) * gain;
+                }
+                voice.position += step;
+            }
+        }
+        self.voices
+            .retain(|voice| (voice.position as usize) < voice.pcm.frames());
+        for sample in out {
+            *sample = sample.clamp(-1.0, 1.0);
+        }
+    }
+}
@@ -0,0 +1,747 @@
+use core::ffi::c_void;
+use core::slice;
+use std::any::TypeId;
+use std::hash::{DefaultHasher, Hash, Hasher};
+use std::panic::{AssertUnwindSafe, catch_unwind};
+
+use crate::runtime::Mat2D;
+use crate::{abi, png};
+
+pub type BlendMode = abi::rive_rs_blend_mode;
+pub type BufferType = abi::rive_rs_render_buffer_type;
+pub type FillRule = abi::rive_rs_fill_rule;
+pub type ImageFilter = abi::rive_rs_image_filter;
+pub type ImageSampler = abi::rive_rs_image_sampler;
+pub type ImageWrap = abi::rive_rs_image_wrap;
+pub type PaintStyle = abi::rive_rs_paint_style;
+pub type StrokeCap = abi::rive_rs_stroke_cap;
+pub type StrokeJoin = abi::rive_rs_stroke_join;
+
+/// A gradient color stop; `color` is non-premultiplied ARGB.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct GradientStop {
+    pub color: u32,
+    pub position: f32,
+}
+
+/// Path object created by [`RenderBackend::make_path`].
+pub trait BackendPath {
+    fn rewind(&mut self);
+    fn set_fill_rule(&mut self, fill_rule: FillRule);
+    fn move_to(&mut self, x: f32, y: f32);
+    fn line_to(&mut self, x: f32, y: f32);
+    fn cubic_to(&mut self, out_x: f32, out_y: f32, in_x: f32, in_y: f32, x: f32, y: f32);
+    fn close(&mut self);
+    fn add_path(&mut self, other: &Self, transform: &Mat2D);
+}
+
+/// Paint object created by [`RenderBackend::make_paint`].
+pub trait BackendPaint {
+    type Shader;
+
+    fn set_color(&mut self, argb: u32);
+    fn set_style(&mut self, style: PaintStyle);
+    fn set_thickness(&mut self, thickness: f32);
+    fn set_join(&mut self, join: StrokeJoin);
+    fn set_cap(&mut self, cap: StrokeCap);
+    fn set_blend_mode(&mut self, blend_mode: BlendMode);
+    fn set_shader(&mut self, shader: Option<&Self::Shader>);
+    fn set_feather(&mut self, feather: f32);
+}
+
+/// Gradient shader created by [`RenderBackend::make_linear_gradient`] or
+/// [`RenderBackend::make_radial_gradient`]. Shaders are immutable once built.
+pub trait BackendShader {}
+
+/// Image object created by [`RenderBackend::decode_image`].
+pub trait BackendImage {
+    fn width(&self) -> u32;
+    fn height(&self) -> u32;
+
+    /// Decoded unpremultiplied RGBA8 pixels, `width * height * 4` bytes, if
+    /// the backend keeps them.
+    fn pixels(&self) -> Option<&[u8]> {
+        None
+    }
+}
+
+/// Vertex/index buffer created by [`RenderBackend::make_buffer`].
+pub trait BackendBuffer {
+    fn bytes(&self) -> &[u8];
+    fn bytes_mut(&mut self) -> &mut [u8];
+}
+
+/// A drawing backend the runtime factory forwards object creation to.
+///
+/// Install one with [`Factory::from_backend`](crate::Factory::from_backend).
+/// Callbacks that panic are treated as no-ops (constructors yield a null
+/// object) rather than unwinding into the runtime.
+pub trait RenderBackend: 'static {
+    type Path: BackendPath;
+    type Paint: BackendPaint<Shader = Self::Shader>;
+    type Shader: BackendShader;
+    type Image: BackendImage;
+    type Buffer: BackendBuffer;
+
+    fn make_path(&mut self, fill_rule: FillRule) -> Self::Path;
+    fn make_paint(&mut self) -> Self::Paint;
+    fn make_linear_gradient(
+        &mut self,
+        start: (f32, f32),
+        end: (f32, f32),
+        stops: &[GradientStop],
+    ) -> Self::Shader;
+    fn make_radial_gradient(
+        &mut self,
+        center: (f32, f32),
+        radius: f32,
+        stops: &[GradientStop],
+    ) -> Self::Shader;
+    fn decode_image(&mut self, bytes: &[u8]) -> Option<Self::Image>;
+
+    /// Wraps unpremultiplied RGBA8 pixels, e.g. the contents of a
+    /// [`RenderTarget`](crate::RenderTarget). Defaults to decoding them as a
+    /// PNG.
+    fn make_image(&mut self, width: u32, height: u32, rgba: &[u8]) -> Option<Self::Image> {
+        self.decode_image(&png::encode(width, height, rgba))
+    }
+
+    /// The buffer's [`BackendBuffer::bytes_mut`] must span at least `size`
+    /// bytes; shorter buffers are dropped and the runtime gets none.
+    fn make_buffer(&mut self, buffer_type: BufferType, flags: u32, size: usize) -> Self::Buffer;
+}
+
+/// Draw target for objects created by [`BackendRenderer::Backend`].
+///
+/// Wrap one in [`BackendRendererHandle`](crate::BackendRendererHandle) to
+/// draw artboards into it. Objects created by a different backend are skipped.
+pub trait BackendRenderer {
+    type Backend: RenderBackend;
+
+    fn save(&mut self);
+    fn restore(&mut self);
+    fn transform(&mut self, matrix: &Mat2D);
+    fn modulate_opacity(&mut self, opacity: f32);
+    fn draw_path(
+        &mut self,
+        path: &<Self::Backend as RenderBackend>::Path,
+        paint: &<Self::Backend as RenderBackend>::Paint,
+    );
+    fn clip_path(&mut self, path: &<Self::Backend as RenderBackend>::Path);
+    fn draw_image(
+        &mut self,
+        image: &<Self::Backend as RenderBackend>::Image,
+        sampler: ImageSampler,
+        blend_mode: BlendMode,
+        opacity: f32,
+    );
+    #[allow(clippy::too_many_arguments)]
+    fn draw_image_mesh(
+        &mut self,
+        image: &<Self::Backend as RenderBackend>::Image,
+        sampler: ImageSampler,
+        vertices: &<Self::Backend as RenderBackend>::Buffer,
+        uv_coords: &<Self::Backend as RenderBackend>::Buffer,
+        indices: &<Self::Backend as RenderBackend>::Buffer,
+        vertex_count: u32,
+        index_count: u32,
+        blend_mode: BlendMode,
+        opacity: f32,
+    );
+}
+
+// Keyed by the object types rather than the backend itself, so backends that
+// share object types (recording and software) draw each other's objects.
+pub(crate) fn backend_key<B: RenderBackend>() -> u64 {
+    let mut hasher = DefaultHasher::new();
+    TypeId::of::<(B::Path, B::Paint, B::Shader, B::Image, B::Buffer)>().hash(&mut hasher);
+    hasher.finish()
+}
+
+// Panics must not unwind into the provider; a panicking callback degrades to
+// a no-op (or a null object for constructors).
+pub(crate) fn guard<T>(fallback: T, f: impl FnOnce() -> T) -> T {
+    catch_unwind(AssertUnwindSafe(f)).unwrap_or(fallback)
+}
+
+pub(crate) fn into_handle<T>(value: T) -> *mut c_void {
+    Box::into_raw(Box::new(value)).cast::<c_void>()
+}
+
+/// # Safety
+///
+/// `handle` must have been produced by `into_handle::<T>` and not yet released.
+pub(crate) unsafe fn release_handle<T>(handle: *mut c_void) {
+    if !handle.is_null() {
+        // SAFETY: caller guarantees `handle` came from `into_handle::<T>`.
+        drop(unsafe { Box::from_raw(handle.cast::<T>()) });
+    }
+}
+
+/// # Safety
+///
+/// `handle` must be a live handle of type `T`.
+unsafe fn handle_ref<'a, T>(handle: *const c_void) -> &'a T {
+    // SAFETY: caller guarantees `handle` is a live `T`.
+    unsafe { &*handle.cast::<T>() }
+}
+
+/// # Safety
+///
+/// `handle` must be a live handle of type `T` not aliased for the call.
+pub(crate) unsafe fn handle_mut<'a, T>(handle: *mut c_void) -> &'a mut T {
+    // SAFETY: caller guarantees `handle` is a live, unaliased `T`.
+    unsafe { &mut *handle.cast::<T>() }
+}
+
+// Keeps the size the runtime asked for, since it writes that many bytes
+// through `buffer_map` whatever slice the backend returns.
+struct BufferHandle<T> {
+    buffer: T,
+    size_in_bytes: usize,
+}
+
+fn gradient_stops(colors: *const u32, stops: *const f32, count: usize) -> Vec<GradientStop> {
+    if colors.is_null() || stops.is_null() || count == 0 {
+        return Vec::new();
+    }
+    // SAFETY: provider passes `count` colors and stops valid for the call.
+    let (colors, stops) = unsafe {
+        (
+            slice::from_raw_parts(colors, count),
+            slice::from_raw_parts(stops, count),
+        )
+    };
+    colors
+        .iter()
+        .zip(stops)
+        .map(|(&color, &position)| GradientStop { color, position })
+        .collect()
+}
+
+pub(crate) fn backend_vtable<B: RenderBackend>(backend: B) -> abi::rive_rs_render_backend {
+    abi::rive_rs_render_backend {
+        user_data: into_handle(backend),
+        backend_key: backend_key::<B>(),
+        release: Some(release_backend_callback::<B>),
+        make_path: Some(make_path::<B>),
+        make_paint: Some(make_paint::<B>),
+        make_linear_gradient: Some(make_linear_gradient::<B>),
+        make_radial_gradient: Some(make_radial_gradient::<B>),
+        decode_image: Some(decode_image::<B>),
+        make_buffer: Some(make_buffer::<B>),
+        release_path: Some(release_object::<B::Path>),
+        release_paint: Some(release_object::<B::Paint>),
+        release_shader: Some(release_object::<B::Shader>),
+        release_image: Some(release_object::<B::Image>),
+        release_buffer: Some(release_object::<BufferHandle<B::Buffer>>),
+        path_rewind: Some(path_rewind::<B>),
+        path_fill_rule: Some(path_fill_rule::<B>),
+        path_move_to: Some(path_move_to::<B>),
+        path_line_to: Some(path_line_to::<B>),
+        path_cubic_to: Some(path_cubic_to::<B>),
+        path_close: Some(path_close::<B>),
+        path_add_path: Some(path_add_path::<B>),
+        paint_color: Some(paint_color::<B>),
+        paint_style: Some(paint_style::<B>),
+        paint_thickness: Some(paint_thickness::<B>),
+        paint_join: Some(paint_join::<B>),
+        paint_cap: Some(paint_cap::<B>),
+        paint_blend_mode: Some(paint_blend_mode::<B>),
+        paint_shader: Some(paint_shader::<B>),
+        paint_feather: Some(paint_feather::<B>),
+        image_size: Some(image_size::<B>),
+        image_pixels: Some(image_pixels::<B>),
+        buffer_map: Some(buffer_map::<B>),
+        buffer_unmap: Some(buffer_unmap),
+        make_image: Some(make_image::<B>),
+    }
+}
+
+/// # Safety
+///
+/// `user_data` must be the `user_data` of a vtable built by
+/// `backend_vtable::<B>` whose ownership was not taken by the provider.
+pub(crate) unsafe fn release_backend<B: RenderBackend>(user_data: *mut c_void) {
+    // SAFETY: forwarded caller contract.
+    unsafe { release_handle::<B>(user_data) };
+}
+
+unsafe extern "C" fn release_backend_callback<B: RenderBackend>(user_data: *mut c_void) {
+    // SAFETY: the provider releases the backend exactly once.
+    guard((), || unsafe { release_handle::<B>(user_data) });
+}
+
+unsafe extern "C" fn release_object<T>(_user_data: *mut c_void, object: *mut c_void) {
+    // SAFETY: the provider releases each object exactly once.
+    guard((), || unsafe { release_handle::<T>(object) });
+}
+
+unsafe extern "C" fn make_path<B: RenderBackend>(
+    user_data: *mut c_void,
+    fill_rule: FillRule,
+) -> *mut c_void {
+    guard(core::ptr::null_mut(), || {
+        // SAFETY: `user_data` is the backend installed by `backend_vtable`.
+        let backend = unsafe { handle_mut::<B>(user_data) };
+        into_handle(backend.make_path(fill_rule))
+    })
+}
+
+unsafe extern "C" fn make_paint<B: RenderBackend>(user_data: *mut c_void) -> *mut c_void {
+    guard(core::ptr::null_mut(), || {
+        // SAFETY: `user_data` is the backend installed by `backend_vtable`.
+        let backend = unsafe { handle_mut::<B>(user_data) };
+        into_handle(backend.make_paint())
+    })
+}
+
+#[allow(clippy::too_many_arguments)]
+unsafe extern "C" fn make_linear_gradient<B: RenderBackend>(
+    user_data: *mut c_void,
+    start_x: f32,
+    start_y: f32,
+    end_x: f32,
+    end_y: f32,
+    colors: *const u32,
+    stops: *const f32,
+    count: usize,
+) -> *mut c_void {
+    guard(core::ptr::null_mut(), || {
+        // SAFETY: `user_data` is the backend installed by `backend_vtable`.
+        let backend = unsafe { handle_mut::<B>(user_data) };
+        let stops = gradient_stops(colors, stops, count);
+        into_handle(backend.make_linear_gradient((start_x, start_y), (end_x, end_y), &stops))
+    })
+}
+
+unsafe extern "C" fn make_radial_gradient<B: RenderBackend>(
+    user_data: *mut c_void,
+    center_x: f32,
+    center_y: f32,
+    radius: f32,
+    colors: *const u32,
+    stops: *const f32,
+    count: usize,
+) -> *mut c_void {
+    guard(core::ptr::null_mut(), || {
+        // SAFETY: `user_data` is the backend installed by `backend_vtable`.
+        let backend = unsafe { handle_mut::<B>(user_data) };
+        let stops = gradient_stops(colors, stops, count);
+        into_handle(backend.make_radial_gradient((center_x, center_y), radius, &stops))
+    })
+}
+
+unsafe extern "C" fn decode_image<B: RenderBackend>(
+    user_data: *mut c_void,
+    bytes: abi::rive_rs_bytes_view,
+) -> *mut c_void {
+    guard(core::ptr::null_mut(), || {
+        // SAFETY: `user_data` is the backend installed by `backend_vtable`.
+        let backend = unsafe { handle_mut::<B>(user_data) };
+        let bytes = if bytes.ptr.is_null() || bytes.len == 0 {
+            &[][..]
+        } else {
+            // SAFETY: provider passes a byte view valid for the call.
+            unsafe { slice::from_raw_parts(bytes.ptr, bytes.len) }
+        };
+        backend
+            .decode_image(bytes)
+            .map_or(core::ptr::null_mut(), into_handle)
+    })
+}
+
+unsafe extern "C" fn make_image<B: RenderBackend>(
+    user_data: *mut c_void,
+    width: u32,
+    height: u32,
+    rgba: *const u8,
+) -> *mut c_void {
+    let len = width as usize * height as usize * 4;
+    if rgba.is_null() || len == 0 {
+        return core::ptr::null_mut();
+    }
+    guard(core::ptr::null_mut(), || {
+        // SAFETY: `user_data` is the backend installed by `backend_vtable`.
+        let backend = unsafe { handle_mut::<B>(user_data) };
+        // SAFETY: provider passes `width * height * 4` bytes valid for the call.
+        let rgba = unsafe { slice::from_raw_parts(rgba, len) };
+        backend
+            .make_image(width, height, rgba)
+            .map_or(core::ptr::null_mut(), into_handle)
+    })
+}
+
+unsafe extern "C" fn make_buffer<B: RenderBackend>(
+    user_data: *mut c_void,
+    buffer_type: BufferType,
+    flags: u32,
+    size_in_bytes: usize,
+) -> *mut c_void {
+    guard(core::ptr::null_mut(), || {
+        // SAFETY: `user_data` is the backend installed by `backend_vtable`.
+        let backend = unsafe { handle_mut::<B>(user_data) };
+        let mut buffer = backend.make_buffer(buffer_type, flags, size_in_bytes);
+        if buffer.bytes_mut().len() < size_in_bytes {
+            return core::ptr::null_mut();
+        }
+        into_handle(BufferHandle {
+            buffer,
+            size_in_bytes,
+        })
+    })
+}
+
+unsafe extern "C" fn path_rewind<B: RenderBackend>(_user_data: *mut c_void, path: *mut c_void) {
+    // SAFETY: `path` was created by `make_path::<B>`.
+    guard((), || unsafe { handle_mut::<B::Path>(path) }.rewind());
+}
+
+unsafe extern "C" fn path_fill_rule<B: RenderBackend>(
+    _user_data: *mut c_void,
+    path: *mut c_void,
+    fill_rule: FillRule,
+) {
+    // SAFETY: `path` was created by `make_path::<B>`.
+    guard((), || {
+        unsafe { handle_mut::<B::Path>(path) }.set_fill_rule(fill_rule)
+    });
+}
+
+unsafe extern "C" fn path_move_to<B: RenderBackend>(
+    _user_data: *mut c_void,
+    path: *mut c_void,
+    x: f32,
+    y: f32,
+) {
+    // SAFETY: `path` was created by `make_path::<B>`.
+    guard((), || unsafe { handle_mut::<B::Path>(path) }.move_to(x, y));
+}
+
+unsafe extern "C" fn path_line_to<B: RenderBackend>(
+    _user_data: *mut c_void,
+    path: *mut c_void,
+    x: f32,
+    y: f32,
+) {
+    // SAFETY: `path` was created by `make_path::<B>`.
+    guard((), || unsafe { handle_mut::<B::Path>(path) }.line_to(x, y));
+}
+
+#[allow(clippy::too_many_arguments)]
+unsafe extern "C" fn path_cubic_to<B: RenderBackend>(
+    _user_data: *mut c_void,
+    path: *mut c_void,
+    out_x: f32,
+    out_y: f32,
+    in_x: f32,
+    in_y: f32,
+    x: f32,
+    y: f32,
+) {
+    guard((), || {
+        // SAFETY: `path` was created by `make_path::<B>`.
+        unsafe { handle_mut::<B::Path>(path) }.cubic_to(out_x, out_y, in_x, in_y, x, y)
+    });
+}
+
+unsafe extern "C" fn path_close<B: RenderBackend>(_user_data: *mut c_void, path: *mut c_void) {
+    // SAFETY: `path` was created by `make_path::<B>`.
+    guard((), || unsafe { handle_mut::<B::Path>(path) }.close());
+}
+
+unsafe extern "C" fn path_add_path<B: RenderBackend>(
+    _user_data: *mut c_void,
+    path: *mut c_void,
+    other: *const c_void,
+    transform: *const abi::rive_rs_mat2d,
+) {
+    if transform.is_null() || core::ptr::eq(path.cast_const(), other) {
+        return;
+    }
+    guard((), || {
+        // SAFETY: both paths were created by `make_path::<B>` and are distinct.
+        let (path, other) = unsafe { (handle_mut::<B::Path>(path), handle_ref::<B::Path>(other)) };
+        // SAFETY: checked non-null above; valid for the call.
+        path.add_path(other, unsafe { &*transform });
+    });
+}
+
+unsafe extern "C" fn paint_color<B: RenderBackend>(
+    _user_data: *mut c_void,
+    paint: *mut c_void,
+    argb: u32,
+) {
+    // SAFETY: `paint` was created by `make_paint::<B>`.
+    guard((), || {
+        unsafe { handle_mut::<B::Paint>(paint) }.set_color(argb)
+    });
+}
+
+unsafe extern "C" fn paint_style<B: RenderBackend>(
+    _user_data: *mut c_void,
+    paint: *mut c_void,
+    style: PaintStyle,
+) {
+    // SAFETY: `paint` was created by `make_paint::<B>`.
+    guard((), || {
+        unsafe { handle_mut::<B::Paint>(paint) }.set_style(style)
+    });
+}
+
+unsafe extern "C" fn paint_thickness<B: RenderBackend>(
+    _user_data: *mut c_void,
+    paint: *mut c_void,
+    thickness: f32,
+) {
+    // SAFETY: `paint` was created by `make_paint::<B>`.
+    guard((), || {
+        unsafe { handle_mut::<B::Paint>(paint) }.set_thickness(thickness)
+    });
+}
+
+unsafe extern "C" fn paint_join<B: RenderBackend>(
+    _user_data: *mut c_void,
+    paint: *mut c_void,
+    join: StrokeJoin,
+) {
+    // SAFETY: `paint` was created by `make_paint::<B>`.
+    guard((), || {
+        unsafe { handle_mut::<B::Paint>(paint) }.set_join(join)
+    });
+}
+
+unsafe extern "C" fn paint_cap<B: RenderBackend>(
+    _user_data: *mut c_void,
+    paint: *mut c_void,
+    cap: StrokeCap,
+) {
+    // SAFETY: `paint` was created by `make_paint::<B>`.
+    guard((), || unsafe { handle_mut::<B::Paint>(paint) }.set_cap(cap));
+}
+
+unsafe extern "C" fn paint_blend_mode<B: RenderBackend>(
+    _user_data: *mut c_void,
+    paint: *mut c_void,
+    blend_mode: BlendMode,
+) {
+    // SAFETY: `paint` was created by `make_paint::<B>`.
+    guard((), || {
+        unsafe { handle_mut::<B::Paint>(paint) }.set_blend_mode(blend_mode)
+    });
+}
+
+unsafe extern "C" fn paint_shader<B: RenderBackend>(
+    _user_data: *mut c_void,
+    paint: *mut c_void,
+    shader: *const c_void,
+) {
+    guard((), || {
+        // SAFETY: `paint` was created by `make_paint::<B>`; a non-null
+        // `shader` was created by one of the `make_*_gradient::<B>` callbacks.
+        let paint = unsafe { handle_mut::<B::Paint>(paint) };
+        let shader = (!shader.is_null()).then(|| unsafe { handle_ref::<B::Shader>(shader) });
+        paint.set_shader(shader);
+    });
+}
+
+unsafe extern "C" fn paint_feather<B: RenderBackend>(
+    _user_data: *mut c_void,
+    paint: *mut c_void,
+    feather: f32,
+) {
+    // SAFETY: `paint` was created by `make_paint::<B>`.
+    guard((), || {
+        unsafe { handle_mut::<B::Paint>(paint) }.set_feather(feather)
+    });
+}
+
+unsafe extern "C" fn image_size<B: RenderBackend>(
+    _user_data: *mut c_void,
+    image: *const c_void,
+    out_width: *mut u32,
+    out_height: *mut u32,
+) {
+    if out_width.is_null() || out_height.is_null() {
+        return;
+    }
+    let (width, height) = guard((0, 0), || {
+        // SAFETY: `image` was created by `decode_image::<B>`.
+        let image = unsafe { handle_ref::<B::Image>(image) };
+        (image.width(), image.height())
+    });
+    // SAFETY: out pointers checked non-null above.
+    unsafe {
+        *out_width = width;
+        *out_height = height;
+    }
+}
+
+unsafe extern "C" fn image_pixels<B: RenderBackend>(
+    _user_data: *mut c_void,
+    image: *const c_void,
+) -> *const u8 {
+    guard(core::ptr::null(), || {
+        // SAFETY: `image` was created by `decode_image::<B>`.
+        let image = unsafe { handle_ref::<B::Image>(image) };
+        image
+            .pixels()
+            .filter(|pixels| pixels.len() == image.width() as usize * image.height() as usize * 4)
+            .map_or(core::ptr::null(), <[u8]>::as_ptr)
+    })
+}
+
+unsafe extern "C" fn buffer_map<B: RenderBackend>(
+    _user_data: *mut c_void,
+    buffer: *mut c_void,
+) -> *mut u8 {
+    guard(core::ptr::null_mut(), || {
+        // SAFETY: `buffer` was created by `make_buffer::<B>`.
+        let handle = unsafe { handle_mut::<BufferHandle<B::Buffer>>(buffer) };
+        let bytes = handle.buffer.bytes_mut();
+        if bytes.len() < handle.size_in_bytes {
+            return core::ptr::null_mut();
+        }
+        bytes.as_mut_ptr()
+    })
+}
+
+unsafe extern "C" fn buffer_unmap(_user_data: *mut c_void, _buffer: *mut c_void) {}
+
+pub(crate) fn renderer_callbacks<R: BackendRenderer>(
+    renderer: *mut R,
+) -> abi::rive_rs_renderer_callbacks {
+    abi::rive_rs_renderer_callbacks {
+        user_data: renderer.cast::<c_void>(),
+        backend_key: backend_key::<R::Backend>(),
+        save: Some(renderer_save::<R>),
+        restore: Some(renderer_restore::<R>),
+        transform: Some(renderer_transform::<R>),
+        modulate_opacity: Some(renderer_modulate_opacity::<R>),
+        draw_path: Some(renderer_draw_path::<R>),
+        clip_path: Some(renderer_clip_path::<R>),
+        draw_image: Some(renderer_draw_image::<R>),
+        draw_image_mesh: Some(renderer_draw_image_mesh::<R>),
+    }
+}
+
+type PathOf<R> = <<R as BackendRenderer>::Backend as RenderBackend>::Path;
+type PaintOf<R> = <<R as BackendRenderer>::Backend as RenderBackend>::Paint;
+type ImageOf<R> = <<R as BackendRenderer>::Backend as RenderBackend>::Image;
+type BufferOf<R> = <<R as BackendRenderer>::Backend as RenderBackend>::Buffer;
+
+unsafe extern "C" fn renderer_save<R: BackendRenderer>(user_data: *mut c_void) {
+    // SAFETY: `user_data` is the renderer installed by `renderer_callbacks`.
+    guard((), || unsafe { handle_mut::<R>(user_data) }.save());
+}
+
+unsafe extern "C" fn renderer_restore<R: BackendRenderer>(user_data: *mut c_void) {
+    // SAFETY: `user_data` is the renderer installed by `renderer_callbacks`.
+    guard((), || unsafe { handle_mut::<R>(user_data) }.restore());
+}
+
+unsafe extern "C" fn renderer_transform<R: BackendRenderer>(
+    user_data: *mut c_void,
+    matrix: *const abi::rive_rs_mat2d,
+) {
+    if matrix.is_null() {
+        return;
+    }
+    // SAFETY: `user_data` is the renderer; `matrix` is valid for the call.
+    guard((), || {
+        unsafe { handle_mut::<R>(user_data) }.transform(unsafe { &*matrix })
+    });
+}
+
+unsafe extern "C" fn renderer_modulate_opacity<R: BackendRenderer>(
+    user_data: *mut c_void,
+    opacity: f32,
+) {
+    // SAFETY: `user_data` is the renderer installed by `renderer_callbacks`.
+    guard((), || {
+        unsafe { handle_mut::<R>(user_data) }.modulate_opacity(opacity)
+    });
+}
+
+unsafe extern "C" fn renderer_draw_path<R: BackendRenderer>(
+    user_data: *mut c_void,
+    path: *const c_void,
+    paint: *const c_void,
+) {
+    guard((), || {
+        // SAFETY: the provider only forwards objects whose backend key matches
+        // this renderer, so `path` and `paint` belong to `R::Backend`.
+        let renderer = unsafe { handle_mut::<R>(user_data) };
+        let (path, paint) = unsafe {
+            (
+                handle_ref::<PathOf<R>>(path),
+                handle_ref::<PaintOf<R>>(paint),
+            )
+        };
+        renderer.draw_path(path, paint);
+    });
+}
+
+unsafe extern "C" fn renderer_clip_path<R: BackendRenderer>(
+    user_data: *mut c_void,
+    path: *const c_void,
+) {
+    guard((), || {
+        // SAFETY: backend key matched; `path` belongs to `R::Backend`.
+        let renderer = unsafe { handle_mut::<R>(user_data) };
+        renderer.clip_path(unsafe { handle_ref::<PathOf<R>>(path) });
+    });
+}
+
+unsafe extern "C" fn renderer_draw_image<R: BackendRenderer>(
+    user_data: *mut c_void,
+    image: *const c_void,
+    sampler: ImageSampler,
+    blend_mode: BlendMode,
+    opacity: f32,
+) {
+    guard((), || {
+        // SAFETY: backend key matched; `image` belongs to `R::Backend`.
+        let renderer = unsafe { handle_mut::<R>(user_data) };
+        let image = unsafe { handle_ref::<ImageOf<R>>(image) };
+        renderer.draw_image(image, sampler, blend_mode, opacity);
+    });
+}
+
+#[allow(clippy::too_many_arguments)]
+unsafe extern "C" fn renderer_draw_image_mesh<R: BackendRenderer>(
+    user_data: *mut c_void,
+    image: *const c_void,
+    sampler: ImageSampler,
+    vertices: *const c_void,
+    uv_coords: *const c_void,
+    indices: *const c_void,
+    vertex_count: u32,
+    index_count: u32,
+    blend_mode: BlendMode,
+    opacity: f32,
+) {
+    guard((), || {
+        // SAFETY: backend key matched; every object belongs to `R::Backend`.
+        let renderer = unsafe { handle_mut::<R>(user_data) };
+        let (image, vertices, uv_coords, indices) = unsafe {
+            (
+                handle_ref::<ImageOf<R>>(image),
+                &handle_ref::<BufferHandle<BufferOf<R>>>(vertices).buffer,
+                &handle_ref::<BufferHandle<BufferOf<R>>>(uv_coords).buffer,
+                &handle_ref::<BufferHandle<BufferOf<R>>>(indices).buffer,
+            )
+        };
+        renderer.draw_image_mesh(
+            image,
+            sampler,
+            vertices,
+            uv_coords,
+            indices,
+            vertex_count,
+            index_count,
+            blend_mode,
+            opacity,
+        );
+    });
+}
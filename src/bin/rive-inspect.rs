@@ -0,0 +1,87 @@
+//! Structural inspector for `.riv` files.
+//!
+//! Loads a file and prints its artboards, animations, state machines and
+//! inputs, events, text runs, view models, enums and assets as JSON.
+
+use std::error::Error as StdError;
+use std::fs;
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use rive_rs::inspect_riv;
+
+const USAGE: &str = "\
+usage: rive-inspect <file.riv> [options]
+
+options:
+  -o, --output <path>           write the JSON report to a file (default: stdout)
+";
+
+struct Args {
+    file: PathBuf,
+    output: Option<PathBuf>,
+}
+
+type CliResult<T> = Result<T, Box<dyn StdError>>;
+
+fn parse_args(mut raw: impl Iterator<Item = String>) -> CliResult<Option<Args>> {
+    let mut file = None;
+    let mut output = None;
+    while let Some(arg) = raw.next() {
+        if arg == "-h" || arg == "--help" {
+            return Ok(None);
+        }
+        if !arg.starts_with('-') {
+            if file.replace(PathBuf::from(&arg)).is_some() {
+                return Err(format!("unexpected argument `{arg}`").into());
+            }
+            continue;
+        }
+        match arg.as_str() {
+            "-o" | "--output" => {
+                let value = raw
+                    .next()
+                    .ok_or_else(|| format!("missing value for `{arg}`"))?;
+                output = Some(PathBuf::from(value));
+            }
+            _ => return Err(format!("unknown option `{arg}`").into()),
+        }
+    }
+    Ok(Some(Args {
+        file: file.ok_or("missing input file")?,
+        output,
+    }))
+}
+
+fn run(args: Args) -> CliResult<()> {
+    let bytes = fs::read(&args.file)
+        .map_err(|err| format!("failed to read {}: {err}", args.file.display()))?;
+    let json = inspect_riv(&bytes)?.to_json();
+    match args.output {
+        Some(path) => fs::write(&path, json)
+            .map_err(|err| format!("failed to write {}: {err}", path.display()))?,
+        None => print!("{json}"),
+    }
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    let args = match parse_args(std::env::args().skip(1)) {
+        Ok(Some(args)) => args,
+        Ok(None) => {
+            print!("{USAGE}");
+            return ExitCode::SUCCESS;
+        }
+        Err(err) => {
+            eprintln!("rive-inspect: {err}\n\n{USAGE}");
+            return ExitCode::from(2);
+        }
+    };
+    match run(args) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("rive-inspect: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,500 @@
+//! Batch renderer for `.riv` files.
+//!
+//! Loads a file, selects an artboard plus an optional animation or state
+//! machine, applies input and view-model values from the command line, steps
+//! the timeline and writes the rendered frames to disk.
+
+use std::error::Error as StdError;
+use std::fs;
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use rive_rs::{
+    Alignment, Artboard, DataType, ExportOptions, Factory, File, Fit, LinearAnimationInstance,
+    SmiInputType, StateMachineInstance, Timeline, ViewModelInstance, encode_apng, encode_gif,
+    export_frames_with, write_png_sequence,
+};
+
+const USAGE: &str = "\
+usage: rive-render <file.riv> [options]
+
+selection:
+  --artboard <name|index>       artboard to render (default: the default artboard)
+  --animation <name>            play a linear animation
+  --state-machine <name>        run a state machine
+
+values:
+  --input <name>[=<value>][@<when>]
+                                set a state-machine input (bool/number) or fire a
+                                trigger, before the first frame or at <when>: a
+                                frame index (`@30`) or a time in seconds (`@1.5s`)
+  --view-model <path>=<value>   set a view-model property on the default instance
+  --trigger <path>              fire a view-model trigger
+
+timeline:
+  --fps <n>                     frames per second (default: 30)
+  --frames <n>                  number of frames (default: 1)
+  --duration <seconds>          alternative to --frames
+
+output:
+  --size <width>x<height>       canvas size (default: artboard size)
+  --fit <fit>                   fill, contain, cover, fit-width, fit-height, none,
+                                scale-down, layout (default: contain)
+  --alignment <alignment>       top-left, top-center, top-right, center-left, center,
+                                center-right, bottom-left, bottom-center, bottom-right
+  --background <#rrggbb|#aarrggbb>
+  --format <png|apng|gif>       png writes a numbered sequence (default: png)
+  -o, --output <path>           output directory for png, file for apng/gif
+                                (default: current directory / render.<format>)
+";
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+enum Format {
+    Png,
+    Apng,
+    Gif,
+}
+
+enum Length {
+    Frames(u32),
+    Seconds(f32),
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+enum When {
+    Frame(u32),
+    Seconds(f32),
+}
+
+struct Input {
+    name: String,
+    value: Option<String>,
+    when: Option<When>,
+}
+
+struct Args {
+    file: PathBuf,
+    artboard: Option<String>,
+    animation: Option<String>,
+    state_machine: Option<String>,
+    inputs: Vec<Input>,
+    view_model: Vec<(String, String)>,
+    triggers: Vec<String>,
+    fps: f32,
+    length: Length,
+    size: Option<(u32, u32)>,
+    fit: Fit,
+    alignment: Alignment,
+    background: u32,
+    format: Format,
+    output: Option<PathBuf>,
+}
+
+type CliResult<T> = Result<T, Box<dyn StdError>>;
+
+fn parse_fit(value: &str) -> CliResult<Fit> {
+    Ok(match value {
+        "fill" => Fit::RIVE_RS_FIT_FILL,
+        "contain" => Fit::RIVE_RS_FIT_CONTAIN,
+        "cover" => Fit::RIVE_RS_FIT_COVER,
+        "fit-width" => Fit::RIVE_RS_FIT_FIT_WIDTH,
+        "fit-height" => Fit::RIVE_RS_FIT_FIT_HEIGHT,
+        "none" => Fit::RIVE_RS_FIT_NONE,
+        "scale-down" => Fit::RIVE_RS_FIT_SCALE_DOWN,
+        "layout" => Fit::RIVE_RS_FIT_LAYOUT,
+        _ => return Err(format!("unknown fit `{value}`").into()),
+    })
+}
+
+fn parse_alignment(value: &str) -> CliResult<Alignment> {
+    Ok(match value {
+        "top-left" => Alignment::RIVE_RS_ALIGNMENT_TOP_LEFT,
+        "top-center" => Alignment::RIVE_RS_ALIGNMENT_TOP_CENTER,
+        "top-right" => Alignment::RIVE_RS_ALIGNMENT_TOP_RIGHT,
+        "center-left" => Alignment::RIVE_RS_ALIGNMENT_CENTER_LEFT,
+        "center" => Alignment::RIVE_RS_ALIGNMENT_CENTER,
+        "center-right" => Alignment::RIVE_RS_ALIGNMENT_CENTER_RIGHT,
+        "bottom-left" => Alignment::RIVE_RS_ALIGNMENT_BOTTOM_LEFT,
+        "bottom-center" => Alignment::RIVE_RS_ALIGNMENT_BOTTOM_CENTER,
+        "bottom-right" => Alignment::RIVE_RS_ALIGNMENT_BOTTOM_RIGHT,
+        _ => return Err(format!("unknown alignment `{value}`").into()),
+    })
+}
+
+/// Parses `#rrggbb` (opaque) or `#aarrggbb` into ARGB.
+fn parse_color(value: &str) -> CliResult<u32> {
+    let hex = value.strip_prefix('#').unwrap_or(value);
+    let parsed = u32::from_str_radix(hex, 16).map_err(|_| format!("invalid color `{value}`"))?;
+    match hex.len() {
+        6 => Ok(0xff00_0000 | parsed),
+        8 => Ok(parsed),
+        _ => Err(format!("invalid color `{value}`").into()),
+    }
+}
+
+fn parse_size(value: &str) -> CliResult<(u32, u32)> {
+    let (width, height) = value
+        .split_once('x')
+        .ok_or_else(|| format!("invalid size `{value}`, expected <width>x<height>"))?;
+    Ok((width.parse()?, height.parse()?))
+}
+
+/// Parses `<name>[=<value>][@<frame>|@<seconds>s]`.
+fn parse_input(value: &str) -> CliResult<Input> {
+    let (input, when) = match value.rsplit_once('@') {
+        Some((input, when)) => {
+            let when = match when.strip_suffix('s') {
+                Some(seconds) => When::Seconds(
+                    seconds
+                        .parse()
+                        .ok()
+                        .filter(|seconds: &f32| seconds.is_finite() && *seconds >= 0.0)
+                        .ok_or_else(|| format!("invalid time `{when}` in `{value}`"))?,
+                ),
+                None => When::Frame(
+                    when.parse()
+                        .map_err(|_| format!("invalid frame `{when}` in `{value}`"))?,
+                ),
+            };
+            (input, Some(when))
+        }
+        None => (value, None),
+    };
+    let (name, value) = match input.split_once('=') {
+        Some((name, value)) => (name, Some(value.to_owned())),
+        None => (input, None),
+    };
+    if name.is_empty() {
+        return Err(format!("missing input name in `{input}`").into());
+    }
+    Ok(Input {
+        name: name.to_owned(),
+        value,
+        when,
+    })
+}
+
+fn parse_bool(value: &str) -> Option<bool> {
+    match value {
+        "true" | "1" | "on" | "yes" => Some(true),
+        "false" | "0" | "off" | "no" => Some(false),
+        _ => None,
+    }
+}
+
+fn parse_args(mut raw: impl Iterator<Item = String>) -> CliResult<Option<Args>> {
+    let mut file = None;
+    let mut args = Args {
+        file: PathBuf::new(),
+        artboard: None,
+        animation: None,
+        state_machine: None,
+        inputs: Vec::new(),
+        view_model: Vec::new(),
+        triggers: Vec::new(),
+        fps: 30.0,
+        length: Length::Frames(1),
+        size: None,
+        fit: Fit::RIVE_RS_FIT_CONTAIN,
+        alignment: Alignment::RIVE_RS_ALIGNMENT_CENTER,
+        background: 0,
+        format: Format::Png,
+        output: None,
+    };
+
+    while let Some(arg) = raw.next() {
+        if arg == "-h" || arg == "--help" {
+            return Ok(None);
+        }
+        if !arg.starts_with('-') {
+            if file.replace(PathBuf::from(&arg)).is_some() {
+                return Err(format!("unexpected argument `{arg}`").into());
+            }
+            continue;
+        }
+        let mut value = || {
+            raw.next()
+                .ok_or_else(|| format!("missing value for `{arg}`"))
+        };
+        match arg.as_str() {
+            "--artboard" => args.artboard = Some(value()?),
+            "--animation" => args.animation = Some(value()?),
+            "--state-machine" => args.state_machine = Some(value()?),
+            "--input" => args.inputs.push(parse_input(&value()?)?),
+            "--view-model" => {
+                let value = value()?;
+                let (path, value) = value
+                    .split_once('=')
+                    .ok_or_else(|| format!("expected <path>=<value>, got `{value}`"))?;
+                args.view_model.push((path.to_owned(), value.to_owned()));
+            }
+            "--trigger" => args.triggers.push(value()?),
+            "--fps" => args.fps = value()?.parse()?,
+            "--frames" => args.length = Length::Frames(value()?.parse()?),
+            "--duration" => args.length = Length::Seconds(value()?.parse()?),
+            "--size" => args.size = Some(parse_size(&value()?)?),
+            "--fit" => args.fit = parse_fit(&value()?)?,
+            "--alignment" => args.alignment = parse_alignment(&value()?)?,
+            "--background" => args.background = parse_color(&value()?)?,
+            "--format" => {
+                args.format = match value()?.as_str() {
+                    "png" => Format::Png,
+                    "apng" => Format::Apng,
+                    "gif" => Format::Gif,
+                    other => return Err(format!("unknown format `{other}`").into()),
+                }
+            }
+            "-o" | "--output" => args.output = Some(PathBuf::from(value()?)),
+            _ => return Err(format!("unknown option `{arg}`").into()),
+        }
+    }
+
+    args.file = file.ok_or("missing input file")?;
+    if args.animation.is_some() && args.state_machine.is_some() {
+        return Err("--animation and --state-machine are mutually exclusive".into());
+    }
+    if !args.inputs.is_empty() && args.state_machine.is_none() {
+        return Err("--input requires --state-machine".into());
+    }
+    if !(args.fps.is_finite() && args.fps > 0.0) {
+        return Err("--fps must be positive".into());
+    }
+    let frame_count = args.frame_count();
+    for input in &args.inputs {
+        if input.frame(args.fps) >= frame_count {
+            return Err(format!(
+                "input `{}` is scheduled after the last frame ({})",
+                input.name,
+                frame_count - 1
+            )
+            .into());
+        }
+    }
+    Ok(Some(args))
+}
+
+impl Args {
+    fn frame_count(&self) -> u32 {
+        match self.length {
+            Length::Frames(frames) => frames,
+            Length::Seconds(seconds) => (seconds * self.fps).ceil().max(1.0) as u32,
+        }
+    }
+}
+
+impl Input {
+    /// The frame whose state first reflects the change; frame `n` is shown at
+    /// `n / fps` seconds.
+    fn frame(&self, fps: f32) -> u32 {
+        match self.when {
+            None => 0,
+            Some(When::Frame(frame)) => frame,
+            // Tolerates rounding, so `1.5s` at 30 fps is frame 45, not 46.
+            Some(When::Seconds(seconds)) => (seconds * fps - 1e-3).ceil().max(0.0) as u32,
+        }
+    }
+}
+
+fn select_artboard(file: &File, selector: Option<&str>) -> CliResult<Artboard> {
+    let Some(selector) = selector else {
+        return Ok(file.default_artboard()?);
+    };
+    match file.artboard_by_name(selector) {
+        Ok(artboard) => Ok(artboard),
+        Err(err) => match selector.parse::<usize>() {
+            Ok(index) => Ok(file.artboard_by_index(index)?),
+            Err(_) => Err(format!("artboard `{selector}`: {err}").into()),
+        },
+    }
+}
+
+fn apply_inputs<'a>(
+    state_machine: &mut StateMachineInstance,
+    inputs: impl IntoIterator<Item = &'a Input>,
+) -> CliResult<()> {
+    for Input { name, value, .. } in inputs {
+        let input = state_machine
+            .input_by_name(name)
+            .map_err(|err| format!("input `{name}`: {err}"))?;
+        match (input.input_type(), value.as_deref()) {
+            (SmiInputType::RIVE_RS_SMI_INPUT_BOOL, Some(value)) => {
+                let value =
+                    parse_bool(value).ok_or_else(|| format!("input `{name}` expects a bool"))?;
+                input.as_bool()?.set(value);
+            }
+            (SmiInputType::RIVE_RS_SMI_INPUT_NUMBER, Some(value)) => {
+                input.as_number()?.set(value.parse()?);
+            }
+            (SmiInputType::RIVE_RS_SMI_INPUT_TRIGGER, None) => input.as_trigger()?.fire(),
+            (SmiInputType::RIVE_RS_SMI_INPUT_TRIGGER, Some(_)) => {
+                return Err(format!("trigger input `{name}` does not take a value").into());
+            }
+            (_, None) => return Err(format!("input `{name}` needs a value").into()),
+        }
+    }
+    Ok(())
+}
+
+/// The declared type of the property at `path`, looking through nested
+/// view models for `/`-separated paths.
+fn property_type(instance: &ViewModelInstance, path: &str) -> CliResult<DataType> {
+    let (nested, name) = match path.rsplit_once('/') {
+        Some((parent, name)) => (Some(instance.view_model(parent)?), name),
+        None => (None, path),
+    };
+    let owner = nested.as_ref().unwrap_or(instance);
+    for index in 0..owner.property_count() {
+        let property = owner.property_at(index)?;
+        if property.name == name {
+            return Ok(property.data_type);
+        }
+    }
+    Err("no such property".into())
+}
+
+fn set_view_model_value(
+    instance: &mut ViewModelInstance,
+    path: &str,
+    value: &str,
+) -> CliResult<()> {
+    let data_type = property_type(instance, path)?;
+    match data_type {
+        DataType::RIVE_RS_DATA_TYPE_NUMBER | DataType::RIVE_RS_DATA_TYPE_INTEGER => {
+            instance.set_number(path, value.parse()?)?
+        }
+        DataType::RIVE_RS_DATA_TYPE_BOOLEAN => {
+            let value = parse_bool(value).ok_or_else(|| format!("`{path}` expects a bool"))?;
+            instance.set_boolean(path, value)?
+        }
+        DataType::RIVE_RS_DATA_TYPE_COLOR => {
+            instance.set_color(path, parse_color(value)? as i32)?
+        }
+        DataType::RIVE_RS_DATA_TYPE_ENUM => match value.parse::<u32>() {
+            Ok(index) => instance.set_enum_index(path, index)?,
+            Err(_) => instance.set_enum_value(path, value)?,
+        },
+        DataType::RIVE_RS_DATA_TYPE_STRING => instance.set_string(path, value)?,
+        other => return Err(format!("`{path}` has unsupported type {other:?}").into()),
+    }
+    Ok(())
+}
+
+fn run(args: Args) -> CliResult<()> {
+    let bytes = fs::read(&args.file)
+        .map_err(|err| format!("failed to read {}: {err}", args.file.display()))?;
+    let factory = Factory::new_software()?;
+    let file = factory.load_file(&bytes)?;
+    let mut artboard = select_artboard(&file, args.artboard.as_deref())?;
+
+    let mut view_model = None;
+    if !args.view_model.is_empty() || !args.triggers.is_empty() {
+        let mut instance = file
+            .default_artboard_view_model(&mut artboard)?
+            .default_instance()?;
+        for (path, value) in &args.view_model {
+            set_view_model_value(&mut instance, path, value)
+                .map_err(|err| format!("view-model `{path}`: {err}"))?;
+        }
+        for path in &args.triggers {
+            instance.fire_trigger(path)?;
+        }
+        view_model = Some(instance);
+    }
+
+    let mut animation = match &args.animation {
+        Some(name) => Some(LinearAnimationInstance::new(
+            artboard.animation_by_name(name)?,
+            &mut artboard,
+        )?),
+        None => None,
+    };
+    let mut state_machine = match &args.state_machine {
+        Some(name) => Some(StateMachineInstance::new(
+            artboard.state_machine_by_name(name)?,
+            &mut artboard,
+        )?),
+        None => None,
+    };
+    if let Some(instance) = &view_model {
+        match state_machine.as_mut() {
+            Some(state_machine) => state_machine.bind_view_model_instance(instance)?,
+            None => artboard.bind_view_model_instance(instance)?,
+        }
+    }
+
+    let (width, height) = args.size.unwrap_or((
+        artboard.width().round().max(1.0) as u32,
+        artboard.height().round().max(1.0) as u32,
+    ));
+    let mut options = ExportOptions::new(width, height);
+    options.fps = args.fps;
+    options.frame_count = args.frame_count();
+    options.fit = args.fit;
+    options.alignment = args.alignment;
+    options.background = args.background;
+
+    // `parse_args` rejects both at once.
+    let timeline = match (animation.as_mut(), state_machine.as_mut()) {
+        (Some(animation), None) => Timeline::Animation(animation),
+        (None, Some(state_machine)) => Timeline::StateMachine(state_machine),
+        _ => Timeline::Static,
+    };
+    let frames = export_frames_with(&mut artboard, timeline, &options, |index, timeline| {
+        let Timeline::StateMachine(state_machine) = timeline else {
+            return Ok(());
+        };
+        let due = args
+            .inputs
+            .iter()
+            .filter(|input| input.frame(args.fps) == index);
+        apply_inputs(state_machine, due)
+    })?;
+
+    match args.format {
+        Format::Png => {
+            let directory = args.output.unwrap_or_else(|| PathBuf::from("."));
+            let stem = args
+                .file
+                .file_stem()
+                .map(|stem| format!("{}_", stem.to_string_lossy()))
+                .unwrap_or_default();
+            for path in write_png_sequence(&frames, &directory, &stem)? {
+                println!("{}", path.display());
+            }
+        }
+        Format::Apng | Format::Gif => {
+            let (bytes, extension) = if args.format == Format::Gif {
+                (encode_gif(&frames, args.fps)?, "gif")
+            } else {
+                (encode_apng(&frames, args.fps)?, "png")
+            };
+            let path = args
+                .output
+                .unwrap_or_else(|| PathBuf::from(format!("render.{extension}")));
+            fs::write(&path, bytes)?;
+            println!("{}", path.display());
+        }
+    }
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    let args = match parse_args(std::env::args().skip(1)) {
+        Ok(Some(args)) => args,
+        Ok(None) => {
+            print!("{USAGE}");
+            return ExitCode::SUCCESS;
+        }
+        Err(err) => {
+            eprintln!("rive-render: {err}\n\n{USAGE}");
+            return ExitCode::from(2);
+        }
+    };
+    match run(args) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("rive-render: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,188 @@
+// Asset bundle readers: zip (stored or deflated) and ustar/GNU tar, the
+// latter optionally gzipped, with GNU and pax long names. Zip64 and
+// encrypted entries are not supported.
+
+use crate::inflate::inflate;
+
+const ZIP_LOCAL_HEADER: u32 = 0x0403_4b50;
+const ZIP_CENTRAL_HEADER: u32 = 0x0201_4b50;
+const ZIP_END_OF_DIRECTORY: [u8; 4] = [0x50, 0x4b, 0x05, 0x06];
+const TAR_BLOCK: usize = 512;
+/// Compressed bundles are rejected once they inflate past this many bytes.
+const MAX_INFLATED_SIZE: usize = 1 << 30;
+
+/// Regular-file entries of a zip, tar or gzipped tar archive as
+/// `(path, contents)`, in archive order.
+pub(crate) fn read_entries(bytes: &[u8]) -> Option<Vec<(String, Vec<u8>)>> {
+    if bytes.starts_with(b"PK") {
+        read_zip(bytes)
+    } else if bytes.starts_with(&[0x1f, 0x8b]) {
+        read_tar(&gunzip(bytes)?)
+    } else {
+        read_tar(bytes)
+    }
+}
+
+fn u16_at(bytes: &[u8], offset: usize) -> Option<usize> {
+    let field = bytes.get(offset..offset.checked_add(2)?)?;
+    Some(u16::from_le_bytes([field[0], field[1]]) as usize)
+}
+
+fn u32_at(bytes: &[u8], offset: usize) -> Option<u32> {
+    let field = bytes.get(offset..offset.checked_add(4)?)?;
+    Some(u32::from_le_bytes([field[0], field[1], field[2], field[3]]))
+}
+
+fn read_zip(bytes: &[u8]) -> Option<Vec<(String, Vec<u8>)>> {
+    // The end-of-central-directory record trails an archive comment of up
+    // to 64 KiB.
+    let last = bytes.len().checked_sub(22)?;
+    let end = (last.saturating_sub(0xffff)..=last)
+        .rev()
+        .find(|&offset| bytes[offset..offset + 4] == ZIP_END_OF_DIRECTORY)?;
+    let count = u16_at(bytes, end + 10)?;
+    let mut offset = u32_at(bytes, end + 16)? as usize;
+
+    let mut entries = Vec::with_capacity(count);
+    let mut total = 0usize;
+    for _ in 0..count {
+        if u32_at(bytes, offset)? != ZIP_CENTRAL_HEADER {
+            return None;
+        }
+        let flags = u16_at(bytes, offset + 8)?;
+        let method = u16_at(bytes, offset + 10)?;
+        let compressed = u32_at(bytes, offset + 20)? as usize;
+        let size = u32_at(bytes, offset + 24)? as usize;
+        let name_len = u16_at(bytes, offset + 28)?;
+        let extra_len = u16_at(bytes, offset + 30)?;
+        let comment_len = u16_at(bytes, offset + 32)?;
+        let local = u32_at(bytes, offset + 42)? as usize;
+        let name = bytes.get(offset + 46..offset + 46 + name_len)?;
+        offset += 46 + name_len + extra_len + comment_len;
+
+        if name.ends_with(b"/") {
+            continue;
+        }
+        if flags & 1 != 0 || u32_at(bytes, local)? != ZIP_LOCAL_HEADER {
+            return None;
+        }
+        let start = local + 30 + u16_at(bytes, local + 26)? + u16_at(bytes, local + 28)?;
+        let data = bytes.get(start..start.checked_add(compressed)?)?;
+        total = total
+            .checked_add(size)
+            .filter(|&total| total <= MAX_INFLATED_SIZE)?;
+        let contents = match method {
+            0 => data.to_vec(),
+            8 => inflate(data, size)?,
+            _ => return None,
+        };
+        if contents.len() != size {
+            return None;
+        }
+        entries.push((String::from_utf8_lossy(name).into_owned(), contents));
+    }
+    Some(entries)
+}
+
+fn gunzip(bytes: &[u8]) -> Option<Vec<u8>> {
+    let header = bytes.get(..10)?;
+    if header[2] != 8 {
+        return None;
+    }
+    let flags = header[3];
+    let mut offset = 10;
+    if flags & 0x04 != 0 {
+        offset += 2 + u16_at(bytes, offset)?;
+    }
+    for field in [0x08, 0x10] {
+        if flags & field != 0 {
+            offset += bytes.get(offset..)?.iter().position(|&byte| byte == 0)? + 1;
+        }
+    }
+    if flags & 0x02 != 0 {
+        offset += 2;
+    }
+    inflate(bytes.get(offset..)?, MAX_INFLATED_SIZE)
+}
+
+fn c_string(field: &[u8]) -> String {
+    let end = field
+        .iter()
+        .position(|&byte| byte == 0)
+        .unwrap_or(field.len());
+    String::from_utf8_lossy(&field[..end]).into_owned()
+}
+
+fn octal(field: &[u8]) -> Option<u64> {
+    let text = core::str::from_utf8(field).ok()?;
+    let text = text.trim_matches(|c| c == '\0' || c == ' ');
+    if text.is_empty() {
+        return Some(0);
+    }
+    u64::from_str_radix(text, 8).ok()
+}
+
+fn tar_name(header: &[u8]) -> String {
+    let name = c_string(&header[..100]);
+    if &header[257..262] == b"ustar" {
+        let prefix = c_string(&header[345..500]);
+        if !prefix.is_empty() {
+            return format!("{prefix}/{name}");
+        }
+    }
+    name
+}
+
+// Pax extended headers are `"<len> <key>=<value>\n"` records.
+fn pax_path(data: &[u8]) -> Option<String> {
+    let text = String::from_utf8_lossy(data);
+    text.lines().find_map(|record| {
+        let (_, field) = record.split_once(' ')?;
+        field.strip_prefix("path=").map(str::to_owned)
+    })
+}
+
+fn read_tar(bytes: &[u8]) -> Option<Vec<(String, Vec<u8>)>> {
+    if bytes.len() < TAR_BLOCK {
+        return None;
+    }
+    let mut entries = Vec::new();
+    let mut long_name = None;
+    let mut offset = 0;
+    while offset < bytes.len() {
+        let header = bytes.get(offset..offset + TAR_BLOCK)?;
+        if header.iter().all(|&byte| byte == 0) {
+            break;
+        }
+        let checksum: u64 = header
+            .iter()
+            .enumerate()
+            .map(|(index, &byte)| {
+                if (148..156).contains(&index) {
+                    32
+                } else {
+                    byte as u64
+                }
+            })
+            .sum();
+        if octal(&header[148..156])? != checksum {
+            return None;
+        }
+        let size = usize::try_from(octal(&header[124..136])?).ok()?;
+        let start = offset + TAR_BLOCK;
+        let data = bytes.get(start..start.checked_add(size)?)?;
+        offset = start + size.div_ceil(TAR_BLOCK) * TAR_BLOCK;
+
+        match header[156] {
+            b'0' | 0 => {
+                let name = long_name.take().unwrap_or_else(|| tar_name(header));
+                entries.push((name, data.to_vec()));
+            }
+            b'L' => long_name = Some(c_string(data)),
+            b'x' => long_name = pax_path(data),
+            // Directories, links and global pax headers carry no asset bytes.
+            _ => long_name = None,
+        }
+    }
+    Some(entries)
+}
@@ -0,0 +1,319 @@
+use core::fmt;
+use std::cell::RefCell;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+use std::ptr::NonNull;
+use std::rc::Rc;
+use std::sync::mpsc::{self, Receiver, TryRecvError};
+
+use crate::assets::AssetKind;
+use crate::resolver::is_plain_name;
+use crate::runtime::{Factory, File, FileAsset};
+use crate::{Error, LoadOutcome};
+
+/// Base URL the Rive editor publishes hosted assets under; used when an
+/// asset does not name its own.
+pub const DEFAULT_CDN_BASE_URL: &str = "https://public.rive.app/cdn/uuid";
+
+/// Completion for one [`HttpTransport::get`].
+pub type FetchCallback = Box<dyn FnOnce(io::Result<Vec<u8>>) + Send>;
+
+/// Performs the HTTP GETs behind a [`CdnFetcher`].
+///
+/// Transports may finish a request before `get` returns or later from any
+/// thread, which lets them sit on top of a thread pool or an async client.
+/// Plain `Fn(&str) -> io::Result<Vec<u8>>` closures are blocking transports.
+pub trait HttpTransport: 'static {
+    /// Starts fetching `url`; `done` must be called at most once. A
+    /// request whose callback is dropped uncalled fails.
+    fn get(&self, url: &str, done: FetchCallback);
+}
+
+impl<F> HttpTransport for F
+where
+    F: Fn(&str) -> io::Result<Vec<u8>> + 'static,
+{
+    fn get(&self, url: &str, done: FetchCallback) {
+        done(self(url));
+    }
+}
+
+/// Content-addressed store of hosted asset bytes, one file per CDN UUID.
+#[derive(Debug, Clone)]
+pub struct DiskCache {
+    dir: PathBuf,
+}
+
+impl DiskCache {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    /// Where the bytes for `uuid` live, or `None` if `uuid` is not a plain
+    /// file name.
+    pub fn path(&self, uuid: &str) -> Option<PathBuf> {
+        is_plain_name(uuid).then(|| self.dir.join(uuid))
+    }
+
+    pub fn get(&self, uuid: &str) -> Option<Vec<u8>> {
+        fs::read(self.path(uuid)?).ok()
+    }
+
+    /// Stores `bytes` for `uuid`. Entries are written to a temporary file
+    /// and renamed into place, so readers never see partial contents.
+    pub fn put(&self, uuid: &str, bytes: &[u8]) -> io::Result<()> {
+        let path = self
+            .path(uuid)
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "invalid CDN uuid"))?;
+        fs::create_dir_all(&self.dir)?;
+        let partial = self
+            .dir
+            .join(format!(".{uuid}.{}.partial", std::process::id()));
+        fs::write(&partial, bytes)?;
+        fs::rename(&partial, &path).inspect_err(|_| {
+            let _ = fs::remove_file(&partial);
+        })
+    }
+}
+
+/// Why a hosted asset could not be attached.
+#[derive(Debug)]
+pub enum FetchError {
+    Transport(io::Error),
+    /// The bytes arrived but the factory could not decode them.
+    Decode(Error),
+}
+
+impl fmt::Display for FetchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Transport(err) => write!(f, "fetch failed: {err}"),
+            Self::Decode(err) => write!(f, "decode failed: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for FetchError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Transport(err) => Some(err),
+            Self::Decode(err) => Some(err),
+        }
+    }
+}
+
+/// The CDN URL of a hosted asset, or `None` for assets without a UUID.
+pub fn cdn_url(asset: &FileAsset) -> Option<String> {
+    let uuid = asset.cdn_uuid();
+    if uuid.is_empty() {
+        return None;
+    }
+    let base = asset.cdn_base_url();
+    let base = if base.is_empty() {
+        DEFAULT_CDN_BASE_URL
+    } else {
+        base.trim_end_matches('/')
+    };
+    Some(format!("{base}/{uuid}"))
+}
+
+/// Decodes `bytes` with `factory` and attaches the result to `asset`.
+pub fn attach_asset(asset: &mut FileAsset, factory: &Factory, bytes: &[u8]) -> Result<(), Error> {
+    match AssetKind::of(asset) {
+        AssetKind::Image => asset.set_render_image(Some(&factory.decode_image(bytes)?)),
+        AssetKind::Font => asset.set_font(&factory.decode_font(bytes)?),
+        AssetKind::Audio => asset.set_audio_source(&factory.decode_audio(bytes)?),
+        AssetKind::Other => asset.decode(factory, bytes),
+    }
+}
+
+/// Fetches CDN-hosted assets over a pluggable [`HttpTransport`], with an
+/// optional [`DiskCache`].
+///
+/// [`CdnFetcher::load_file`] attaches cached assets while the file loads
+/// and requests the rest; the returned [`HostedAssets`] attaches them as
+/// their bytes arrive, so the file can be shown before its hosted assets.
+#[derive(Clone)]
+pub struct CdnFetcher {
+    transport: Rc<dyn HttpTransport>,
+    cache: Option<DiskCache>,
+}
+
+impl CdnFetcher {
+    pub fn new(transport: impl HttpTransport) -> Self {
+        Self {
+            transport: Rc::new(transport),
+            cache: None,
+        }
+    }
+
+    pub fn with_cache(mut self, cache: DiskCache) -> Self {
+        self.cache = Some(cache);
+        self
+    }
+
+    pub fn cache(&self) -> Option<&DiskCache> {
+        self.cache.as_ref()
+    }
+
+    /// Loads a file and starts fetching every hosted asset the cache does
+    /// not already hold. Assets with in-band bytes are left to the runtime.
+    pub fn load_file(
+        &self,
+        factory: &Factory,
+        bytes: &[u8],
+    ) -> Result<(File, HostedAssets), Error> {
+        let requested = Rc::new(RefCell::new(Vec::new()));
+        let cache = self.cache.clone();
+        let pending = requested.clone();
+        let file = factory.load_file_with_loader(
+            bytes,
+            move |asset: &mut FileAsset, in_band: &[u8], factory: &Factory| {
+                if !in_band.is_empty() {
+                    return LoadOutcome::Fallback;
+                }
+                let Some(url) = cdn_url(asset) else {
+                    return LoadOutcome::Fallback;
+                };
+                let cached = cache
+                    .as_ref()
+                    .and_then(|cache| cache.get(&asset.cdn_uuid()));
+                if let Some(bytes) = cached
+                    && attach_asset(asset, factory, &bytes).is_ok()
+                {
+                    return LoadOutcome::Loaded;
+                }
+                if let Some(raw) = NonNull::new(asset.as_raw()) {
+                    pending.borrow_mut().push((raw, url));
+                }
+                LoadOutcome::Fallback
+            },
+        )?;
+
+        let (sender, receiver) = mpsc::channel();
+        let mut assets = Vec::new();
+        for (index, (raw, url)) in requested.take().into_iter().enumerate() {
+            // SAFETY: the asset belongs to `file`, which `HostedAssets` keeps
+            // alive alongside it.
+            let asset = unsafe { FileAsset::from_raw(raw.as_ptr()) }?;
+            let sender = sender.clone();
+            self.transport.get(
+                &url,
+                Box::new(move |result| {
+                    let _ = sender.send((index, result));
+                }),
+            );
+            assets.push(HostedAsset {
+                uuid: asset.cdn_uuid(),
+                asset,
+                url,
+                done: false,
+            });
+        }
+
+        let hosted = HostedAssets {
+            _file: file.clone(),
+            factory: factory.clone(),
+            cache: self.cache.clone(),
+            assets,
+            receiver,
+        };
+        Ok((file, hosted))
+    }
+}
+
+struct HostedAsset {
+    asset: FileAsset,
+    uuid: String,
+    url: String,
+    done: bool,
+}
+
+/// One hosted asset whose request finished.
+#[derive(Debug)]
+pub struct FetchOutcome {
+    pub uuid: String,
+    pub url: String,
+    pub result: Result<(), FetchError>,
+}
+
+/// Hosted assets of a file loaded with [`CdnFetcher::load_file`] whose
+/// requests are still in flight.
+///
+/// Call [`HostedAssets::poll`] once per frame, or [`HostedAssets::wait`]
+/// to block; arrived bytes are cached, decoded and attached to the file.
+pub struct HostedAssets {
+    _file: File,
+    factory: Factory,
+    cache: Option<DiskCache>,
+    assets: Vec<HostedAsset>,
+    receiver: Receiver<(usize, io::Result<Vec<u8>>)>,
+}
+
+impl HostedAssets {
+    /// URLs of the assets still waiting on the transport.
+    pub fn pending(&self) -> impl Iterator<Item = &str> {
+        self.assets
+            .iter()
+            .filter(|hosted| !hosted.done)
+            .map(|hosted| hosted.url.as_str())
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.assets.iter().all(|hosted| hosted.done)
+    }
+
+    /// Attaches whatever has arrived without blocking.
+    pub fn poll(&mut self) -> Vec<FetchOutcome> {
+        let mut outcomes = Vec::new();
+        while !self.is_complete() {
+            match self.receiver.try_recv() {
+                Ok((index, result)) => outcomes.extend(self.finish(index, result)),
+                Err(TryRecvError::Empty) => break,
+                Err(TryRecvError::Disconnected) => outcomes.extend(self.abandon()),
+            }
+        }
+        outcomes
+    }
+
+    /// Blocks until every request has finished.
+    pub fn wait(&mut self) -> Vec<FetchOutcome> {
+        let mut outcomes = Vec::new();
+        while !self.is_complete() {
+            match self.receiver.recv() {
+                Ok((index, result)) => outcomes.extend(self.finish(index, result)),
+                Err(_) => outcomes.extend(self.abandon()),
+            }
+        }
+        outcomes
+    }
+
+    fn finish(&mut self, index: usize, result: io::Result<Vec<u8>>) -> Option<FetchOutcome> {
+        let hosted = self.assets.get_mut(index).filter(|hosted| !hosted.done)?;
+        hosted.done = true;
+        let result = result.map_err(FetchError::Transport).and_then(|bytes| {
+            attach_asset(&mut hosted.asset, &self.factory, &bytes).map_err(FetchError::Decode)?;
+            if let Some(cache) = &self.cache {
+                // The cache is best-effort; the asset is attached either way.
+                let _ = cache.put(&hosted.uuid, &bytes);
+            }
+            Ok(())
+        });
+        Some(FetchOutcome {
+            uuid: hosted.uuid.clone(),
+            url: hosted.url.clone(),
+            result,
+        })
+    }
+
+    // Every sender is gone, so the remaining callbacks were dropped uncalled.
+    fn abandon(&mut self) -> Vec<FetchOutcome> {
+        (0..self.assets.len())
+            .filter_map(|index| {
+                let dropped = io::Error::new(io::ErrorKind::Interrupted, "request dropped");
+                self.finish(index, Err(dropped))
+            })
+            .collect()
+    }
+}
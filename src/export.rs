@@ -0,0 +1,250 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::runtime::{
+    Aabb, Alignment, Artboard, Fit, LinearAnimationInstance, SoftwareRenderer,
+    StateMachineInstance, compute_alignment,
+};
+use crate::{Error, Status, gif, png, raster};
+
+/// What drives the artboard between exported frames.
+pub enum Timeline<'a> {
+    /// Only the artboard itself is advanced.
+    Static,
+    Animation(&'a mut LinearAnimationInstance),
+    StateMachine(&'a mut StateMachineInstance),
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct ExportOptions {
+    pub width: u32,
+    pub height: u32,
+    pub fps: f32,
+    pub frame_count: u32,
+    pub fit: Fit,
+    pub alignment: Alignment,
+    /// Non-premultiplied ARGB composited under every frame; `0` keeps
+    /// frames transparent.
+    pub background: u32,
+}
+
+impl ExportOptions {
+    pub fn new(width: u32, height: u32) -> Self {
+        Self {
+            width,
+            height,
+            fps: 30.0,
+            frame_count: 1,
+            fit: Fit::RIVE_RS_FIT_CONTAIN,
+            alignment: Alignment::RIVE_RS_ALIGNMENT_CENTER,
+            background: 0,
+        }
+    }
+
+    fn validate(&self) -> Result<(), Error> {
+        let valid = self.width > 0
+            && self.height > 0
+            && self.width <= i32::MAX as u32
+            && self.height <= i32::MAX as u32
+            && self.fps.is_finite()
+            && self.fps > 0.0
+            && self.frame_count > 0;
+        if valid {
+            Ok(())
+        } else {
+            Err(invalid_argument())
+        }
+    }
+}
+
+/// One rasterized frame as unpremultiplied RGBA8, row-major.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Frame {
+    pub width: u32,
+    pub height: u32,
+    pub rgba: Vec<u8>,
+}
+
+impl Frame {
+    pub fn to_png(&self) -> Vec<u8> {
+        png::encode(self.width, self.height, &self.rgba)
+    }
+}
+
+fn invalid_argument() -> Error {
+    Error::from_status(Status::RIVE_RS_STATUS_INVALID_ARGUMENT)
+}
+
+fn advance(
+    artboard: &mut Artboard,
+    timeline: &mut Timeline<'_>,
+    seconds: f32,
+) -> Result<(), Error> {
+    match timeline {
+        Timeline::Static => {
+            artboard.advance(seconds)?;
+        }
+        Timeline::Animation(animation) => {
+            animation.advance(seconds)?;
+            animation.apply(artboard, 1.0)?;
+            artboard.advance(seconds)?;
+        }
+        Timeline::StateMachine(state_machine) => {
+            state_machine.advance_and_apply(seconds)?;
+        }
+    }
+    Ok(())
+}
+
+fn composite_background(pixels: &mut [u8], argb: u32) {
+    if argb >> 24 == 0 {
+        return;
+    }
+    let [a, r, g, b] = argb.to_be_bytes();
+    let background = [r, g, b].map(|channel| (channel as u32 * a as u32 + 127) / 255);
+    for pixel in pixels.chunks_exact_mut(4) {
+        let inverse = 255 - pixel[3] as u32;
+        for (channel, &under) in pixel[..3].iter_mut().zip(&background) {
+            *channel = (*channel as u32 + (under * inverse + 127) / 255).min(255) as u8;
+        }
+        pixel[3] = (pixel[3] as u32 + (a as u32 * inverse + 127) / 255).min(255) as u8;
+    }
+}
+
+fn target_frame(options: &ExportOptions) -> Aabb {
+    Aabb {
+        min_x: 0.0,
+        min_y: 0.0,
+        max_x: options.width as f32,
+        max_y: options.height as f32,
+    }
+}
+
+/// Rasterizes the artboard's current state into `renderer`, which must match
+/// the option's size.
+pub(crate) fn render_frame(
+    renderer: &mut SoftwareRenderer,
+    artboard: &mut Artboard,
+    options: &ExportOptions,
+) -> Result<Frame, Error> {
+    let alignment = compute_alignment(
+        options.fit,
+        options.alignment,
+        &target_frame(options),
+        &artboard.bounds(),
+        1.0,
+    )?;
+    renderer.clear()?;
+    renderer.save()?;
+    renderer.transform(&alignment)?;
+    artboard.draw_software(renderer)?;
+    renderer.restore()?;
+
+    let mut rgba = renderer.pixels().to_vec();
+    composite_background(&mut rgba, options.background);
+    raster::unpremultiply(&mut rgba);
+    Ok(Frame {
+        width: options.width,
+        height: options.height,
+        rgba,
+    })
+}
+
+pub(crate) fn new_renderer(options: &ExportOptions) -> Result<SoftwareRenderer, Error> {
+    options.validate()?;
+    SoftwareRenderer::new(options.width as i32, options.height as i32)
+}
+
+/// Steps `timeline` at `options.fps` and rasterizes `options.frame_count`
+/// frames, fitting the artboard bounds into the target size. The first frame
+/// is taken before any time has elapsed.
+pub fn export_frames(
+    artboard: &mut Artboard,
+    timeline: Timeline<'_>,
+    options: &ExportOptions,
+) -> Result<Vec<Frame>, Error> {
+    export_frames_with(artboard, timeline, options, |_, _| Ok(()))
+}
+
+/// Like [`export_frames`], but calls `before_frame` with each frame's index
+/// before the timeline advances to it, e.g. to change inputs mid-export.
+pub fn export_frames_with<E: From<Error>>(
+    artboard: &mut Artboard,
+    mut timeline: Timeline<'_>,
+    options: &ExportOptions,
+    mut before_frame: impl FnMut(u32, &mut Timeline<'_>) -> Result<(), E>,
+) -> Result<Vec<Frame>, E> {
+    let mut renderer = new_renderer(options)?;
+    let step = 1.0 / options.fps;
+    let mut frames = Vec::with_capacity(options.frame_count as usize);
+    for index in 0..options.frame_count {
+        before_frame(index, &mut timeline)?;
+        advance(artboard, &mut timeline, if index == 0 { 0.0 } else { step })?;
+        frames.push(render_frame(&mut renderer, artboard, options)?);
+    }
+    Ok(frames)
+}
+
+fn frame_size(frames: &[Frame]) -> Result<(u32, u32), Error> {
+    let first = frames.first().ok_or_else(invalid_argument)?;
+    let consistent = frames.iter().all(|frame| {
+        frame.width == first.width
+            && frame.height == first.height
+            && frame.rgba.len() == first.width as usize * first.height as usize * 4
+    });
+    if !consistent || first.width == 0 || first.height == 0 {
+        return Err(invalid_argument());
+    }
+    Ok((first.width, first.height))
+}
+
+/// Writes `<prefix>00000.png`, `<prefix>00001.png`, ... into `directory`.
+pub fn write_png_sequence(
+    frames: &[Frame],
+    directory: &Path,
+    prefix: &str,
+) -> io::Result<Vec<PathBuf>> {
+    fs::create_dir_all(directory)?;
+    frames
+        .iter()
+        .enumerate()
+        .map(|(index, frame)| {
+            let path = directory.join(format!("{prefix}{index:05}.png"));
+            fs::write(&path, frame.to_png())?;
+            Ok(path)
+        })
+        .collect()
+}
+
+/// Encodes `frames` as a looping APNG played back at `fps`.
+pub fn encode_apng(frames: &[Frame], fps: f32) -> Result<Vec<u8>, Error> {
+    let (width, height) = frame_size(frames)?;
+    if !(fps.is_finite() && fps > 0.0) {
+        return Err(invalid_argument());
+    }
+    let delay = (1000.0 / fps).round().clamp(1.0, u16::MAX as f32) as u16;
+    Ok(png::encode_animated(
+        width,
+        height,
+        frames.iter().map(|frame| frame.rgba.as_slice()),
+        delay,
+        1000,
+    ))
+}
+
+/// Encodes `frames` as a looping GIF played back at `fps`. GIF delays have
+/// centisecond resolution and alpha is thresholded to fully transparent.
+pub fn encode_gif(frames: &[Frame], fps: f32) -> Result<Vec<u8>, Error> {
+    let (width, height) = frame_size(frames)?;
+    if !(fps.is_finite() && fps > 0.0) || width > u16::MAX as u32 || height > u16::MAX as u32 {
+        return Err(invalid_argument());
+    }
+    let delay = (100.0 / fps).round().clamp(1.0, u16::MAX as f32) as u16;
+    Ok(gif::encode_animated(
+        width as u16,
+        height as u16,
+        frames.iter().map(|frame| frame.rgba.as_slice()),
+        delay,
+    ))
+}
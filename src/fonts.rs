@@ -0,0 +1,74 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::runtime::{Factory, Font};
+
+const FONT_EXTENSIONS: [&str; 4] = ["ttf", "otf", "ttc", "otc"];
+
+/// Font files under `dir` and its subdirectories, sorted by path, the way
+/// fontconfig scans its `<dir>` entries.
+pub fn font_files(dir: impl AsRef<Path>) -> io::Result<Vec<PathBuf>> {
+    let mut files = Vec::new();
+    let mut pending = vec![dir.as_ref().to_path_buf()];
+    while let Some(dir) = pending.pop() {
+        for entry in fs::read_dir(&dir)? {
+            let entry = entry?;
+            let path = entry.path();
+            if entry.file_type()?.is_dir() {
+                pending.push(path);
+            } else if path
+                .extension()
+                .and_then(|ext| ext.to_str())
+                .is_some_and(|ext| {
+                    FONT_EXTENSIONS
+                        .iter()
+                        .any(|known| ext.eq_ignore_ascii_case(known))
+                })
+            {
+                files.push(path);
+            }
+        }
+    }
+    files.sort();
+    Ok(files)
+}
+
+/// The platform's usual font directories that exist on this machine.
+pub fn system_font_dirs() -> Vec<PathBuf> {
+    let mut dirs = Vec::new();
+    let home = std::env::var_os("HOME").map(PathBuf::from);
+    if cfg!(target_os = "macos") {
+        dirs.extend(home.iter().map(|home| home.join("Library/Fonts")));
+        dirs.push(PathBuf::from("/Library/Fonts"));
+        dirs.push(PathBuf::from("/System/Library/Fonts"));
+    } else if cfg!(windows) {
+        let windir = std::env::var_os("WINDIR").unwrap_or_else(|| "C:\\Windows".into());
+        dirs.push(PathBuf::from(windir).join("Fonts"));
+        if let Some(local) = std::env::var_os("LOCALAPPDATA") {
+            dirs.push(PathBuf::from(local).join("Microsoft\\Windows\\Fonts"));
+        }
+    } else {
+        let data_home = std::env::var_os("XDG_DATA_HOME")
+            .map(PathBuf::from)
+            .or_else(|| home.as_ref().map(|home| home.join(".local/share")));
+        dirs.extend(data_home.map(|data| data.join("fonts")));
+        dirs.extend(home.iter().map(|home| home.join(".fonts")));
+        dirs.push(PathBuf::from("/usr/local/share/fonts"));
+        dirs.push(PathBuf::from("/usr/share/fonts"));
+    }
+    dirs.retain(|dir| dir.is_dir());
+    dirs
+}
+
+/// Decodes every font under `dir`, in [`font_files`] order. Files the
+/// factory cannot decode are skipped.
+pub fn load_fonts(factory: &Factory, dir: impl AsRef<Path>) -> io::Result<Vec<Font>> {
+    let mut fonts = Vec::new();
+    for path in font_files(dir)? {
+        if let Ok(font) = factory.decode_font(&fs::read(&path)?) {
+            fonts.push(font);
+        }
+    }
+    Ok(fonts)
+}
@@ -0,0 +1,237 @@
+use std::collections::HashMap;
+
+const MAX_CODE: u16 = 4095;
+// Pixels below this alpha become the transparent palette entry.
+const ALPHA_THRESHOLD: u8 = 128;
+
+fn color_key(pixel: &[u8]) -> u16 {
+    (pixel[0] as u16 >> 3) << 10 | (pixel[1] as u16 >> 3) << 5 | pixel[2] as u16 >> 3
+}
+
+struct ColorBox {
+    colors: Vec<(u16, u32)>,
+}
+
+impl ColorBox {
+    fn channel(key: u16, channel: usize) -> u16 {
+        (key >> (10 - channel * 5)) & 31
+    }
+
+    fn widest_channel(&self) -> (usize, u16) {
+        (0..3)
+            .map(|channel| {
+                let (min, max) = self.colors.iter().fold((31, 0), |(min, max), &(key, _)| {
+                    let value = Self::channel(key, channel);
+                    (value.min(min), value.max(max))
+                });
+                (channel, max.saturating_sub(min))
+            })
+            .max_by_key(|&(_, range)| range)
+            .unwrap_or((0, 0))
+    }
+
+    fn average(&self) -> [u8; 3] {
+        let total: u64 = self.colors.iter().map(|&(_, count)| count as u64).sum();
+        let mut sums = [0u64; 3];
+        for &(key, count) in &self.colors {
+            for (channel, sum) in sums.iter_mut().enumerate() {
+                *sum += (Self::channel(key, channel) as u64 * 8 + 4) * count as u64;
+            }
+        }
+        sums.map(|sum| (sum / total.max(1)).min(255) as u8)
+    }
+}
+
+/// Median-cut quantization over 15-bit colors. Returns the palette and the
+/// palette index of every color present in `histogram`.
+fn quantize(histogram: &HashMap<u16, u32>, max_colors: usize) -> (Vec<[u8; 3]>, HashMap<u16, u8>) {
+    let mut boxes = vec![ColorBox {
+        colors: histogram
+            .iter()
+            .map(|(&key, &count)| (key, count))
+            .collect(),
+    }];
+    while boxes.len() < max_colors {
+        let Some((index, channel)) = boxes
+            .iter()
+            .enumerate()
+            .filter(|(_, color_box)| color_box.colors.len() > 1)
+            .map(|(index, color_box)| (index, color_box.widest_channel()))
+            .max_by_key(|&(_, (_, range))| range)
+            .map(|(index, (channel, _))| (index, channel))
+        else {
+            break;
+        };
+        let mut colors = std::mem::take(&mut boxes[index].colors);
+        colors.sort_by_key(|&(key, _)| ColorBox::channel(key, channel));
+        let total: u64 = colors.iter().map(|&(_, count)| count as u64).sum();
+        let mut running = 0;
+        let split = colors
+            .iter()
+            .position(|&(_, count)| {
+                running += count as u64;
+                running * 2 >= total
+            })
+            .map_or(1, |position| position + 1)
+            .clamp(1, colors.len() - 1);
+        let upper = colors.split_off(split);
+        boxes[index].colors = colors;
+        boxes.push(ColorBox { colors: upper });
+    }
+
+    let mut lookup = HashMap::with_capacity(histogram.len());
+    let palette = boxes
+        .iter()
+        .enumerate()
+        .map(|(index, color_box)| {
+            for &(key, _) in &color_box.colors {
+                lookup.insert(key, index as u8);
+            }
+            color_box.average()
+        })
+        .collect();
+    (palette, lookup)
+}
+
+struct CodeWriter {
+    out: Vec<u8>,
+    bits: u32,
+    count: u32,
+    width: u32,
+    next_code: u16,
+}
+
+impl CodeWriter {
+    fn emit(&mut self, code: u16) {
+        self.bits |= (code as u32) << self.count;
+        self.count += self.width;
+        while self.count >= 8 {
+            self.out.push(self.bits as u8);
+            self.bits >>= 8;
+            self.count -= 8;
+        }
+        if self.next_code as u32 >= 1 << self.width && self.width < 12 {
+            self.width += 1;
+        }
+    }
+}
+
+fn lzw(indices: &[u8], min_code_size: u32) -> Vec<u8> {
+    let clear = 1u16 << min_code_size;
+    let end = clear + 1;
+    let mut writer = CodeWriter {
+        out: Vec::new(),
+        bits: 0,
+        count: 0,
+        width: min_code_size + 1,
+        next_code: end + 1,
+    };
+    let mut table: HashMap<(u16, u8), u16> = HashMap::new();
+    writer.emit(clear);
+
+    let Some((&first, rest)) = indices.split_first() else {
+        writer.emit(end);
+        return writer.out;
+    };
+    let mut prefix = first as u16;
+    for &index in rest {
+        if let Some(&code) = table.get(&(prefix, index)) {
+            prefix = code;
+            continue;
+        }
+        writer.emit(prefix);
+        if writer.next_code >= MAX_CODE {
+            writer.emit(clear);
+            table.clear();
+            writer.next_code = end + 1;
+            writer.width = min_code_size + 1;
+        } else {
+            table.insert((prefix, index), writer.next_code);
+            writer.next_code += 1;
+        }
+        prefix = index as u16;
+    }
+    writer.emit(prefix);
+    writer.emit(end);
+    if writer.count > 0 {
+        writer.out.push(writer.bits as u8);
+    }
+    writer.out
+}
+
+fn sub_blocks(out: &mut Vec<u8>, data: &[u8]) {
+    for block in data.chunks(255) {
+        out.push(block.len() as u8);
+        out.extend_from_slice(block);
+    }
+    out.push(0);
+}
+
+/// Encodes same-sized unpremultiplied RGBA8 frames as a looping GIF with a
+/// per-frame palette. `delay` is in hundredths of a second.
+pub(crate) fn encode_animated<'a>(
+    width: u16,
+    height: u16,
+    frames: impl Iterator<Item = &'a [u8]>,
+    delay: u16,
+) -> Vec<u8> {
+    let mut out = Vec::new();
+    out.extend_from_slice(b"GIF89a");
+    out.extend_from_slice(&width.to_le_bytes());
+    out.extend_from_slice(&height.to_le_bytes());
+    out.extend_from_slice(&[0, 0, 0]);
+    // NETSCAPE2.0 application extension: loop forever.
+    out.extend_from_slice(b"\x21\xff\x0bNETSCAPE2.0\x03\x01\x00\x00\x00");
+
+    for rgba in frames {
+        let mut histogram = HashMap::new();
+        let mut transparent = false;
+        for pixel in rgba.chunks_exact(4) {
+            if pixel[3] < ALPHA_THRESHOLD {
+                transparent = true;
+            } else {
+                *histogram.entry(color_key(pixel)).or_insert(0u32) += 1;
+            }
+        }
+        let (mut palette, lookup) = quantize(&histogram, 255);
+        let transparent_index = palette.len() as u8;
+        if transparent || palette.is_empty() {
+            palette.push([0, 0, 0]);
+        }
+        let bits = (palette.len().max(2) as u32)
+            .next_power_of_two()
+            .trailing_zeros();
+        palette.resize(1 << bits, [0, 0, 0]);
+
+        let indices: Vec<u8> = rgba
+            .chunks_exact(4)
+            .map(|pixel| {
+                if pixel[3] < ALPHA_THRESHOLD {
+                    transparent_index
+                } else {
+                    lookup[&color_key(pixel)]
+                }
+            })
+            .collect();
+
+        // Graphic control: restore to background so transparent areas do
+        // not accumulate across frames.
+        out.extend_from_slice(&[0x21, 0xf9, 0x04, 0x08 | transparent as u8]);
+        out.extend_from_slice(&delay.to_le_bytes());
+        out.extend_from_slice(&[transparent_index, 0]);
+
+        out.push(0x2c);
+        out.extend_from_slice(&[0, 0, 0, 0]);
+        out.extend_from_slice(&width.to_le_bytes());
+        out.extend_from_slice(&height.to_le_bytes());
+        out.push(0x80 | (bits - 1) as u8);
+        for color in &palette {
+            out.extend_from_slice(color);
+        }
+        let min_code_size = bits.max(2);
+        out.push(min_code_size as u8);
+        sub_blocks(&mut out, &lzw(&indices, min_code_size));
+    }
+    out.push(0x3b);
+    out
+}
@@ -0,0 +1,397 @@
+//! Golden-image snapshot testing for artboards (`golden` feature).
+//!
+//! A [`Golden`] renders artboards headlessly and compares the result against
+//! reference PNGs stored in a directory. On a mismatch it writes the actual
+//! frame and a diff image next to each other in the output directory. Set
+//! `RIVE_RS_UPDATE_GOLDENS=1` (or call [`Golden::update`]) to (re)write the
+//! references instead of comparing.
+
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::export::{self, ExportOptions, Frame};
+use crate::runtime::{
+    Alignment, Artboard, Fit, LinearAnimationInstance, SmiInputType, StateMachineInstance, Vec2,
+};
+use crate::{Error, Status, png};
+
+pub const UPDATE_ENV: &str = "RIVE_RS_UPDATE_GOLDENS";
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Tolerance {
+    /// Largest per-channel difference that still counts as a match.
+    pub channel: u8,
+    /// Number of pixels allowed to exceed `channel`.
+    pub max_mismatched_pixels: usize,
+}
+
+impl Default for Tolerance {
+    fn default() -> Self {
+        Self {
+            channel: 2,
+            max_mismatched_pixels: 0,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Comparison {
+    pub mismatched_pixels: usize,
+    pub max_difference: u8,
+    /// Matching pixels faded to grey, mismatched pixels in red.
+    pub diff: Frame,
+}
+
+impl Comparison {
+    pub fn passes(&self, tolerance: &Tolerance) -> bool {
+        self.mismatched_pixels <= tolerance.max_mismatched_pixels
+    }
+}
+
+/// Compares two equally sized frames. Returns `None` when the sizes differ.
+pub fn compare(expected: &Frame, actual: &Frame, tolerance: &Tolerance) -> Option<Comparison> {
+    if expected.width != actual.width
+        || expected.height != actual.height
+        || expected.rgba.len() != actual.rgba.len()
+    {
+        return None;
+    }
+    let mut mismatched_pixels = 0;
+    let mut max_difference = 0;
+    let mut diff = Vec::with_capacity(expected.rgba.len());
+    for (want, got) in expected
+        .rgba
+        .chunks_exact(4)
+        .zip(actual.rgba.chunks_exact(4))
+    {
+        let difference = want
+            .iter()
+            .zip(got)
+            .map(|(&a, &b)| a.abs_diff(b))
+            .max()
+            .unwrap_or(0);
+        max_difference = max_difference.max(difference);
+        if difference > tolerance.channel {
+            mismatched_pixels += 1;
+            diff.extend_from_slice(&[255, 0, 0, 255]);
+        } else {
+            let luma = (want[0] as u32 * 77 + want[1] as u32 * 150 + want[2] as u32 * 29) >> 8;
+            let faded = 255 - ((255 - luma) * want[3] as u32 / 255) / 4;
+            diff.extend_from_slice(&[faded as u8, faded as u8, faded as u8, 255]);
+        }
+    }
+    Some(Comparison {
+        mismatched_pixels,
+        max_difference,
+        diff: Frame {
+            width: expected.width,
+            height: expected.height,
+            rgba: diff,
+        },
+    })
+}
+
+#[derive(Debug)]
+pub enum GoldenError {
+    Runtime(Error),
+    Io {
+        path: PathBuf,
+        source: io::Error,
+    },
+    /// The reference exists but is not a decodable PNG.
+    InvalidReference(PathBuf),
+    MissingReference {
+        reference: PathBuf,
+        actual: PathBuf,
+    },
+    SizeMismatch {
+        name: String,
+        expected: (u32, u32),
+        actual: (u32, u32),
+        actual_path: PathBuf,
+    },
+    Mismatch {
+        name: String,
+        mismatched_pixels: usize,
+        max_difference: u8,
+        actual: PathBuf,
+        diff: PathBuf,
+    },
+}
+
+impl fmt::Display for GoldenError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Runtime(err) => write!(f, "render failed: {err}"),
+            Self::Io { path, source } => write!(f, "{}: {source}", path.display()),
+            Self::InvalidReference(path) => write!(f, "{}: not a valid PNG", path.display()),
+            Self::MissingReference { reference, actual } => write!(
+                f,
+                "missing reference {} (actual frame written to {}; set {UPDATE_ENV}=1 to accept)",
+                reference.display(),
+                actual.display()
+            ),
+            Self::SizeMismatch {
+                name,
+                expected,
+                actual,
+                actual_path,
+            } => write!(
+                f,
+                "{name}: expected {}x{}, rendered {}x{} (written to {})",
+                expected.0,
+                expected.1,
+                actual.0,
+                actual.1,
+                actual_path.display()
+            ),
+            Self::Mismatch {
+                name,
+                mismatched_pixels,
+                max_difference,
+                actual,
+                diff,
+            } => write!(
+                f,
+                "{name}: {mismatched_pixels} pixels differ (max channel difference \
+                 {max_difference}); actual {}, diff {}",
+                actual.display(),
+                diff.display()
+            ),
+        }
+    }
+}
+
+impl std::error::Error for GoldenError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Runtime(err) => Some(err),
+            Self::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+impl From<Error> for GoldenError {
+    fn from(err: Error) -> Self {
+        Self::Runtime(err)
+    }
+}
+
+/// One step of a scripted state-machine run.
+#[derive(Debug, Copy, Clone)]
+pub enum Step<'a> {
+    Advance(f32),
+    SetBool(&'a str, bool),
+    SetNumber(&'a str, f32),
+    Fire(&'a str),
+    PointerDown(Vec2),
+    PointerMove(Vec2),
+    PointerUp(Vec2),
+    /// Renders and checks `<name>_<snapshot>`.
+    Snapshot(&'a str),
+}
+
+#[derive(Debug, Clone)]
+pub struct Golden {
+    reference_dir: PathBuf,
+    output_dir: PathBuf,
+    options: ExportOptions,
+    tolerance: Tolerance,
+    update: bool,
+}
+
+impl Golden {
+    /// References are read from `reference_dir`; failures are written to
+    /// `reference_dir/failures` unless [`Golden::output_dir`] says otherwise.
+    pub fn new(reference_dir: impl Into<PathBuf>, width: u32, height: u32) -> Self {
+        let reference_dir = reference_dir.into();
+        Self {
+            output_dir: reference_dir.join("failures"),
+            reference_dir,
+            options: ExportOptions::new(width, height),
+            tolerance: Tolerance::default(),
+            update: std::env::var_os(UPDATE_ENV).is_some_and(|value| value != "0"),
+        }
+    }
+
+    pub fn output_dir(mut self, output_dir: impl Into<PathBuf>) -> Self {
+        self.output_dir = output_dir.into();
+        self
+    }
+
+    pub fn tolerance(mut self, tolerance: Tolerance) -> Self {
+        self.tolerance = tolerance;
+        self
+    }
+
+    pub fn layout(mut self, fit: Fit, alignment: Alignment) -> Self {
+        self.options.fit = fit;
+        self.options.alignment = alignment;
+        self
+    }
+
+    /// Non-premultiplied ARGB composited under every frame.
+    pub fn background(mut self, argb: u32) -> Self {
+        self.options.background = argb;
+        self
+    }
+
+    pub fn update(mut self, update: bool) -> Self {
+        self.update = update;
+        self
+    }
+
+    pub fn reference_path(&self, name: &str) -> PathBuf {
+        self.reference_dir.join(format!("{name}.png"))
+    }
+
+    /// Renders the artboard's current state without advancing it.
+    pub fn render(&self, artboard: &mut Artboard) -> Result<Frame, GoldenError> {
+        let mut renderer = export::new_renderer(&self.options)?;
+        Ok(export::render_frame(
+            &mut renderer,
+            artboard,
+            &self.options,
+        )?)
+    }
+
+    pub fn check(&self, name: &str, frame: &Frame) -> Result<(), GoldenError> {
+        let reference = self.reference_path(name);
+        if self.update {
+            write(&reference, &frame.to_png())?;
+            return Ok(());
+        }
+        let bytes = match fs::read(&reference) {
+            Ok(bytes) => bytes,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => {
+                let actual = self.write_output(name, "actual", frame)?;
+                return Err(GoldenError::MissingReference { reference, actual });
+            }
+            Err(source) => {
+                return Err(GoldenError::Io {
+                    path: reference,
+                    source,
+                });
+            }
+        };
+        let (width, height, rgba) =
+            png::decode(&bytes).ok_or_else(|| GoldenError::InvalidReference(reference.clone()))?;
+        let expected = Frame {
+            width,
+            height,
+            rgba,
+        };
+        let Some(comparison) = compare(&expected, frame, &self.tolerance) else {
+            return Err(GoldenError::SizeMismatch {
+                name: name.to_owned(),
+                expected: (width, height),
+                actual: (frame.width, frame.height),
+                actual_path: self.write_output(name, "actual", frame)?,
+            });
+        };
+        if comparison.passes(&self.tolerance) {
+            return Ok(());
+        }
+        Err(GoldenError::Mismatch {
+            name: name.to_owned(),
+            mismatched_pixels: comparison.mismatched_pixels,
+            max_difference: comparison.max_difference,
+            actual: self.write_output(name, "actual", frame)?,
+            diff: self.write_output(name, "diff", &comparison.diff)?,
+        })
+    }
+
+    pub fn check_artboard(&self, name: &str, artboard: &mut Artboard) -> Result<(), GoldenError> {
+        let frame = self.render(artboard)?;
+        self.check(name, &frame)
+    }
+
+    /// Seeks `animation` to each time in `times` (seconds) and checks
+    /// `<name>_<milliseconds>ms`.
+    pub fn check_animation_at(
+        &self,
+        name: &str,
+        artboard: &mut Artboard,
+        animation: &mut LinearAnimationInstance,
+        times: &[f32],
+    ) -> Result<(), GoldenError> {
+        for &time in times {
+            animation.set_time(time);
+            animation.apply(artboard, 1.0)?;
+            artboard.advance(0.0)?;
+            let frame = self.render(artboard)?;
+            self.check(&format!("{name}_{}ms", (time * 1000.0).round()), &frame)?;
+        }
+        Ok(())
+    }
+
+    /// Runs `script` against `state_machine`, checking a snapshot at every
+    /// [`Step::Snapshot`].
+    pub fn check_state_machine(
+        &self,
+        name: &str,
+        artboard: &mut Artboard,
+        state_machine: &mut StateMachineInstance,
+        script: &[Step<'_>],
+    ) -> Result<(), GoldenError> {
+        state_machine.advance_and_apply(0.0)?;
+        for step in script {
+            match *step {
+                Step::Advance(seconds) => {
+                    state_machine.advance_and_apply(seconds)?;
+                }
+                Step::SetBool(input, value) => {
+                    let input = state_machine.input_by_name(input)?;
+                    expect_type(input.input_type(), SmiInputType::RIVE_RS_SMI_INPUT_BOOL)?;
+                    input.as_bool()?.set(value);
+                }
+                Step::SetNumber(input, value) => {
+                    let input = state_machine.input_by_name(input)?;
+                    expect_type(input.input_type(), SmiInputType::RIVE_RS_SMI_INPUT_NUMBER)?;
+                    input.as_number()?.set(value);
+                }
+                Step::Fire(input) => {
+                    let input = state_machine.input_by_name(input)?;
+                    expect_type(input.input_type(), SmiInputType::RIVE_RS_SMI_INPUT_TRIGGER)?;
+                    input.as_trigger()?.fire();
+                }
+                Step::PointerDown(point) => state_machine.pointer_down(point, 0)?,
+                Step::PointerMove(point) => state_machine.pointer_move(point, 0)?,
+                Step::PointerUp(point) => state_machine.pointer_up(point, 0)?,
+                Step::Snapshot(snapshot) => {
+                    let frame = self.render(artboard)?;
+                    self.check(&format!("{name}_{snapshot}"), &frame)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn write_output(&self, name: &str, kind: &str, frame: &Frame) -> Result<PathBuf, GoldenError> {
+        let path = self.output_dir.join(format!("{name}.{kind}.png"));
+        write(&path, &frame.to_png())?;
+        Ok(path)
+    }
+}
+
+fn expect_type(actual: SmiInputType, expected: SmiInputType) -> Result<(), Error> {
+    if actual == expected {
+        Ok(())
+    } else {
+        Err(Error::from_status(Status::RIVE_RS_STATUS_INVALID_ARGUMENT))
+    }
+}
+
+fn write(path: &Path, bytes: &[u8]) -> Result<(), GoldenError> {
+    let io_error = |source| GoldenError::Io {
+        path: path.to_owned(),
+        source,
+    };
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(io_error)?;
+    }
+    fs::write(path, bytes).map_err(io_error)
+}
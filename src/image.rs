@@ -0,0 +1,138 @@
+// Largest pixel count the native decoders accept, checked before any
+// buffer is sized from a header.
+pub(crate) const MAX_PIXELS: usize = 1 << 26;
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub(crate) enum ImageFormat {
+    Png,
+    Jpeg,
+    Webp,
+    Gif,
+}
+
+impl ImageFormat {
+    pub(crate) fn mime_type(self) -> &'static str {
+        match self {
+            Self::Png => "image/png",
+            Self::Jpeg => "image/jpeg",
+            Self::Webp => "image/webp",
+            Self::Gif => "image/gif",
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub(crate) struct ImageHeader {
+    pub(crate) format: ImageFormat,
+    pub(crate) width: u32,
+    pub(crate) height: u32,
+}
+
+fn be_u16(bytes: &[u8], at: usize) -> Option<u32> {
+    let b = bytes.get(at..at + 2)?;
+    Some(u16::from_be_bytes([b[0], b[1]]) as u32)
+}
+
+fn le_u16(bytes: &[u8], at: usize) -> Option<u32> {
+    let b = bytes.get(at..at + 2)?;
+    Some(u16::from_le_bytes([b[0], b[1]]) as u32)
+}
+
+fn be_u32(bytes: &[u8], at: usize) -> Option<u32> {
+    let b = bytes.get(at..at + 4)?;
+    Some(u32::from_be_bytes([b[0], b[1], b[2], b[3]]))
+}
+
+fn le_u24(bytes: &[u8], at: usize) -> Option<u32> {
+    let b = bytes.get(at..at + 3)?;
+    Some(u32::from_le_bytes([b[0], b[1], b[2], 0]))
+}
+
+/// Reads the format and pixel size from an encoded image header without
+/// decoding it.
+pub(crate) fn sniff(bytes: &[u8]) -> Option<ImageHeader> {
+    let header = |format, width, height| {
+        (width > 0 && height > 0).then_some(ImageHeader {
+            format,
+            width,
+            height,
+        })
+    };
+
+    if bytes.starts_with(b"\x89PNG\r\n\x1a\n") && bytes.get(12..16) == Some(b"IHDR") {
+        return header(ImageFormat::Png, be_u32(bytes, 16)?, be_u32(bytes, 20)?);
+    }
+
+    if bytes.starts_with(b"GIF87a") || bytes.starts_with(b"GIF89a") {
+        return header(ImageFormat::Gif, le_u16(bytes, 6)?, le_u16(bytes, 8)?);
+    }
+
+    if bytes.starts_with(b"RIFF") && bytes.get(8..12) == Some(b"WEBP") {
+        return match bytes.get(12..16)? {
+            b"VP8 " => header(
+                ImageFormat::Webp,
+                le_u16(bytes, 26)? & 0x3fff,
+                le_u16(bytes, 28)? & 0x3fff,
+            ),
+            b"VP8L" => {
+                let bits = u32::from_le_bytes(bytes.get(21..25)?.try_into().ok()?);
+                header(
+                    ImageFormat::Webp,
+                    (bits & 0x3fff) + 1,
+                    ((bits >> 14) & 0x3fff) + 1,
+                )
+            }
+            b"VP8X" => header(
+                ImageFormat::Webp,
+                le_u24(bytes, 24)? + 1,
+                le_u24(bytes, 27)? + 1,
+            ),
+            _ => None,
+        };
+    }
+
+    if bytes.starts_with(&[0xff, 0xd8]) {
+        let mut at = 2;
+        while at + 4 <= bytes.len() {
+            if bytes[at] != 0xff {
+                return None;
+            }
+            let marker = bytes[at + 1];
+            if marker == 0xff {
+                at += 1;
+                continue;
+            }
+            if (0xd0..=0xd9).contains(&marker) || marker == 0x01 {
+                at += 2;
+                continue;
+            }
+            let length = be_u16(bytes, at + 2)? as usize;
+            let is_frame = matches!(marker, 0xc0..=0xcf) && !matches!(marker, 0xc4 | 0xc8 | 0xcc);
+            if is_frame {
+                return header(
+                    ImageFormat::Jpeg,
+                    be_u16(bytes, at + 7)?,
+                    be_u16(bytes, at + 5)?,
+                );
+            }
+            at += 2 + length;
+        }
+    }
+
+    None
+}
+
+/// Decodes a PNG, JPEG or WebP image into unpremultiplied RGBA8.
+pub(crate) fn decode(bytes: &[u8]) -> Option<(u32, u32, Vec<u8>)> {
+    let header = sniff(bytes)?;
+    if header.width as usize * header.height as usize > MAX_PIXELS {
+        return None;
+    }
+    let (width, height, rgba) = match header.format {
+        ImageFormat::Png => crate::png::decode(bytes)?,
+        ImageFormat::Jpeg => crate::jpeg::decode(bytes)?,
+        ImageFormat::Webp => crate::webp::decode(bytes)?,
+        ImageFormat::Gif => return None,
+    };
+    (rgba.len() == width as usize * height as usize * 4).then_some((width, height, rgba))
+}
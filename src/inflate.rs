@@ -0,0 +1,241 @@
+// zlib/DEFLATE decoder (RFC 1950/1951): stored, fixed and dynamic Huffman
+// blocks, decoded with canonical code tables.
+
+const LENGTH_BASE: [u16; 29] = [
+    3, 4, 5, 6, 7, 8, 9, 10, 11, 13, 15, 17, 19, 23, 27, 31, 35, 43, 51, 59, 67, 83, 99, 115, 131,
+    163, 195, 227, 258,
+];
+const LENGTH_EXTRA: [u8; 29] = [
+    0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 2, 2, 2, 2, 3, 3, 3, 3, 4, 4, 4, 4, 5, 5, 5, 5, 0,
+];
+const DIST_BASE: [u16; 30] = [
+    1, 2, 3, 4, 5, 7, 9, 13, 17, 25, 33, 49, 65, 97, 129, 193, 257, 385, 513, 769, 1025, 1537,
+    2049, 3073, 4097, 6145, 8193, 12289, 16385, 24577,
+];
+const DIST_EXTRA: [u8; 30] = [
+    0, 0, 0, 0, 1, 1, 2, 2, 3, 3, 4, 4, 5, 5, 6, 6, 7, 7, 8, 8, 9, 9, 10, 10, 11, 11, 12, 12, 13,
+    13,
+];
+const CODE_LENGTH_ORDER: [usize; 19] = [
+    16, 17, 18, 0, 8, 7, 9, 6, 10, 5, 11, 4, 12, 3, 13, 2, 14, 1, 15,
+];
+
+struct BitReader<'a> {
+    data: &'a [u8],
+    position: usize,
+    bits: u32,
+    count: u32,
+}
+
+impl<'a> BitReader<'a> {
+    fn new(data: &'a [u8]) -> Self {
+        Self {
+            data,
+            position: 0,
+            bits: 0,
+            count: 0,
+        }
+    }
+
+    fn bits(&mut self, count: u32) -> Option<u32> {
+        while self.count < count {
+            let byte = *self.data.get(self.position)?;
+            self.position += 1;
+            self.bits |= (byte as u32) << self.count;
+            self.count += 8;
+        }
+        let value = self.bits & ((1u64 << count) - 1) as u32;
+        self.bits >>= count;
+        self.count -= count;
+        Some(value)
+    }
+
+    fn align_to_byte(&mut self) {
+        self.bits = 0;
+        self.count = 0;
+    }
+}
+
+struct Huffman {
+    counts: [u16; 16],
+    symbols: Vec<u16>,
+}
+
+impl Huffman {
+    fn new(lengths: &[u8]) -> Option<Self> {
+        let mut counts = [0u16; 16];
+        for &length in lengths {
+            counts[length as usize] += 1;
+        }
+        counts[0] = 0;
+        let mut left = 1i32;
+        for &count in &counts[1..] {
+            left = (left << 1) - count as i32;
+            if left < 0 {
+                return None;
+            }
+        }
+        let mut offsets = [0u16; 16];
+        for length in 1..15 {
+            offsets[length + 1] = offsets[length] + counts[length];
+        }
+        let mut symbols = vec![0u16; lengths.len()];
+        for (symbol, &length) in lengths.iter().enumerate() {
+            if length != 0 {
+                symbols[offsets[length as usize] as usize] = symbol as u16;
+                offsets[length as usize] += 1;
+            }
+        }
+        Some(Self { counts, symbols })
+    }
+
+    fn decode(&self, reader: &mut BitReader<'_>) -> Option<u16> {
+        let (mut code, mut first, mut index) = (0i32, 0i32, 0i32);
+        for length in 1..16 {
+            code |= reader.bits(1)? as i32;
+            let count = self.counts[length] as i32;
+            if code - first < count {
+                return self.symbols.get((index + code - first) as usize).copied();
+            }
+            index += count;
+            first = (first + count) << 1;
+            code <<= 1;
+        }
+        None
+    }
+}
+
+fn fixed_tables() -> (Huffman, Huffman) {
+    let mut lengths = [0u8; 288];
+    lengths[..144].fill(8);
+    lengths[144..256].fill(9);
+    lengths[256..280].fill(7);
+    lengths[280..].fill(8);
+    let literals = Huffman::new(&lengths).expect("fixed literal table");
+    let distances = Huffman::new(&[5; 30]).expect("fixed distance table");
+    (literals, distances)
+}
+
+fn dynamic_tables(reader: &mut BitReader<'_>) -> Option<(Huffman, Huffman)> {
+    let literal_count = reader.bits(5)? as usize + 257;
+    let distance_count = reader.bits(5)? as usize + 1;
+    let code_length_count = reader.bits(4)? as usize + 4;
+    if literal_count > 286 || distance_count > 30 {
+        return None;
+    }
+    let mut code_lengths = [0u8; 19];
+    for &index in &CODE_LENGTH_ORDER[..code_length_count] {
+        code_lengths[index] = reader.bits(3)? as u8;
+    }
+    let code_length_table = Huffman::new(&code_lengths)?;
+
+    let mut lengths = vec![0u8; literal_count + distance_count];
+    let mut index = 0;
+    while index < lengths.len() {
+        let symbol = code_length_table.decode(reader)?;
+        let (value, repeat) = match symbol {
+            0..=15 => (symbol as u8, 1),
+            16 => (
+                *lengths.get(index.checked_sub(1)?)?,
+                3 + reader.bits(2)? as usize,
+            ),
+            17 => (0, 3 + reader.bits(3)? as usize),
+            18 => (0, 11 + reader.bits(7)? as usize),
+            _ => return None,
+        };
+        if index + repeat > lengths.len() {
+            return None;
+        }
+        lengths[index..index + repeat].fill(value);
+        index += repeat;
+    }
+    if lengths[256] == 0 {
+        return None;
+    }
+    Some((
+        Huffman::new(&lengths[..literal_count])?,
+        Huffman::new(&lengths[literal_count..])?,
+    ))
+}
+
+fn inflate_block(
+    reader: &mut BitReader<'_>,
+    out: &mut Vec<u8>,
+    limit: usize,
+    literals: &Huffman,
+    distances: &Huffman,
+) -> Option<()> {
+    loop {
+        if out.len() > limit {
+            return None;
+        }
+        let symbol = literals.decode(reader)? as usize;
+        match symbol {
+            0..=255 => out.push(symbol as u8),
+            256 => return Some(()),
+            _ => {
+                let code = symbol - 257;
+                let length = *LENGTH_BASE.get(code)? as usize
+                    + reader.bits(LENGTH_EXTRA[code] as u32)? as usize;
+                let code = distances.decode(reader)? as usize;
+                let distance =
+                    *DIST_BASE.get(code)? as usize + reader.bits(DIST_EXTRA[code] as u32)? as usize;
+                let start = out.len().checked_sub(distance)?;
+                for offset in 0..length {
+                    out.push(out[start + offset]);
+                }
+            }
+        }
+    }
+}
+
+/// Decodes a raw DEFLATE stream, failing once the output grows past
+/// `limit` bytes.
+pub(crate) fn inflate(data: &[u8], limit: usize) -> Option<Vec<u8>> {
+    let mut reader = BitReader::new(data);
+    let mut out = Vec::new();
+    loop {
+        let last = reader.bits(1)? == 1;
+        match reader.bits(2)? {
+            0 => {
+                reader.align_to_byte();
+                let header = data.get(reader.position..reader.position + 4)?;
+                let length = u16::from_le_bytes([header[0], header[1]]);
+                let complement = u16::from_le_bytes([header[2], header[3]]);
+                if length != !complement {
+                    return None;
+                }
+                let start = reader.position + 4;
+                if out.len() + length as usize > limit {
+                    return None;
+                }
+                out.extend_from_slice(data.get(start..start + length as usize)?);
+                reader.position = start + length as usize;
+            }
+            1 => {
+                let (literals, distances) = fixed_tables();
+                inflate_block(&mut reader, &mut out, limit, &literals, &distances)?;
+            }
+            2 => {
+                let (literals, distances) = dynamic_tables(&mut reader)?;
+                inflate_block(&mut reader, &mut out, limit, &literals, &distances)?;
+            }
+            _ => return None,
+        }
+        if last {
+            return (out.len() <= limit).then_some(out);
+        }
+    }
+}
+
+/// Decodes a zlib stream. The Adler-32 trailer is not verified; PNG chunks
+/// already carry CRCs.
+pub(crate) fn zlib_decompress(data: &[u8], limit: usize) -> Option<Vec<u8>> {
+    let (&cmf, rest) = data.split_first()?;
+    let (&flg, rest) = rest.split_first()?;
+    if cmf & 0x0f != 8 || !(((cmf as u16) << 8) | flg as u16).is_multiple_of(31) || flg & 0x20 != 0
+    {
+        return None;
+    }
+    inflate(rest, limit)
+}
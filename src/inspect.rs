@@ -0,0 +1,457 @@
+use std::fmt::Write as _;
+
+use crate::Error;
+use crate::assets::{AssetInfo, AssetKind, inspect_assets};
+use crate::runtime::{
+    Artboard, DataEnumInfo, DataType, EventInfo, EventPropertyValue, Factory, PropertyInfo,
+    SmiInputType, StateMachineInstance, Vec2, ViewModel,
+};
+
+/// Structure of a `.riv` file, from [`inspect_riv`].
+#[derive(Debug, Clone)]
+pub struct FileReport {
+    pub artboards: Vec<ArtboardReport>,
+    pub view_models: Vec<ViewModelReport>,
+    pub enums: Vec<DataEnumInfo>,
+    pub assets: Vec<AssetInfo>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ArtboardReport {
+    pub name: String,
+    pub width: f32,
+    pub height: f32,
+    pub origin: Vec2,
+    pub animations: Vec<AnimationReport>,
+    pub state_machines: Vec<StateMachineReport>,
+    pub events: Vec<EventInfo>,
+    pub text_runs: Vec<TextRunReport>,
+}
+
+/// Timing of a linear animation. Durations and work areas are in frames.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AnimationReport {
+    pub name: String,
+    pub fps: u32,
+    pub duration: u32,
+    pub work_start: u32,
+    pub work_end: u32,
+    pub enable_work_area: bool,
+    pub loop_value: u32,
+    pub speed: f32,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct StateMachineReport {
+    pub name: String,
+    pub inputs: Vec<InputReport>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct InputReport {
+    pub name: String,
+    pub value: InputValue,
+}
+
+/// An input's type and the value a fresh state machine instance starts with.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum InputValue {
+    Bool(bool),
+    Number(f32),
+    Trigger,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TextRunReport {
+    pub name: String,
+    pub text: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct ViewModelReport {
+    pub name: String,
+    pub properties: Vec<PropertyInfo>,
+    pub instances: Vec<String>,
+}
+
+/// Loads a `.riv` file and lists its artboards, view models, enums and
+/// assets.
+///
+/// Every artboard is instanced, and a state machine instance is created for
+/// each state machine to read its inputs.
+pub fn inspect_riv(bytes: &[u8]) -> Result<FileReport, Error> {
+    let assets = inspect_assets(bytes)?;
+    let file = Factory::new()?.load_file(bytes)?;
+
+    let mut artboards = Vec::with_capacity(file.artboard_count());
+    for index in 0..file.artboard_count() {
+        artboards.push(artboard_report(&mut file.artboard_by_index(index)?)?);
+    }
+    let mut view_models = Vec::with_capacity(file.view_model_count());
+    for index in 0..file.view_model_count() {
+        view_models.push(view_model_report(&file.view_model_by_index(index)?)?);
+    }
+    Ok(FileReport {
+        artboards,
+        view_models,
+        enums: file.enums()?,
+        assets,
+    })
+}
+
+fn artboard_report(artboard: &mut Artboard) -> Result<ArtboardReport, Error> {
+    let mut animations = Vec::with_capacity(artboard.animation_count());
+    for index in 0..artboard.animation_count() {
+        let animation = artboard.animation_by_index(index)?;
+        animations.push(AnimationReport {
+            name: animation.name(),
+            fps: animation.fps(),
+            duration: animation.duration(),
+            work_start: animation.work_start(),
+            work_end: animation.work_end(),
+            enable_work_area: animation.enable_work_area(),
+            loop_value: animation.loop_value(),
+            speed: animation.speed(),
+        });
+    }
+
+    let mut state_machines = Vec::with_capacity(artboard.state_machine_count());
+    for index in 0..artboard.state_machine_count() {
+        let machine = artboard.state_machine_by_index(index)?;
+        let name = machine.name();
+        let mut instance = StateMachineInstance::new(machine, artboard)?;
+        let mut inputs = Vec::with_capacity(instance.input_count());
+        for input_index in 0..instance.input_count() {
+            let input = instance.input(input_index)?;
+            let value = match input.input_type() {
+                SmiInputType::RIVE_RS_SMI_INPUT_BOOL => InputValue::Bool(input.as_bool()?.get()),
+                SmiInputType::RIVE_RS_SMI_INPUT_NUMBER => {
+                    InputValue::Number(input.as_number()?.get())
+                }
+                SmiInputType::RIVE_RS_SMI_INPUT_TRIGGER => InputValue::Trigger,
+            };
+            inputs.push(InputReport {
+                name: input.name(),
+                value,
+            });
+        }
+        state_machines.push(StateMachineReport { name, inputs });
+    }
+
+    let mut events = Vec::with_capacity(artboard.event_count());
+    for index in 0..artboard.event_count() {
+        events.push(artboard.event_at(index)?);
+    }
+    let mut text_runs = Vec::with_capacity(artboard.text_value_run_count());
+    for index in 0..artboard.text_value_run_count() {
+        text_runs.push(TextRunReport {
+            name: artboard.text_value_run_name_at(index)?,
+            text: artboard.text_value_run_text_at(index)?,
+        });
+    }
+
+    Ok(ArtboardReport {
+        name: artboard.name(),
+        width: artboard.width(),
+        height: artboard.height(),
+        origin: artboard.origin(),
+        animations,
+        state_machines,
+        events,
+        text_runs,
+    })
+}
+
+fn view_model_report(view_model: &ViewModel) -> Result<ViewModelReport, Error> {
+    let mut properties = Vec::with_capacity(view_model.property_count());
+    for index in 0..view_model.property_count() {
+        properties.push(view_model.property_at(index)?);
+    }
+    let mut instances = Vec::with_capacity(view_model.instance_count());
+    for index in 0..view_model.instance_count() {
+        instances.push(view_model.instance_name_at(index)?);
+    }
+    Ok(ViewModelReport {
+        name: view_model.name(),
+        properties,
+        instances,
+    })
+}
+
+impl FileReport {
+    /// Pretty-printed JSON with two-space indentation.
+    pub fn to_json(&self) -> String {
+        let mut out = String::new();
+        self.json().write(&mut out, 0);
+        out.push('\n');
+        out
+    }
+
+    fn json(&self) -> Json {
+        Json::object([
+            (
+                "artboards",
+                Json::array(self.artboards.iter().map(ArtboardReport::json)),
+            ),
+            (
+                "viewModels",
+                Json::array(self.view_models.iter().map(ViewModelReport::json)),
+            ),
+            ("enums", Json::array(self.enums.iter().map(enum_json))),
+            ("assets", Json::array(self.assets.iter().map(asset_json))),
+        ])
+    }
+}
+
+impl ArtboardReport {
+    fn json(&self) -> Json {
+        Json::object([
+            ("name", Json::string(&self.name)),
+            ("width", Json::Number(self.width)),
+            ("height", Json::Number(self.height)),
+            (
+                "origin",
+                Json::object([
+                    ("x", Json::Number(self.origin.x)),
+                    ("y", Json::Number(self.origin.y)),
+                ]),
+            ),
+            (
+                "animations",
+                Json::array(self.animations.iter().map(AnimationReport::json)),
+            ),
+            (
+                "stateMachines",
+                Json::array(self.state_machines.iter().map(StateMachineReport::json)),
+            ),
+            ("events", Json::array(self.events.iter().map(event_json))),
+            (
+                "textRuns",
+                Json::array(self.text_runs.iter().map(|run| {
+                    Json::object([
+                        ("name", Json::string(&run.name)),
+                        ("text", Json::string(&run.text)),
+                    ])
+                })),
+            ),
+        ])
+    }
+}
+
+impl AnimationReport {
+    fn json(&self) -> Json {
+        let loop_value = match self.loop_value {
+            0 => Json::string("oneShot"),
+            1 => Json::string("loop"),
+            2 => Json::string("pingPong"),
+            other => Json::Integer(other as u64),
+        };
+        Json::object([
+            ("name", Json::string(&self.name)),
+            ("fps", Json::Integer(self.fps as u64)),
+            ("duration", Json::Integer(self.duration as u64)),
+            ("workStart", Json::Integer(self.work_start as u64)),
+            ("workEnd", Json::Integer(self.work_end as u64)),
+            ("enableWorkArea", Json::Bool(self.enable_work_area)),
+            ("loop", loop_value),
+            ("speed", Json::Number(self.speed)),
+        ])
+    }
+}
+
+impl StateMachineReport {
+    fn json(&self) -> Json {
+        let inputs = self.inputs.iter().map(|input| {
+            let (kind, value) = match input.value {
+                InputValue::Bool(value) => ("bool", Json::Bool(value)),
+                InputValue::Number(value) => ("number", Json::Number(value)),
+                InputValue::Trigger => ("trigger", Json::Null),
+            };
+            Json::object([
+                ("name", Json::string(&input.name)),
+                ("type", Json::string(kind)),
+                ("value", value),
+            ])
+        });
+        Json::object([
+            ("name", Json::string(&self.name)),
+            ("inputs", Json::array(inputs)),
+        ])
+    }
+}
+
+impl ViewModelReport {
+    fn json(&self) -> Json {
+        let properties = self.properties.iter().map(|property| {
+            Json::object([
+                ("name", Json::string(&property.name)),
+                ("type", Json::string(data_type_name(property.data_type))),
+            ])
+        });
+        Json::object([
+            ("name", Json::string(&self.name)),
+            ("properties", Json::array(properties)),
+            (
+                "instances",
+                Json::array(self.instances.iter().map(|name| Json::string(name))),
+            ),
+        ])
+    }
+}
+
+fn event_json(event: &EventInfo) -> Json {
+    let properties = event.properties.iter().map(|property| {
+        let value = match &property.value {
+            EventPropertyValue::Bool(value) => Json::Bool(*value),
+            EventPropertyValue::Number(value) => Json::Number(*value),
+            EventPropertyValue::String(value) => Json::string(value),
+        };
+        Json::object([("name", Json::string(&property.name)), ("value", value)])
+    });
+    Json::object([
+        ("name", Json::string(&event.name)),
+        ("type", Json::Integer(event.event_type as u64)),
+        ("url", event.url.as_deref().map_or(Json::Null, Json::string)),
+        (
+            "target",
+            event.target.as_deref().map_or(Json::Null, Json::string),
+        ),
+        ("properties", Json::array(properties)),
+    ])
+}
+
+fn enum_json(data_enum: &DataEnumInfo) -> Json {
+    Json::object([
+        ("name", Json::string(&data_enum.name)),
+        (
+            "values",
+            Json::array(data_enum.values.iter().map(|value| Json::string(value))),
+        ),
+    ])
+}
+
+fn asset_json(asset: &AssetInfo) -> Json {
+    let kind = match asset.kind {
+        AssetKind::Image => "image",
+        AssetKind::Font => "font",
+        AssetKind::Audio => "audio",
+        AssetKind::Other => "other",
+    };
+    Json::object([
+        ("kind", Json::string(kind)),
+        ("name", Json::string(&asset.name)),
+        ("uniqueFilename", Json::string(&asset.unique_filename)),
+        ("fileExtension", Json::string(&asset.file_extension)),
+        ("cdnUuid", Json::string(&asset.cdn_uuid)),
+        ("embedded", Json::Bool(asset.embedded)),
+        ("embeddedSize", Json::Integer(asset.embedded_size as u64)),
+    ])
+}
+
+fn data_type_name(data_type: DataType) -> &'static str {
+    match data_type {
+        DataType::RIVE_RS_DATA_TYPE_NONE => "none",
+        DataType::RIVE_RS_DATA_TYPE_STRING => "string",
+        DataType::RIVE_RS_DATA_TYPE_NUMBER => "number",
+        DataType::RIVE_RS_DATA_TYPE_BOOLEAN => "boolean",
+        DataType::RIVE_RS_DATA_TYPE_COLOR => "color",
+        DataType::RIVE_RS_DATA_TYPE_LIST => "list",
+        DataType::RIVE_RS_DATA_TYPE_ENUM => "enum",
+        DataType::RIVE_RS_DATA_TYPE_TRIGGER => "trigger",
+        DataType::RIVE_RS_DATA_TYPE_VIEW_MODEL => "viewModel",
+        DataType::RIVE_RS_DATA_TYPE_INTEGER => "integer",
+        DataType::RIVE_RS_DATA_TYPE_LIST_INDEX => "listIndex",
+        DataType::RIVE_RS_DATA_TYPE_IMAGE => "image",
+        DataType::RIVE_RS_DATA_TYPE_ARTBOARD => "artboard",
+    }
+}
+
+enum Json {
+    Null,
+    Bool(bool),
+    Integer(u64),
+    Number(f32),
+    String(String),
+    Array(Vec<Json>),
+    Object(Vec<(&'static str, Json)>),
+}
+
+impl Json {
+    fn string(value: &str) -> Self {
+        Json::String(value.to_owned())
+    }
+
+    fn array(items: impl IntoIterator<Item = Json>) -> Self {
+        Json::Array(items.into_iter().collect())
+    }
+
+    fn object<const N: usize>(fields: [(&'static str, Json); N]) -> Self {
+        Json::Object(fields.into())
+    }
+
+    fn write(&self, out: &mut String, depth: usize) {
+        match self {
+            Json::Null => out.push_str("null"),
+            Json::Bool(value) => out.push_str(if *value { "true" } else { "false" }),
+            // JSON has no NaN or infinity.
+            Json::Number(value) if !value.is_finite() => out.push_str("null"),
+            Json::Integer(value) => {
+                let _ = write!(out, "{value}");
+            }
+            Json::Number(value) => {
+                let _ = write!(out, "{value}");
+            }
+            Json::String(value) => write_string(out, value),
+            Json::Array(items) if items.is_empty() => out.push_str("[]"),
+            Json::Array(items) => {
+                out.push('[');
+                for (index, item) in items.iter().enumerate() {
+                    out.push_str(if index == 0 { "\n" } else { ",\n" });
+                    indent(out, depth + 1);
+                    item.write(out, depth + 1);
+                }
+                out.push('\n');
+                indent(out, depth);
+                out.push(']');
+            }
+            Json::Object(fields) if fields.is_empty() => out.push_str("{}"),
+            Json::Object(fields) => {
+                out.push('{');
+                for (index, (key, value)) in fields.iter().enumerate() {
+                    out.push_str(if index == 0 { "\n" } else { ",\n" });
+                    indent(out, depth + 1);
+                    write_string(out, key);
+                    out.push_str(": ");
+                    value.write(out, depth + 1);
+                }
+                out.push('\n');
+                indent(out, depth);
+                out.push('}');
+            }
+        }
+    }
+}
+
+fn indent(out: &mut String, depth: usize) {
+    for _ in 0..depth {
+        out.push_str("  ");
+    }
+}
+
+fn write_string(out: &mut String, value: &str) {
+    out.push('"');
+    for ch in value.chars() {
+        match ch {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            ch if (ch as u32) < 0x20 => {
+                let _ = write!(out, "\\u{:04x}", ch as u32);
+            }
+            ch => out.push(ch),
+        }
+    }
+    out.push('"');
+}
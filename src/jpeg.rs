@@ -0,0 +1,951 @@
+// Baseline and progressive Huffman JPEG decoder. Reconstruction follows
+// libjpeg's defaults (integer IDCT, fancy upsampling, fixed-point YCbCr) so
+// results match what browsers and image tools show.
+
+const ZIGZAG: [usize; 64] = [
+    0, 1, 8, 16, 9, 2, 3, 10, 17, 24, 32, 25, 18, 11, 4, 5, 12, 19, 26, 33, 40, 48, 41, 34, 27, 20,
+    13, 6, 7, 14, 21, 28, 35, 42, 49, 56, 57, 50, 43, 36, 29, 22, 15, 23, 30, 37, 44, 51, 58, 59,
+    52, 45, 38, 31, 39, 46, 53, 60, 61, 54, 47, 55, 62, 63,
+];
+
+// Largest accepted width or height; keeps coefficient buffers bounded.
+const MAX_DIMENSION: usize = 16384;
+const LOOKUP_BITS: u32 = 9;
+
+#[derive(Clone)]
+struct Huffman {
+    // (code length, value) for every `LOOKUP_BITS` prefix; length 0 = slow path.
+    lookup: Vec<(u8, u8)>,
+    max_code: [i32; 18],
+    offset: [i32; 17],
+    values: Vec<u8>,
+}
+
+impl Huffman {
+    fn new(counts: &[u8; 16], values: &[u8]) -> Option<Self> {
+        let mut lookup = vec![(0u8, 0u8); 1 << LOOKUP_BITS];
+        let mut max_code = [-1i32; 18];
+        let mut offset = [0i32; 17];
+        let mut code = 0i32;
+        let mut index = 0usize;
+        for length in 1..=16usize {
+            let count = counts[length - 1] as usize;
+            offset[length] = index as i32 - code;
+            for _ in 0..count {
+                let value = *values.get(index)?;
+                if code >= 1 << length {
+                    return None;
+                }
+                if length as u32 <= LOOKUP_BITS {
+                    let shift = LOOKUP_BITS - length as u32;
+                    let start = (code as usize) << shift;
+                    for entry in &mut lookup[start..start + (1 << shift)] {
+                        *entry = (length as u8, value);
+                    }
+                }
+                code += 1;
+                index += 1;
+            }
+            if count > 0 {
+                max_code[length] = code - 1;
+            }
+            code <<= 1;
+        }
+        max_code[17] = i32::MAX;
+        Some(Self {
+            lookup,
+            max_code,
+            offset,
+            values: values[..index].to_vec(),
+        })
+    }
+}
+
+struct BitReader<'a> {
+    data: &'a [u8],
+    position: usize,
+    bits: u64,
+    count: u32,
+    at_marker: bool,
+}
+
+impl<'a> BitReader<'a> {
+    fn new(data: &'a [u8], position: usize) -> Self {
+        Self {
+            data,
+            position,
+            bits: 0,
+            count: 0,
+            at_marker: false,
+        }
+    }
+
+    fn fill(&mut self) {
+        while self.count <= 56 {
+            let mut byte = 0;
+            if !self.at_marker {
+                match self.data.get(self.position) {
+                    Some(0xff) => match self.data.get(self.position + 1) {
+                        Some(0) => {
+                            byte = 0xff;
+                            self.position += 2;
+                        }
+                        _ => self.at_marker = true,
+                    },
+                    Some(&value) => {
+                        byte = value;
+                        self.position += 1;
+                    }
+                    None => self.at_marker = true,
+                }
+            }
+            self.bits |= (byte as u64) << (56 - self.count);
+            self.count += 8;
+        }
+    }
+
+    fn bits(&mut self, count: u32) -> i32 {
+        if count == 0 {
+            return 0;
+        }
+        self.fill();
+        let value = (self.bits >> (64 - count)) as i32;
+        self.bits <<= count;
+        self.count -= count;
+        value
+    }
+
+    fn bit(&mut self) -> bool {
+        self.bits(1) != 0
+    }
+
+    fn receive_extend(&mut self, count: u32) -> i32 {
+        let value = self.bits(count);
+        if count > 0 && value < 1 << (count - 1) {
+            value - (1 << count) + 1
+        } else {
+            value
+        }
+    }
+
+    fn decode(&mut self, table: &Huffman) -> Option<u8> {
+        self.fill();
+        let peek = (self.bits >> (64 - LOOKUP_BITS)) as usize;
+        let (length, value) = table.lookup[peek];
+        if length > 0 {
+            self.bits <<= length;
+            self.count -= length as u32;
+            return Some(value);
+        }
+        let mut code = 0i32;
+        for length in 1..=16 {
+            code = (code << 1) | self.bits(1);
+            if code <= table.max_code[length] {
+                return table
+                    .values
+                    .get((code + table.offset[length]) as usize)
+                    .copied();
+            }
+        }
+        None
+    }
+
+    // Drops buffered bits and skips past the next RSTn marker.
+    fn restart(&mut self) -> Option<()> {
+        self.bits = 0;
+        self.count = 0;
+        self.at_marker = false;
+        while self.position + 1 < self.data.len() {
+            if self.data[self.position] == 0xff
+                && (0xd0..=0xd7).contains(&self.data[self.position + 1])
+            {
+                self.position += 2;
+                return Some(());
+            }
+            self.position += 1;
+        }
+        None
+    }
+}
+
+struct Component {
+    id: u8,
+    h: usize,
+    v: usize,
+    quant: usize,
+    // Size of the component's sample plane before upsampling.
+    width: usize,
+    height: usize,
+    blocks_wide: usize,
+    blocks_high: usize,
+    coefficients: Vec<i16>,
+    dc_table: usize,
+    ac_table: usize,
+    dc_prediction: i32,
+}
+
+impl Component {
+    fn block(&mut self, x: usize, y: usize) -> &mut [i16] {
+        let index = (y * self.blocks_wide + x) * 64;
+        &mut self.coefficients[index..index + 64]
+    }
+}
+
+struct Frame {
+    width: usize,
+    height: usize,
+    progressive: bool,
+    components: Vec<Component>,
+    h_max: usize,
+    v_max: usize,
+    mcus_wide: usize,
+    mcus_high: usize,
+}
+
+struct Scan {
+    components: Vec<usize>,
+    spectral_start: usize,
+    spectral_end: usize,
+    approx_high: u32,
+    approx_low: u32,
+}
+
+struct Decoder {
+    quant: [[u16; 64]; 4],
+    dc_tables: [Option<Huffman>; 4],
+    ac_tables: [Option<Huffman>; 4],
+    restart_interval: usize,
+    eob_run: u32,
+    adobe_transform: Option<u8>,
+}
+
+impl Decoder {
+    fn decode_block(
+        &mut self,
+        reader: &mut BitReader<'_>,
+        component: &mut Component,
+        x: usize,
+        y: usize,
+        scan: &Scan,
+        progressive: bool,
+    ) -> Option<()> {
+        let dc_table = component.dc_table;
+        let ac_table = component.ac_table;
+        if !progressive {
+            let table = self.dc_tables[dc_table].as_ref()?;
+            let size = reader.decode(table)? as u32;
+            if size > 16 {
+                return None;
+            }
+            component.dc_prediction = component
+                .dc_prediction
+                .wrapping_add(reader.receive_extend(size));
+            let dc = component.dc_prediction as i16;
+            let table = self.ac_tables[ac_table].as_ref()?;
+            let block = component.block(x, y);
+            block[0] = dc;
+            let mut k = 1;
+            while k < 64 {
+                let symbol = reader.decode(table)?;
+                let (run, size) = ((symbol >> 4) as usize, (symbol & 15) as u32);
+                if size == 0 {
+                    if run != 15 {
+                        break;
+                    }
+                    k += 16;
+                    continue;
+                }
+                k += run;
+                if k > 63 {
+                    return None;
+                }
+                block[ZIGZAG[k]] = reader.receive_extend(size) as i16;
+                k += 1;
+            }
+            return Some(());
+        }
+
+        if scan.spectral_start == 0 {
+            if scan.approx_high == 0 {
+                let table = self.dc_tables[dc_table].as_ref()?;
+                let size = reader.decode(table)? as u32;
+                if size > 16 {
+                    return None;
+                }
+                component.dc_prediction = component
+                    .dc_prediction
+                    .wrapping_add(reader.receive_extend(size));
+                let value = component.dc_prediction << scan.approx_low;
+                component.block(x, y)[0] = value as i16;
+            } else if reader.bit() {
+                component.block(x, y)[0] |= 1 << scan.approx_low;
+            }
+            return Some(());
+        }
+
+        let table = self.ac_tables[ac_table].as_ref()?;
+        let block = component.block(x, y);
+        let (start, end) = (scan.spectral_start, scan.spectral_end);
+        if scan.approx_high == 0 {
+            if self.eob_run > 0 {
+                self.eob_run -= 1;
+                return Some(());
+            }
+            let mut k = start;
+            while k <= end {
+                let symbol = reader.decode(table)?;
+                let (run, size) = ((symbol >> 4) as u32, (symbol & 15) as u32);
+                if size == 0 {
+                    if run < 15 {
+                        self.eob_run = (1 << run) - 1 + reader.bits(run) as u32;
+                        break;
+                    }
+                    k += 16;
+                    continue;
+                }
+                k += run as usize;
+                if k > 63 {
+                    return None;
+                }
+                block[ZIGZAG[k]] = (reader.receive_extend(size) * (1 << scan.approx_low)) as i16;
+                k += 1;
+            }
+            return Some(());
+        }
+
+        // Successive approximation refinement of AC coefficients.
+        let positive = 1i16 << scan.approx_low;
+        let negative = -1i16 << scan.approx_low;
+        let refine = |reader: &mut BitReader<'_>, coefficient: &mut i16| {
+            if reader.bit() && *coefficient & positive == 0 {
+                *coefficient = coefficient.wrapping_add(if *coefficient >= 0 {
+                    positive
+                } else {
+                    negative
+                });
+            }
+        };
+        let mut k = start;
+        if self.eob_run == 0 {
+            while k <= end {
+                let symbol = reader.decode(table)?;
+                let mut run = (symbol >> 4) as i32;
+                let size = symbol & 15;
+                let mut value = 0;
+                if size != 0 {
+                    if size != 1 {
+                        return None;
+                    }
+                    value = if reader.bit() { positive } else { negative };
+                } else if run != 15 {
+                    self.eob_run = (1 << run) + reader.bits(run as u32) as u32;
+                    break;
+                }
+                while k <= end {
+                    let coefficient = &mut block[ZIGZAG[k]];
+                    if *coefficient != 0 {
+                        refine(reader, coefficient);
+                    } else {
+                        if run == 0 {
+                            break;
+                        }
+                        run -= 1;
+                    }
+                    k += 1;
+                }
+                if value != 0 {
+                    block[ZIGZAG[k.min(63)]] = value;
+                }
+                k += 1;
+            }
+        }
+        if self.eob_run > 0 {
+            while k <= end {
+                let coefficient = &mut block[ZIGZAG[k]];
+                if *coefficient != 0 {
+                    refine(reader, coefficient);
+                }
+                k += 1;
+            }
+            self.eob_run -= 1;
+        }
+        Some(())
+    }
+
+    fn decode_scan(
+        &mut self,
+        data: &[u8],
+        position: usize,
+        frame: &mut Frame,
+        scan: &Scan,
+    ) -> Option<usize> {
+        let mut reader = BitReader::new(data, position);
+        for &index in &scan.components {
+            frame.components[index].dc_prediction = 0;
+        }
+        self.eob_run = 0;
+        let progressive = frame.progressive;
+
+        let single = scan.components.len() == 1;
+        let (units_wide, units_high) = if single {
+            let component = &frame.components[scan.components[0]];
+            (component.width.div_ceil(8), component.height.div_ceil(8))
+        } else {
+            (frame.mcus_wide, frame.mcus_high)
+        };
+        let total = units_wide * units_high;
+        for unit in 0..total {
+            if self.restart_interval > 0 && unit > 0 && unit % self.restart_interval == 0 {
+                reader.restart()?;
+                for &index in &scan.components {
+                    frame.components[index].dc_prediction = 0;
+                }
+                self.eob_run = 0;
+            }
+            let (unit_x, unit_y) = (unit % units_wide, unit / units_wide);
+            if single {
+                let component = &mut frame.components[scan.components[0]];
+                self.decode_block(&mut reader, component, unit_x, unit_y, scan, progressive)?;
+                continue;
+            }
+            for &index in &scan.components {
+                let component = &mut frame.components[index];
+                let (h, v) = (component.h, component.v);
+                for block_y in 0..v {
+                    for block_x in 0..h {
+                        let (x, y) = (unit_x * h + block_x, unit_y * v + block_y);
+                        self.decode_block(&mut reader, component, x, y, scan, progressive)?;
+                    }
+                }
+            }
+        }
+        Some(reader.position)
+    }
+}
+
+const CONST_BITS: i32 = 13;
+const PASS1_BITS: i32 = 2;
+
+fn descale(value: i64, shift: i32) -> i64 {
+    (value + (1 << (shift - 1))) >> shift
+}
+
+// The shared odd/even butterfly of libjpeg's `jpeg_idct_islow`. It runs on
+// i64 so crafted coefficients and quantizers cannot overflow; valid data
+// stays within i32 and rounds exactly as libjpeg does.
+fn idct_1d(input: [i64; 8]) -> ([i64; 4], [i64; 4]) {
+    let (z2, z3) = (input[2], input[6]);
+    let z1 = (z2 + z3) * 4433;
+    let tmp2 = z1 - z3 * 15137;
+    let tmp3 = z1 + z2 * 6270;
+    let tmp0 = (input[0] + input[4]) << CONST_BITS;
+    let tmp1 = (input[0] - input[4]) << CONST_BITS;
+    let even = [tmp0 + tmp3, tmp1 + tmp2, tmp1 - tmp2, tmp0 - tmp3];
+
+    let (mut t0, mut t1, mut t2, mut t3) = (input[7], input[5], input[3], input[1]);
+    let (mut z1, mut z2, mut z3, mut z4) = (t0 + t3, t1 + t2, t0 + t2, t1 + t3);
+    let z5 = (z3 + z4) * 9633;
+    t0 *= 2446;
+    t1 *= 16819;
+    t2 *= 25172;
+    t3 *= 12299;
+    z1 *= -7373;
+    z2 *= -20995;
+    z3 = z3 * -16069 + z5;
+    z4 = z4 * -3196 + z5;
+    let odd = [t3 + z1 + z4, t2 + z2 + z3, t1 + z2 + z4, t0 + z1 + z3];
+    (even, odd)
+}
+
+fn idct_block(coefficients: &[i16], quant: &[u16; 64], out: &mut [u8], stride: usize) {
+    let mut workspace = [0i64; 64];
+    for column in 0..8 {
+        let input: [i64; 8] = core::array::from_fn(|row| {
+            coefficients[row * 8 + column] as i64 * quant[row * 8 + column] as i64
+        });
+        if input[1..].iter().all(|&value| value == 0) {
+            for row in 0..8 {
+                workspace[row * 8 + column] = input[0] << PASS1_BITS;
+            }
+            continue;
+        }
+        let (even, odd) = idct_1d(input);
+        let shift = CONST_BITS - PASS1_BITS;
+        for i in 0..4 {
+            workspace[i * 8 + column] = descale(even[i] + odd[i], shift);
+            workspace[(7 - i) * 8 + column] = descale(even[i] - odd[i], shift);
+        }
+    }
+    for row in 0..8 {
+        let input: [i64; 8] = core::array::from_fn(|i| workspace[row * 8 + i]);
+        let line = &mut out[row * stride..row * stride + 8];
+        if input[1..].iter().all(|&value| value == 0) {
+            let value = (descale(input[0], PASS1_BITS + 3) + 128).clamp(0, 255) as u8;
+            line.fill(value);
+            continue;
+        }
+        let (even, odd) = idct_1d(input);
+        let shift = CONST_BITS + PASS1_BITS + 3;
+        for i in 0..4 {
+            line[i] = (descale(even[i] + odd[i], shift) + 128).clamp(0, 255) as u8;
+            line[7 - i] = (descale(even[i] - odd[i], shift) + 128).clamp(0, 255) as u8;
+        }
+    }
+}
+
+struct Plane {
+    width: usize,
+    height: usize,
+    stride: usize,
+    samples: Vec<u8>,
+}
+
+impl Plane {
+    fn at(&self, x: usize, y: usize) -> i32 {
+        self.samples[y * self.stride + x] as i32
+    }
+}
+
+fn reconstruct(component: &Component, quant: &[u16; 64]) -> Plane {
+    let stride = component.blocks_wide * 8;
+    let mut samples = vec![0u8; stride * component.blocks_high * 8];
+    for block_y in 0..component.blocks_high {
+        for block_x in 0..component.blocks_wide {
+            let index = (block_y * component.blocks_wide + block_x) * 64;
+            let offset = block_y * 8 * stride + block_x * 8;
+            idct_block(
+                &component.coefficients[index..index + 64],
+                quant,
+                &mut samples[offset..],
+                stride,
+            );
+        }
+    }
+    Plane {
+        width: component.width,
+        height: component.height,
+        stride,
+        samples,
+    }
+}
+
+// Upsamples to `width x height` with libjpeg's triangle filters for 2x
+// horizontal/vertical ratios and sample replication otherwise.
+fn upsample(
+    plane: &Plane,
+    h_factor: usize,
+    v_factor: usize,
+    width: usize,
+    height: usize,
+) -> Vec<u8> {
+    if h_factor == 1 && v_factor == 1 {
+        let mut out = Vec::with_capacity(width * height);
+        for y in 0..height {
+            out.extend_from_slice(&plane.samples[y * plane.stride..y * plane.stride + width]);
+        }
+        return out;
+    }
+    let out_width = plane.width * h_factor;
+    let mut out = vec![0u8; out_width * plane.height * v_factor];
+    let last_x = plane.width - 1;
+    let last_y = plane.height - 1;
+    match (h_factor, v_factor) {
+        (2, 1) => {
+            for y in 0..plane.height {
+                let row = &mut out[y * out_width..(y + 1) * out_width];
+                for x in 0..plane.width {
+                    let value = plane.at(x, y) * 3;
+                    if plane.width == 1 {
+                        row[0] = plane.at(0, y) as u8;
+                        row[1] = plane.at(0, y) as u8;
+                        continue;
+                    }
+                    row[x * 2] = if x == 0 {
+                        plane.at(0, y) as u8
+                    } else {
+                        ((value + plane.at(x - 1, y) + 1) >> 2) as u8
+                    };
+                    row[x * 2 + 1] = if x == last_x {
+                        plane.at(x, y) as u8
+                    } else {
+                        ((value + plane.at(x + 1, y) + 2) >> 2) as u8
+                    };
+                }
+            }
+        }
+        (1, 2) => {
+            for y in 0..plane.height {
+                for (half, neighbor, bias) in
+                    [(0, y.saturating_sub(1), 1), (1, (y + 1).min(last_y), 2)]
+                {
+                    let row = &mut out[(y * 2 + half) * out_width..(y * 2 + half + 1) * out_width];
+                    for (x, sample) in row.iter_mut().enumerate() {
+                        *sample = ((plane.at(x, y) * 3 + plane.at(x, neighbor) + bias) >> 2) as u8;
+                    }
+                }
+            }
+        }
+        (2, 2) => {
+            for y in 0..plane.height {
+                for (half, neighbor) in [(0, y.saturating_sub(1)), (1, (y + 1).min(last_y))] {
+                    let row = &mut out[(y * 2 + half) * out_width..(y * 2 + half + 1) * out_width];
+                    let column = |x: usize| plane.at(x, y) * 3 + plane.at(x, neighbor);
+                    if plane.width == 1 {
+                        let sum = column(0);
+                        row[0] = ((sum * 4 + 8) >> 4) as u8;
+                        row[1] = ((sum * 4 + 7) >> 4) as u8;
+                        continue;
+                    }
+                    for x in 0..plane.width {
+                        let this = column(x);
+                        row[x * 2] = if x == 0 {
+                            ((this * 4 + 8) >> 4) as u8
+                        } else {
+                            ((this * 3 + column(x - 1) + 8) >> 4) as u8
+                        };
+                        row[x * 2 + 1] = if x == last_x {
+                            ((this * 4 + 7) >> 4) as u8
+                        } else {
+                            ((this * 3 + column(x + 1) + 7) >> 4) as u8
+                        };
+                    }
+                }
+            }
+        }
+        _ => {
+            for y in 0..plane.height * v_factor {
+                for x in 0..out_width {
+                    out[y * out_width + x] = plane.at(x / h_factor, y / v_factor) as u8;
+                }
+            }
+        }
+    }
+    let mut cropped = Vec::with_capacity(width * height);
+    for y in 0..height {
+        let start = y * out_width;
+        cropped.extend_from_slice(&out[start..start + width]);
+    }
+    cropped
+}
+
+fn fix(value: f64) -> i32 {
+    (value * 65536.0 + 0.5) as i32
+}
+
+fn ycc_to_rgb(y: i32, cb: i32, cr: i32) -> [u8; 3] {
+    let (cb, cr) = (cb - 128, cr - 128);
+    let r = y + ((fix(1.40200) * cr + (1 << 15)) >> 16);
+    let g = y + ((-fix(0.34414) * cb + (1 << 15) - fix(0.71414) * cr) >> 16);
+    let b = y + ((fix(1.77200) * cb + (1 << 15)) >> 16);
+    [r, g, b].map(|channel| channel.clamp(0, 255) as u8)
+}
+
+fn read_u16(data: &[u8], position: usize) -> Option<usize> {
+    Some(u16::from_be_bytes([*data.get(position)?, *data.get(position + 1)?]) as usize)
+}
+
+fn parse_frame(segment: &[u8], progressive: bool) -> Option<Frame> {
+    if *segment.first()? != 8 {
+        return None;
+    }
+    let height = read_u16(segment, 1)?;
+    let width = read_u16(segment, 3)?;
+    let count = *segment.get(5)? as usize;
+    if width == 0
+        || height == 0
+        || width > MAX_DIMENSION
+        || height > MAX_DIMENSION
+        || width * height > crate::image::MAX_PIXELS
+    {
+        return None;
+    }
+    if !matches!(count, 1 | 3 | 4) {
+        return None;
+    }
+    let mut components = Vec::with_capacity(count);
+    for index in 0..count {
+        let spec = segment.get(6 + index * 3..9 + index * 3)?;
+        let (h, v) = ((spec[1] >> 4) as usize, (spec[1] & 15) as usize);
+        if !(1..=4).contains(&h) || !(1..=4).contains(&v) || spec[2] > 3 {
+            return None;
+        }
+        components.push(Component {
+            id: spec[0],
+            h,
+            v,
+            quant: spec[2] as usize,
+            width: 0,
+            height: 0,
+            blocks_wide: 0,
+            blocks_high: 0,
+            coefficients: Vec::new(),
+            dc_table: 0,
+            ac_table: 0,
+            dc_prediction: 0,
+        });
+    }
+    let h_max = components.iter().map(|c| c.h).max()?;
+    let v_max = components.iter().map(|c| c.v).max()?;
+    let mcus_wide = width.div_ceil(8 * h_max);
+    let mcus_high = height.div_ceil(8 * v_max);
+    for component in &mut components {
+        component.width = (width * component.h).div_ceil(h_max);
+        component.height = (height * component.v).div_ceil(v_max);
+        component.blocks_wide = mcus_wide * component.h;
+        component.blocks_high = mcus_high * component.v;
+    }
+    Some(Frame {
+        width,
+        height,
+        progressive,
+        components,
+        h_max,
+        v_max,
+        mcus_wide,
+        mcus_high,
+    })
+}
+
+fn parse_scan(segment: &[u8], frame: &mut Frame) -> Option<Scan> {
+    let count = *segment.first()? as usize;
+    if count == 0 || count > 4 {
+        return None;
+    }
+    let mut components = Vec::with_capacity(count);
+    for index in 0..count {
+        let spec = segment.get(1 + index * 2..3 + index * 2)?;
+        let component = frame.components.iter().position(|c| c.id == spec[0])?;
+        let (dc, ac) = ((spec[1] >> 4) as usize, (spec[1] & 15) as usize);
+        if dc > 3 || ac > 3 {
+            return None;
+        }
+        frame.components[component].dc_table = dc;
+        frame.components[component].ac_table = ac;
+        components.push(component);
+    }
+    let tail = segment.get(1 + count * 2..4 + count * 2)?;
+    let scan = Scan {
+        components,
+        spectral_start: tail[0] as usize,
+        spectral_end: tail[1] as usize,
+        approx_high: (tail[2] >> 4) as u32,
+        approx_low: (tail[2] & 15) as u32,
+    };
+    let valid = if frame.progressive {
+        scan.spectral_end < 64
+            && scan.spectral_start <= scan.spectral_end
+            && (scan.spectral_start > 0 || scan.spectral_end == 0)
+            && (scan.spectral_start == 0 || scan.components.len() == 1)
+            && scan.approx_low < 14
+    } else {
+        true
+    };
+    valid.then_some(scan)
+}
+
+// Allocates coefficients for components this scan codes first, once the
+// rest of the data could hold them: each coded block takes at least one bit.
+// Progressive AC and refinement scans must follow a component's DC scan.
+fn allocate_coefficients(frame: &mut Frame, scan: &Scan, available: usize) -> Option<()> {
+    let first_coded = |index: &&usize| frame.components[**index].coefficients.is_empty();
+    let pending: Vec<usize> = scan
+        .components
+        .iter()
+        .filter(first_coded)
+        .copied()
+        .collect();
+    if pending.is_empty() {
+        return Some(());
+    }
+    if frame.progressive && (scan.spectral_start > 0 || scan.approx_high > 0) {
+        return None;
+    }
+    let coded_blocks: usize = pending
+        .iter()
+        .map(|&index| {
+            let component = &frame.components[index];
+            component.width.div_ceil(8) * component.height.div_ceil(8)
+        })
+        .sum();
+    if coded_blocks > available.saturating_mul(8) {
+        return None;
+    }
+    for index in pending {
+        let component = &mut frame.components[index];
+        component.coefficients = vec![0; component.blocks_wide * component.blocks_high * 64];
+    }
+    Some(())
+}
+
+/// Decodes a baseline or progressive JPEG into RGBA8.
+pub(crate) fn decode(data: &[u8]) -> Option<(u32, u32, Vec<u8>)> {
+    if data.get(..2)? != [0xff, 0xd8] {
+        return None;
+    }
+    let mut decoder = Decoder {
+        quant: [[0; 64]; 4],
+        dc_tables: [None, None, None, None],
+        ac_tables: [None, None, None, None],
+        restart_interval: 0,
+        eob_run: 0,
+        adobe_transform: None,
+    };
+    let mut frame: Option<Frame> = None;
+    let mut position = 2;
+
+    loop {
+        // Skip fill bytes and anything that is not a marker.
+        while *data.get(position)? != 0xff {
+            position += 1;
+        }
+        while *data.get(position)? == 0xff {
+            position += 1;
+        }
+        let marker = *data.get(position)?;
+        position += 1;
+        match marker {
+            0xd9 => break,
+            0xd0..=0xd7 | 0x01 => continue,
+            _ => {}
+        }
+        let length = read_u16(data, position)?;
+        if length < 2 {
+            return None;
+        }
+        let segment = data.get(position + 2..position + length)?;
+        position += length;
+        match marker {
+            0xc0..=0xc2 => {
+                if frame.is_some() {
+                    return None;
+                }
+                frame = Some(parse_frame(segment, marker == 0xc2)?);
+            }
+            0xc3 | 0xc5..=0xc7 | 0xc9..=0xcb | 0xcd..=0xcf => return None,
+            0xc4 => {
+                let mut rest = segment;
+                while !rest.is_empty() {
+                    let class = rest[0] >> 4;
+                    let index = (rest[0] & 15) as usize;
+                    let counts: [u8; 16] = rest.get(1..17)?.try_into().ok()?;
+                    let total: usize = counts.iter().map(|&count| count as usize).sum();
+                    let values = rest.get(17..17 + total)?;
+                    if index > 3 || class > 1 || total > 256 {
+                        return None;
+                    }
+                    let table = Huffman::new(&counts, values)?;
+                    if class == 0 {
+                        decoder.dc_tables[index] = Some(table);
+                    } else {
+                        decoder.ac_tables[index] = Some(table);
+                    }
+                    rest = &rest[17 + total..];
+                }
+            }
+            0xdb => {
+                let mut rest = segment;
+                while !rest.is_empty() {
+                    let precision = rest[0] >> 4;
+                    let index = (rest[0] & 15) as usize;
+                    if index > 3 || precision > 1 {
+                        return None;
+                    }
+                    let size = if precision == 0 { 64 } else { 128 };
+                    let values = rest.get(1..1 + size)?;
+                    for k in 0..64 {
+                        let value = if precision == 0 {
+                            values[k] as u16
+                        } else {
+                            u16::from_be_bytes([values[k * 2], values[k * 2 + 1]])
+                        };
+                        decoder.quant[index][ZIGZAG[k]] = value;
+                    }
+                    rest = &rest[1 + size..];
+                }
+            }
+            0xdd => decoder.restart_interval = read_u16(segment, 0)?,
+            0xee if segment.len() >= 12 && segment.starts_with(b"Adobe") => {
+                decoder.adobe_transform = Some(segment[11]);
+            }
+            0xda => {
+                let frame = frame.as_mut()?;
+                let scan = parse_scan(segment, frame)?;
+                allocate_coefficients(frame, &scan, data.len() - position)?;
+                position = decoder.decode_scan(data, position, frame, &scan)?;
+            }
+            _ => {}
+        }
+    }
+
+    let frame = frame?;
+    if frame
+        .components
+        .iter()
+        .any(|component| component.coefficients.is_empty())
+    {
+        return None;
+    }
+    let planes: Vec<Vec<u8>> = frame
+        .components
+        .iter()
+        .map(|component| {
+            let plane = reconstruct(component, &decoder.quant[component.quant]);
+            let (h, v) = (frame.h_max / component.h, frame.v_max / component.v);
+            if frame.h_max % component.h != 0 || frame.v_max % component.v != 0 {
+                // Non-integral ratios: nearest sample.
+                let mut out = Vec::with_capacity(frame.width * frame.height);
+                for y in 0..frame.height {
+                    for x in 0..frame.width {
+                        let sx = (x * component.h / frame.h_max).min(plane.width - 1);
+                        let sy = (y * component.v / frame.v_max).min(plane.height - 1);
+                        out.push(plane.at(sx, sy) as u8);
+                    }
+                }
+                out
+            } else {
+                upsample(&plane, h, v, frame.width, frame.height)
+            }
+        })
+        .collect();
+
+    let pixel_count = frame.width * frame.height;
+    let mut rgba = Vec::with_capacity(pixel_count * 4);
+    let ids: Vec<u8> = frame.components.iter().map(|c| c.id).collect();
+    match planes.len() {
+        1 => {
+            for &gray in &planes[0] {
+                rgba.extend_from_slice(&[gray, gray, gray, 255]);
+            }
+        }
+        3 => {
+            let is_rgb = decoder.adobe_transform == Some(0) || ids == b"RGB";
+            for ((&a, &b), &c) in planes[0].iter().zip(&planes[1]).zip(&planes[2]) {
+                let rgb = if is_rgb {
+                    [a, b, c]
+                } else {
+                    ycc_to_rgb(a as i32, b as i32, c as i32)
+                };
+                rgba.extend_from_slice(&[rgb[0], rgb[1], rgb[2], 255]);
+            }
+        }
+        _ => {
+            // Adobe CMYK/YCCK JPEGs store inverted ink values.
+            let ycck = decoder.adobe_transform == Some(2);
+            let inks = planes[0]
+                .iter()
+                .zip(&planes[1])
+                .zip(&planes[2])
+                .zip(&planes[3]);
+            for (((&a, &b), &c), &k) in inks {
+                let cmy = if ycck {
+                    ycc_to_rgb(a as i32, b as i32, c as i32).map(|value| 255 - value)
+                } else {
+                    [a, b, c]
+                };
+                let rgb = cmy.map(|value| ((value as u32 * k as u32 + 127) / 255) as u8);
+                rgba.extend_from_slice(&[rgb[0], rgb[1], rgb[2], 255]);
+            }
+        }
+    }
+    Some((frame.width as u32, frame.height as u32, rgba))
+}
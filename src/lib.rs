@@ -1,16 +1,123 @@
 #![doc = include_str!("../README.md")]
 
 pub mod abi;
+mod assets;
+mod audio;
+mod backend;
+mod bundle;
+mod cdn;
+mod deflate;
 mod error;
+mod export;
+mod fonts;
+mod gif;
+#[cfg(feature = "golden")]
+pub mod golden;
+mod image;
+mod inflate;
+mod inspect;
+mod jpeg;
+mod memory;
+mod opentype;
+mod png;
+mod raster;
+mod recording;
+mod resolver;
+mod riv;
 mod runtime;
+mod software;
+mod svg;
+mod wav;
+mod webp;
 
+pub use assets::AssetInfo;
+pub use assets::AssetKind;
+pub use assets::AssetLoader;
+pub use assets::LoadOutcome;
+pub use assets::inspect_assets;
+pub use audio::AudioEngine;
+pub use backend::BackendBuffer;
+pub use backend::BackendImage;
+pub use backend::BackendPaint;
+pub use backend::BackendPath;
+pub use backend::BackendRenderer;
+pub use backend::BackendShader;
+pub use backend::BlendMode;
+pub use backend::BufferType;
+pub use backend::FillRule;
+pub use backend::GradientStop;
+pub use backend::ImageFilter;
+pub use backend::ImageSampler;
+pub use backend::ImageWrap;
+pub use backend::PaintStyle;
+pub use backend::RenderBackend;
+pub use backend::StrokeCap;
+pub use backend::StrokeJoin;
+pub use cdn::CdnFetcher;
+pub use cdn::DEFAULT_CDN_BASE_URL;
+pub use cdn::DiskCache;
+pub use cdn::FetchCallback;
+pub use cdn::FetchError;
+pub use cdn::FetchOutcome;
+pub use cdn::HostedAssets;
+pub use cdn::HttpTransport;
+pub use cdn::attach_asset;
+pub use cdn::cdn_url;
 pub use error::Error;
+pub use export::ExportOptions;
+pub use export::Frame;
+pub use export::Timeline;
+pub use export::encode_apng;
+pub use export::encode_gif;
+pub use export::export_frames;
+pub use export::export_frames_with;
+pub use export::write_png_sequence;
+pub use fonts::font_files;
+pub use fonts::load_fonts;
+pub use fonts::system_font_dirs;
+pub use inspect::AnimationReport;
+pub use inspect::ArtboardReport;
+pub use inspect::FileReport;
+pub use inspect::InputReport;
+pub use inspect::InputValue;
+pub use inspect::StateMachineReport;
+pub use inspect::TextRunReport;
+pub use inspect::ViewModelReport;
+pub use inspect::inspect_riv;
+pub use memory::MemoryStats;
+pub use opentype::FontStyle;
+pub use recording::DisplayList;
+pub use recording::DrawCommand;
+pub use recording::PathVerb;
+pub use recording::RecordedBuffer;
+pub use recording::RecordedImage;
+pub use recording::RecordedPaint;
+pub use recording::RecordedPath;
+pub use recording::RecordedShader;
+pub use recording::RecordingBackend;
+pub use resolver::AssetRecord;
+pub use resolver::AssetReport;
+pub use resolver::AssetResolver;
+pub use resolver::AssetStatus;
+pub use riv::AssetEdit;
+pub use riv::EmbeddedAsset;
+pub use riv::HeaderError;
+pub use riv::RIV_MAJOR_VERSION;
+pub use riv::RivHeader;
+pub use riv::extract_embedded_assets;
+pub use riv::inspect_header;
+pub use riv::rewrite_assets;
 pub use runtime::Aabb;
+pub use runtime::ActiveTransition;
 pub use runtime::Alignment;
 pub use runtime::Artboard;
 pub use runtime::AudioSource;
+pub use runtime::BackendRendererHandle;
 pub use runtime::BindableArtboard;
 pub use runtime::BoneHandle;
+pub use runtime::ComponentInfo;
+pub use runtime::ConditionOp;
+pub use runtime::ConditionType;
 pub use runtime::DataEnumInfo;
 pub use runtime::DataType;
 pub use runtime::EventInfo;
@@ -24,12 +131,20 @@ pub use runtime::FileAssetLoaderCallbacks;
 pub use runtime::Fit;
 pub use runtime::FlattenedPath;
 pub use runtime::Font;
+pub use runtime::LayerCurrentState;
+pub use runtime::LayerStateInfo;
+pub use runtime::LayerStateType;
 pub use runtime::LinearAnimation;
 pub use runtime::LinearAnimationInstance;
+pub use runtime::ListenerInfo;
+pub use runtime::ListenerType;
 pub use runtime::Mat2D;
 pub use runtime::NodeHandle;
 pub use runtime::PropertyInfo;
+pub use runtime::RecordingRenderer;
 pub use runtime::RenderImage;
+pub use runtime::RenderTarget;
+pub use runtime::Renderer;
 pub use runtime::ReportedEvent;
 pub use runtime::RootBoneHandle;
 pub use runtime::SmiBool;
@@ -37,10 +152,15 @@ pub use runtime::SmiInput;
 pub use runtime::SmiInputType;
 pub use runtime::SmiNumber;
 pub use runtime::SmiTrigger;
+pub use runtime::SoftwareRenderer;
 pub use runtime::StateMachine;
 pub use runtime::StateMachineInstance;
+pub use runtime::StateMachineLayerInfo;
+pub use runtime::SvgRenderer;
 pub use runtime::TextValueRunHandle;
 pub use runtime::TransformComponentHandle;
+pub use runtime::TransitionConditionInfo;
+pub use runtime::TransitionInfo;
 pub use runtime::Vec2;
 pub use runtime::ViewModel;
 pub use runtime::ViewModelInstance;
@@ -51,10 +171,11 @@ pub use runtime::compute_alignment;
 pub use runtime::map_xy;
 pub use runtime::mat2d_invert;
 pub use runtime::mat2d_multiply;
+pub use runtime::set_font_fallbacks;
 
 pub type Status = abi::rive_rs_status;
 
-pub const ABI_VERSION: u32 = 1;
+pub const ABI_VERSION: u32 = 2;
 
 #[inline]
 pub fn status_ok(status: Status) -> bool {
@@ -0,0 +1,218 @@
+use std::cell::{Cell, RefCell};
+use std::collections::{HashMap, HashSet};
+use std::ops::AddAssign;
+use std::ptr::NonNull;
+use std::rc::{Rc, Weak};
+
+use crate::abi;
+use crate::assets::{AssetKind, AssetLoader};
+use crate::runtime::{Artboard, Factory, File, FileAsset};
+use crate::{Error, LoadOutcome};
+
+/// Memory held by loaded files, from [`File::memory_stats`] or summed over a
+/// factory's live files by [`Factory::memory_stats`].
+///
+/// Image sizes are decoded pixels at four bytes per pixel. Font and audio
+/// sizes are the encoded bytes the runtime decoded them from, known for
+/// in-band contents only.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub struct MemoryStats {
+    pub files: usize,
+    pub images: usize,
+    pub image_bytes: usize,
+    /// Images dropped by [`File::release_images`] and not drawn since.
+    pub released_images: usize,
+    /// Encoded in-band images kept to decode released ones again; see
+    /// [`Factory::load_file_retaining_images`].
+    pub retained_image_bytes: usize,
+    pub fonts: usize,
+    pub font_bytes: usize,
+    pub audio: usize,
+    pub audio_bytes: usize,
+    /// Core objects across the file's artboards.
+    pub objects: usize,
+    /// Estimated size of those objects, excluding the buffers they own.
+    pub object_bytes: usize,
+}
+
+impl AddAssign for MemoryStats {
+    fn add_assign(&mut self, other: Self) {
+        self.files += other.files;
+        self.images += other.images;
+        self.image_bytes += other.image_bytes;
+        self.released_images += other.released_images;
+        self.retained_image_bytes += other.retained_image_bytes;
+        self.fonts += other.fonts;
+        self.font_bytes += other.font_bytes;
+        self.audio += other.audio;
+        self.audio_bytes += other.audio_bytes;
+        self.objects += other.objects;
+        self.object_bytes += other.object_bytes;
+    }
+}
+
+/// Live files loaded through a factory and its clones.
+pub(crate) type FileRegistry = Rc<RefCell<Vec<Weak<FileMemory>>>>;
+
+// Asset sizes seen while a file loaded, keyed by asset pointer. Encoded
+// images are kept only when the file was loaded to retain them, so released
+// pixels can be decoded again.
+pub(crate) struct FileMemory {
+    factory: Factory,
+    file: Cell<Option<NonNull<abi::rive_rs_file>>>,
+    retain_images: bool,
+    sizes: RefCell<HashMap<usize, usize>>,
+    images: RefCell<HashMap<usize, Box<[u8]>>>,
+    released: RefCell<HashSet<usize>>,
+}
+
+impl FileMemory {
+    pub(crate) fn new(factory: &Factory, retain_images: bool) -> Rc<Self> {
+        Rc::new(Self {
+            factory: factory.clone(),
+            file: Cell::new(None),
+            retain_images,
+            sizes: RefCell::default(),
+            images: RefCell::default(),
+            released: RefCell::default(),
+        })
+    }
+
+    pub(crate) fn factory(&self) -> &Factory {
+        &self.factory
+    }
+
+    /// Ties this record to its loaded file and lists it with the factory.
+    pub(crate) fn register(
+        self: &Rc<Self>,
+        file: NonNull<abi::rive_rs_file>,
+        files: &FileRegistry,
+    ) {
+        self.file.set(Some(file));
+        let mut files = files.borrow_mut();
+        files.retain(|file| file.strong_count() > 0);
+        files.push(Rc::downgrade(self));
+    }
+
+    fn record(&self, asset: &FileAsset, bytes: &[u8]) {
+        let key = asset.as_raw() as usize;
+        self.sizes.borrow_mut().insert(key, bytes.len());
+        if self.retain_images && AssetKind::of(asset) == AssetKind::Image {
+            self.images.borrow_mut().insert(key, Box::from(bytes));
+        }
+    }
+}
+
+/// Records the bytes every asset loads from before `inner` sees them.
+pub(crate) struct RecordingLoader<L> {
+    pub(crate) inner: L,
+    pub(crate) memory: Rc<FileMemory>,
+}
+
+impl<L: AssetLoader> AssetLoader for RecordingLoader<L> {
+    fn load(&mut self, asset: &mut FileAsset, in_band: &[u8], factory: &Factory) -> LoadOutcome {
+        if !in_band.is_empty() {
+            self.memory.record(asset, in_band);
+        }
+        self.inner.load(asset, in_band, factory)
+    }
+}
+
+pub(crate) fn factory_stats(files: &FileRegistry) -> MemoryStats {
+    let mut stats = MemoryStats::default();
+    for memory in files.borrow().iter().filter_map(Weak::upgrade) {
+        let Some(raw) = memory.file.get() else {
+            continue;
+        };
+        // SAFETY: the record is only reachable while a `File` or the loader
+        // the runtime keeps with the file holds it, so the file is alive.
+        let file = unsafe { File::retain_raw(raw, memory.clone()) };
+        stats += file_stats(&file, &memory);
+    }
+    stats
+}
+
+pub(crate) fn file_stats(file: &File, memory: &FileMemory) -> MemoryStats {
+    let mut stats = MemoryStats {
+        files: 1,
+        retained_image_bytes: memory
+            .images
+            .borrow()
+            .values()
+            .map(|bytes| bytes.len())
+            .sum(),
+        objects: file.object_count(),
+        object_bytes: file.object_bytes(),
+        ..MemoryStats::default()
+    };
+    let sizes = memory.sizes.borrow();
+    for asset in file.assets() {
+        let key = asset.as_raw() as usize;
+        let size = sizes.get(&key).copied().unwrap_or(0);
+        match AssetKind::of(&asset) {
+            AssetKind::Image => match asset.render_image() {
+                Some(image) => {
+                    stats.images += 1;
+                    stats.image_bytes += image.width() as usize * image.height() as usize * 4;
+                }
+                None if memory.released.borrow().contains(&key) => stats.released_images += 1,
+                None => {}
+            },
+            AssetKind::Font if asset.is_loaded() => {
+                stats.fonts += 1;
+                stats.font_bytes += size;
+            }
+            AssetKind::Audio if asset.is_loaded() => {
+                stats.audio += 1;
+                stats.audio_bytes += size;
+            }
+            _ => {}
+        }
+    }
+    stats
+}
+
+pub(crate) fn release_images(file: &File, memory: &FileMemory) -> usize {
+    let images = memory.images.borrow();
+    let mut released = memory.released.borrow_mut();
+    let mut count = 0;
+    for mut asset in file.assets() {
+        let key = asset.as_raw() as usize;
+        if !images.contains_key(&key) || asset.render_image().is_none() {
+            continue;
+        }
+        if asset.set_render_image(None).is_ok() {
+            released.insert(key);
+            count += 1;
+        }
+    }
+    count
+}
+
+/// Decodes the released images `artboard` draws again, before it draws.
+pub(crate) fn restore_drawn_images(
+    artboard: &Artboard,
+    memory: &Rc<FileMemory>,
+) -> Result<(), Error> {
+    if memory.released.borrow().is_empty() {
+        return Ok(());
+    }
+    let Some(raw) = memory.file.get() else {
+        return Ok(());
+    };
+    // SAFETY: the record is alive, so the file is too; see `factory_stats`.
+    let file = unsafe { File::retain_raw(raw, memory.clone()) };
+    let images = memory.images.borrow();
+    for mut asset in file.assets() {
+        let key = asset.as_raw() as usize;
+        if !memory.released.borrow().contains(&key) || !artboard.uses_image_asset(&asset) {
+            continue;
+        }
+        let Some(bytes) = images.get(&key) else {
+            continue;
+        };
+        asset.decode(&memory.factory, bytes)?;
+        memory.released.borrow_mut().remove(&key);
+    }
+    Ok(())
+}
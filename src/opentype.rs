@@ -0,0 +1,288 @@
+// OpenType/TrueType table reader for font introspection: `name`, `OS/2`,
+// `head`, `hhea`, `hmtx` and `cmap` formats 4 and 12. Collections use their
+// first face, matching the runtime's decoder. Everything is read up front;
+// the font bytes are not kept.
+
+/// Slant of a font face.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum FontStyle {
+    Normal,
+    Italic,
+    Oblique,
+}
+
+#[derive(Debug, Copy, Clone)]
+enum CharMap {
+    Segments(usize),
+    Groups(usize),
+}
+
+// Characters `start..=end` map to consecutive glyphs from `glyph`.
+#[derive(Debug, Copy, Clone)]
+struct GlyphRun {
+    start: u32,
+    end: u32,
+    glyph: u32,
+}
+
+#[derive(Debug)]
+pub(crate) struct FontFace {
+    pub(crate) family: String,
+    pub(crate) weight: u16,
+    pub(crate) style: FontStyle,
+    pub(crate) units_per_em: u16,
+    pub(crate) ascent: i16,
+    pub(crate) descent: i16,
+    pub(crate) line_gap: i16,
+    runs: Box<[GlyphRun]>,
+    advances: Box<[u16]>,
+    glyph_count: usize,
+}
+
+fn u16_at(data: &[u8], offset: usize) -> Option<u16> {
+    let bytes = data.get(offset..offset.checked_add(2)?)?;
+    Some(u16::from_be_bytes([bytes[0], bytes[1]]))
+}
+
+fn i16_at(data: &[u8], offset: usize) -> Option<i16> {
+    u16_at(data, offset).map(|value| value as i16)
+}
+
+fn u32_at(data: &[u8], offset: usize) -> Option<u32> {
+    let bytes = data.get(offset..offset.checked_add(4)?)?;
+    Some(u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+}
+
+fn table(data: &[u8], face: usize, tag: &[u8; 4]) -> Option<usize> {
+    let count = u16_at(data, face + 4)? as usize;
+    (0..count).find_map(|index| {
+        let record = face + 12 + index * 16;
+        (data.get(record..record + 4)? == tag).then_some(())?;
+        let offset = u32_at(data, record + 8)? as usize;
+        let len = u32_at(data, record + 12)? as usize;
+        data.get(offset..offset.checked_add(len)?)?;
+        Some(offset)
+    })
+}
+
+fn family_name(data: &[u8], name: usize) -> Option<String> {
+    let count = u16_at(data, name + 2)? as usize;
+    let strings = name + u16_at(data, name + 4)? as usize;
+    let mut best: Option<(u32, String)> = None;
+    for index in 0..count {
+        let record = name + 6 + index * 12;
+        let platform = u16_at(data, record)?;
+        let encoding = u16_at(data, record + 2)?;
+        let language = u16_at(data, record + 4)?;
+        let name_id = u16_at(data, record + 6)?;
+        let len = u16_at(data, record + 8)? as usize;
+        let offset = strings + u16_at(data, record + 10)? as usize;
+        // Typographic family first, then the legacy family; Unicode
+        // records first, US English among Windows records.
+        let id_rank = match name_id {
+            16 => 0,
+            1 => 4,
+            _ => continue,
+        };
+        let platform_rank = match (platform, encoding, language) {
+            (3, 1 | 10, 0x409) => 0,
+            (3, 1 | 10, _) | (0, _, _) => 1,
+            (1, 0, _) => 2,
+            _ => continue,
+        };
+        let rank = id_rank + platform_rank;
+        if best.as_ref().is_some_and(|(best, _)| *best <= rank) {
+            continue;
+        }
+        let bytes = data.get(offset..offset + len)?;
+        let text = if platform == 1 {
+            bytes.iter().map(|&byte| byte as char).collect()
+        } else {
+            let units: Vec<u16> = bytes
+                .chunks_exact(2)
+                .map(|pair| u16::from_be_bytes([pair[0], pair[1]]))
+                .collect();
+            String::from_utf16_lossy(&units)
+        };
+        best = Some((rank, text));
+    }
+    best.map(|(_, text)| text)
+}
+
+fn char_map(data: &[u8], cmap: usize) -> Option<CharMap> {
+    let count = u16_at(data, cmap + 2)? as usize;
+    let mut best: Option<(u8, CharMap)> = None;
+    for index in 0..count {
+        let record = cmap + 4 + index * 8;
+        let platform = u16_at(data, record)?;
+        let encoding = u16_at(data, record + 2)?;
+        let subtable = cmap + u32_at(data, record + 4)? as usize;
+        let unicode = platform == 0 || (platform == 3 && matches!(encoding, 1 | 10));
+        if !unicode {
+            continue;
+        }
+        let candidate = match u16_at(data, subtable)? {
+            12 => (0, CharMap::Groups(subtable)),
+            4 => (1, CharMap::Segments(subtable)),
+            _ => continue,
+        };
+        if best.is_none_or(|(rank, _)| candidate.0 < rank) {
+            best = Some(candidate);
+        }
+    }
+    best.map(|(_, map)| map)
+}
+
+// Glyph runs of the chosen `cmap` subtable, sorted by character.
+fn glyph_runs(data: &[u8], map: CharMap) -> Option<Vec<GlyphRun>> {
+    let mut runs = Vec::new();
+    match map {
+        CharMap::Groups(table) => {
+            let count = u32_at(data, table + 12)? as usize;
+            for index in 0..count {
+                let group = table + 16 + index * 12;
+                let start = u32_at(data, group)?;
+                let end = u32_at(data, group + 4)?;
+                if start <= end {
+                    runs.push(GlyphRun {
+                        start,
+                        end,
+                        glyph: u32_at(data, group + 8)?,
+                    });
+                }
+            }
+            runs.sort_by_key(|run| run.start);
+        }
+        CharMap::Segments(table) => {
+            let segments = u16_at(data, table + 6)? as usize / 2;
+            let ends = table + 14;
+            let starts = ends + segments * 2 + 2;
+            let deltas = starts + segments * 2;
+            let ranges = deltas + segments * 2;
+            // First character not claimed by an earlier segment; a lookup
+            // takes the first segment whose end is not below the character.
+            let mut first = 0;
+            for segment in 0..segments {
+                let end = u16_at(data, ends + segment * 2)?;
+                let start = u16_at(data, starts + segment * 2)?;
+                let delta = u16_at(data, deltas + segment * 2)?;
+                let range = u16_at(data, ranges + segment * 2)? as usize;
+                let codes = (start as u32).max(first)..=end as u32;
+                first = first.max(end as u32 + 1);
+                for code in codes {
+                    let glyph = if range == 0 {
+                        (code as u16).wrapping_add(delta)
+                    } else {
+                        let entry =
+                            ranges + segment * 2 + range + (code - start as u32) as usize * 2;
+                        match u16_at(data, entry) {
+                            None | Some(0) => 0,
+                            Some(glyph) => glyph.wrapping_add(delta),
+                        }
+                    };
+                    if glyph == 0 {
+                        continue;
+                    }
+                    match runs.last_mut() {
+                        Some(run)
+                            if run.end + 1 == code
+                                && run.glyph + (code - run.start) == glyph as u32 =>
+                        {
+                            run.end = code;
+                        }
+                        _ => runs.push(GlyphRun {
+                            start: code,
+                            end: code,
+                            glyph: glyph as u32,
+                        }),
+                    }
+                }
+            }
+        }
+    }
+    Some(runs)
+}
+
+impl FontFace {
+    pub(crate) fn parse(data: &[u8]) -> Option<Self> {
+        let face = if data.get(..4)? == b"ttcf" {
+            u32_at(data, 12)? as usize
+        } else {
+            0
+        };
+
+        let head = table(data, face, b"head")?;
+        let hhea = table(data, face, b"hhea")?;
+        let hmtx = table(data, face, b"hmtx")?;
+        let maxp = table(data, face, b"maxp")?;
+        let units_per_em = u16_at(data, head + 18)?;
+        let mac_style = u16_at(data, head + 44)?;
+        let long_metrics = u16_at(data, hhea + 34)? as usize;
+        let glyph_count = u16_at(data, maxp + 4)? as usize;
+        if units_per_em == 0 || long_metrics == 0 {
+            return None;
+        }
+        let advances = (0..long_metrics)
+            .map(|index| u16_at(data, hmtx + index * 4))
+            .collect::<Option<_>>()?;
+
+        let mut weight = if mac_style & 1 != 0 { 700 } else { 400 };
+        let mut style = if mac_style & 2 != 0 {
+            FontStyle::Italic
+        } else {
+            FontStyle::Normal
+        };
+        let mut ascent = i16_at(data, hhea + 4)?;
+        let mut descent = i16_at(data, hhea + 6)?;
+        let mut line_gap = i16_at(data, hhea + 8)?;
+        if let Some(os2) = table(data, face, b"OS/2") {
+            weight = u16_at(data, os2 + 4)?;
+            let selection = u16_at(data, os2 + 62)?;
+            if selection & (1 << 9) != 0 {
+                style = FontStyle::Oblique;
+            } else if selection & 1 != 0 {
+                style = FontStyle::Italic;
+            }
+            // USE_TYPO_METRICS asks for the OS/2 typographic values.
+            if selection & (1 << 7) != 0 {
+                ascent = i16_at(data, os2 + 68)?;
+                descent = i16_at(data, os2 + 70)?;
+                line_gap = i16_at(data, os2 + 72)?;
+            }
+        }
+
+        let family = table(data, face, b"name")
+            .and_then(|name| family_name(data, name))
+            .unwrap_or_default();
+        let runs = table(data, face, b"cmap")
+            .and_then(|cmap| char_map(data, cmap))
+            .and_then(|map| glyph_runs(data, map))
+            .unwrap_or_default();
+
+        Some(Self {
+            family,
+            weight,
+            style,
+            units_per_em,
+            ascent,
+            descent,
+            line_gap,
+            runs: runs.into(),
+            advances,
+            glyph_count,
+        })
+    }
+
+    pub(crate) fn glyph(&self, c: char) -> Option<u16> {
+        let code = c as u32;
+        let index = self.runs.partition_point(|run| run.end < code);
+        let run = self.runs.get(index).filter(|run| run.start <= code)?;
+        let glyph = u16::try_from(run.glyph.checked_add(code - run.start)?).ok()?;
+        (glyph != 0 && (glyph as usize) < self.glyph_count).then_some(glyph)
+    }
+
+    pub(crate) fn advance(&self, glyph: u16) -> Option<u16> {
+        let index = (glyph as usize).min(self.advances.len() - 1);
+        self.advances.get(index).copied()
+    }
+}
@@ -0,0 +1,394 @@
+use crate::deflate::zlib_compress;
+
+const SIGNATURE: &[u8; 8] = b"\x89PNG\r\n\x1a\n";
+
+fn crc32(bytes: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for &byte in bytes {
+        crc ^= byte as u32;
+        for _ in 0..8 {
+            crc = if crc & 1 != 0 {
+                (crc >> 1) ^ 0xedb8_8320
+            } else {
+                crc >> 1
+            };
+        }
+    }
+    !crc
+}
+
+fn chunk(out: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    out.extend_from_slice(&(data.len() as u32).to_be_bytes());
+    let start = out.len();
+    out.extend_from_slice(kind);
+    out.extend_from_slice(data);
+    let crc = crc32(&out[start..]);
+    out.extend_from_slice(&crc.to_be_bytes());
+}
+
+fn paeth(a: u8, b: u8, c: u8) -> u8 {
+    let p = a as i16 + b as i16 - c as i16;
+    let (pa, pb, pc) = (
+        (p - a as i16).abs(),
+        (p - b as i16).abs(),
+        (p - c as i16).abs(),
+    );
+    if pa <= pb && pa <= pc {
+        a
+    } else if pb <= pc {
+        b
+    } else {
+        c
+    }
+}
+
+// Filters each row with whichever of the five PNG filters yields the smallest
+// sum of absolute residuals, then deflates the result.
+fn image_data(width: u32, height: u32, rgba: &[u8]) -> Vec<u8> {
+    let stride = width as usize * 4;
+    let mut filtered = Vec::with_capacity((stride + 1) * height as usize);
+    let mut candidate = vec![0u8; stride];
+    let mut best = vec![0u8; stride];
+    let zero = vec![0u8; stride];
+
+    for y in 0..height as usize {
+        let row = &rgba[y * stride..(y + 1) * stride];
+        let above = if y == 0 {
+            &zero[..]
+        } else {
+            &rgba[(y - 1) * stride..y * stride]
+        };
+        let mut best_filter = 0;
+        let mut best_score = u64::MAX;
+        for filter in 0..5u8 {
+            for x in 0..stride {
+                let a = if x >= 4 { row[x - 4] } else { 0 };
+                let b = above[x];
+                let c = if x >= 4 { above[x - 4] } else { 0 };
+                let predicted = match filter {
+                    0 => 0,
+                    1 => a,
+                    2 => b,
+                    3 => ((a as u16 + b as u16) / 2) as u8,
+                    _ => paeth(a, b, c),
+                };
+                candidate[x] = row[x].wrapping_sub(predicted);
+            }
+            let score = candidate
+                .iter()
+                .map(|&value| (value as i8).unsigned_abs() as u64)
+                .sum();
+            if score < best_score {
+                best_score = score;
+                best_filter = filter;
+                best.copy_from_slice(&candidate);
+            }
+        }
+        filtered.push(best_filter);
+        filtered.extend_from_slice(&best);
+    }
+    zlib_compress(&filtered)
+}
+
+fn header(out: &mut Vec<u8>, width: u32, height: u32) {
+    out.extend_from_slice(SIGNATURE);
+    let mut ihdr = Vec::with_capacity(13);
+    ihdr.extend_from_slice(&width.to_be_bytes());
+    ihdr.extend_from_slice(&height.to_be_bytes());
+    // 8-bit RGBA, deflate, adaptive filtering, no interlace.
+    ihdr.extend_from_slice(&[8, 6, 0, 0, 0]);
+    chunk(out, b"IHDR", &ihdr);
+}
+
+/// Encodes unpremultiplied RGBA8 pixels as a PNG.
+pub(crate) fn encode(width: u32, height: u32, rgba: &[u8]) -> Vec<u8> {
+    let mut out = Vec::new();
+    header(&mut out, width, height);
+    chunk(&mut out, b"IDAT", &image_data(width, height, rgba));
+    chunk(&mut out, b"IEND", &[]);
+    out
+}
+
+/// Encodes same-sized unpremultiplied RGBA8 frames as a looping APNG, each
+/// frame shown for `delay_num / delay_den` seconds.
+pub(crate) fn encode_animated<'a>(
+    width: u32,
+    height: u32,
+    frames: impl ExactSizeIterator<Item = &'a [u8]>,
+    delay_num: u16,
+    delay_den: u16,
+) -> Vec<u8> {
+    let mut out = Vec::new();
+    header(&mut out, width, height);
+    let mut actl = Vec::with_capacity(8);
+    actl.extend_from_slice(&(frames.len() as u32).to_be_bytes());
+    actl.extend_from_slice(&0u32.to_be_bytes());
+    chunk(&mut out, b"acTL", &actl);
+
+    let mut sequence = 0u32;
+    for (index, rgba) in frames.enumerate() {
+        let mut fctl = Vec::with_capacity(26);
+        fctl.extend_from_slice(&sequence.to_be_bytes());
+        fctl.extend_from_slice(&width.to_be_bytes());
+        fctl.extend_from_slice(&height.to_be_bytes());
+        fctl.extend_from_slice(&0u32.to_be_bytes());
+        fctl.extend_from_slice(&0u32.to_be_bytes());
+        fctl.extend_from_slice(&delay_num.to_be_bytes());
+        fctl.extend_from_slice(&delay_den.to_be_bytes());
+        // APNG_DISPOSE_OP_NONE, APNG_BLEND_OP_SOURCE: every frame is complete.
+        fctl.extend_from_slice(&[0, 0]);
+        chunk(&mut out, b"fcTL", &fctl);
+        sequence += 1;
+
+        let data = image_data(width, height, rgba);
+        if index == 0 {
+            chunk(&mut out, b"IDAT", &data);
+        } else {
+            let mut fdat = Vec::with_capacity(data.len() + 4);
+            fdat.extend_from_slice(&sequence.to_be_bytes());
+            fdat.extend_from_slice(&data);
+            chunk(&mut out, b"fdAT", &fdat);
+            sequence += 1;
+        }
+    }
+    chunk(&mut out, b"IEND", &[]);
+    out
+}
+
+fn unfilter(filter: u8, row: &mut [u8], above: &[u8], bpp: usize) -> Option<()> {
+    for x in 0..row.len() {
+        let a = if x >= bpp { row[x - bpp] } else { 0 };
+        let b = above[x];
+        let c = if x >= bpp { above[x - bpp] } else { 0 };
+        let predicted = match filter {
+            0 => 0,
+            1 => a,
+            2 => b,
+            3 => ((a as u16 + b as u16) / 2) as u8,
+            4 => paeth(a, b, c),
+            _ => return None,
+        };
+        row[x] = row[x].wrapping_add(predicted);
+    }
+    Some(())
+}
+
+struct Header {
+    width: u32,
+    height: u32,
+    bit_depth: u8,
+    color_type: u8,
+    interlaced: bool,
+}
+
+impl Header {
+    fn channels(&self) -> usize {
+        match self.color_type {
+            0 | 3 => 1,
+            4 => 2,
+            2 => 3,
+            _ => 4,
+        }
+    }
+
+    fn bits_per_pixel(&self) -> usize {
+        self.channels() * self.bit_depth as usize
+    }
+}
+
+// Expands one unfiltered row into RGBA8 pixels written at `x0, x0 + dx, ...`.
+fn expand_row(
+    header: &Header,
+    row: &[u8],
+    palette: &[[u8; 4]],
+    transparent: Option<[u16; 3]>,
+    out: &mut [u8],
+    pixel_count: usize,
+    mut target: impl FnMut(usize) -> usize,
+) -> Option<()> {
+    let depth = header.bit_depth as usize;
+    let sample = |index: usize| -> u16 {
+        match depth {
+            16 => u16::from_be_bytes([row[index * 2], row[index * 2 + 1]]),
+            8 => row[index] as u16,
+            _ => {
+                let bit = index * depth;
+                let shift = 8 - depth - bit % 8;
+                (row[bit / 8] as u16 >> shift) & ((1 << depth) - 1)
+            }
+        }
+    };
+    let scale = |value: u16| -> u8 {
+        match depth {
+            16 => (value >> 8) as u8,
+            8 => value as u8,
+            _ => (value as u32 * 255 / ((1 << depth) - 1)) as u8,
+        }
+    };
+    let channels = header.channels();
+    for x in 0..pixel_count {
+        let base = x * channels;
+        let rgba = match header.color_type {
+            0 => {
+                let gray = sample(base);
+                let alpha = if transparent.is_some_and(|key| key[0] == gray) {
+                    0
+                } else {
+                    255
+                };
+                let gray = scale(gray);
+                [gray, gray, gray, alpha]
+            }
+            2 => {
+                let rgb = [sample(base), sample(base + 1), sample(base + 2)];
+                let alpha = if transparent == Some(rgb) { 0 } else { 255 };
+                [scale(rgb[0]), scale(rgb[1]), scale(rgb[2]), alpha]
+            }
+            3 => *palette.get(sample(base) as usize)?,
+            4 => {
+                let gray = scale(sample(base));
+                [gray, gray, gray, scale(sample(base + 1))]
+            }
+            _ => [
+                scale(sample(base)),
+                scale(sample(base + 1)),
+                scale(sample(base + 2)),
+                scale(sample(base + 3)),
+            ],
+        };
+        let at = target(x) * 4;
+        out[at..at + 4].copy_from_slice(&rgba);
+    }
+    Some(())
+}
+
+/// Decodes a PNG (any bit depth and color type, optionally interlaced) into
+/// unpremultiplied RGBA8. Only the default image is decoded for APNGs.
+pub(crate) fn decode(bytes: &[u8]) -> Option<(u32, u32, Vec<u8>)> {
+    let mut rest = bytes.strip_prefix(SIGNATURE)?;
+    let mut header = None;
+    let mut palette = Vec::new();
+    let mut transparency = None;
+    let mut data = Vec::new();
+    while rest.len() >= 12 {
+        let length = u32::from_be_bytes(rest[..4].try_into().ok()?) as usize;
+        let kind: [u8; 4] = rest[4..8].try_into().ok()?;
+        let body = rest.get(8..8 + length)?;
+        let crc = u32::from_be_bytes(rest.get(8 + length..12 + length)?.try_into().ok()?);
+        if crc32(&rest[4..8 + length]) != crc {
+            return None;
+        }
+        rest = &rest[12 + length..];
+        match &kind {
+            b"IHDR" if body.len() == 13 => {
+                let parsed = Header {
+                    width: u32::from_be_bytes(body[..4].try_into().ok()?),
+                    height: u32::from_be_bytes(body[4..8].try_into().ok()?),
+                    bit_depth: body[8],
+                    color_type: body[9],
+                    interlaced: body[12] == 1,
+                };
+                let valid_depth = match parsed.color_type {
+                    0 => matches!(parsed.bit_depth, 1 | 2 | 4 | 8 | 16),
+                    3 => matches!(parsed.bit_depth, 1 | 2 | 4 | 8),
+                    2 | 4 | 6 => matches!(parsed.bit_depth, 8 | 16),
+                    _ => false,
+                };
+                if !valid_depth || parsed.width == 0 || parsed.height == 0 || body[10] != 0 {
+                    return None;
+                }
+                header = Some(parsed);
+            }
+            b"PLTE" => {
+                palette = body
+                    .chunks_exact(3)
+                    .map(|rgb| [rgb[0], rgb[1], rgb[2], 255])
+                    .collect();
+            }
+            b"tRNS" => match header.as_ref()?.color_type {
+                3 => {
+                    for (entry, &alpha) in palette.iter_mut().zip(body) {
+                        entry[3] = alpha;
+                    }
+                }
+                0 if body.len() >= 2 => {
+                    let gray = u16::from_be_bytes([body[0], body[1]]);
+                    transparency = Some([gray; 3]);
+                }
+                2 if body.len() >= 6 => {
+                    transparency = Some([
+                        u16::from_be_bytes([body[0], body[1]]),
+                        u16::from_be_bytes([body[2], body[3]]),
+                        u16::from_be_bytes([body[4], body[5]]),
+                    ]);
+                }
+                _ => {}
+            },
+            b"IDAT" => data.extend_from_slice(body),
+            b"IEND" => break,
+            _ => {}
+        }
+    }
+
+    let header = header?;
+    let (width, height) = (header.width as usize, header.height as usize);
+    let mut out = vec![0u8; width.checked_mul(height)?.checked_mul(4)?];
+    let bpp = header.bits_per_pixel().div_ceil(8);
+
+    // Adam7 passes as (x0, y0, dx, dy); a single full pass otherwise.
+    let passes: &[(usize, usize, usize, usize)] = if header.interlaced {
+        &[
+            (0, 0, 8, 8),
+            (4, 0, 8, 8),
+            (0, 4, 4, 8),
+            (2, 0, 4, 4),
+            (0, 2, 2, 4),
+            (1, 0, 2, 2),
+            (0, 1, 1, 2),
+        ]
+    } else {
+        &[(0, 0, 1, 1)]
+    };
+    // Each row of each pass is a filter byte plus its packed pixels; the
+    // image data never needs to inflate past that.
+    let mut expected = 0usize;
+    for &(x0, y0, dx, dy) in passes {
+        if x0 >= width || y0 >= height {
+            continue;
+        }
+        let pass_width = (width + dx - 1 - x0) / dx;
+        let pass_height = (height + dy - 1 - y0) / dy;
+        let stride = pass_width.checked_mul(header.bits_per_pixel())?.div_ceil(8);
+        expected = expected.checked_add(pass_height.checked_mul(1 + stride)?)?;
+    }
+    let raw = crate::inflate::zlib_decompress(&data, expected)?;
+    let mut position = 0;
+    for &(x0, y0, dx, dy) in passes {
+        let pass_width = (width + dx - 1 - x0) / dx;
+        let pass_height = (height + dy - 1 - y0) / dy;
+        if x0 >= width || y0 >= height || pass_width == 0 || pass_height == 0 {
+            continue;
+        }
+        let stride = (pass_width * header.bits_per_pixel()).div_ceil(8);
+        let mut above = vec![0u8; stride];
+        let mut row = vec![0u8; stride];
+        for pass_y in 0..pass_height {
+            let filter = *raw.get(position)?;
+            row.copy_from_slice(raw.get(position + 1..position + 1 + stride)?);
+            position += 1 + stride;
+            unfilter(filter, &mut row, &above, bpp)?;
+            let y = y0 + pass_y * dy;
+            expand_row(
+                &header,
+                &row,
+                &palette,
+                transparency,
+                &mut out,
+                pass_width,
+                |x| y * width + x0 + x * dx,
+            )?;
+            core::mem::swap(&mut above, &mut row);
+        }
+    }
+    Some((header.width, header.height, out))
+}
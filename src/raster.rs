@@ -0,0 +1,892 @@
+use core::f32::consts::PI;
+
+use crate::backend::{
+    BlendMode, FillRule, GradientStop, ImageFilter, ImageSampler, ImageWrap, StrokeCap, StrokeJoin,
+};
+use crate::runtime::Mat2D;
+
+// Vertical samples per pixel row; horizontal coverage is computed exactly.
+const SUBSAMPLES: usize = 16;
+const MITER_LIMIT: f32 = 4.0;
+
+pub(crate) const IDENTITY: Mat2D = Mat2D {
+    xx: 1.0,
+    xy: 0.0,
+    yx: 0.0,
+    yy: 1.0,
+    tx: 0.0,
+    ty: 0.0,
+};
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub(crate) struct Point {
+    pub(crate) x: f32,
+    pub(crate) y: f32,
+}
+
+impl Point {
+    pub(crate) const fn new(x: f32, y: f32) -> Self {
+        Self { x, y }
+    }
+
+    fn add(self, other: Point) -> Point {
+        Point::new(self.x + other.x, self.y + other.y)
+    }
+
+    fn sub(self, other: Point) -> Point {
+        Point::new(self.x - other.x, self.y - other.y)
+    }
+
+    fn scale(self, factor: f32) -> Point {
+        Point::new(self.x * factor, self.y * factor)
+    }
+
+    fn dot(self, other: Point) -> f32 {
+        self.x * other.x + self.y * other.y
+    }
+
+    fn cross(self, other: Point) -> f32 {
+        self.x * other.y - self.y * other.x
+    }
+
+    fn length(self) -> f32 {
+        self.dot(self).sqrt()
+    }
+
+    fn perp(self) -> Point {
+        Point::new(-self.y, self.x)
+    }
+
+    fn is_finite(self) -> bool {
+        self.x.is_finite() && self.y.is_finite()
+    }
+}
+
+pub(crate) fn transform_point(matrix: &Mat2D, point: Point) -> Point {
+    Point::new(
+        matrix.xx * point.x + matrix.yx * point.y + matrix.tx,
+        matrix.xy * point.x + matrix.yy * point.y + matrix.ty,
+    )
+}
+
+pub(crate) fn matrix_scale(matrix: &Mat2D) -> f32 {
+    (matrix.xx * matrix.yy - matrix.xy * matrix.yx).abs().sqrt()
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub(crate) enum PathCommand {
+    MoveTo(Point),
+    LineTo(Point),
+    CubicTo(Point, Point, Point),
+    Close,
+}
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct Contour {
+    pub(crate) points: Vec<Point>,
+    pub(crate) closed: bool,
+}
+
+pub(crate) fn flatten(commands: &[PathCommand], matrix: &Mat2D, tolerance: f32) -> Vec<Contour> {
+    let mut contours = Vec::new();
+    let mut current = Contour::default();
+    let mut start = Point::new(0.0, 0.0);
+    let mut last = start;
+
+    for command in commands {
+        match *command {
+            PathCommand::MoveTo(p) => {
+                if !current.points.is_empty() {
+                    contours.push(core::mem::take(&mut current));
+                }
+                start = transform_point(matrix, p);
+                last = start;
+                current.points.push(start);
+            }
+            PathCommand::LineTo(p) => {
+                if current.points.is_empty() {
+                    current.points.push(last);
+                }
+                last = transform_point(matrix, p);
+                current.points.push(last);
+            }
+            PathCommand::CubicTo(c1, c2, p) => {
+                if current.points.is_empty() {
+                    current.points.push(last);
+                }
+                let c1 = transform_point(matrix, c1);
+                let c2 = transform_point(matrix, c2);
+                let end = transform_point(matrix, p);
+                flatten_cubic(last, c1, c2, end, tolerance, &mut current.points);
+                last = end;
+            }
+            PathCommand::Close => {
+                if !current.points.is_empty() {
+                    current.closed = true;
+                    contours.push(core::mem::take(&mut current));
+                }
+                last = start;
+            }
+        }
+    }
+    if !current.points.is_empty() {
+        contours.push(current);
+    }
+    contours
+}
+
+fn flatten_cubic(p0: Point, c1: Point, c2: Point, p3: Point, tolerance: f32, out: &mut Vec<Point>) {
+    let dd = p0
+        .sub(c1.scale(2.0))
+        .add(c2)
+        .length()
+        .max(c1.sub(c2.scale(2.0)).add(p3).length());
+    let segments = (0.75 * dd / tolerance.max(1e-3)).sqrt().ceil();
+    let segments = if segments.is_finite() {
+        (segments as usize).clamp(1, 256)
+    } else {
+        1
+    };
+    for i in 1..=segments {
+        let t = i as f32 / segments as f32;
+        let mt = 1.0 - t;
+        let a = mt * mt * mt;
+        let b = 3.0 * mt * mt * t;
+        let c = 3.0 * mt * t * t;
+        let d = t * t * t;
+        out.push(Point::new(
+            a * p0.x + b * c1.x + c * c2.x + d * p3.x,
+            a * p0.y + b * c1.y + c * c2.y + d * p3.y,
+        ));
+    }
+}
+
+pub(crate) fn transform_contours(contours: &mut [Contour], matrix: &Mat2D) {
+    for contour in contours {
+        for point in &mut contour.points {
+            *point = transform_point(matrix, *point);
+        }
+    }
+}
+
+fn circle(center: Point, radius: f32, device_radius: f32) -> Contour {
+    let tolerance = 0.2_f32;
+    let segments = if device_radius <= tolerance {
+        8
+    } else {
+        let step = 2.0 * (1.0 - tolerance / device_radius).clamp(-1.0, 1.0).acos();
+        ((2.0 * PI / step.max(1e-3)).ceil() as usize).clamp(8, 256)
+    };
+    let points = (0..segments)
+        .map(|i| {
+            let angle = i as f32 / segments as f32 * 2.0 * PI;
+            Point::new(
+                center.x + radius * angle.cos(),
+                center.y + radius * angle.sin(),
+            )
+        })
+        .collect();
+    Contour {
+        points,
+        closed: true,
+    }
+}
+
+fn polygon(points: Vec<Point>) -> Contour {
+    Contour {
+        points,
+        closed: true,
+    }
+}
+
+/// Expands `contours` into closed polygons covering the stroke outline.
+///
+/// Every returned polygon is wound the same way so the union can be filled
+/// with the non-zero rule.
+pub(crate) fn stroke(
+    contours: &[Contour],
+    thickness: f32,
+    join: StrokeJoin,
+    cap: StrokeCap,
+    device_scale: f32,
+) -> Vec<Contour> {
+    let half = thickness * 0.5;
+    let device_radius = half * device_scale;
+    let mut out = Vec::new();
+
+    for contour in contours {
+        let mut points: Vec<Point> = Vec::with_capacity(contour.points.len());
+        for &point in &contour.points {
+            if !point.is_finite() {
+                continue;
+            }
+            if points
+                .last()
+                .is_none_or(|last: &Point| point.sub(*last).length() > 1e-6)
+            {
+                points.push(point);
+            }
+        }
+        if contour.closed
+            && points.len() > 1
+            && points[0].sub(points[points.len() - 1]).length() <= 1e-6
+        {
+            points.pop();
+        }
+
+        if points.len() == 1 {
+            let p = points[0];
+            match cap {
+                StrokeCap::RIVE_RS_STROKE_CAP_ROUND => out.push(circle(p, half, device_radius)),
+                StrokeCap::RIVE_RS_STROKE_CAP_SQUARE => out.push(polygon(vec![
+                    Point::new(p.x - half, p.y - half),
+                    Point::new(p.x + half, p.y - half),
+                    Point::new(p.x + half, p.y + half),
+                    Point::new(p.x - half, p.y + half),
+                ])),
+                StrokeCap::RIVE_RS_STROKE_CAP_BUTT => {}
+            }
+            continue;
+        }
+        if points.len() < 2 {
+            continue;
+        }
+
+        let closed = contour.closed && points.len() > 2;
+        let segment_count = if closed {
+            points.len()
+        } else {
+            points.len() - 1
+        };
+        let directions: Vec<Point> = (0..segment_count)
+            .map(|i| {
+                let a = points[i];
+                let b = points[(i + 1) % points.len()];
+                let d = b.sub(a);
+                d.scale(1.0 / d.length())
+            })
+            .collect();
+
+        for (i, direction) in directions.iter().enumerate() {
+            let a = points[i];
+            let b = points[(i + 1) % points.len()];
+            let offset = direction.perp().scale(half);
+            out.push(polygon(vec![
+                a.add(offset),
+                b.add(offset),
+                b.sub(offset),
+                a.sub(offset),
+            ]));
+        }
+
+        let join_vertices = if closed {
+            0..points.len()
+        } else {
+            1..points.len() - 1
+        };
+        for vertex in join_vertices {
+            let d0 = directions[(vertex + segment_count - 1) % segment_count];
+            let d1 = directions[vertex % segment_count];
+            push_join(&mut out, points[vertex], d0, d1, half, join, device_radius);
+        }
+
+        if !closed {
+            let first = points[0];
+            let last = points[points.len() - 1];
+            let d_first = directions[0];
+            let d_last = directions[segment_count - 1];
+            push_cap(
+                &mut out,
+                first,
+                d_first.scale(-1.0),
+                half,
+                cap,
+                device_radius,
+            );
+            push_cap(&mut out, last, d_last, half, cap, device_radius);
+        }
+    }
+
+    for polygon in &mut out {
+        if signed_area(&polygon.points) < 0.0 {
+            polygon.points.reverse();
+        }
+    }
+    out
+}
+
+fn push_join(
+    out: &mut Vec<Contour>,
+    vertex: Point,
+    d0: Point,
+    d1: Point,
+    half: f32,
+    join: StrokeJoin,
+    device_radius: f32,
+) {
+    let cross = d0.cross(d1);
+    let dot = d0.dot(d1);
+    if cross.abs() < 1e-6 && dot > 0.0 {
+        return;
+    }
+    let side = if cross > 0.0 { -1.0 } else { 1.0 };
+    let n0 = d0.perp().scale(side * half);
+    let n1 = d1.perp().scale(side * half);
+    match join {
+        StrokeJoin::RIVE_RS_STROKE_JOIN_ROUND => out.push(circle(vertex, half, device_radius)),
+        StrokeJoin::RIVE_RS_STROKE_JOIN_MITER if 1.0 + dot > 2.0 / (MITER_LIMIT * MITER_LIMIT) => {
+            let miter = vertex.add(n0.add(n1).scale(1.0 / (1.0 + dot)));
+            out.push(polygon(vec![vertex, vertex.add(n0), miter, vertex.add(n1)]));
+        }
+        _ => out.push(polygon(vec![vertex, vertex.add(n0), vertex.add(n1)])),
+    }
+}
+
+fn push_cap(
+    out: &mut Vec<Contour>,
+    point: Point,
+    outward: Point,
+    half: f32,
+    cap: StrokeCap,
+    device_radius: f32,
+) {
+    match cap {
+        StrokeCap::RIVE_RS_STROKE_CAP_BUTT => {}
+        StrokeCap::RIVE_RS_STROKE_CAP_ROUND => out.push(circle(point, half, device_radius)),
+        StrokeCap::RIVE_RS_STROKE_CAP_SQUARE => {
+            let normal = outward.perp().scale(half);
+            let extent = outward.scale(half);
+            out.push(polygon(vec![
+                point.add(normal),
+                point.add(normal).add(extent),
+                point.sub(normal).add(extent),
+                point.sub(normal),
+            ]));
+        }
+    }
+}
+
+fn signed_area(points: &[Point]) -> f32 {
+    let mut area = 0.0;
+    for (i, a) in points.iter().enumerate() {
+        let b = points[(i + 1) % points.len()];
+        area += a.cross(b);
+    }
+    area * 0.5
+}
+
+/// Anti-aliased coverage for a rectangular region of the target.
+#[derive(Debug, Clone)]
+pub(crate) struct Mask {
+    pub(crate) x: usize,
+    pub(crate) y: usize,
+    pub(crate) width: usize,
+    pub(crate) height: usize,
+    pub(crate) coverage: Vec<f32>,
+}
+
+impl Mask {
+    /// A mask that covers nothing.
+    pub(crate) fn empty() -> Self {
+        Self {
+            x: 0,
+            y: 0,
+            width: 0,
+            height: 0,
+            coverage: Vec::new(),
+        }
+    }
+
+    /// Coverage of both masks, allocated only over where their bounds
+    /// overlap.
+    pub(crate) fn intersect(&self, other: &Mask) -> Mask {
+        let (x0, y0) = (self.x.max(other.x), self.y.max(other.y));
+        let x1 = (self.x + self.width).min(other.x + other.width);
+        let y1 = (self.y + self.height).min(other.y + other.height);
+        if x0 >= x1 || y0 >= y1 {
+            return Mask::empty();
+        }
+        let mut coverage = Vec::with_capacity((x1 - x0) * (y1 - y0));
+        for y in y0..y1 {
+            coverage.extend((x0..x1).map(|x| self.at(x, y) * other.at(x, y)));
+        }
+        Mask {
+            x: x0,
+            y: y0,
+            width: x1 - x0,
+            height: y1 - y0,
+            coverage,
+        }
+    }
+
+    pub(crate) fn at(&self, x: usize, y: usize) -> f32 {
+        if x < self.x || y < self.y || x >= self.x + self.width || y >= self.y + self.height {
+            return 0.0;
+        }
+        self.coverage[(y - self.y) * self.width + (x - self.x)]
+    }
+}
+
+struct Edge {
+    x0: f32,
+    y0: f32,
+    y1: f32,
+    slope: f32,
+    winding: i32,
+}
+
+fn inside(fill_rule: FillRule, winding: i32) -> bool {
+    match fill_rule {
+        FillRule::RIVE_RS_FILL_RULE_NON_ZERO => winding != 0,
+        FillRule::RIVE_RS_FILL_RULE_EVEN_ODD => winding & 1 != 0,
+        FillRule::RIVE_RS_FILL_RULE_CLOCKWISE => winding > 0,
+    }
+}
+
+/// Rasterizes device-space `contours` (implicitly closed) into a coverage mask
+/// clipped to a `width` x `height` target.
+pub(crate) fn fill_mask(
+    contours: &[Contour],
+    fill_rule: FillRule,
+    width: usize,
+    height: usize,
+) -> Option<Mask> {
+    let mut edges = Vec::new();
+    let (mut min_x, mut min_y) = (f32::INFINITY, f32::INFINITY);
+    let (mut max_x, mut max_y) = (f32::NEG_INFINITY, f32::NEG_INFINITY);
+
+    for contour in contours {
+        let points = &contour.points;
+        if points.len() < 2 || !points.iter().all(|p| p.is_finite()) {
+            continue;
+        }
+        for (i, &a) in points.iter().enumerate() {
+            let b = points[(i + 1) % points.len()];
+            min_x = min_x.min(a.x);
+            min_y = min_y.min(a.y);
+            max_x = max_x.max(a.x);
+            max_y = max_y.max(a.y);
+            if a.y == b.y {
+                continue;
+            }
+            let (top, bottom, winding) = if a.y < b.y { (a, b, -1) } else { (b, a, 1) };
+            edges.push(Edge {
+                x0: top.x,
+                y0: top.y,
+                y1: bottom.y,
+                slope: (bottom.x - top.x) / (bottom.y - top.y),
+                winding,
+            });
+        }
+    }
+    if edges.is_empty() {
+        return None;
+    }
+
+    let left = min_x.floor().max(0.0) as usize;
+    let top = min_y.floor().max(0.0) as usize;
+    let right = (max_x.ceil().max(0.0) as usize).min(width);
+    let bottom = (max_y.ceil().max(0.0) as usize).min(height);
+    if left >= right || top >= bottom {
+        return None;
+    }
+
+    let mask_width = right - left;
+    let mask_height = bottom - top;
+    let mut coverage = vec![0.0f32; mask_width * mask_height];
+    let mut partial = vec![0.0f32; mask_width];
+    let mut spans = vec![0.0f32; mask_width + 1];
+    let mut crossings: Vec<(f32, i32)> = Vec::new();
+
+    edges.sort_by(|a, b| a.y0.total_cmp(&b.y0));
+    let mut next_edge = 0;
+    let mut active: Vec<usize> = Vec::new();
+    let weight = 1.0 / SUBSAMPLES as f32;
+    let span_left = left as f32;
+    let span_right = right as f32;
+
+    for row in top..bottom {
+        let row_top = row as f32;
+        while next_edge < edges.len() && edges[next_edge].y0 < row_top + 1.0 {
+            active.push(next_edge);
+            next_edge += 1;
+        }
+        active.retain(|&index| edges[index].y1 > row_top);
+        if active.is_empty() {
+            continue;
+        }
+
+        for sample in 0..SUBSAMPLES {
+            let y = row_top + (sample as f32 + 0.5) * weight;
+            crossings.clear();
+            for &index in &active {
+                let edge = &edges[index];
+                if y >= edge.y0 && y < edge.y1 {
+                    crossings.push((edge.x0 + (y - edge.y0) * edge.slope, edge.winding));
+                }
+            }
+            crossings.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+            let mut winding = 0;
+            for pair in crossings.windows(2) {
+                winding += pair[0].1;
+                if !inside(fill_rule, winding) {
+                    continue;
+                }
+                let xa = pair[0].0.clamp(span_left, span_right) - span_left;
+                let xb = pair[1].0.clamp(span_left, span_right) - span_left;
+                if xb <= xa {
+                    continue;
+                }
+                let ia = xa as usize;
+                let ib = xb as usize;
+                if ia == ib {
+                    partial[ia] += (xb - xa) * weight;
+                } else {
+                    partial[ia] += (ia as f32 + 1.0 - xa) * weight;
+                    spans[ia + 1] += weight;
+                    spans[ib] -= weight;
+                    if ib < mask_width {
+                        partial[ib] += (xb - ib as f32) * weight;
+                    }
+                }
+            }
+        }
+
+        let row_coverage = &mut coverage[(row - top) * mask_width..(row - top + 1) * mask_width];
+        let mut running = 0.0;
+        for (x, value) in row_coverage.iter_mut().enumerate() {
+            running += spans[x];
+            *value = (partial[x] + running).clamp(0.0, 1.0);
+        }
+        partial.fill(0.0);
+        spans.fill(0.0);
+    }
+
+    Some(Mask {
+        x: left,
+        y: top,
+        width: mask_width,
+        height: mask_height,
+        coverage,
+    })
+}
+
+/// Converts premultiplied RGBA8 pixels to unpremultiplied in place.
+pub(crate) fn unpremultiply(pixels: &mut [u8]) {
+    for pixel in pixels.chunks_exact_mut(4) {
+        let alpha = pixel[3] as u32;
+        if alpha != 0 && alpha != 255 {
+            for channel in &mut pixel[..3] {
+                *channel = ((*channel as u32 * 255 + alpha / 2) / alpha).min(255) as u8;
+            }
+        }
+    }
+}
+
+pub(crate) fn unpack_argb(argb: u32) -> [f32; 4] {
+    let a = ((argb >> 24) & 0xff) as f32 / 255.0;
+    let r = ((argb >> 16) & 0xff) as f32 / 255.0;
+    let g = ((argb >> 8) & 0xff) as f32 / 255.0;
+    let b = (argb & 0xff) as f32 / 255.0;
+    [r * a, g * a, b * a, a]
+}
+
+pub(crate) fn gradient_color(stops: &[GradientStop], t: f32) -> [f32; 4] {
+    let (Some(first), Some(last)) = (stops.first(), stops.last()) else {
+        return [0.0; 4];
+    };
+    if !t.is_finite() || t <= first.position {
+        return unpack_argb(first.color);
+    }
+    if t >= last.position {
+        return unpack_argb(last.color);
+    }
+    for pair in stops.windows(2) {
+        let (a, b) = (pair[0], pair[1]);
+        if t < b.position {
+            let span = b.position - a.position;
+            let f = if span > 0.0 {
+                (t - a.position) / span
+            } else {
+                1.0
+            };
+            let channel = |shift: u32| {
+                let ca = ((a.color >> shift) & 0xff) as f32;
+                let cb = ((b.color >> shift) & 0xff) as f32;
+                (ca + (cb - ca) * f) / 255.0
+            };
+            let alpha = channel(24);
+            return [
+                channel(16) * alpha,
+                channel(8) * alpha,
+                channel(0) * alpha,
+                alpha,
+            ];
+        }
+    }
+    unpack_argb(last.color)
+}
+
+/// Premultiplied RGBA8 pixels with a row stride of `width * 4`.
+#[derive(Debug, Clone)]
+pub(crate) struct Pixmap {
+    pub(crate) width: usize,
+    pub(crate) height: usize,
+    pub(crate) data: Vec<u8>,
+}
+
+impl Pixmap {
+    pub(crate) fn new(width: usize, height: usize) -> Self {
+        Self {
+            width,
+            height,
+            data: vec![0; width * height * 4],
+        }
+    }
+
+    pub(crate) fn clear(&mut self) {
+        self.data.fill(0);
+    }
+
+    pub(crate) fn pixel(&self, x: usize, y: usize) -> [f32; 4] {
+        let offset = (y * self.width + x) * 4;
+        let p = &self.data[offset..offset + 4];
+        [
+            p[0] as f32 / 255.0,
+            p[1] as f32 / 255.0,
+            p[2] as f32 / 255.0,
+            p[3] as f32 / 255.0,
+        ]
+    }
+
+    /// Composites `shade` through `mask`, scaled by `opacity` and `clip`.
+    pub(crate) fn fill(
+        &mut self,
+        mask: &Mask,
+        clip: Option<&Mask>,
+        opacity: f32,
+        blend_mode: BlendMode,
+        mut shade: impl FnMut(f32, f32) -> [f32; 4],
+    ) {
+        for my in 0..mask.height {
+            let y = mask.y + my;
+            for mx in 0..mask.width {
+                let x = mask.x + mx;
+                let mut coverage = mask.coverage[my * mask.width + mx] * opacity;
+                if let Some(clip) = clip {
+                    coverage *= clip.at(x, y);
+                }
+                if coverage <= 0.0 {
+                    continue;
+                }
+                let src = shade(x as f32 + 0.5, y as f32 + 0.5);
+                let src = [
+                    src[0] * coverage,
+                    src[1] * coverage,
+                    src[2] * coverage,
+                    src[3] * coverage,
+                ];
+                let dst = self.pixel(x, y);
+                let out = blend(blend_mode, src, dst);
+                let offset = (y * self.width + x) * 4;
+                for (channel, value) in self.data[offset..offset + 4].iter_mut().zip(out) {
+                    *channel = (value.clamp(0.0, 1.0) * 255.0 + 0.5) as u8;
+                }
+            }
+        }
+    }
+}
+
+fn blend_channel(mode: BlendMode, cb: f32, cs: f32) -> f32 {
+    match mode {
+        BlendMode::RIVE_RS_BLEND_MODE_MULTIPLY => cb * cs,
+        BlendMode::RIVE_RS_BLEND_MODE_SCREEN => cb + cs - cb * cs,
+        BlendMode::RIVE_RS_BLEND_MODE_OVERLAY => hard_light(cs, cb),
+        BlendMode::RIVE_RS_BLEND_MODE_DARKEN => cb.min(cs),
+        BlendMode::RIVE_RS_BLEND_MODE_LIGHTEN => cb.max(cs),
+        BlendMode::RIVE_RS_BLEND_MODE_COLOR_DODGE => {
+            if cb <= 0.0 {
+                0.0
+            } else if cs >= 1.0 {
+                1.0
+            } else {
+                (cb / (1.0 - cs)).min(1.0)
+            }
+        }
+        BlendMode::RIVE_RS_BLEND_MODE_COLOR_BURN => {
+            if cb >= 1.0 {
+                1.0
+            } else if cs <= 0.0 {
+                0.0
+            } else {
+                1.0 - ((1.0 - cb) / cs).min(1.0)
+            }
+        }
+        BlendMode::RIVE_RS_BLEND_MODE_HARD_LIGHT => hard_light(cb, cs),
+        BlendMode::RIVE_RS_BLEND_MODE_SOFT_LIGHT => {
+            if cs <= 0.5 {
+                cb - (1.0 - 2.0 * cs) * cb * (1.0 - cb)
+            } else {
+                let d = if cb <= 0.25 {
+                    ((16.0 * cb - 12.0) * cb + 4.0) * cb
+                } else {
+                    cb.sqrt()
+                };
+                cb + (2.0 * cs - 1.0) * (d - cb)
+            }
+        }
+        BlendMode::RIVE_RS_BLEND_MODE_DIFFERENCE => (cb - cs).abs(),
+        BlendMode::RIVE_RS_BLEND_MODE_EXCLUSION => cb + cs - 2.0 * cb * cs,
+        _ => cs,
+    }
+}
+
+fn hard_light(cb: f32, cs: f32) -> f32 {
+    if cs <= 0.5 {
+        cb * 2.0 * cs
+    } else {
+        let s = 2.0 * cs - 1.0;
+        cb + s - cb * s
+    }
+}
+
+fn lum(c: [f32; 3]) -> f32 {
+    0.3 * c[0] + 0.59 * c[1] + 0.11 * c[2]
+}
+
+fn clip_color(c: [f32; 3]) -> [f32; 3] {
+    let l = lum(c);
+    let n = c[0].min(c[1]).min(c[2]);
+    let x = c[0].max(c[1]).max(c[2]);
+    let mut out = c;
+    if n < 0.0 && l - n > 0.0 {
+        out = out.map(|v| l + (v - l) * l / (l - n));
+    }
+    if x > 1.0 && x - l > 0.0 {
+        out = out.map(|v| l + (v - l) * (1.0 - l) / (x - l));
+    }
+    out
+}
+
+fn set_lum(c: [f32; 3], l: f32) -> [f32; 3] {
+    let d = l - lum(c);
+    clip_color(c.map(|v| v + d))
+}
+
+fn sat(c: [f32; 3]) -> f32 {
+    c[0].max(c[1]).max(c[2]) - c[0].min(c[1]).min(c[2])
+}
+
+fn set_sat(c: [f32; 3], s: f32) -> [f32; 3] {
+    let max = c[0].max(c[1]).max(c[2]);
+    let min = c[0].min(c[1]).min(c[2]);
+    if max <= min {
+        return [0.0; 3];
+    }
+    c.map(|v| (v - min) * s / (max - min))
+}
+
+fn blend_non_separable(mode: BlendMode, cb: [f32; 3], cs: [f32; 3]) -> Option<[f32; 3]> {
+    Some(match mode {
+        BlendMode::RIVE_RS_BLEND_MODE_HUE => set_lum(set_sat(cs, sat(cb)), lum(cb)),
+        BlendMode::RIVE_RS_BLEND_MODE_SATURATION => set_lum(set_sat(cb, sat(cs)), lum(cb)),
+        BlendMode::RIVE_RS_BLEND_MODE_COLOR => set_lum(cs, lum(cb)),
+        BlendMode::RIVE_RS_BLEND_MODE_LUMINOSITY => set_lum(cb, lum(cs)),
+        _ => return None,
+    })
+}
+
+/// Blends premultiplied `src` over premultiplied `dst` using the W3C
+/// compositing formulas.
+pub(crate) fn blend(mode: BlendMode, src: [f32; 4], dst: [f32; 4]) -> [f32; 4] {
+    let sa = src[3];
+    let da = dst[3];
+    if mode == BlendMode::RIVE_RS_BLEND_MODE_SRC_OVER || da <= 0.0 || sa <= 0.0 {
+        let inv = 1.0 - sa;
+        return [
+            src[0] + dst[0] * inv,
+            src[1] + dst[1] * inv,
+            src[2] + dst[2] * inv,
+            sa + da * inv,
+        ];
+    }
+
+    let cs = [src[0] / sa, src[1] / sa, src[2] / sa];
+    let cb = [dst[0] / da, dst[1] / da, dst[2] / da];
+    let mixed = blend_non_separable(mode, cb, cs)
+        .unwrap_or_else(|| [0, 1, 2].map(|i| blend_channel(mode, cb[i], cs[i])));
+    let mut out = [0.0; 4];
+    for i in 0..3 {
+        out[i] = src[i] * (1.0 - da) + dst[i] * (1.0 - sa) + sa * da * mixed[i];
+    }
+    out[3] = sa + da * (1.0 - sa);
+    out
+}
+
+/// Unpremultiplied RGBA8 image pixels with a row stride of `width * 4`.
+#[derive(Debug, Clone)]
+pub(crate) struct ImagePixels<'a> {
+    pub(crate) width: usize,
+    pub(crate) height: usize,
+    pub(crate) data: &'a [u8],
+}
+
+fn wrap_coordinate(value: i64, size: usize, wrap: ImageWrap) -> usize {
+    let size = size as i64;
+    let wrapped = match wrap {
+        ImageWrap::RIVE_RS_IMAGE_WRAP_CLAMP => value.clamp(0, size - 1),
+        ImageWrap::RIVE_RS_IMAGE_WRAP_REPEAT => value.rem_euclid(size),
+        ImageWrap::RIVE_RS_IMAGE_WRAP_MIRROR => {
+            let m = value.rem_euclid(size * 2);
+            if m >= size { size * 2 - 1 - m } else { m }
+        }
+    };
+    wrapped as usize
+}
+
+impl ImagePixels<'_> {
+    // Premultiplied so bilinear filtering doesn't bleed hidden colors.
+    fn texel(&self, x: usize, y: usize) -> [f32; 4] {
+        let offset = (y * self.width + x) * 4;
+        let p = &self.data[offset..offset + 4];
+        let alpha = p[3] as f32 / 255.0;
+        [
+            p[0] as f32 / 255.0 * alpha,
+            p[1] as f32 / 255.0 * alpha,
+            p[2] as f32 / 255.0 * alpha,
+            alpha,
+        ]
+    }
+
+    /// Samples at `(u, v)` in pixel units.
+    pub(crate) fn sample(&self, u: f32, v: f32, sampler: ImageSampler) -> [f32; 4] {
+        if self.width == 0 || self.height == 0 || !u.is_finite() || !v.is_finite() {
+            return [0.0; 4];
+        }
+        let wrap_x = |x: i64| wrap_coordinate(x, self.width, sampler.wrap_x);
+        let wrap_y = |y: i64| wrap_coordinate(y, self.height, sampler.wrap_y);
+        match sampler.filter {
+            ImageFilter::RIVE_RS_IMAGE_FILTER_NEAREST => {
+                self.texel(wrap_x(u.floor() as i64), wrap_y(v.floor() as i64))
+            }
+            ImageFilter::RIVE_RS_IMAGE_FILTER_BILINEAR => {
+                let (u, v) = (u - 0.5, v - 0.5);
+                let (x0, y0) = (u.floor(), v.floor());
+                let (fx, fy) = (u - x0, v - y0);
+                let (x0, y0) = (x0 as i64, y0 as i64);
+                let (xa, xb) = (wrap_x(x0), wrap_x(x0 + 1));
+                let (ya, yb) = (wrap_y(y0), wrap_y(y0 + 1));
+                let (p00, p10) = (self.texel(xa, ya), self.texel(xb, ya));
+                let (p01, p11) = (self.texel(xa, yb), self.texel(xb, yb));
+                let mut out = [0.0; 4];
+                for i in 0..4 {
+                    let top = p00[i] + (p10[i] - p00[i]) * fx;
+                    let bottom = p01[i] + (p11[i] - p01[i]) * fx;
+                    out[i] = top + (bottom - top) * fy;
+                }
+                out
+            }
+        }
+    }
+}
@@ -0,0 +1,1145 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::{Arc, OnceLock};
+
+use crate::backend::{
+    BackendBuffer, BackendImage, BackendPaint, BackendPath, BackendRenderer, BackendShader,
+    BlendMode, BufferType, FillRule, GradientStop, ImageFilter, ImageSampler, ImageWrap,
+    PaintStyle, RenderBackend, StrokeCap, StrokeJoin,
+};
+use crate::image;
+use crate::runtime::Mat2D;
+use crate::{Error, Status};
+
+const MAGIC: &[u8; 4] = b"RRDL";
+const FORMAT_VERSION: u32 = 1;
+
+/// Factory backend that keeps every path, paint and image for recording.
+pub struct RecordingBackend;
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum PathVerb {
+    MoveTo {
+        x: f32,
+        y: f32,
+    },
+    LineTo {
+        x: f32,
+        y: f32,
+    },
+    CubicTo {
+        out_x: f32,
+        out_y: f32,
+        in_x: f32,
+        in_y: f32,
+        x: f32,
+        y: f32,
+    },
+    Close,
+}
+
+impl PathVerb {
+    fn transformed(self, m: &Mat2D) -> Self {
+        let map = |x: f32, y: f32| (m.xx * x + m.yx * y + m.tx, m.xy * x + m.yy * y + m.ty);
+        match self {
+            Self::MoveTo { x, y } => {
+                let (x, y) = map(x, y);
+                Self::MoveTo { x, y }
+            }
+            Self::LineTo { x, y } => {
+                let (x, y) = map(x, y);
+                Self::LineTo { x, y }
+            }
+            Self::CubicTo {
+                out_x,
+                out_y,
+                in_x,
+                in_y,
+                x,
+                y,
+            } => {
+                let (out_x, out_y) = map(out_x, out_y);
+                let (in_x, in_y) = map(in_x, in_y);
+                let (x, y) = map(x, y);
+                Self::CubicTo {
+                    out_x,
+                    out_y,
+                    in_x,
+                    in_y,
+                    x,
+                    y,
+                }
+            }
+            Self::Close => Self::Close,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RecordedPath {
+    pub fill_rule: FillRule,
+    pub verbs: Vec<PathVerb>,
+}
+
+impl BackendPath for RecordedPath {
+    fn rewind(&mut self) {
+        self.verbs.clear();
+    }
+
+    fn set_fill_rule(&mut self, fill_rule: FillRule) {
+        self.fill_rule = fill_rule;
+    }
+
+    fn move_to(&mut self, x: f32, y: f32) {
+        self.verbs.push(PathVerb::MoveTo { x, y });
+    }
+
+    fn line_to(&mut self, x: f32, y: f32) {
+        self.verbs.push(PathVerb::LineTo { x, y });
+    }
+
+    fn cubic_to(&mut self, out_x: f32, out_y: f32, in_x: f32, in_y: f32, x: f32, y: f32) {
+        self.verbs.push(PathVerb::CubicTo {
+            out_x,
+            out_y,
+            in_x,
+            in_y,
+            x,
+            y,
+        });
+    }
+
+    fn close(&mut self) {
+        self.verbs.push(PathVerb::Close);
+    }
+
+    fn add_path(&mut self, other: &Self, transform: &Mat2D) {
+        self.verbs
+            .extend(other.verbs.iter().map(|verb| verb.transformed(transform)));
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum RecordedShader {
+    Linear {
+        start: (f32, f32),
+        end: (f32, f32),
+        stops: Vec<GradientStop>,
+    },
+    Radial {
+        center: (f32, f32),
+        radius: f32,
+        stops: Vec<GradientStop>,
+    },
+}
+
+impl BackendShader for RecordedShader {}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RecordedPaint {
+    pub color: u32,
+    pub style: PaintStyle,
+    pub thickness: f32,
+    pub join: StrokeJoin,
+    pub cap: StrokeCap,
+    pub blend_mode: BlendMode,
+    pub shader: Option<RecordedShader>,
+    pub feather: f32,
+}
+
+impl Default for RecordedPaint {
+    fn default() -> Self {
+        Self {
+            color: 0xff00_0000,
+            style: PaintStyle::RIVE_RS_PAINT_STYLE_FILL,
+            thickness: 1.0,
+            join: StrokeJoin::RIVE_RS_STROKE_JOIN_MITER,
+            cap: StrokeCap::RIVE_RS_STROKE_CAP_BUTT,
+            blend_mode: BlendMode::RIVE_RS_BLEND_MODE_SRC_OVER,
+            shader: None,
+            feather: 0.0,
+        }
+    }
+}
+
+impl BackendPaint for RecordedPaint {
+    type Shader = RecordedShader;
+
+    fn set_color(&mut self, argb: u32) {
+        self.color = argb;
+    }
+
+    fn set_style(&mut self, style: PaintStyle) {
+        self.style = style;
+    }
+
+    fn set_thickness(&mut self, thickness: f32) {
+        self.thickness = thickness;
+    }
+
+    fn set_join(&mut self, join: StrokeJoin) {
+        self.join = join;
+    }
+
+    fn set_cap(&mut self, cap: StrokeCap) {
+        self.cap = cap;
+    }
+
+    fn set_blend_mode(&mut self, blend_mode: BlendMode) {
+        self.blend_mode = blend_mode;
+    }
+
+    fn set_shader(&mut self, shader: Option<&RecordedShader>) {
+        self.shader = shader.cloned();
+    }
+
+    fn set_feather(&mut self, feather: f32) {
+        self.feather = feather;
+    }
+}
+
+/// Encoded image bytes as handed to the factory, decoded once on first use.
+#[derive(Clone)]
+pub struct RecordedImage {
+    pub bytes: Arc<[u8]>,
+    pub width: u32,
+    pub height: u32,
+    // Decoded on first `pixels()` call and shared between clones.
+    pixels: Arc<OnceLock<Option<Box<[u8]>>>>,
+}
+
+impl RecordedImage {
+    pub fn new(bytes: Arc<[u8]>, width: u32, height: u32) -> Self {
+        Self {
+            bytes,
+            width,
+            height,
+            pixels: Arc::default(),
+        }
+    }
+
+    // An image whose pixels were already decoded from `bytes`.
+    pub(crate) fn decoded(bytes: Arc<[u8]>, width: u32, height: u32, rgba: Vec<u8>) -> Self {
+        Self {
+            bytes,
+            width,
+            height,
+            pixels: Arc::new(OnceLock::from(Some(rgba.into()))),
+        }
+    }
+}
+
+impl fmt::Debug for RecordedImage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RecordedImage")
+            .field("bytes", &self.bytes)
+            .field("width", &self.width)
+            .field("height", &self.height)
+            .finish_non_exhaustive()
+    }
+}
+
+impl PartialEq for RecordedImage {
+    fn eq(&self, other: &Self) -> bool {
+        self.bytes == other.bytes && self.width == other.width && self.height == other.height
+    }
+}
+
+impl BackendImage for RecordedImage {
+    fn width(&self) -> u32 {
+        self.width
+    }
+
+    fn height(&self) -> u32 {
+        self.height
+    }
+
+    fn pixels(&self) -> Option<&[u8]> {
+        self.pixels
+            .get_or_init(|| {
+                image::decode(&self.bytes)
+                    .filter(|&(width, height, _)| (width, height) == (self.width, self.height))
+                    .map(|(_, _, rgba)| rgba.into())
+            })
+            .as_deref()
+    }
+}
+
+pub struct RecordedBuffer {
+    bytes: Vec<u8>,
+}
+
+impl BackendBuffer for RecordedBuffer {
+    fn bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    fn bytes_mut(&mut self) -> &mut [u8] {
+        &mut self.bytes
+    }
+}
+
+impl RenderBackend for RecordingBackend {
+    type Path = RecordedPath;
+    type Paint = RecordedPaint;
+    type Shader = RecordedShader;
+    type Image = RecordedImage;
+    type Buffer = RecordedBuffer;
+
+    fn make_path(&mut self, fill_rule: FillRule) -> RecordedPath {
+        RecordedPath {
+            fill_rule,
+            verbs: Vec::new(),
+        }
+    }
+
+    fn make_paint(&mut self) -> RecordedPaint {
+        RecordedPaint::default()
+    }
+
+    fn make_linear_gradient(
+        &mut self,
+        start: (f32, f32),
+        end: (f32, f32),
+        stops: &[GradientStop],
+    ) -> RecordedShader {
+        RecordedShader::Linear {
+            start,
+            end,
+            stops: stops.to_vec(),
+        }
+    }
+
+    fn make_radial_gradient(
+        &mut self,
+        center: (f32, f32),
+        radius: f32,
+        stops: &[GradientStop],
+    ) -> RecordedShader {
+        RecordedShader::Radial {
+            center,
+            radius,
+            stops: stops.to_vec(),
+        }
+    }
+
+    fn decode_image(&mut self, bytes: &[u8]) -> Option<RecordedImage> {
+        let header = image::sniff(bytes)?;
+        Some(RecordedImage::new(
+            bytes.into(),
+            header.width,
+            header.height,
+        ))
+    }
+
+    fn make_buffer(
+        &mut self,
+        _buffer_type: BufferType,
+        _flags: u32,
+        size: usize,
+    ) -> RecordedBuffer {
+        RecordedBuffer {
+            bytes: vec![0; size],
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum DrawCommand {
+    Save,
+    Restore,
+    Transform(Mat2D),
+    ModulateOpacity(f32),
+    DrawPath {
+        path: RecordedPath,
+        paint: RecordedPaint,
+    },
+    ClipPath(RecordedPath),
+    DrawImage {
+        image: RecordedImage,
+        sampler: ImageSampler,
+        blend_mode: BlendMode,
+        opacity: f32,
+    },
+    DrawImageMesh {
+        image: RecordedImage,
+        sampler: ImageSampler,
+        vertices: Vec<f32>,
+        uv_coords: Vec<f32>,
+        indices: Vec<u16>,
+        blend_mode: BlendMode,
+        opacity: f32,
+    },
+}
+
+/// Ordered draw calls captured by a `RecordingRenderer`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DisplayList {
+    pub commands: Vec<DrawCommand>,
+}
+
+impl DisplayList {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.commands.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.commands.is_empty()
+    }
+
+    /// Records a save followed by a rectangular clip; undone by a restore.
+    pub(crate) fn save_clip_rect(&mut self, left: f32, top: f32, right: f32, bottom: f32) {
+        self.commands.push(DrawCommand::Save);
+        self.commands.push(DrawCommand::ClipPath(RecordedPath {
+            fill_rule: FillRule::RIVE_RS_FILL_RULE_NON_ZERO,
+            verbs: vec![
+                PathVerb::MoveTo { x: left, y: top },
+                PathVerb::LineTo { x: right, y: top },
+                PathVerb::LineTo {
+                    x: right,
+                    y: bottom,
+                },
+                PathVerb::LineTo { x: left, y: bottom },
+                PathVerb::Close,
+            ],
+        }));
+    }
+
+    /// Re-issues every command against `renderer`, rebuilding paths, paints
+    /// and images through `backend`. Images the backend cannot decode are
+    /// skipped.
+    pub fn replay<R: BackendRenderer>(&self, backend: &mut R::Backend, renderer: &mut R) {
+        let mut images = HashMap::new();
+        for command in &self.commands {
+            match command {
+                DrawCommand::Save => renderer.save(),
+                DrawCommand::Restore => renderer.restore(),
+                DrawCommand::Transform(matrix) => renderer.transform(matrix),
+                DrawCommand::ModulateOpacity(opacity) => renderer.modulate_opacity(*opacity),
+                DrawCommand::DrawPath { path, paint } => {
+                    let path = replay_path(backend, path);
+                    let paint = replay_paint(backend, paint);
+                    renderer.draw_path(&path, &paint);
+                }
+                DrawCommand::ClipPath(path) => {
+                    renderer.clip_path(&replay_path(backend, path));
+                }
+                DrawCommand::DrawImage {
+                    image,
+                    sampler,
+                    blend_mode,
+                    opacity,
+                } => {
+                    if let Some(image) = replay_image(backend, &mut images, image) {
+                        renderer.draw_image(image, *sampler, *blend_mode, *opacity);
+                    }
+                }
+                DrawCommand::DrawImageMesh {
+                    image,
+                    sampler,
+                    vertices,
+                    uv_coords,
+                    indices,
+                    blend_mode,
+                    opacity,
+                } => {
+                    let vertex_buffer = replay_buffer(
+                        backend,
+                        BufferType::RIVE_RS_RENDER_BUFFER_TYPE_VERTEX,
+                        &f32_bytes(vertices),
+                    );
+                    let uv_buffer = replay_buffer(
+                        backend,
+                        BufferType::RIVE_RS_RENDER_BUFFER_TYPE_VERTEX,
+                        &f32_bytes(uv_coords),
+                    );
+                    let index_buffer = replay_buffer(
+                        backend,
+                        BufferType::RIVE_RS_RENDER_BUFFER_TYPE_INDEX,
+                        &indices
+                            .iter()
+                            .flat_map(|i| i.to_ne_bytes())
+                            .collect::<Vec<_>>(),
+                    );
+                    if let Some(image) = replay_image(backend, &mut images, image) {
+                        renderer.draw_image_mesh(
+                            image,
+                            *sampler,
+                            &vertex_buffer,
+                            &uv_buffer,
+                            &index_buffer,
+                            (vertices.len() / 2) as u32,
+                            indices.len() as u32,
+                            *blend_mode,
+                            *opacity,
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut writer = Writer::default();
+        writer.bytes(MAGIC);
+        writer.u32(FORMAT_VERSION);
+        writer.len(self.commands.len());
+        for command in &self.commands {
+            writer.command(command);
+        }
+        writer.out
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        let mut reader = Reader { bytes, offset: 0 };
+        if reader.take(MAGIC.len())? != MAGIC || reader.u32()? != FORMAT_VERSION {
+            return Err(decode_error());
+        }
+        let count = reader.len()?;
+        let mut commands = Vec::with_capacity(count.min(bytes.len()));
+        for _ in 0..count {
+            commands.push(reader.command()?);
+        }
+        if reader.offset != bytes.len() {
+            return Err(decode_error());
+        }
+        Ok(Self { commands })
+    }
+}
+
+impl BackendRenderer for DisplayList {
+    type Backend = RecordingBackend;
+
+    fn save(&mut self) {
+        self.commands.push(DrawCommand::Save);
+    }
+
+    fn restore(&mut self) {
+        self.commands.push(DrawCommand::Restore);
+    }
+
+    fn transform(&mut self, matrix: &Mat2D) {
+        self.commands.push(DrawCommand::Transform(*matrix));
+    }
+
+    fn modulate_opacity(&mut self, opacity: f32) {
+        self.commands.push(DrawCommand::ModulateOpacity(opacity));
+    }
+
+    fn draw_path(&mut self, path: &RecordedPath, paint: &RecordedPaint) {
+        self.commands.push(DrawCommand::DrawPath {
+            path: path.clone(),
+            paint: paint.clone(),
+        });
+    }
+
+    fn clip_path(&mut self, path: &RecordedPath) {
+        self.commands.push(DrawCommand::ClipPath(path.clone()));
+    }
+
+    fn draw_image(
+        &mut self,
+        image: &RecordedImage,
+        sampler: ImageSampler,
+        blend_mode: BlendMode,
+        opacity: f32,
+    ) {
+        self.commands.push(DrawCommand::DrawImage {
+            image: image.clone(),
+            sampler,
+            blend_mode,
+            opacity,
+        });
+    }
+
+    fn draw_image_mesh(
+        &mut self,
+        image: &RecordedImage,
+        sampler: ImageSampler,
+        vertices: &RecordedBuffer,
+        uv_coords: &RecordedBuffer,
+        indices: &RecordedBuffer,
+        vertex_count: u32,
+        index_count: u32,
+        blend_mode: BlendMode,
+        opacity: f32,
+    ) {
+        let floats = vertex_count as usize * 2;
+        let mut vertices = read_f32s(&vertices.bytes);
+        let mut uv_coords = read_f32s(&uv_coords.bytes);
+        let mut indices = read_u16s(&indices.bytes);
+        vertices.truncate(floats);
+        uv_coords.truncate(floats);
+        indices.truncate(index_count as usize);
+        self.commands.push(DrawCommand::DrawImageMesh {
+            image: image.clone(),
+            sampler,
+            vertices,
+            uv_coords,
+            indices,
+            blend_mode,
+            opacity,
+        });
+    }
+}
+
+// Render buffers hold the runtime's in-memory values, so native-endian; only
+// the serialized display list format is little-endian.
+pub(crate) fn read_f32s(bytes: &[u8]) -> Vec<f32> {
+    bytes
+        .chunks_exact(4)
+        .map(|b| f32::from_ne_bytes([b[0], b[1], b[2], b[3]]))
+        .collect()
+}
+
+pub(crate) fn read_u16s(bytes: &[u8]) -> Vec<u16> {
+    bytes
+        .chunks_exact(2)
+        .map(|b| u16::from_ne_bytes([b[0], b[1]]))
+        .collect()
+}
+
+fn f32_bytes(values: &[f32]) -> Vec<u8> {
+    values.iter().flat_map(|v| v.to_ne_bytes()).collect()
+}
+
+fn replay_path<B: RenderBackend>(backend: &mut B, recorded: &RecordedPath) -> B::Path {
+    let mut path = backend.make_path(recorded.fill_rule);
+    for verb in &recorded.verbs {
+        match *verb {
+            PathVerb::MoveTo { x, y } => path.move_to(x, y),
+            PathVerb::LineTo { x, y } => path.line_to(x, y),
+            PathVerb::CubicTo {
+                out_x,
+                out_y,
+                in_x,
+                in_y,
+                x,
+                y,
+            } => path.cubic_to(out_x, out_y, in_x, in_y, x, y),
+            PathVerb::Close => path.close(),
+        }
+    }
+    path
+}
+
+fn replay_paint<B: RenderBackend>(backend: &mut B, recorded: &RecordedPaint) -> B::Paint {
+    let mut paint = backend.make_paint();
+    paint.set_color(recorded.color);
+    paint.set_style(recorded.style);
+    paint.set_thickness(recorded.thickness);
+    paint.set_join(recorded.join);
+    paint.set_cap(recorded.cap);
+    paint.set_blend_mode(recorded.blend_mode);
+    paint.set_feather(recorded.feather);
+    let shader = recorded.shader.as_ref().map(|shader| match shader {
+        RecordedShader::Linear { start, end, stops } => {
+            backend.make_linear_gradient(*start, *end, stops)
+        }
+        RecordedShader::Radial {
+            center,
+            radius,
+            stops,
+        } => backend.make_radial_gradient(*center, *radius, stops),
+    });
+    paint.set_shader(shader.as_ref());
+    paint
+}
+
+fn replay_image<'a, B: RenderBackend>(
+    backend: &mut B,
+    images: &'a mut HashMap<*const u8, Option<B::Image>>,
+    recorded: &RecordedImage,
+) -> Option<&'a B::Image> {
+    images
+        .entry(recorded.bytes.as_ptr())
+        .or_insert_with(|| backend.decode_image(&recorded.bytes))
+        .as_ref()
+}
+
+fn replay_buffer<B: RenderBackend>(
+    backend: &mut B,
+    buffer_type: BufferType,
+    bytes: &[u8],
+) -> B::Buffer {
+    let mut buffer = backend.make_buffer(buffer_type, 0, bytes.len());
+    let target = buffer.bytes_mut();
+    let len = target.len().min(bytes.len());
+    target[..len].copy_from_slice(&bytes[..len]);
+    buffer
+}
+
+fn decode_error() -> Error {
+    Error::from_status(Status::RIVE_RS_STATUS_DECODE_ERROR)
+}
+
+macro_rules! enum_from_i32 {
+    ($name:ident, $ty:ty, [$($variant:ident),+ $(,)?]) => {
+        fn $name(value: i32) -> Result<$ty, Error> {
+            $(
+                if value == <$ty>::$variant as i32 {
+                    return Ok(<$ty>::$variant);
+                }
+            )+
+            Err(decode_error())
+        }
+    };
+}
+
+enum_from_i32!(
+    fill_rule_from_i32,
+    FillRule,
+    [
+        RIVE_RS_FILL_RULE_NON_ZERO,
+        RIVE_RS_FILL_RULE_EVEN_ODD,
+        RIVE_RS_FILL_RULE_CLOCKWISE,
+    ]
+);
+enum_from_i32!(
+    paint_style_from_i32,
+    PaintStyle,
+    [RIVE_RS_PAINT_STYLE_STROKE, RIVE_RS_PAINT_STYLE_FILL]
+);
+enum_from_i32!(
+    stroke_join_from_i32,
+    StrokeJoin,
+    [
+        RIVE_RS_STROKE_JOIN_MITER,
+        RIVE_RS_STROKE_JOIN_ROUND,
+        RIVE_RS_STROKE_JOIN_BEVEL,
+    ]
+);
+enum_from_i32!(
+    stroke_cap_from_i32,
+    StrokeCap,
+    [
+        RIVE_RS_STROKE_CAP_BUTT,
+        RIVE_RS_STROKE_CAP_ROUND,
+        RIVE_RS_STROKE_CAP_SQUARE,
+    ]
+);
+enum_from_i32!(
+    blend_mode_from_i32,
+    BlendMode,
+    [
+        RIVE_RS_BLEND_MODE_SRC_OVER,
+        RIVE_RS_BLEND_MODE_SCREEN,
+        RIVE_RS_BLEND_MODE_OVERLAY,
+        RIVE_RS_BLEND_MODE_DARKEN,
+        RIVE_RS_BLEND_MODE_LIGHTEN,
+        RIVE_RS_BLEND_MODE_COLOR_DODGE,
+        RIVE_RS_BLEND_MODE_COLOR_BURN,
+        RIVE_RS_BLEND_MODE_HARD_LIGHT,
+        RIVE_RS_BLEND_MODE_SOFT_LIGHT,
+        RIVE_RS_BLEND_MODE_DIFFERENCE,
+        RIVE_RS_BLEND_MODE_EXCLUSION,
+        RIVE_RS_BLEND_MODE_MULTIPLY,
+        RIVE_RS_BLEND_MODE_HUE,
+        RIVE_RS_BLEND_MODE_SATURATION,
+        RIVE_RS_BLEND_MODE_COLOR,
+        RIVE_RS_BLEND_MODE_LUMINOSITY,
+    ]
+);
+enum_from_i32!(
+    image_wrap_from_i32,
+    ImageWrap,
+    [
+        RIVE_RS_IMAGE_WRAP_CLAMP,
+        RIVE_RS_IMAGE_WRAP_REPEAT,
+        RIVE_RS_IMAGE_WRAP_MIRROR,
+    ]
+);
+enum_from_i32!(
+    image_filter_from_i32,
+    ImageFilter,
+    [RIVE_RS_IMAGE_FILTER_BILINEAR, RIVE_RS_IMAGE_FILTER_NEAREST]
+);
+
+// Command tags; append only so older display lists keep decoding.
+const TAG_SAVE: u8 = 0;
+const TAG_RESTORE: u8 = 1;
+const TAG_TRANSFORM: u8 = 2;
+const TAG_MODULATE_OPACITY: u8 = 3;
+const TAG_DRAW_PATH: u8 = 4;
+const TAG_CLIP_PATH: u8 = 5;
+const TAG_DRAW_IMAGE: u8 = 6;
+const TAG_DRAW_IMAGE_MESH: u8 = 7;
+
+const VERB_MOVE: u8 = 0;
+const VERB_LINE: u8 = 1;
+const VERB_CUBIC: u8 = 2;
+const VERB_CLOSE: u8 = 3;
+
+const SHADER_NONE: u8 = 0;
+const SHADER_LINEAR: u8 = 1;
+const SHADER_RADIAL: u8 = 2;
+
+#[derive(Default)]
+struct Writer {
+    out: Vec<u8>,
+}
+
+impl Writer {
+    fn bytes(&mut self, bytes: &[u8]) {
+        self.out.extend_from_slice(bytes);
+    }
+
+    fn u8(&mut self, value: u8) {
+        self.out.push(value);
+    }
+
+    fn u32(&mut self, value: u32) {
+        self.bytes(&value.to_le_bytes());
+    }
+
+    fn i32(&mut self, value: i32) {
+        self.bytes(&value.to_le_bytes());
+    }
+
+    fn f32(&mut self, value: f32) {
+        self.bytes(&value.to_le_bytes());
+    }
+
+    fn len(&mut self, len: usize) {
+        self.u32(len as u32);
+    }
+
+    fn mat2d(&mut self, m: &Mat2D) {
+        for value in [m.xx, m.xy, m.yx, m.yy, m.tx, m.ty] {
+            self.f32(value);
+        }
+    }
+
+    fn path(&mut self, path: &RecordedPath) {
+        self.i32(path.fill_rule as i32);
+        self.len(path.verbs.len());
+        for verb in &path.verbs {
+            match *verb {
+                PathVerb::MoveTo { x, y } => {
+                    self.u8(VERB_MOVE);
+                    self.f32(x);
+                    self.f32(y);
+                }
+                PathVerb::LineTo { x, y } => {
+                    self.u8(VERB_LINE);
+                    self.f32(x);
+                    self.f32(y);
+                }
+                PathVerb::CubicTo {
+                    out_x,
+                    out_y,
+                    in_x,
+                    in_y,
+                    x,
+                    y,
+                } => {
+                    self.u8(VERB_CUBIC);
+                    for value in [out_x, out_y, in_x, in_y, x, y] {
+                        self.f32(value);
+                    }
+                }
+                PathVerb::Close => self.u8(VERB_CLOSE),
+            }
+        }
+    }
+
+    fn stops(&mut self, stops: &[GradientStop]) {
+        self.len(stops.len());
+        for stop in stops {
+            self.u32(stop.color);
+            self.f32(stop.position);
+        }
+    }
+
+    fn paint(&mut self, paint: &RecordedPaint) {
+        self.u32(paint.color);
+        self.i32(paint.style as i32);
+        self.f32(paint.thickness);
+        self.i32(paint.join as i32);
+        self.i32(paint.cap as i32);
+        self.i32(paint.blend_mode as i32);
+        self.f32(paint.feather);
+        match &paint.shader {
+            None => self.u8(SHADER_NONE),
+            Some(RecordedShader::Linear { start, end, stops }) => {
+                self.u8(SHADER_LINEAR);
+                for value in [start.0, start.1, end.0, end.1] {
+                    self.f32(value);
+                }
+                self.stops(stops);
+            }
+            Some(RecordedShader::Radial {
+                center,
+                radius,
+                stops,
+            }) => {
+                self.u8(SHADER_RADIAL);
+                for value in [center.0, center.1, *radius] {
+                    self.f32(value);
+                }
+                self.stops(stops);
+            }
+        }
+    }
+
+    fn image(&mut self, image: &RecordedImage, sampler: &ImageSampler) {
+        self.u32(image.width);
+        self.u32(image.height);
+        self.len(image.bytes.len());
+        self.bytes(&image.bytes);
+        self.i32(sampler.wrap_x as i32);
+        self.i32(sampler.wrap_y as i32);
+        self.i32(sampler.filter as i32);
+    }
+
+    fn command(&mut self, command: &DrawCommand) {
+        match command {
+            DrawCommand::Save => self.u8(TAG_SAVE),
+            DrawCommand::Restore => self.u8(TAG_RESTORE),
+            DrawCommand::Transform(matrix) => {
+                self.u8(TAG_TRANSFORM);
+                self.mat2d(matrix);
+            }
+            DrawCommand::ModulateOpacity(opacity) => {
+                self.u8(TAG_MODULATE_OPACITY);
+                self.f32(*opacity);
+            }
+            DrawCommand::DrawPath { path, paint } => {
+                self.u8(TAG_DRAW_PATH);
+                self.path(path);
+                self.paint(paint);
+            }
+            DrawCommand::ClipPath(path) => {
+                self.u8(TAG_CLIP_PATH);
+                self.path(path);
+            }
+            DrawCommand::DrawImage {
+                image,
+                sampler,
+                blend_mode,
+                opacity,
+            } => {
+                self.u8(TAG_DRAW_IMAGE);
+                self.image(image, sampler);
+                self.i32(*blend_mode as i32);
+                self.f32(*opacity);
+            }
+            DrawCommand::DrawImageMesh {
+                image,
+                sampler,
+                vertices,
+                uv_coords,
+                indices,
+                blend_mode,
+                opacity,
+            } => {
+                self.u8(TAG_DRAW_IMAGE_MESH);
+                self.image(image, sampler);
+                for floats in [vertices, uv_coords] {
+                    self.len(floats.len());
+                    floats.iter().for_each(|value| self.f32(*value));
+                }
+                self.len(indices.len());
+                indices
+                    .iter()
+                    .for_each(|index| self.bytes(&index.to_le_bytes()));
+                self.i32(*blend_mode as i32);
+                self.f32(*opacity);
+            }
+        }
+    }
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, len: usize) -> Result<&'a [u8], Error> {
+        let end = self.offset.checked_add(len).ok_or_else(decode_error)?;
+        let bytes = self.bytes.get(self.offset..end).ok_or_else(decode_error)?;
+        self.offset = end;
+        Ok(bytes)
+    }
+
+    fn array<const N: usize>(&mut self) -> Result<[u8; N], Error> {
+        let mut out = [0; N];
+        out.copy_from_slice(self.take(N)?);
+        Ok(out)
+    }
+
+    fn u8(&mut self) -> Result<u8, Error> {
+        Ok(self.array::<1>()?[0])
+    }
+
+    fn u16(&mut self) -> Result<u16, Error> {
+        Ok(u16::from_le_bytes(self.array()?))
+    }
+
+    fn u32(&mut self) -> Result<u32, Error> {
+        Ok(u32::from_le_bytes(self.array()?))
+    }
+
+    fn i32(&mut self) -> Result<i32, Error> {
+        Ok(i32::from_le_bytes(self.array()?))
+    }
+
+    fn f32(&mut self) -> Result<f32, Error> {
+        Ok(f32::from_le_bytes(self.array()?))
+    }
+
+    fn len(&mut self) -> Result<usize, Error> {
+        Ok(self.u32()? as usize)
+    }
+
+    // Caps preallocation by the bytes left so corrupt counts cannot
+    // trigger huge allocations.
+    fn vec<T>(
+        &mut self,
+        item_size: usize,
+        mut item: impl FnMut(&mut Self) -> Result<T, Error>,
+    ) -> Result<Vec<T>, Error> {
+        let len = self.len()?;
+        let remaining = (self.bytes.len() - self.offset) / item_size.max(1);
+        let mut out = Vec::with_capacity(len.min(remaining));
+        for _ in 0..len {
+            out.push(item(self)?);
+        }
+        Ok(out)
+    }
+
+    fn mat2d(&mut self) -> Result<Mat2D, Error> {
+        Ok(Mat2D {
+            xx: self.f32()?,
+            xy: self.f32()?,
+            yx: self.f32()?,
+            yy: self.f32()?,
+            tx: self.f32()?,
+            ty: self.f32()?,
+        })
+    }
+
+    fn path(&mut self) -> Result<RecordedPath, Error> {
+        let fill_rule = fill_rule_from_i32(self.i32()?)?;
+        let verbs = self.vec(1, |reader| {
+            Ok(match reader.u8()? {
+                VERB_MOVE => PathVerb::MoveTo {
+                    x: reader.f32()?,
+                    y: reader.f32()?,
+                },
+                VERB_LINE => PathVerb::LineTo {
+                    x: reader.f32()?,
+                    y: reader.f32()?,
+                },
+                VERB_CUBIC => PathVerb::CubicTo {
+                    out_x: reader.f32()?,
+                    out_y: reader.f32()?,
+                    in_x: reader.f32()?,
+                    in_y: reader.f32()?,
+                    x: reader.f32()?,
+                    y: reader.f32()?,
+                },
+                VERB_CLOSE => PathVerb::Close,
+                _ => return Err(decode_error()),
+            })
+        })?;
+        Ok(RecordedPath { fill_rule, verbs })
+    }
+
+    fn stops(&mut self) -> Result<Vec<GradientStop>, Error> {
+        self.vec(8, |reader| {
+            Ok(GradientStop {
+                color: reader.u32()?,
+                position: reader.f32()?,
+            })
+        })
+    }
+
+    fn paint(&mut self) -> Result<RecordedPaint, Error> {
+        let color = self.u32()?;
+        let style = paint_style_from_i32(self.i32()?)?;
+        let thickness = self.f32()?;
+        let join = stroke_join_from_i32(self.i32()?)?;
+        let cap = stroke_cap_from_i32(self.i32()?)?;
+        let blend_mode = blend_mode_from_i32(self.i32()?)?;
+        let feather = self.f32()?;
+        let shader = match self.u8()? {
+            SHADER_NONE => None,
+            SHADER_LINEAR => Some(RecordedShader::Linear {
+                start: (self.f32()?, self.f32()?),
+                end: (self.f32()?, self.f32()?),
+                stops: self.stops()?,
+            }),
+            SHADER_RADIAL => Some(RecordedShader::Radial {
+                center: (self.f32()?, self.f32()?),
+                radius: self.f32()?,
+                stops: self.stops()?,
+            }),
+            _ => return Err(decode_error()),
+        };
+        Ok(RecordedPaint {
+            color,
+            style,
+            thickness,
+            join,
+            cap,
+            blend_mode,
+            shader,
+            feather,
+        })
+    }
+
+    fn image(&mut self) -> Result<(RecordedImage, ImageSampler), Error> {
+        let width = self.u32()?;
+        let height = self.u32()?;
+        let len = self.len()?;
+        let bytes = self.take(len)?.into();
+        let sampler = ImageSampler {
+            wrap_x: image_wrap_from_i32(self.i32()?)?,
+            wrap_y: image_wrap_from_i32(self.i32()?)?,
+            filter: image_filter_from_i32(self.i32()?)?,
+        };
+        Ok((RecordedImage::new(bytes, width, height), sampler))
+    }
+
+    fn command(&mut self) -> Result<DrawCommand, Error> {
+        Ok(match self.u8()? {
+            TAG_SAVE => DrawCommand::Save,
+            TAG_RESTORE => DrawCommand::Restore,
+            TAG_TRANSFORM => DrawCommand::Transform(self.mat2d()?),
+            TAG_MODULATE_OPACITY => DrawCommand::ModulateOpacity(self.f32()?),
+            TAG_DRAW_PATH => DrawCommand::DrawPath {
+                path: self.path()?,
+                paint: self.paint()?,
+            },
+            TAG_CLIP_PATH => DrawCommand::ClipPath(self.path()?),
+            TAG_DRAW_IMAGE => {
+                let (image, sampler) = self.image()?;
+                DrawCommand::DrawImage {
+                    image,
+                    sampler,
+                    blend_mode: blend_mode_from_i32(self.i32()?)?,
+                    opacity: self.f32()?,
+                }
+            }
+            TAG_DRAW_IMAGE_MESH => {
+                let (image, sampler) = self.image()?;
+                DrawCommand::DrawImageMesh {
+                    image,
+                    sampler,
+                    vertices: self.vec(4, Self::f32)?,
+                    uv_coords: self.vec(4, Self::f32)?,
+                    indices: self.vec(2, Self::u16)?,
+                    blend_mode: blend_mode_from_i32(self.i32()?)?,
+                    opacity: self.f32()?,
+                }
+            }
+            _ => return Err(decode_error()),
+        })
+    }
+}
@@ -0,0 +1,270 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::ffi::OsStr;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+use std::sync::Arc;
+
+use crate::assets::{AssetKind, AssetLoader, LoadOutcome};
+use crate::bundle;
+use crate::runtime::{Factory, File, FileAsset};
+use crate::{Error, Status};
+
+/// How an [`AssetResolver`] satisfied an asset.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AssetStatus {
+    /// Decoded from a resolver source; `source` is the file path or the
+    /// bundle entry the bytes came from.
+    Resolved { source: String },
+    /// The file carries the asset in-band; the runtime decodes it.
+    Embedded,
+    /// No source had a decodable match, and no bundle had several entries
+    /// with a candidate name.
+    Missing,
+    /// Not resolved because a bundle holds several entries with a
+    /// candidate name; `paths` lists them in archive order.
+    Ambiguous { paths: Vec<String> },
+}
+
+/// One asset seen while loading a file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AssetRecord {
+    pub name: String,
+    pub unique_filename: String,
+    pub kind: AssetKind,
+    pub status: AssetStatus,
+}
+
+/// What [`AssetResolver::load_file`] did with each asset, in load order.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AssetReport {
+    pub assets: Vec<AssetRecord>,
+}
+
+impl AssetReport {
+    pub fn resolved(&self) -> impl Iterator<Item = &AssetRecord> {
+        self.with_status(|status| matches!(status, AssetStatus::Resolved { .. }))
+    }
+
+    pub fn embedded(&self) -> impl Iterator<Item = &AssetRecord> {
+        self.with_status(|status| *status == AssetStatus::Embedded)
+    }
+
+    pub fn missing(&self) -> impl Iterator<Item = &AssetRecord> {
+        self.with_status(|status| *status == AssetStatus::Missing)
+    }
+
+    pub fn ambiguous(&self) -> impl Iterator<Item = &AssetRecord> {
+        self.with_status(|status| matches!(status, AssetStatus::Ambiguous { .. }))
+    }
+
+    fn with_status(
+        &self,
+        filter: impl Fn(&AssetStatus) -> bool,
+    ) -> impl Iterator<Item = &AssetRecord> {
+        self.assets
+            .iter()
+            .filter(move |record| filter(&record.status))
+    }
+}
+
+// Bundle entries keyed by base name, each with its full path in the bundle.
+type BundleEntries = HashMap<String, Vec<(String, Vec<u8>)>>;
+
+#[derive(Debug, Clone)]
+enum Source {
+    Directory(PathBuf),
+    Bundle(Arc<BundleEntries>),
+}
+
+enum Found {
+    File(String, Vec<u8>),
+    Ambiguous(Vec<String>),
+}
+
+impl Source {
+    fn find(&self, file_name: &str) -> Option<Found> {
+        match self {
+            Self::Directory(dir) => {
+                let path = dir.join(file_name);
+                let bytes = fs::read(&path).ok()?;
+                Some(Found::File(path.display().to_string(), bytes))
+            }
+            Self::Bundle(entries) => match entries.get(file_name)?.as_slice() {
+                [(path, bytes)] => Some(Found::File(path.clone(), bytes.clone())),
+                matches => Some(Found::Ambiguous(
+                    matches.iter().map(|(path, _)| path.clone()).collect(),
+                )),
+            },
+        }
+    }
+}
+
+/// Resolves out-of-band assets from directories and zip or tar bundles.
+///
+/// Assets are matched by unique filename, then name plus extension, then
+/// CDN UUID; each candidate name is tried against every source in the
+/// order they were added. A match that fails to decode moves on to the
+/// next candidate, as does a name shared by several entries of one bundle.
+#[derive(Debug, Clone, Default)]
+pub struct AssetResolver {
+    sources: Vec<Source>,
+}
+
+impl AssetResolver {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a directory whose files are looked up by name when assets load.
+    pub fn directory(mut self, path: impl Into<PathBuf>) -> Self {
+        self.sources.push(Source::Directory(path.into()));
+        self
+    }
+
+    /// Adds a zip, tar or gzipped tar bundle. Entries are matched by their
+    /// base name; a name shared by entries in different directories matches
+    /// none of them and is reported as [`AssetStatus::Ambiguous`]. A path
+    /// repeated in a tar keeps its last contents.
+    pub fn bundle(mut self, bytes: &[u8]) -> Result<Self, Error> {
+        let entries = bundle::read_entries(bytes)
+            .ok_or_else(|| Error::from_status(Status::RIVE_RS_STATUS_DECODE_ERROR))?;
+        let mut files = BundleEntries::new();
+        for (path, contents) in entries {
+            let name = path.rsplit('/').next().unwrap_or(&path).to_owned();
+            let matches = files.entry(name).or_default();
+            match matches.iter_mut().find(|(existing, _)| *existing == path) {
+                Some(entry) => entry.1 = contents,
+                None => matches.push((path, contents)),
+            }
+        }
+        self.sources.push(Source::Bundle(Arc::new(files)));
+        Ok(self)
+    }
+
+    /// Reads a bundle from disk; see [`AssetResolver::bundle`].
+    pub fn bundle_file(self, path: impl AsRef<Path>) -> io::Result<Self> {
+        let bytes = fs::read(path)?;
+        self.bundle(&bytes)
+            .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))
+    }
+
+    /// Looks `file_name` up in each source in order, returning where the
+    /// bytes came from and the bytes themselves. Bundles with several
+    /// entries of that name are skipped.
+    pub fn find(&self, file_name: &str) -> Option<(String, Vec<u8>)> {
+        if !is_plain_name(file_name) {
+            return None;
+        }
+        self.sources
+            .iter()
+            .find_map(|source| match source.find(file_name)? {
+                Found::File(origin, bytes) => Some((origin, bytes)),
+                Found::Ambiguous(_) => None,
+            })
+    }
+
+    /// Resolves one asset. Assets with in-band bytes are left to the
+    /// runtime and reported as [`AssetStatus::Embedded`].
+    pub fn resolve(&self, asset: &mut FileAsset, in_band: &[u8], factory: &Factory) -> AssetStatus {
+        if !in_band.is_empty() {
+            return AssetStatus::Embedded;
+        }
+        let mut ambiguous = Vec::new();
+        for file_name in candidate_names(asset) {
+            for source in &self.sources {
+                match source.find(&file_name) {
+                    Some(Found::File(origin, bytes)) if asset.decode(factory, &bytes).is_ok() => {
+                        return AssetStatus::Resolved { source: origin };
+                    }
+                    Some(Found::Ambiguous(paths)) => ambiguous.extend(paths),
+                    _ => {}
+                }
+            }
+        }
+        if ambiguous.is_empty() {
+            AssetStatus::Missing
+        } else {
+            AssetStatus::Ambiguous { paths: ambiguous }
+        }
+    }
+
+    /// Loads a file, resolving its out-of-band assets through this
+    /// resolver, and reports how each asset was satisfied.
+    pub fn load_file(&self, factory: &Factory, bytes: &[u8]) -> Result<(File, AssetReport), Error> {
+        let report = Rc::new(RefCell::new(AssetReport::default()));
+        let loader = ReportingLoader {
+            resolver: self.clone(),
+            report: report.clone(),
+        };
+        let file = factory.load_file_with_loader(bytes, loader)?;
+        let report = report.borrow().clone();
+        Ok((file, report))
+    }
+}
+
+impl AssetLoader for AssetResolver {
+    fn load(&mut self, asset: &mut FileAsset, in_band: &[u8], factory: &Factory) -> LoadOutcome {
+        outcome(&self.resolve(asset, in_band, factory))
+    }
+}
+
+struct ReportingLoader {
+    resolver: AssetResolver,
+    report: Rc<RefCell<AssetReport>>,
+}
+
+impl AssetLoader for ReportingLoader {
+    fn load(&mut self, asset: &mut FileAsset, in_band: &[u8], factory: &Factory) -> LoadOutcome {
+        let status = self.resolver.resolve(asset, in_band, factory);
+        let outcome = outcome(&status);
+        self.report.borrow_mut().assets.push(AssetRecord {
+            name: asset.name(),
+            unique_filename: asset.unique_filename(),
+            kind: AssetKind::of(asset),
+            status,
+        });
+        outcome
+    }
+}
+
+fn outcome(status: &AssetStatus) -> LoadOutcome {
+    match status {
+        AssetStatus::Resolved { .. } => LoadOutcome::Loaded,
+        AssetStatus::Embedded | AssetStatus::Missing | AssetStatus::Ambiguous { .. } => {
+            LoadOutcome::Fallback
+        }
+    }
+}
+
+fn candidate_names(asset: &FileAsset) -> Vec<String> {
+    let extension = asset.file_extension();
+    let with_extension = |stem: String| {
+        if stem.is_empty() || extension.is_empty() || stem.ends_with(&format!(".{extension}")) {
+            stem
+        } else {
+            format!("{stem}.{extension}")
+        }
+    };
+    let uuid = asset.cdn_uuid();
+    let mut names = Vec::new();
+    for name in [
+        asset.unique_filename(),
+        with_extension(asset.name()),
+        uuid.clone(),
+        with_extension(uuid),
+    ] {
+        if is_plain_name(&name) && !names.contains(&name) {
+            names.push(name);
+        }
+    }
+    names
+}
+
+// Asset names come from the file; anything that could leave a source
+// directory is never looked up.
+pub(crate) fn is_plain_name(name: &str) -> bool {
+    Path::new(name).file_name() == Some(OsStr::new(name))
+}
@@ -0,0 +1,409 @@
+// Minimal `.riv` header reader and object walker for reading and rewriting
+// embedded asset bytes. Objects are copied through untouched; property types
+// come from the header's table of contents, which exporters write for every
+// key in use.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::ops::RangeInclusive;
+
+use crate::assets::AssetKind;
+use crate::{Error, Status};
+
+const FINGERPRINT: &[u8] = b"RIVE";
+const IMAGE_ASSET: u64 = 105;
+const FILE_ASSET_CONTENTS: u64 = 106;
+const FONT_ASSET: u64 = 141;
+const AUDIO_ASSET: u64 = 406;
+const ASSET_NAME: u64 = 203;
+const FILE_ASSET_CONTENTS_BYTES: u64 = 212;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum FieldType {
+    Uint,
+    String,
+    Float,
+    Color,
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+    position: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn is_done(&self) -> bool {
+        self.position >= self.bytes.len()
+    }
+
+    fn take(&mut self, len: usize) -> Result<&'a [u8], Error> {
+        let end = self.position.checked_add(len).ok_or_else(decode_error)?;
+        let slice = self
+            .bytes
+            .get(self.position..end)
+            .ok_or_else(decode_error)?;
+        self.position = end;
+        Ok(slice)
+    }
+
+    fn varuint(&mut self) -> Result<u64, Error> {
+        let mut value = 0u64;
+        for shift in (0..64).step_by(7) {
+            let byte = self.take(1)?[0];
+            value |= u64::from(byte & 0x7f) << shift;
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+        }
+        Err(decode_error())
+    }
+
+    fn u32(&mut self) -> Result<u32, Error> {
+        let bytes = self.take(4)?;
+        Ok(u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+    }
+}
+
+struct Object<'a> {
+    start: usize,
+    end: usize,
+    strings: Vec<(u64, &'a [u8])>,
+}
+
+impl<'a> Object<'a> {
+    fn string(&self, key: u64) -> Option<&'a [u8]> {
+        self.strings
+            .iter()
+            .find(|(property, _)| *property == key)
+            .map(|(_, value)| *value)
+    }
+}
+
+struct Asset {
+    kind: AssetKind,
+    name: String,
+    object: usize,
+    contents: Option<usize>,
+}
+
+struct RivFile<'a> {
+    bytes: &'a [u8],
+    header_end: usize,
+    objects: Vec<Object<'a>>,
+    assets: Vec<Asset>,
+}
+
+fn decode_error() -> Error {
+    Error::from_status(Status::RIVE_RS_STATUS_DECODE_ERROR)
+}
+
+fn asset_kind(type_key: u64) -> Option<AssetKind> {
+    match type_key {
+        IMAGE_ASSET => Some(AssetKind::Image),
+        FONT_ASSET => Some(AssetKind::Font),
+        AUDIO_ASSET => Some(AssetKind::Audio),
+        _ => None,
+    }
+}
+
+/// The runtime's `.riv` major version, mirroring `rive::File::majorVersion`.
+/// Files of any minor version load as long as the major version matches.
+pub const RIV_MAJOR_VERSION: u32 = 7;
+
+/// Version and id from the header of a `.riv` file, from [`inspect_header`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RivHeader {
+    pub major_version: u32,
+    pub minor_version: u32,
+    pub file_id: u32,
+    /// Major versions this runtime loads.
+    pub supported_major_versions: RangeInclusive<u32>,
+}
+
+impl RivHeader {
+    pub fn is_supported(&self) -> bool {
+        self.supported_major_versions.contains(&self.major_version)
+    }
+}
+
+/// Why [`inspect_header`] rejected a file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HeaderError {
+    /// The bytes end before the header does.
+    Truncated,
+    /// The bytes are not a `.riv` header: a missing `RIVE` fingerprint or an
+    /// out-of-range field.
+    Malformed,
+    /// A well-formed header from an editor this runtime cannot load.
+    UnsupportedVersion(RivHeader),
+}
+
+impl fmt::Display for HeaderError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Truncated => write!(f, "truncated .riv header"),
+            Self::Malformed => write!(f, "not a .riv file"),
+            Self::UnsupportedVersion(header) => write!(
+                f,
+                ".riv version {}.{} is not supported; this runtime loads major version {}",
+                header.major_version,
+                header.minor_version,
+                header.supported_major_versions.start()
+            ),
+        }
+    }
+}
+
+impl std::error::Error for HeaderError {}
+
+impl From<HeaderError> for Error {
+    fn from(err: HeaderError) -> Self {
+        match err {
+            HeaderError::Truncated | HeaderError::Malformed => decode_error(),
+            HeaderError::UnsupportedVersion(_) => {
+                Error::from_status(Status::RIVE_RS_STATUS_UNSUPPORTED)
+            }
+        }
+    }
+}
+
+fn header_varuint(reader: &mut Reader<'_>) -> Result<u64, HeaderError> {
+    let mut value = 0u64;
+    for shift in (0..64).step_by(7) {
+        let byte = reader.take(1).map_err(|_| HeaderError::Truncated)?[0];
+        value |= u64::from(byte & 0x7f) << shift;
+        if byte & 0x80 == 0 {
+            return Ok(value);
+        }
+    }
+    Err(HeaderError::Malformed)
+}
+
+fn header_u32(reader: &mut Reader<'_>) -> Result<u32, HeaderError> {
+    u32::try_from(header_varuint(reader)?).map_err(|_| HeaderError::Malformed)
+}
+
+// Reads the fingerprint, versions, file id and the table of contents, leaving
+// `reader` on the first object.
+fn read_header(
+    reader: &mut Reader<'_>,
+) -> Result<(RivHeader, HashMap<u64, FieldType>), HeaderError> {
+    let available = reader.bytes.len().min(FINGERPRINT.len());
+    if reader.bytes[..available] != FINGERPRINT[..available] {
+        return Err(HeaderError::Malformed);
+    }
+    reader
+        .take(FINGERPRINT.len())
+        .map_err(|_| HeaderError::Truncated)?;
+    let header = RivHeader {
+        major_version: header_u32(reader)?,
+        minor_version: header_u32(reader)?,
+        file_id: header_u32(reader)?,
+        supported_major_versions: RIV_MAJOR_VERSION..=RIV_MAJOR_VERSION,
+    };
+
+    let mut keys = Vec::new();
+    loop {
+        let key = header_varuint(reader)?;
+        if key == 0 {
+            break;
+        }
+        keys.push(key);
+    }
+    let mut types = HashMap::new();
+    // Two bits of field type per key, four keys to each little-endian u32.
+    for chunk in keys.chunks(4) {
+        let bits = reader.u32().map_err(|_| HeaderError::Truncated)?;
+        for (slot, &key) in chunk.iter().enumerate() {
+            let field = match (bits >> (slot * 2)) & 3 {
+                0 => FieldType::Uint,
+                1 => FieldType::String,
+                2 => FieldType::Float,
+                _ => FieldType::Color,
+            };
+            types.insert(key, field);
+        }
+    }
+    Ok((header, types))
+}
+
+fn parse(bytes: &[u8]) -> Result<RivFile<'_>, Error> {
+    let mut reader = Reader { bytes, position: 0 };
+    let (_, mut types) = read_header(&mut reader)?;
+    types.entry(ASSET_NAME).or_insert(FieldType::String);
+    types
+        .entry(FILE_ASSET_CONTENTS_BYTES)
+        .or_insert(FieldType::String);
+    let header_end = reader.position;
+
+    let mut objects = Vec::new();
+    let mut assets: Vec<Asset> = Vec::new();
+    while !reader.is_done() {
+        let start = reader.position;
+        let type_key = reader.varuint()?;
+        let mut strings = Vec::new();
+        loop {
+            let key = reader.varuint()?;
+            if key == 0 {
+                break;
+            }
+            let field = types
+                .get(&key)
+                .ok_or_else(|| Error::from_status(Status::RIVE_RS_STATUS_UNSUPPORTED))?;
+            match field {
+                FieldType::Uint => {
+                    reader.varuint()?;
+                }
+                FieldType::String => {
+                    let len = usize::try_from(reader.varuint()?).map_err(|_| decode_error())?;
+                    strings.push((key, reader.take(len)?));
+                }
+                FieldType::Float | FieldType::Color => {
+                    reader.take(4)?;
+                }
+            }
+        }
+        let object = Object {
+            start,
+            end: reader.position,
+            strings,
+        };
+
+        if let Some(kind) = asset_kind(type_key) {
+            let name = object.string(ASSET_NAME).unwrap_or_default();
+            assets.push(Asset {
+                kind,
+                name: String::from_utf8_lossy(name).into_owned(),
+                object: objects.len(),
+                contents: None,
+            });
+        } else if type_key == FILE_ASSET_CONTENTS {
+            // Contents belong to the asset declared just before them.
+            if let Some(asset) = assets.last_mut() {
+                asset.contents = Some(objects.len());
+            }
+        }
+        objects.push(object);
+    }
+
+    Ok(RivFile {
+        bytes,
+        header_end,
+        objects,
+        assets,
+    })
+}
+
+fn write_varuint(out: &mut Vec<u8>, mut value: u64) {
+    loop {
+        let byte = (value & 0x7f) as u8;
+        value >>= 7;
+        if value == 0 {
+            out.push(byte);
+            return;
+        }
+        out.push(byte | 0x80);
+    }
+}
+
+fn write_contents(out: &mut Vec<u8>, bytes: &[u8]) {
+    write_varuint(out, FILE_ASSET_CONTENTS);
+    write_varuint(out, FILE_ASSET_CONTENTS_BYTES);
+    write_varuint(out, bytes.len() as u64);
+    out.extend_from_slice(bytes);
+    write_varuint(out, 0);
+}
+
+/// In-band bytes of one asset in a `.riv` file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EmbeddedAsset {
+    /// Position among all of the file's assets, in the order
+    /// [`inspect_assets`](crate::inspect_assets) lists them.
+    pub index: usize,
+    pub kind: AssetKind,
+    pub name: String,
+    pub bytes: Vec<u8>,
+}
+
+/// A change to one asset for [`rewrite_assets`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AssetEdit {
+    /// Embed these bytes, replacing any existing in-band contents.
+    Embed(Vec<u8>),
+    /// Drop the in-band contents so the asset resolves out of band.
+    Reference,
+}
+
+/// Copies the in-band bytes of every embedded image, font and audio asset
+/// out of a `.riv` file.
+pub fn extract_embedded_assets(riv: &[u8]) -> Result<Vec<EmbeddedAsset>, Error> {
+    let file = parse(riv)?;
+    let embedded = file
+        .assets
+        .iter()
+        .enumerate()
+        .filter_map(|(index, asset)| {
+            let contents = &file.objects[asset.contents?];
+            Some(EmbeddedAsset {
+                index,
+                kind: asset.kind,
+                name: asset.name.clone(),
+                bytes: contents
+                    .string(FILE_ASSET_CONTENTS_BYTES)
+                    .unwrap_or_default()
+                    .to_vec(),
+            })
+        })
+        .collect();
+    Ok(embedded)
+}
+
+/// Writes a copy of a `.riv` file with `edits` applied, keyed by asset
+/// index. Everything else is copied byte for byte.
+pub fn rewrite_assets(riv: &[u8], edits: &[(usize, AssetEdit)]) -> Result<Vec<u8>, Error> {
+    let file = parse(riv)?;
+    let mut by_object = HashMap::new();
+    let mut contents_edits = HashMap::new();
+    for (index, edit) in edits {
+        let asset = file
+            .assets
+            .get(*index)
+            .ok_or_else(|| Error::from_status(Status::RIVE_RS_STATUS_OUT_OF_RANGE))?;
+        match asset.contents {
+            Some(contents) => contents_edits.insert(contents, edit),
+            None => by_object.insert(asset.object, edit),
+        };
+    }
+
+    let mut out = Vec::with_capacity(riv.len());
+    out.extend_from_slice(&file.bytes[..file.header_end]);
+    for (position, object) in file.objects.iter().enumerate() {
+        match contents_edits.get(&position) {
+            Some(AssetEdit::Embed(bytes)) => write_contents(&mut out, bytes),
+            Some(AssetEdit::Reference) => {}
+            None => out.extend_from_slice(&file.bytes[object.start..object.end]),
+        }
+        if let Some(AssetEdit::Embed(bytes)) = by_object.get(&position) {
+            write_contents(&mut out, bytes);
+        }
+    }
+    Ok(out)
+}
+
+/// Reads the header of a `.riv` file without loading it.
+///
+/// Fails with [`HeaderError::UnsupportedVersion`] when the runtime would
+/// refuse the file's major version, so callers can tell a version mismatch
+/// from a damaged file before handing the bytes to
+/// [`Factory::load_file`](crate::Factory::load_file).
+pub fn inspect_header(riv: &[u8]) -> Result<RivHeader, HeaderError> {
+    let mut reader = Reader {
+        bytes: riv,
+        position: 0,
+    };
+    let (header, _) = read_header(&mut reader)?;
+    if !header.is_supported() {
+        return Err(HeaderError::UnsupportedVersion(header));
+    }
+    Ok(header)
+}
@@ -1,10 +1,22 @@
 use core::ffi::{c_char, c_void};
 use core::ptr;
 use core::slice;
+use std::mem::ManuallyDrop;
 use std::ptr::NonNull;
+use std::rc::{Rc, Weak};
+use std::sync::Arc;
 
 use crate::abi;
-use crate::{Error, Status, status_ok};
+use crate::assets::{self, AssetLoader};
+use crate::audio;
+use crate::backend::{self, BackendRenderer, RenderBackend};
+use crate::memory::{self, FileMemory, FileRegistry, MemoryStats, RecordingLoader};
+use crate::opentype::{FontFace, FontStyle};
+use crate::raster::{self, Pixmap};
+use crate::recording::{DisplayList, RecordingBackend};
+use crate::software::{SoftwareBackend, SoftwareCanvas};
+use crate::wav::{self, Pcm};
+use crate::{Error, LoadOutcome, Status, status_ok};
 
 pub type Aabb = abi::rive_rs_aabb;
 pub type Alignment = abi::rive_rs_alignment;
@@ -12,6 +24,10 @@ pub type DataType = abi::rive_rs_data_type;
 pub type Fit = abi::rive_rs_fit;
 pub type Mat2D = abi::rive_rs_mat2d;
 pub type EventPropertyType = abi::rive_rs_event_property_type;
+pub type ConditionOp = abi::rive_rs_condition_op;
+pub type ConditionType = abi::rive_rs_condition_type;
+pub type LayerStateType = abi::rive_rs_layer_state_type;
+pub type ListenerType = abi::rive_rs_listener_type;
 pub type SmiInputType = abi::rive_rs_smi_input_type;
 pub type Vec2 = abi::rive_rs_vec2;
 
@@ -49,6 +65,110 @@ pub struct EventInfo {
     pub properties: Vec<EventProperty>,
 }
 
+/// One core object of an artboard, from [`Artboard::components`].
+///
+/// `index` is the object's position in the artboard, which is what `parent`
+/// and `children` refer to and what [`Artboard::node_by_index`] and its
+/// siblings take. `type_name` comes from a fixed table in the provider and is
+/// empty for types it does not list; `type_key` is always set.
+#[derive(Debug, Clone)]
+pub struct ComponentInfo {
+    pub index: usize,
+    pub type_key: u16,
+    pub type_name: String,
+    pub name: String,
+    pub parent: Option<usize>,
+    pub children: Vec<usize>,
+    pub is_transform_component: bool,
+    pub is_node: bool,
+    pub is_bone: bool,
+    pub is_root_bone: bool,
+}
+
+/// One layer of a state machine, from [`StateMachine::layers`]. States keep
+/// their order in the layer, which transitions' `target_state` refers to.
+#[derive(Debug, Clone)]
+pub struct StateMachineLayerInfo {
+    pub name: String,
+    pub states: Vec<LayerStateInfo>,
+}
+
+/// A state and its outgoing transitions. Animation states are named after
+/// their animation; other states have an empty name.
+#[derive(Debug, Clone)]
+pub struct LayerStateInfo {
+    pub state_type: LayerStateType,
+    pub name: String,
+    pub transitions: Vec<TransitionInfo>,
+}
+
+/// A transition out of a state. `duration` and `exit_time` are milliseconds,
+/// or percentages of the source state's animation when the matching
+/// `*_is_percentage` flag is set.
+#[derive(Debug, Clone)]
+pub struct TransitionInfo {
+    pub target_state: Option<usize>,
+    pub duration: u32,
+    pub duration_is_percentage: bool,
+    pub enable_exit_time: bool,
+    pub exit_time: u32,
+    pub exit_time_is_percentage: bool,
+    pub disabled: bool,
+    pub conditions: Vec<TransitionConditionInfo>,
+}
+
+/// A condition on a state machine input. `input` indexes the state
+/// machine's inputs, in the order [`StateMachineInstance::input`] uses.
+/// `value` is the compared number for number conditions; bool conditions
+/// test for `true` with `EQUAL` and `false` with `NOT_EQUAL`.
+#[derive(Debug, Clone)]
+pub struct TransitionConditionInfo {
+    pub condition_type: ConditionType,
+    pub input: Option<usize>,
+    pub input_name: String,
+    pub op: ConditionOp,
+    pub value: f32,
+}
+
+/// A state machine listener. `target_index` is the artboard object it
+/// listens on, as in [`ComponentInfo::index`].
+#[derive(Debug, Clone)]
+pub struct ListenerInfo {
+    pub name: String,
+    pub listener_type: ListenerType,
+    pub target_index: usize,
+    pub action_count: usize,
+}
+
+/// The live state of one layer, from [`StateMachineInstance::current_states`].
+///
+/// `state` indexes the layer's states as in [`StateMachine::layers`]. Layers
+/// that have not changed state yet report their entry state.
+/// `elapsed_seconds` counts the seconds advanced since the advance that
+/// entered the state, for every state type.
+#[derive(Debug, Clone)]
+pub struct LayerCurrentState {
+    pub layer_name: String,
+    pub state: usize,
+    pub state_type: LayerStateType,
+    pub name: String,
+    pub elapsed_seconds: f32,
+    pub transition: Option<ActiveTransition>,
+}
+
+/// The transition a layer is mixing through. `source_state` and `transition`
+/// locate it in [`StateMachine::layers`], where the source is the any state
+/// for transitions taken from it; `from_state` is the state being mixed out.
+/// `mix` runs from 0 to 1 over `mix_seconds`.
+#[derive(Debug, Clone)]
+pub struct ActiveTransition {
+    pub source_state: usize,
+    pub transition: usize,
+    pub from_state: Option<usize>,
+    pub mix: f32,
+    pub mix_seconds: f32,
+}
+
 #[derive(Debug, Clone)]
 pub struct ReportedEvent {
     pub event: EventInfo,
@@ -69,6 +189,7 @@ impl FileAssetLoaderCallbacks {
             raw: abi::rive_rs_file_asset_loader_callbacks {
                 load_contents,
                 user_data,
+                release: None,
             },
         }
     }
@@ -127,6 +248,20 @@ fn empty_event_info() -> abi::rive_rs_event_info {
     }
 }
 
+fn empty_component_info() -> abi::rive_rs_component_info {
+    abi::rive_rs_component_info {
+        name: empty_str_view(),
+        type_name: empty_str_view(),
+        type_key: 0,
+        has_parent: false,
+        parent_index: 0,
+        is_transform_component: false,
+        is_node: false,
+        is_bone: false,
+        is_root_bone: false,
+    }
+}
+
 fn empty_event_property_info() -> abi::rive_rs_event_property_info {
     abi::rive_rs_event_property_info {
         name: empty_str_view(),
@@ -173,15 +308,18 @@ pub fn abi_version() -> u32 {
 
 pub struct Factory {
     raw: NonNull<abi::rive_rs_factory>,
+    files: FileRegistry,
 }
 
 impl Factory {
+    /// The runtime's no-op factory: files load and animate, but their render
+    /// objects draw nothing, so drawing their artboards into the crate's
+    /// renderers fails with `RIVE_RS_STATUS_UNSUPPORTED`. Use
+    /// [`Factory::new_recording`] to draw with the crate's renderers.
     pub fn new() -> Result<Self, Error> {
         // SAFETY: FFI constructor with no arguments.
         let raw = unsafe { abi::rive_rs_factory_default() };
-        Ok(Self {
-            raw: non_null(raw)?,
-        })
+        Ok(Self::from_raw(non_null(raw)?))
     }
 
     pub fn new_webgl2() -> Result<Self, Error> {
@@ -189,7 +327,7 @@ impl Factory {
         let raw = unsafe { abi::rive_rs_factory_webgl2() };
         let raw = NonNull::new(raw)
             .ok_or_else(|| Error::from_status(Status::RIVE_RS_STATUS_UNSUPPORTED))?;
-        Ok(Self { raw })
+        Ok(Self::from_raw(raw))
     }
 
     pub fn new_webgpu() -> Result<Self, Error> {
@@ -197,21 +335,113 @@ impl Factory {
         let raw = unsafe { abi::rive_rs_factory_webgpu() };
         let raw = NonNull::new(raw)
             .ok_or_else(|| Error::from_status(Status::RIVE_RS_STATUS_UNSUPPORTED))?;
-        Ok(Self { raw })
+        Ok(Self::from_raw(raw))
+    }
+
+    /// Factory for `SoftwareRenderer`. Paint feathering is not rasterized, so
+    /// feathered fills and strokes draw with hard edges.
+    pub fn new_software() -> Result<Self, Error> {
+        Self::from_backend(SoftwareBackend)
+    }
+
+    /// Factory whose render objects keep their geometry and paint state, so
+    /// artboards loaded through it can be drawn by `RecordingRenderer`,
+    /// `SvgRenderer` and `SoftwareRenderer`.
+    pub fn new_recording() -> Result<Self, Error> {
+        Self::from_backend(RecordingBackend)
+    }
+
+    fn from_raw(raw: NonNull<abi::rive_rs_factory>) -> Self {
+        Self {
+            raw,
+            files: FileRegistry::default(),
+        }
+    }
+
+    /// Wraps a provider-owned handle without taking a reference.
+    ///
+    /// # Safety
+    ///
+    /// `raw` must stay valid while the returned wrapper is in use.
+    pub(crate) unsafe fn borrow_raw(raw: NonNull<abi::rive_rs_factory>) -> ManuallyDrop<Self> {
+        ManuallyDrop::new(Self::from_raw(raw))
+    }
+
+    pub fn from_backend<B: RenderBackend>(backend: B) -> Result<Self, Error> {
+        let vtable = backend::backend_vtable(backend);
+        let mut out = ptr::null_mut();
+        // SAFETY: vtable is fully populated and out pointer is writable.
+        let status = unsafe { abi::rive_rs_factory_from_backend(&vtable, &mut out) };
+        if !status_ok(status) {
+            // SAFETY: the provider does not take ownership of the backend on failure.
+            unsafe { backend::release_backend::<B>(vtable.user_data) };
+            return Err(Error::from_status(status));
+        }
+        Ok(Self::from_raw(non_null(out)?))
     }
 
     pub fn as_raw(&self) -> *mut abi::rive_rs_factory {
         self.raw.as_ptr()
     }
 
+    /// Whether render objects made by this factory draw anything; false only
+    /// for [`Factory::new`].
+    pub fn draws(&self) -> bool {
+        // SAFETY: valid handle for call duration.
+        unsafe { abi::rive_rs_factory_draws(self.as_raw()) }
+    }
+
     pub fn load_file(&self, bytes: &[u8]) -> Result<File, Error> {
+        self.load_file_with_loader(bytes, |_: &mut FileAsset, _: &[u8], _: &Factory| {
+            LoadOutcome::Fallback
+        })
+    }
+
+    /// Loads a file, resolving its assets through `loader`. The file keeps
+    /// the loader alive for as long as the runtime may need it.
+    pub fn load_file_with_loader(
+        &self,
+        bytes: &[u8],
+        loader: impl AssetLoader,
+    ) -> Result<File, Error> {
+        self.load_file_tracked(bytes, loader, false)
+    }
+
+    /// Like [`Factory::load_file_with_loader`], but keeps a copy of every
+    /// encoded in-band image so [`File::release_images`] can drop their
+    /// pixels; artboards decode them again when they next draw them.
+    pub fn load_file_retaining_images(
+        &self,
+        bytes: &[u8],
+        loader: impl AssetLoader,
+    ) -> Result<File, Error> {
+        self.load_file_tracked(bytes, loader, true)
+    }
+
+    fn load_file_tracked(
+        &self,
+        bytes: &[u8],
+        loader: impl AssetLoader,
+        retain_images: bool,
+    ) -> Result<File, Error> {
+        let memory = FileMemory::new(self, retain_images);
+        let callbacks = assets::loader_callbacks(RecordingLoader {
+            inner: loader,
+            memory: memory.clone(),
+        });
         let mut out = ptr::null_mut();
-        // SAFETY: pointers are valid for call duration; out pointer is writable.
-        let status = unsafe { abi::rive_rs_load_file(self.as_raw(), bytes_view(bytes), &mut out) };
+        // SAFETY: pointers are valid for call duration; the provider owns the
+        // loader from here on and frees it through `callbacks.release`.
+        let status = unsafe {
+            abi::rive_rs_load_file_with_asset_loader(
+                self.as_raw(),
+                bytes_view(bytes),
+                &callbacks,
+                &mut out,
+            )
+        };
         status_result(status)?;
-        Ok(File {
-            raw: non_null(out)?,
-        })
+        Ok(self.track_file(non_null(out)?, memory))
     }
 
     /// # Safety
@@ -234,11 +464,23 @@ impl Factory {
             )
         };
         status_result(status)?;
-        Ok(File {
-            raw: non_null(out)?,
-        })
+        Ok(self.track_file(non_null(out)?, FileMemory::new(self, false)))
+    }
+
+    fn track_file(&self, raw: NonNull<abi::rive_rs_file>, memory: Rc<FileMemory>) -> File {
+        memory.register(raw, &self.files);
+        File { raw, memory }
+    }
+
+    /// Totals [`File::memory_stats`] over the live files loaded through this
+    /// factory handle and its clones.
+    pub fn memory_stats(&self) -> MemoryStats {
+        memory::factory_stats(&self.files)
     }
 
+    /// Decodes an audio file with the runtime's decoder. WAV files are also
+    /// decoded by the crate, which makes their samples readable through
+    /// [`AudioSource::decode_pcm`]; other formats decode without that.
     pub fn decode_audio(&self, bytes: &[u8]) -> Result<AudioSource, Error> {
         let mut out = ptr::null_mut();
         // SAFETY: pointers are valid for call duration; out pointer is writable.
@@ -247,9 +489,13 @@ impl Factory {
         status_result(status)?;
         Ok(AudioSource {
             raw: non_null(out)?,
+            pcm: wav::decode(bytes).map(Arc::new),
         })
     }
 
+    /// Decodes a font through the runtime. Introspection is available when
+    /// the crate's table reader also understands the bytes. Fails with a
+    /// decode error unless the provider is built with `runtime-text`.
     pub fn decode_font(&self, bytes: &[u8]) -> Result<Font, Error> {
         let mut out = ptr::null_mut();
         // SAFETY: pointers are valid for call duration; out pointer is writable.
@@ -258,6 +504,21 @@ impl Factory {
         status_result(status)?;
         Ok(Font {
             raw: non_null(out)?,
+            face: FontFace::parse(bytes).map(Arc::new),
+        })
+    }
+
+    /// Decodes a PNG, JPEG or WebP image through this factory's backend.
+    /// Fails as unsupported on [`Factory::new`], whose images hold no
+    /// pixels; the recording, software and GPU factories decode.
+    pub fn decode_image(&self, bytes: &[u8]) -> Result<RenderImage, Error> {
+        let mut out = ptr::null_mut();
+        // SAFETY: pointers are valid for call duration; out pointer is writable.
+        let status =
+            unsafe { abi::rive_rs_decode_render_image(self.as_raw(), bytes_view(bytes), &mut out) };
+        status_result(status)?;
+        Ok(RenderImage {
+            raw: non_null(out)?,
         })
     }
 }
@@ -266,7 +527,10 @@ impl Clone for Factory {
     fn clone(&self) -> Self {
         // SAFETY: intrusive ref-count increment on valid handle.
         unsafe { abi::rive_rs_factory_ref(self.as_raw()) };
-        Self { raw: self.raw }
+        Self {
+            raw: self.raw,
+            files: self.files.clone(),
+        }
     }
 }
 
@@ -279,9 +543,24 @@ impl Drop for Factory {
 
 pub struct File {
     raw: NonNull<abi::rive_rs_file>,
+    memory: Rc<FileMemory>,
 }
 
 impl File {
+    /// Takes a new reference to a loaded file.
+    ///
+    /// # Safety
+    ///
+    /// `raw` must point to a live file.
+    pub(crate) unsafe fn retain_raw(
+        raw: NonNull<abi::rive_rs_file>,
+        memory: Rc<FileMemory>,
+    ) -> Self {
+        // SAFETY: caller guarantees `raw` is live.
+        unsafe { abi::rive_rs_file_ref(raw.as_ptr()) };
+        Self { raw, memory }
+    }
+
     pub fn as_raw(&self) -> *mut abi::rive_rs_file {
         self.raw.as_ptr()
     }
@@ -298,6 +577,7 @@ impl File {
         status_result(status)?;
         Ok(Artboard {
             raw: non_null(out)?,
+            memory: Some(Rc::downgrade(&self.memory)),
         })
     }
 
@@ -308,6 +588,7 @@ impl File {
         status_result(status)?;
         Ok(Artboard {
             raw: non_null(out)?,
+            memory: Some(Rc::downgrade(&self.memory)),
         })
     }
 
@@ -319,6 +600,7 @@ impl File {
         status_result(status)?;
         Ok(Artboard {
             raw: non_null(out)?,
+            memory: Some(Rc::downgrade(&self.memory)),
         })
     }
 
@@ -464,13 +746,61 @@ impl File {
         }
         Ok(enums)
     }
+
+    pub fn asset_count(&self) -> usize {
+        // SAFETY: valid handle.
+        unsafe { abi::rive_rs_file_asset_count(self.as_raw()) }
+    }
+
+    /// An asset of this file; it stays valid while the file is alive.
+    pub fn asset_at(&self, index: usize) -> Result<FileAsset, Error> {
+        let mut out = ptr::null_mut();
+        // SAFETY: valid handle and out pointer.
+        let status = unsafe { abi::rive_rs_file_asset_at(self.as_raw(), index, &mut out) };
+        status_result(status)?;
+        // SAFETY: the runtime returned an asset owned by this file.
+        unsafe { FileAsset::from_raw(out) }
+    }
+
+    pub fn assets(&self) -> Vec<FileAsset> {
+        (0..self.asset_count())
+            .filter_map(|index| self.asset_at(index).ok())
+            .collect()
+    }
+
+    /// Core objects across the file's artboards.
+    pub fn object_count(&self) -> usize {
+        // SAFETY: valid handle.
+        unsafe { abi::rive_rs_file_object_count(self.as_raw()) }
+    }
+
+    /// Estimated bytes the file's core objects occupy.
+    pub fn object_bytes(&self) -> usize {
+        // SAFETY: valid handle.
+        unsafe { abi::rive_rs_file_object_bytes(self.as_raw()) }
+    }
+
+    pub fn memory_stats(&self) -> MemoryStats {
+        memory::file_stats(self, &self.memory)
+    }
+
+    /// Drops the decoded pixels of the file's in-band images, for files
+    /// loaded with [`Factory::load_file_retaining_images`]. An artboard
+    /// decodes the released images it uses again before it next draws.
+    /// Returns how many were released.
+    pub fn release_images(&self) -> usize {
+        memory::release_images(self, &self.memory)
+    }
 }
 
 impl Clone for File {
     fn clone(&self) -> Self {
         // SAFETY: intrusive ref-count increment on valid handle.
         unsafe { abi::rive_rs_file_ref(self.as_raw()) };
-        Self { raw: self.raw }
+        Self {
+            raw: self.raw,
+            memory: self.memory.clone(),
+        }
     }
 }
 
@@ -483,6 +813,9 @@ impl Drop for File {
 
 pub struct Artboard {
     raw: NonNull<abi::rive_rs_artboard>,
+    // Memory record of the file the artboard came from, to decode released
+    // images before drawing.
+    memory: Option<Weak<FileMemory>>,
 }
 
 impl Artboard {
@@ -498,27 +831,99 @@ impl Artboard {
         Ok(changed)
     }
 
-    pub fn draw_raw(&mut self, renderer: *mut abi::rive_rs_renderer) -> Result<(), Error> {
+    /// # Safety
+    ///
+    /// `renderer` must be null or a live `rive_rs_renderer` that nothing else
+    /// uses for the duration of the call.
+    pub unsafe fn draw_raw(&mut self, renderer: *mut abi::rive_rs_renderer) -> Result<(), Error> {
         if renderer.is_null() {
             return Err(Error::null_handle());
         }
-        // SAFETY: valid pointers for call duration.
+        self.restore_images()?;
+        // SAFETY: the caller guarantees `renderer`; the artboard handle is valid.
         let status = unsafe { abi::rive_rs_artboard_draw(self.as_raw(), renderer) };
         status_result(status)
     }
 
+    /// Whether an image component of the artboard draws `asset`.
+    pub fn uses_image_asset(&self, asset: &FileAsset) -> bool {
+        // SAFETY: valid handles for call duration.
+        unsafe { abi::rive_rs_artboard_uses_image_asset(self.as_raw(), asset.as_raw()) }
+    }
+
+    // Decodes the images `File::release_images` dropped that this artboard
+    // is about to draw.
+    fn restore_images(&self) -> Result<(), Error> {
+        match self.memory.as_ref().and_then(Weak::upgrade) {
+            Some(memory) => memory::restore_drawn_images(self, &memory),
+            None => Ok(()),
+        }
+    }
+
+    /// Fails with `RIVE_RS_STATUS_UNSUPPORTED` when the artboard's file was
+    /// loaded through the no-op [`Factory::new`], which would draw nothing.
+    fn check_draws(&self) -> Result<(), Error> {
+        match self.memory.as_ref().and_then(Weak::upgrade) {
+            Some(memory) if !memory.factory().draws() => {
+                Err(Error::from_status(Status::RIVE_RS_STATUS_UNSUPPORTED))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Draws into any [`Renderer`]; see the `draw_*` methods for the
+    /// renderer-specific entry points.
+    pub fn draw<R: Renderer + ?Sized>(&mut self, renderer: &mut R) -> Result<(), Error> {
+        renderer.draw_artboard(self)
+    }
+
     pub fn draw_webgl2(&mut self, renderer: &mut WebGl2Renderer) -> Result<(), Error> {
+        self.restore_images()?;
         // SAFETY: valid handles for call duration.
         let status = unsafe { abi::rive_rs_artboard_draw_webgl2(self.as_raw(), renderer.as_raw()) };
         status_result(status)
     }
 
     pub fn draw_webgpu(&mut self, renderer: &mut WebGpuRenderer) -> Result<(), Error> {
+        self.restore_images()?;
         // SAFETY: valid handles for call duration.
         let status = unsafe { abi::rive_rs_artboard_draw_webgpu(self.as_raw(), renderer.as_raw()) };
         status_result(status)
     }
 
+    pub fn draw_backend<R: BackendRenderer>(
+        &mut self,
+        renderer: &mut BackendRendererHandle<R>,
+    ) -> Result<(), Error> {
+        self.check_draws()?;
+        self.restore_images()?;
+        // SAFETY: valid handles for call duration; the renderer is borrowed
+        // mutably so it is not otherwise accessed while drawing.
+        let status = unsafe { abi::rive_rs_artboard_draw(self.as_raw(), renderer.as_raw()) };
+        status_result(status)
+    }
+
+    pub fn draw_software(&mut self, renderer: &mut SoftwareRenderer) -> Result<(), Error> {
+        self.draw_backend(&mut renderer.handle)
+    }
+
+    pub fn draw_recording(&mut self, renderer: &mut RecordingRenderer) -> Result<(), Error> {
+        self.draw_backend(&mut renderer.handle)
+    }
+
+    pub fn draw_svg(&mut self, renderer: &mut SvgRenderer) -> Result<(), Error> {
+        self.draw_backend(&mut renderer.handle)
+    }
+
+    /// Draws the current frame into an SVG document sized to the artboard
+    /// bounds. Fails with `RIVE_RS_STATUS_UNSUPPORTED` for artboards loaded
+    /// through [`Factory::new`], whose shapes would not appear.
+    pub fn to_svg(&mut self) -> Result<String, Error> {
+        let mut renderer = SvgRenderer::with_view_box(self.bounds())?;
+        self.draw_svg(&mut renderer)?;
+        Ok(renderer.to_svg())
+    }
+
     pub fn did_change(&self) -> bool {
         // SAFETY: valid handle.
         unsafe { abi::rive_rs_artboard_did_change(self.as_raw()) }
@@ -565,6 +970,12 @@ impl Artboard {
         unsafe { abi::rive_rs_artboard_set_frame_origin(self.as_raw(), frame_origin) };
     }
 
+    /// The artboard's origin as a fraction of its size.
+    pub fn origin(&self) -> Vec2 {
+        // SAFETY: valid handle.
+        unsafe { abi::rive_rs_artboard_origin(self.as_raw()) }
+    }
+
     pub fn has_audio(&self) -> bool {
         // SAFETY: valid handle.
         unsafe { abi::rive_rs_artboard_has_audio(self.as_raw()) }
@@ -819,6 +1230,93 @@ impl Artboard {
         })
     }
 
+    /// Number of core objects in the artboard, the artboard itself included.
+    pub fn object_count(&self) -> usize {
+        // SAFETY: valid handle.
+        unsafe { abi::rive_rs_artboard_object_count(self.as_raw()) }
+    }
+
+    pub fn component_at(&self, index: usize) -> Result<ComponentInfo, Error> {
+        let mut out = empty_component_info();
+        // SAFETY: valid handle and out pointer.
+        let status = unsafe { abi::rive_rs_artboard_component_at(self.as_raw(), index, &mut out) };
+        status_result(status)?;
+        Ok(ComponentInfo {
+            index,
+            type_key: out.type_key,
+            type_name: string_from_view(out.type_name),
+            name: string_from_view(out.name),
+            parent: out.has_parent.then_some(out.parent_index),
+            children: Vec::new(),
+            is_transform_component: out.is_transform_component,
+            is_node: out.is_node,
+            is_bone: out.is_bone,
+            is_root_bone: out.is_root_bone,
+        })
+    }
+
+    /// Every object in the artboard in file order, with `children` filled in
+    /// from the parent links.
+    pub fn components(&self) -> Vec<ComponentInfo> {
+        let mut components: Vec<ComponentInfo> = (0..self.object_count())
+            .filter_map(|index| self.component_at(index).ok())
+            .collect();
+        for index in 0..components.len() {
+            if let Some(parent) = components[index].parent
+                && let Some(parent) = components.get_mut(parent)
+            {
+                parent.children.push(index);
+            }
+        }
+        components
+    }
+
+    /// The transform component at `index`, or `INVALID_ARGUMENT` when the
+    /// object there is some other type.
+    pub fn transform_component_by_index(
+        &mut self,
+        index: usize,
+    ) -> Result<TransformComponentHandle, Error> {
+        let mut out = ptr::null_mut();
+        // SAFETY: valid handle and out pointer.
+        let status =
+            unsafe { abi::rive_rs_artboard_transform_component_at(self.as_raw(), index, &mut out) };
+        status_result(status)?;
+        Ok(TransformComponentHandle {
+            raw: non_null(out)?,
+        })
+    }
+
+    pub fn node_by_index(&mut self, index: usize) -> Result<NodeHandle, Error> {
+        let mut out = ptr::null_mut();
+        // SAFETY: valid handle and out pointer.
+        let status = unsafe { abi::rive_rs_artboard_node_at(self.as_raw(), index, &mut out) };
+        status_result(status)?;
+        Ok(NodeHandle {
+            raw: non_null(out)?,
+        })
+    }
+
+    pub fn bone_by_index(&mut self, index: usize) -> Result<BoneHandle, Error> {
+        let mut out = ptr::null_mut();
+        // SAFETY: valid handle and out pointer.
+        let status = unsafe { abi::rive_rs_artboard_bone_at(self.as_raw(), index, &mut out) };
+        status_result(status)?;
+        Ok(BoneHandle {
+            raw: non_null(out)?,
+        })
+    }
+
+    pub fn root_bone_by_index(&mut self, index: usize) -> Result<RootBoneHandle, Error> {
+        let mut out = ptr::null_mut();
+        // SAFETY: valid handle and out pointer.
+        let status = unsafe { abi::rive_rs_artboard_root_bone_at(self.as_raw(), index, &mut out) };
+        status_result(status)?;
+        Ok(RootBoneHandle {
+            raw: non_null(out)?,
+        })
+    }
+
     pub fn text_value_run_by_name(&mut self, name: &str) -> Result<TextValueRunHandle, Error> {
         let mut out = ptr::null_mut();
         // SAFETY: valid handle, string view, and out pointer.
@@ -872,7 +1370,10 @@ impl Clone for Artboard {
     fn clone(&self) -> Self {
         // SAFETY: intrusive ref-count increment on valid handle.
         unsafe { abi::rive_rs_artboard_ref(self.as_raw()) };
-        Self { raw: self.raw }
+        Self {
+            raw: self.raw,
+            memory: self.memory.clone(),
+        }
     }
 }
 
@@ -883,6 +1384,121 @@ impl Drop for Artboard {
     }
 }
 
+/// Renderer operations shared by every target an [`Artboard`] can draw into.
+///
+/// Implemented by the built-in renderers; custom renderers implement
+/// [`Renderer::draw_artboard`] on top of [`Artboard::draw_raw`] or
+/// [`Artboard::draw_backend`].
+pub trait Renderer {
+    fn clear(&mut self) -> Result<(), Error>;
+    fn flush(&mut self) -> Result<(), Error>;
+    fn resize(&mut self, width: i32, height: i32) -> Result<(), Error>;
+    fn save(&mut self) -> Result<(), Error>;
+    fn restore(&mut self) -> Result<(), Error>;
+    fn transform(&mut self, matrix: &Mat2D) -> Result<(), Error>;
+    fn modulate_opacity(&mut self, opacity: f32) -> Result<(), Error>;
+
+    /// Redirects drawing into `target`, or back to the renderer's own surface
+    /// for `None`. Unsupported by default.
+    fn bind_target(&mut self, target: Option<&RenderTarget>) -> Result<(), Error> {
+        let _ = target;
+        Err(Error::from_status(Status::RIVE_RS_STATUS_UNSUPPORTED))
+    }
+
+    fn align(
+        &mut self,
+        fit: Fit,
+        alignment: Alignment,
+        frame: &Aabb,
+        content: &Aabb,
+        scale_factor: f32,
+    ) -> Result<(), Error> {
+        if !scale_factor.is_finite() {
+            return Err(Error::from_status(Status::RIVE_RS_STATUS_INVALID_ARGUMENT));
+        }
+        let matrix = compute_alignment(fit, alignment, frame, content, scale_factor)?;
+        self.transform(&matrix)
+    }
+
+    fn save_clip_rect(&mut self, left: f32, top: f32, right: f32, bottom: f32)
+    -> Result<(), Error>;
+    fn restore_clip_rect(&mut self) -> Result<(), Error>;
+
+    /// Draws `artboard` with the renderer's current transform and clip.
+    fn draw_artboard(&mut self, artboard: &mut Artboard) -> Result<(), Error>;
+}
+
+macro_rules! forward_renderer {
+    ($renderer:ty, $draw:ident) => {
+        impl Renderer for $renderer {
+            fn clear(&mut self) -> Result<(), Error> {
+                Self::clear(self)
+            }
+
+            fn flush(&mut self) -> Result<(), Error> {
+                Self::flush(self)
+            }
+
+            fn resize(&mut self, width: i32, height: i32) -> Result<(), Error> {
+                Self::resize(self, width, height)
+            }
+
+            fn save(&mut self) -> Result<(), Error> {
+                Self::save(self)
+            }
+
+            fn restore(&mut self) -> Result<(), Error> {
+                Self::restore(self)
+            }
+
+            fn transform(&mut self, matrix: &Mat2D) -> Result<(), Error> {
+                Self::transform(self, matrix)
+            }
+
+            fn modulate_opacity(&mut self, opacity: f32) -> Result<(), Error> {
+                Self::modulate_opacity(self, opacity)
+            }
+
+            fn bind_target(&mut self, target: Option<&RenderTarget>) -> Result<(), Error> {
+                Self::bind_target(self, target)
+            }
+
+            fn align(
+                &mut self,
+                fit: Fit,
+                alignment: Alignment,
+                frame: &Aabb,
+                content: &Aabb,
+                scale_factor: f32,
+            ) -> Result<(), Error> {
+                Self::align(self, fit, alignment, frame, content, scale_factor)
+            }
+
+            fn save_clip_rect(
+                &mut self,
+                left: f32,
+                top: f32,
+                right: f32,
+                bottom: f32,
+            ) -> Result<(), Error> {
+                Self::save_clip_rect(self, left, top, right, bottom)
+            }
+
+            fn restore_clip_rect(&mut self) -> Result<(), Error> {
+                Self::restore_clip_rect(self)
+            }
+
+            fn draw_artboard(&mut self, artboard: &mut Artboard) -> Result<(), Error> {
+                artboard.$draw(self)
+            }
+        }
+    };
+}
+
+forward_renderer!(WebGl2Renderer, draw_webgl2);
+forward_renderer!(WebGpuRenderer, draw_webgpu);
+forward_renderer!(SoftwareRenderer, draw_software);
+
 pub struct WebGl2Renderer {
     raw: NonNull<abi::rive_rs_webgl2_renderer>,
 }
@@ -902,6 +1518,15 @@ impl WebGl2Renderer {
         self.raw.as_ptr()
     }
 
+    /// Draws into `target` until another target (or `None`, the canvas) is
+    /// bound. The renderer keeps the target alive while it is bound.
+    pub fn bind_target(&mut self, target: Option<&RenderTarget>) -> Result<(), Error> {
+        let raw = target.map_or(ptr::null_mut(), RenderTarget::as_raw);
+        // SAFETY: valid handles for call duration.
+        let status = unsafe { abi::rive_rs_webgl2_renderer_bind_target(self.as_raw(), raw) };
+        status_result(status)
+    }
+
     pub fn clear(&mut self) -> Result<(), Error> {
         // SAFETY: valid handle.
         let status = unsafe { abi::rive_rs_webgl2_renderer_clear(self.as_raw()) };
@@ -1014,8 +1639,17 @@ impl WebGpuRenderer {
         self.raw.as_ptr()
     }
 
-    pub fn clear(&mut self) -> Result<(), Error> {
-        // SAFETY: valid handle.
+    /// Draws into `target` until another target (or `None`, the canvas) is
+    /// bound. The renderer keeps the target alive while it is bound.
+    pub fn bind_target(&mut self, target: Option<&RenderTarget>) -> Result<(), Error> {
+        let raw = target.map_or(ptr::null_mut(), RenderTarget::as_raw);
+        // SAFETY: valid handles for call duration.
+        let status = unsafe { abi::rive_rs_webgpu_renderer_bind_target(self.as_raw(), raw) };
+        status_result(status)
+    }
+
+    pub fn clear(&mut self) -> Result<(), Error> {
+        // SAFETY: valid handle.
         let status = unsafe { abi::rive_rs_webgpu_renderer_clear(self.as_raw()) };
         status_result(status)
     }
@@ -1038,26 +1672,553 @@ impl WebGpuRenderer {
         status_result(status)
     }
 
-    pub fn restore(&mut self) -> Result<(), Error> {
-        // SAFETY: valid handle.
-        let status = unsafe { abi::rive_rs_webgpu_renderer_restore(self.as_raw()) };
-        status_result(status)
+    pub fn restore(&mut self) -> Result<(), Error> {
+        // SAFETY: valid handle.
+        let status = unsafe { abi::rive_rs_webgpu_renderer_restore(self.as_raw()) };
+        status_result(status)
+    }
+
+    pub fn transform(&mut self, matrix: &Mat2D) -> Result<(), Error> {
+        // SAFETY: valid pointers for call duration.
+        let status = unsafe { abi::rive_rs_webgpu_renderer_transform(self.as_raw(), matrix) };
+        status_result(status)
+    }
+
+    pub fn modulate_opacity(&mut self, opacity: f32) -> Result<(), Error> {
+        // SAFETY: valid handle.
+        let status =
+            unsafe { abi::rive_rs_webgpu_renderer_modulate_opacity(self.as_raw(), opacity) };
+        status_result(status)
+    }
+
+    pub fn align(
+        &mut self,
+        fit: Fit,
+        alignment: Alignment,
+        frame: &Aabb,
+        content: &Aabb,
+        scale_factor: f32,
+    ) -> Result<(), Error> {
+        // SAFETY: valid pointers for call duration.
+        let status = unsafe {
+            abi::rive_rs_webgpu_renderer_align(
+                self.as_raw(),
+                fit,
+                alignment,
+                frame,
+                content,
+                scale_factor,
+            )
+        };
+        status_result(status)
+    }
+
+    pub fn save_clip_rect(
+        &mut self,
+        left: f32,
+        top: f32,
+        right: f32,
+        bottom: f32,
+    ) -> Result<(), Error> {
+        // SAFETY: valid handle.
+        let status = unsafe {
+            abi::rive_rs_webgpu_renderer_save_clip_rect(self.as_raw(), left, top, right, bottom)
+        };
+        status_result(status)
+    }
+
+    pub fn restore_clip_rect(&mut self) -> Result<(), Error> {
+        // SAFETY: valid handle.
+        let status = unsafe { abi::rive_rs_webgpu_renderer_restore_clip_rect(self.as_raw()) };
+        status_result(status)
+    }
+}
+
+impl Drop for WebGpuRenderer {
+    fn drop(&mut self) {
+        // SAFETY: valid handle.
+        unsafe { abi::rive_rs_webgpu_renderer_delete(self.as_raw()) };
+    }
+}
+
+pub struct BackendRendererHandle<R: BackendRenderer> {
+    raw: NonNull<abi::rive_rs_renderer>,
+    renderer: NonNull<R>,
+}
+
+impl<R: BackendRenderer> BackendRendererHandle<R> {
+    pub fn new(renderer: R) -> Result<Self, Error> {
+        let renderer = NonNull::from(Box::leak(Box::new(renderer)));
+        let callbacks = backend::renderer_callbacks(renderer.as_ptr());
+        let mut out = ptr::null_mut();
+        // SAFETY: callbacks are fully populated and out pointer is writable.
+        let status = unsafe { abi::rive_rs_renderer_new(&callbacks, &mut out) };
+        match status_result(status).and_then(|()| non_null(out)) {
+            Ok(raw) => Ok(Self { raw, renderer }),
+            Err(error) => {
+                // SAFETY: renderer was leaked above and nothing references it.
+                drop(unsafe { Box::from_raw(renderer.as_ptr()) });
+                Err(error)
+            }
+        }
+    }
+
+    pub fn as_raw(&self) -> *mut abi::rive_rs_renderer {
+        self.raw.as_ptr()
+    }
+
+    pub fn get(&self) -> &R {
+        // SAFETY: renderer is owned by this handle until drop.
+        unsafe { self.renderer.as_ref() }
+    }
+
+    pub fn get_mut(&mut self) -> &mut R {
+        // SAFETY: renderer is owned by this handle until drop; `&mut self`
+        // guarantees no draw is in flight.
+        unsafe { self.renderer.as_mut() }
+    }
+
+    pub fn into_inner(self) -> R {
+        let this = core::mem::ManuallyDrop::new(self);
+        // SAFETY: valid handle; deleted before the renderer it points at.
+        unsafe { abi::rive_rs_renderer_delete(this.as_raw()) };
+        // SAFETY: renderer was leaked in `new` and is no longer referenced.
+        *unsafe { Box::from_raw(this.renderer.as_ptr()) }
+    }
+}
+
+impl<R: BackendRenderer> Drop for BackendRendererHandle<R> {
+    fn drop(&mut self) {
+        // SAFETY: valid handle; deleted before the renderer it points at.
+        unsafe { abi::rive_rs_renderer_delete(self.as_raw()) };
+        // SAFETY: renderer was leaked in `new` and is no longer referenced.
+        drop(unsafe { Box::from_raw(self.renderer.as_ptr()) });
+    }
+}
+
+pub struct SoftwareRenderer {
+    handle: BackendRendererHandle<SoftwareCanvas>,
+    save_depth: u32,
+    clip_depth: u32,
+    target: Option<RenderTarget>,
+    // The renderer's own surface while `target` is bound.
+    canvas_surface: Option<Pixmap>,
+}
+
+impl SoftwareRenderer {
+    pub fn new(width: i32, height: i32) -> Result<Self, Error> {
+        if width <= 0 || height <= 0 {
+            return Err(Error::from_status(Status::RIVE_RS_STATUS_INVALID_ARGUMENT));
+        }
+        Ok(Self {
+            handle: BackendRendererHandle::new(SoftwareCanvas::new(
+                width as usize,
+                height as usize,
+            ))?,
+            save_depth: 0,
+            clip_depth: 0,
+            target: None,
+            canvas_surface: None,
+        })
+    }
+
+    pub fn as_raw(&self) -> *mut abi::rive_rs_renderer {
+        self.handle.as_raw()
+    }
+
+    fn canvas(&self) -> &SoftwareCanvas {
+        self.handle.get()
+    }
+
+    fn canvas_mut(&mut self) -> &mut SoftwareCanvas {
+        self.handle.get_mut()
+    }
+
+    pub fn width(&self) -> i32 {
+        self.canvas().width() as i32
+    }
+
+    pub fn height(&self) -> i32 {
+        self.canvas().height() as i32
+    }
+
+    /// Premultiplied RGBA8 pixels, row-major with a stride of `width * 4`.
+    pub fn pixels(&self) -> &[u8] {
+        self.canvas().pixels()
+    }
+
+    /// Unpremultiplied RGBA8 copy of [`SoftwareRenderer::pixels`].
+    pub fn to_rgba8(&self) -> Vec<u8> {
+        let mut out = self.pixels().to_vec();
+        raster::unpremultiply(&mut out);
+        out
+    }
+
+    pub fn clear(&mut self) -> Result<(), Error> {
+        self.canvas_mut().clear();
+        Ok(())
+    }
+
+    pub fn flush(&mut self) -> Result<(), Error> {
+        self.resolve_target()
+    }
+
+    pub fn resize(&mut self, width: i32, height: i32) -> Result<(), Error> {
+        if width <= 0 || height <= 0 {
+            return Err(Error::from_status(Status::RIVE_RS_STATUS_INVALID_ARGUMENT));
+        }
+        match &mut self.canvas_surface {
+            Some(surface) => *surface = Pixmap::new(width as usize, height as usize),
+            None => self.canvas_mut().resize(width as usize, height as usize),
+        }
+        Ok(())
+    }
+
+    /// Draws into `target` until another target (or `None`, the renderer's
+    /// own buffer) is bound. While bound, [`SoftwareRenderer::pixels`] reads
+    /// the target's surface; it is copied into the target on
+    /// [`SoftwareRenderer::flush`] and when unbound.
+    pub fn bind_target(&mut self, target: Option<&RenderTarget>) -> Result<(), Error> {
+        self.resolve_target()?;
+        let surface = match target {
+            Some(target) => Pixmap::new(target.width() as usize, target.height() as usize),
+            None => match self.canvas_surface.take() {
+                Some(surface) => surface,
+                None => return Ok(()),
+            },
+        };
+        let previous = self.canvas_mut().replace_pixmap(surface);
+        if self.target.is_none() {
+            self.canvas_surface = Some(previous);
+        }
+        self.target = target.cloned();
+        Ok(())
+    }
+
+    fn resolve_target(&self) -> Result<(), Error> {
+        let Some(target) = &self.target else {
+            return Ok(());
+        };
+        let rgba = self.to_rgba8();
+        // SAFETY: valid handle; the byte view is valid for the call.
+        let status =
+            unsafe { abi::rive_rs_render_target_write_pixels(target.as_raw(), bytes_view(&rgba)) };
+        status_result(status)
+    }
+
+    pub fn save(&mut self) -> Result<(), Error> {
+        BackendRenderer::save(self.canvas_mut());
+        self.save_depth += 1;
+        Ok(())
+    }
+
+    pub fn restore(&mut self) -> Result<(), Error> {
+        if self.save_depth == 0 {
+            return Err(Error::from_status(Status::RIVE_RS_STATUS_OUT_OF_RANGE));
+        }
+        BackendRenderer::restore(self.canvas_mut());
+        self.save_depth -= 1;
+        Ok(())
+    }
+
+    pub fn transform(&mut self, matrix: &Mat2D) -> Result<(), Error> {
+        BackendRenderer::transform(self.canvas_mut(), matrix);
+        Ok(())
+    }
+
+    pub fn modulate_opacity(&mut self, opacity: f32) -> Result<(), Error> {
+        if !opacity.is_finite() {
+            return Err(Error::from_status(Status::RIVE_RS_STATUS_INVALID_ARGUMENT));
+        }
+        BackendRenderer::modulate_opacity(self.canvas_mut(), opacity);
+        Ok(())
+    }
+
+    pub fn align(
+        &mut self,
+        fit: Fit,
+        alignment: Alignment,
+        frame: &Aabb,
+        content: &Aabb,
+        scale_factor: f32,
+    ) -> Result<(), Error> {
+        if !scale_factor.is_finite() {
+            return Err(Error::from_status(Status::RIVE_RS_STATUS_INVALID_ARGUMENT));
+        }
+        let matrix = compute_alignment(fit, alignment, frame, content, scale_factor)?;
+        self.transform(&matrix)
+    }
+
+    pub fn save_clip_rect(
+        &mut self,
+        left: f32,
+        top: f32,
+        right: f32,
+        bottom: f32,
+    ) -> Result<(), Error> {
+        if !left.is_finite() || !top.is_finite() || !right.is_finite() || !bottom.is_finite() {
+            return Err(Error::from_status(Status::RIVE_RS_STATUS_INVALID_ARGUMENT));
+        }
+        self.save()?;
+        self.canvas_mut().clip_rect(left, top, right, bottom);
+        self.clip_depth += 1;
+        Ok(())
+    }
+
+    pub fn restore_clip_rect(&mut self) -> Result<(), Error> {
+        if self.clip_depth == 0 {
+            return Err(Error::from_status(Status::RIVE_RS_STATUS_OUT_OF_RANGE));
+        }
+        self.restore()?;
+        self.clip_depth -= 1;
+        Ok(())
+    }
+
+    pub fn replay(&mut self, list: &DisplayList) -> Result<(), Error> {
+        self.canvas_mut().draw_list(list);
+        Ok(())
+    }
+}
+
+pub struct RecordingRenderer {
+    handle: BackendRendererHandle<DisplayList>,
+    save_depth: u32,
+    clip_depth: u32,
+}
+
+impl RecordingRenderer {
+    pub fn new() -> Result<Self, Error> {
+        Ok(Self {
+            handle: BackendRendererHandle::new(DisplayList::new())?,
+            save_depth: 0,
+            clip_depth: 0,
+        })
+    }
+
+    pub fn as_raw(&self) -> *mut abi::rive_rs_renderer {
+        self.handle.as_raw()
+    }
+
+    pub fn display_list(&self) -> &DisplayList {
+        self.handle.get()
+    }
+
+    /// Takes the recorded commands, including any unrestored saves.
+    pub fn take_display_list(&mut self) -> DisplayList {
+        self.save_depth = 0;
+        self.clip_depth = 0;
+        core::mem::take(self.handle.get_mut())
+    }
+
+    /// Drops the recorded commands, including any unrestored saves.
+    pub fn clear(&mut self) {
+        self.handle.get_mut().commands.clear();
+        self.save_depth = 0;
+        self.clip_depth = 0;
+    }
+}
+
+impl Renderer for RecordingRenderer {
+    fn clear(&mut self) -> Result<(), Error> {
+        Self::clear(self);
+        Ok(())
+    }
+
+    fn flush(&mut self) -> Result<(), Error> {
+        Ok(())
+    }
+
+    // Display lists are resolution independent.
+    fn resize(&mut self, width: i32, height: i32) -> Result<(), Error> {
+        if width <= 0 || height <= 0 {
+            return Err(Error::from_status(Status::RIVE_RS_STATUS_INVALID_ARGUMENT));
+        }
+        Ok(())
+    }
+
+    fn save(&mut self) -> Result<(), Error> {
+        BackendRenderer::save(self.handle.get_mut());
+        self.save_depth += 1;
+        Ok(())
+    }
+
+    fn restore(&mut self) -> Result<(), Error> {
+        if self.save_depth == 0 {
+            return Err(Error::from_status(Status::RIVE_RS_STATUS_OUT_OF_RANGE));
+        }
+        BackendRenderer::restore(self.handle.get_mut());
+        self.save_depth -= 1;
+        Ok(())
+    }
+
+    fn transform(&mut self, matrix: &Mat2D) -> Result<(), Error> {
+        BackendRenderer::transform(self.handle.get_mut(), matrix);
+        Ok(())
+    }
+
+    fn modulate_opacity(&mut self, opacity: f32) -> Result<(), Error> {
+        if !opacity.is_finite() {
+            return Err(Error::from_status(Status::RIVE_RS_STATUS_INVALID_ARGUMENT));
+        }
+        BackendRenderer::modulate_opacity(self.handle.get_mut(), opacity);
+        Ok(())
+    }
+
+    fn save_clip_rect(
+        &mut self,
+        left: f32,
+        top: f32,
+        right: f32,
+        bottom: f32,
+    ) -> Result<(), Error> {
+        if !left.is_finite() || !top.is_finite() || !right.is_finite() || !bottom.is_finite() {
+            return Err(Error::from_status(Status::RIVE_RS_STATUS_INVALID_ARGUMENT));
+        }
+        self.handle
+            .get_mut()
+            .save_clip_rect(left, top, right, bottom);
+        self.save_depth += 1;
+        self.clip_depth += 1;
+        Ok(())
+    }
+
+    fn restore_clip_rect(&mut self) -> Result<(), Error> {
+        if self.clip_depth == 0 {
+            return Err(Error::from_status(Status::RIVE_RS_STATUS_OUT_OF_RANGE));
+        }
+        Renderer::restore(self)?;
+        self.clip_depth -= 1;
+        Ok(())
+    }
+
+    fn draw_artboard(&mut self, artboard: &mut Artboard) -> Result<(), Error> {
+        artboard.draw_recording(self)
+    }
+}
+
+pub struct SvgRenderer {
+    handle: BackendRendererHandle<DisplayList>,
+    view_box: Aabb,
+    save_depth: u32,
+    clip_depth: u32,
+}
+
+impl SvgRenderer {
+    pub fn new(width: f32, height: f32) -> Result<Self, Error> {
+        Self::with_view_box(Aabb {
+            min_x: 0.0,
+            min_y: 0.0,
+            max_x: width,
+            max_y: height,
+        })
+    }
+
+    pub fn with_view_box(view_box: Aabb) -> Result<Self, Error> {
+        let width = view_box.max_x - view_box.min_x;
+        let height = view_box.max_y - view_box.min_y;
+        if !(width.is_finite() && height.is_finite() && width > 0.0 && height > 0.0) {
+            return Err(Error::from_status(Status::RIVE_RS_STATUS_INVALID_ARGUMENT));
+        }
+        Ok(Self {
+            handle: BackendRendererHandle::new(DisplayList::new())?,
+            view_box,
+            save_depth: 0,
+            clip_depth: 0,
+        })
+    }
+
+    pub fn as_raw(&self) -> *mut abi::rive_rs_renderer {
+        self.handle.as_raw()
+    }
+
+    pub fn view_box(&self) -> Aabb {
+        self.view_box
+    }
+
+    pub fn display_list(&self) -> &DisplayList {
+        self.handle.get()
+    }
+
+    /// Drops the recorded commands, including any unrestored saves.
+    pub fn clear(&mut self) {
+        self.handle.get_mut().commands.clear();
+        self.save_depth = 0;
+        self.clip_depth = 0;
+    }
+
+    pub fn transform(&mut self, matrix: &Mat2D) -> Result<(), Error> {
+        BackendRenderer::transform(self.handle.get_mut(), matrix);
+        Ok(())
+    }
+
+    pub fn align(
+        &mut self,
+        fit: Fit,
+        alignment: Alignment,
+        frame: &Aabb,
+        content: &Aabb,
+        scale_factor: f32,
+    ) -> Result<(), Error> {
+        if !scale_factor.is_finite() {
+            return Err(Error::from_status(Status::RIVE_RS_STATUS_INVALID_ARGUMENT));
+        }
+        let matrix = compute_alignment(fit, alignment, frame, content, scale_factor)?;
+        self.transform(&matrix)
+    }
+
+    pub fn to_svg(&self) -> String {
+        self.display_list().to_svg(&self.view_box)
+    }
+}
+
+impl Renderer for SvgRenderer {
+    fn clear(&mut self) -> Result<(), Error> {
+        Self::clear(self);
+        Ok(())
+    }
+
+    fn flush(&mut self) -> Result<(), Error> {
+        Ok(())
+    }
+
+    /// Resizes the view box, keeping its origin.
+    fn resize(&mut self, width: i32, height: i32) -> Result<(), Error> {
+        if width <= 0 || height <= 0 {
+            return Err(Error::from_status(Status::RIVE_RS_STATUS_INVALID_ARGUMENT));
+        }
+        self.view_box.max_x = self.view_box.min_x + width as f32;
+        self.view_box.max_y = self.view_box.min_y + height as f32;
+        Ok(())
+    }
+
+    fn save(&mut self) -> Result<(), Error> {
+        BackendRenderer::save(self.handle.get_mut());
+        self.save_depth += 1;
+        Ok(())
     }
 
-    pub fn transform(&mut self, matrix: &Mat2D) -> Result<(), Error> {
-        // SAFETY: valid pointers for call duration.
-        let status = unsafe { abi::rive_rs_webgpu_renderer_transform(self.as_raw(), matrix) };
-        status_result(status)
+    fn restore(&mut self) -> Result<(), Error> {
+        if self.save_depth == 0 {
+            return Err(Error::from_status(Status::RIVE_RS_STATUS_OUT_OF_RANGE));
+        }
+        BackendRenderer::restore(self.handle.get_mut());
+        self.save_depth -= 1;
+        Ok(())
     }
 
-    pub fn modulate_opacity(&mut self, opacity: f32) -> Result<(), Error> {
-        // SAFETY: valid handle.
-        let status =
-            unsafe { abi::rive_rs_webgpu_renderer_modulate_opacity(self.as_raw(), opacity) };
-        status_result(status)
+    fn transform(&mut self, matrix: &Mat2D) -> Result<(), Error> {
+        Self::transform(self, matrix)
     }
 
-    pub fn align(
+    fn modulate_opacity(&mut self, opacity: f32) -> Result<(), Error> {
+        if !opacity.is_finite() {
+            return Err(Error::from_status(Status::RIVE_RS_STATUS_INVALID_ARGUMENT));
+        }
+        BackendRenderer::modulate_opacity(self.handle.get_mut(), opacity);
+        Ok(())
+    }
+
+    fn align(
         &mut self,
         fit: Fit,
         alignment: Alignment,
@@ -1065,45 +2226,38 @@ impl WebGpuRenderer {
         content: &Aabb,
         scale_factor: f32,
     ) -> Result<(), Error> {
-        // SAFETY: valid pointers for call duration.
-        let status = unsafe {
-            abi::rive_rs_webgpu_renderer_align(
-                self.as_raw(),
-                fit,
-                alignment,
-                frame,
-                content,
-                scale_factor,
-            )
-        };
-        status_result(status)
+        Self::align(self, fit, alignment, frame, content, scale_factor)
     }
 
-    pub fn save_clip_rect(
+    fn save_clip_rect(
         &mut self,
         left: f32,
         top: f32,
         right: f32,
         bottom: f32,
     ) -> Result<(), Error> {
-        // SAFETY: valid handle.
-        let status = unsafe {
-            abi::rive_rs_webgpu_renderer_save_clip_rect(self.as_raw(), left, top, right, bottom)
-        };
-        status_result(status)
+        if !left.is_finite() || !top.is_finite() || !right.is_finite() || !bottom.is_finite() {
+            return Err(Error::from_status(Status::RIVE_RS_STATUS_INVALID_ARGUMENT));
+        }
+        self.handle
+            .get_mut()
+            .save_clip_rect(left, top, right, bottom);
+        self.save_depth += 1;
+        self.clip_depth += 1;
+        Ok(())
     }
 
-    pub fn restore_clip_rect(&mut self) -> Result<(), Error> {
-        // SAFETY: valid handle.
-        let status = unsafe { abi::rive_rs_webgpu_renderer_restore_clip_rect(self.as_raw()) };
-        status_result(status)
+    fn restore_clip_rect(&mut self) -> Result<(), Error> {
+        if self.clip_depth == 0 {
+            return Err(Error::from_status(Status::RIVE_RS_STATUS_OUT_OF_RANGE));
+        }
+        Renderer::restore(self)?;
+        self.clip_depth -= 1;
+        Ok(())
     }
-}
 
-impl Drop for WebGpuRenderer {
-    fn drop(&mut self) {
-        // SAFETY: valid handle.
-        unsafe { abi::rive_rs_webgpu_renderer_delete(self.as_raw()) };
+    fn draw_artboard(&mut self, artboard: &mut Artboard) -> Result<(), Error> {
+        artboard.draw_svg(self)
     }
 }
 
@@ -2015,6 +3169,29 @@ impl LinearAnimationInstance {
         // SAFETY: valid handle.
         unsafe { abi::rive_rs_linear_animation_instance_did_loop(self.as_raw()) }
     }
+
+    /// Number of audio events the timeline crossed during the last advance.
+    pub fn reported_audio_count(&self) -> usize {
+        // SAFETY: valid handle.
+        unsafe { abi::rive_rs_linear_animation_instance_reported_audio_count(self.as_raw()) }
+    }
+
+    /// The audio asset the `index`th audio event of the last advance plays.
+    /// The asset stays valid while its file is alive.
+    pub fn reported_audio_asset(&self, index: usize) -> Result<FileAsset, Error> {
+        let mut out = ptr::null_mut();
+        // SAFETY: valid handle and out pointer.
+        let status = unsafe {
+            abi::rive_rs_linear_animation_instance_reported_audio_asset(
+                self.as_raw(),
+                index,
+                &mut out,
+            )
+        };
+        status_result(status)?;
+        // SAFETY: the runtime returned an asset owned by the instance's file.
+        unsafe { FileAsset::from_raw(out) }
+    }
 }
 
 impl Drop for LinearAnimationInstance {
@@ -2039,6 +3216,158 @@ impl StateMachine {
         let name = unsafe { abi::rive_rs_state_machine_name(self.as_raw()) };
         string_from_view(name)
     }
+
+    pub fn layer_count(&self) -> usize {
+        // SAFETY: valid handle.
+        unsafe { abi::rive_rs_state_machine_layer_count(self.as_raw()) }
+    }
+
+    /// The state graph of every layer.
+    pub fn layers(&self) -> Result<Vec<StateMachineLayerInfo>, Error> {
+        let mut layers = Vec::with_capacity(self.layer_count());
+        for layer_index in 0..self.layer_count() {
+            let mut out = abi::rive_rs_state_machine_layer_info {
+                name: empty_str_view(),
+                state_count: 0,
+            };
+            // SAFETY: valid handle and out pointer.
+            let status = unsafe {
+                abi::rive_rs_state_machine_layer_at(self.as_raw(), layer_index, &mut out)
+            };
+            status_result(status)?;
+            let mut states = Vec::with_capacity(out.state_count);
+            for state_index in 0..out.state_count {
+                states.push(self.state_at(layer_index, state_index)?);
+            }
+            layers.push(StateMachineLayerInfo {
+                name: string_from_view(out.name),
+                states,
+            });
+        }
+        Ok(layers)
+    }
+
+    fn state_at(&self, layer_index: usize, state_index: usize) -> Result<LayerStateInfo, Error> {
+        let mut out = abi::rive_rs_layer_state_info {
+            r#type: LayerStateType::RIVE_RS_LAYER_STATE_OTHER,
+            name: empty_str_view(),
+            transition_count: 0,
+        };
+        // SAFETY: valid handle and out pointer.
+        let status = unsafe {
+            abi::rive_rs_state_machine_state_at(self.as_raw(), layer_index, state_index, &mut out)
+        };
+        status_result(status)?;
+        let mut transitions = Vec::with_capacity(out.transition_count);
+        for transition_index in 0..out.transition_count {
+            transitions.push(self.transition_at(layer_index, state_index, transition_index)?);
+        }
+        Ok(LayerStateInfo {
+            state_type: out.r#type,
+            name: string_from_view(out.name),
+            transitions,
+        })
+    }
+
+    fn transition_at(
+        &self,
+        layer_index: usize,
+        state_index: usize,
+        transition_index: usize,
+    ) -> Result<TransitionInfo, Error> {
+        let mut out = abi::rive_rs_state_transition_info {
+            has_target: false,
+            target_state: 0,
+            duration: 0,
+            duration_is_percentage: false,
+            enable_exit_time: false,
+            exit_time: 0,
+            exit_time_is_percentage: false,
+            disabled: false,
+            condition_count: 0,
+        };
+        // SAFETY: valid handle and out pointer.
+        let status = unsafe {
+            abi::rive_rs_state_machine_transition_at(
+                self.as_raw(),
+                layer_index,
+                state_index,
+                transition_index,
+                &mut out,
+            )
+        };
+        status_result(status)?;
+        let mut conditions = Vec::with_capacity(out.condition_count);
+        for condition_index in 0..out.condition_count {
+            let mut condition = abi::rive_rs_transition_condition_info {
+                r#type: ConditionType::RIVE_RS_CONDITION_OTHER,
+                has_input: false,
+                input_index: 0,
+                input_name: empty_str_view(),
+                op: ConditionOp::RIVE_RS_CONDITION_OP_EQUAL,
+                value: 0.0,
+            };
+            // SAFETY: valid handle and out pointer.
+            let status = unsafe {
+                abi::rive_rs_state_machine_condition_at(
+                    self.as_raw(),
+                    layer_index,
+                    state_index,
+                    transition_index,
+                    condition_index,
+                    &mut condition,
+                )
+            };
+            status_result(status)?;
+            conditions.push(TransitionConditionInfo {
+                condition_type: condition.r#type,
+                input: condition.has_input.then_some(condition.input_index),
+                input_name: string_from_view(condition.input_name),
+                op: condition.op,
+                value: condition.value,
+            });
+        }
+        Ok(TransitionInfo {
+            target_state: out.has_target.then_some(out.target_state),
+            duration: out.duration,
+            duration_is_percentage: out.duration_is_percentage,
+            enable_exit_time: out.enable_exit_time,
+            exit_time: out.exit_time,
+            exit_time_is_percentage: out.exit_time_is_percentage,
+            disabled: out.disabled,
+            conditions,
+        })
+    }
+
+    pub fn listener_count(&self) -> usize {
+        // SAFETY: valid handle.
+        unsafe { abi::rive_rs_state_machine_listener_count(self.as_raw()) }
+    }
+
+    pub fn listener_at(&self, index: usize) -> Result<ListenerInfo, Error> {
+        let mut out = abi::rive_rs_state_machine_listener_info {
+            name: empty_str_view(),
+            r#type: ListenerType::RIVE_RS_LISTENER_OTHER,
+            target_index: 0,
+            action_count: 0,
+        };
+        // SAFETY: valid handle and out pointer.
+        let status =
+            unsafe { abi::rive_rs_state_machine_listener_at(self.as_raw(), index, &mut out) };
+        status_result(status)?;
+        Ok(ListenerInfo {
+            name: string_from_view(out.name),
+            listener_type: out.r#type,
+            target_index: out.target_index,
+            action_count: out.action_count,
+        })
+    }
+
+    pub fn listeners(&self) -> Result<Vec<ListenerInfo>, Error> {
+        (0..self.listener_count())
+            .map(|index| self.listener_at(index))
+            .collect()
+    }
 }
 
 pub struct StateMachineInstance {
@@ -2105,6 +3434,17 @@ impl StateMachineInstance {
         })
     }
 
+    /// Finds an input by name; `NOT_FOUND` when the state machine has none.
+    pub fn input_by_name(&mut self, name: &str) -> Result<SmiInput, Error> {
+        for index in 0..self.input_count() {
+            let input = self.input(index)?;
+            if input.name() == name {
+                return Ok(input);
+            }
+        }
+        Err(Error::from_status(Status::RIVE_RS_STATUS_NOT_FOUND))
+    }
+
     pub fn pointer_down(&mut self, point: Vec2, pointer_id: i32) -> Result<(), Error> {
         // SAFETY: valid handle and plain value arguments.
         let status = unsafe {
@@ -2203,6 +3543,19 @@ impl StateMachineInstance {
         })
     }
 
+    /// The audio asset a reported audio event plays; `NOT_FOUND` for other
+    /// events. The asset stays valid while its file is alive.
+    pub fn reported_event_audio_asset(&self, index: usize) -> Result<FileAsset, Error> {
+        let mut out = ptr::null_mut();
+        // SAFETY: valid handle and out pointer.
+        let status = unsafe {
+            abi::rive_rs_state_machine_reported_event_audio_asset(self.as_raw(), index, &mut out)
+        };
+        status_result(status)?;
+        // SAFETY: the runtime returned an asset owned by the instance's file.
+        unsafe { FileAsset::from_raw(out) }
+    }
+
     pub fn state_changed_count(&self) -> usize {
         // SAFETY: valid handle.
         unsafe { abi::rive_rs_state_machine_state_changed_count(self.as_raw()) }
@@ -2218,6 +3571,59 @@ impl StateMachineInstance {
         Ok(string_from_view(out_name))
     }
 
+    pub fn layer_count(&self) -> usize {
+        // SAFETY: valid handle.
+        unsafe { abi::rive_rs_state_machine_instance_layer_count(self.as_raw()) }
+    }
+
+    /// The active state of every layer and the transition it is mixing
+    /// through. The provider tracks these from the states each advance
+    /// reports as changed; the transition is the first enabled one from the
+    /// previous state, or else from the any state, that targets the new one.
+    pub fn current_states(&self) -> Result<Vec<LayerCurrentState>, Error> {
+        let mut states = Vec::with_capacity(self.layer_count());
+        for layer_index in 0..self.layer_count() {
+            let mut out = abi::rive_rs_layer_current_state_info {
+                layer_name: empty_str_view(),
+                state_index: 0,
+                state_type: LayerStateType::RIVE_RS_LAYER_STATE_OTHER,
+                state_name: empty_str_view(),
+                elapsed_seconds: 0.0,
+                is_transitioning: false,
+                transition_state_index: 0,
+                transition_index: 0,
+                has_state_from: false,
+                state_from_index: 0,
+                mix: 1.0,
+                mix_seconds: 0.0,
+            };
+            // SAFETY: valid handle and out pointer.
+            let status = unsafe {
+                abi::rive_rs_state_machine_instance_current_state_at(
+                    self.as_raw(),
+                    layer_index,
+                    &mut out,
+                )
+            };
+            status_result(status)?;
+            states.push(LayerCurrentState {
+                layer_name: string_from_view(out.layer_name),
+                state: out.state_index,
+                state_type: out.state_type,
+                name: string_from_view(out.state_name),
+                elapsed_seconds: out.elapsed_seconds,
+                transition: out.is_transitioning.then(|| ActiveTransition {
+                    source_state: out.transition_state_index,
+                    transition: out.transition_index,
+                    from_state: out.has_state_from.then_some(out.state_from_index),
+                    mix: out.mix,
+                    mix_seconds: out.mix_seconds,
+                }),
+            });
+        }
+        Ok(states)
+    }
+
     pub fn bind_view_model_instance(&mut self, instance: &ViewModelInstance) -> Result<(), Error> {
         // SAFETY: valid handles for call duration.
         let status = unsafe {
@@ -2337,18 +3743,69 @@ impl SmiTrigger {
 
 pub struct AudioSource {
     raw: NonNull<abi::rive_rs_audio_source>,
+    // Samples decoded by the crate; `None` unless the source was a WAV file.
+    pcm: Option<Arc<Pcm>>,
 }
 
 impl AudioSource {
     pub fn as_raw(&self) -> *mut abi::rive_rs_audio_source {
         self.raw.as_ptr()
     }
+
+    /// Whether the crate decoded the samples itself, which the accessors
+    /// below need.
+    pub fn has_pcm(&self) -> bool {
+        self.pcm.is_some()
+    }
+
+    pub fn channels(&self) -> Option<u16> {
+        self.pcm.as_ref().map(|pcm| pcm.channels)
+    }
+
+    pub fn sample_rate(&self) -> Option<u32> {
+        self.pcm.as_ref().map(|pcm| pcm.sample_rate)
+    }
+
+    pub fn frame_count(&self) -> Option<usize> {
+        self.pcm.as_ref().map(|pcm| pcm.frames())
+    }
+
+    /// Length in seconds.
+    pub fn duration(&self) -> Option<f32> {
+        self.pcm
+            .as_ref()
+            .map(|pcm| (pcm.frames() as f64 / pcm.sample_rate as f64) as f32)
+    }
+
+    /// Interleaved samples in `[-1, 1]` at the source's own rate. Fails as
+    /// unsupported unless the source was a WAV file.
+    pub fn decode_pcm(&self) -> Result<Vec<f32>, Error> {
+        Ok(self.pcm()?.samples.clone())
+    }
+
+    /// Interleaved samples linearly resampled to `sample_rate`.
+    pub fn decode_pcm_at(&self, sample_rate: u32) -> Result<Vec<f32>, Error> {
+        if sample_rate == 0 {
+            return Err(Error::from_status(Status::RIVE_RS_STATUS_INVALID_ARGUMENT));
+        }
+        Ok(self.pcm()?.resample(sample_rate))
+    }
+
+    pub(crate) fn shared_pcm(&self) -> Option<Arc<Pcm>> {
+        self.pcm.clone()
+    }
+
+    fn pcm(&self) -> Result<&Pcm, Error> {
+        self.pcm
+            .as_deref()
+            .ok_or_else(|| Error::from_status(Status::RIVE_RS_STATUS_UNSUPPORTED))
+    }
 }
 
 impl Drop for AudioSource {
     fn drop(&mut self) {
         // SAFETY: intrusive ref-count decrement on valid handle.
-        unsafe { abi::rive_rs_audio_source_unref(self.as_raw()) };
+        unsafe { abi::rive_rs_audio_source_unref(self.raw.as_ptr()) };
     }
 }
 
@@ -2463,11 +3920,32 @@ impl FileAsset {
         status_result(status)
     }
 
+    /// Whether an image, font or audio asset has decoded contents attached.
+    pub fn is_loaded(&self) -> bool {
+        // SAFETY: valid handle.
+        unsafe { abi::rive_rs_file_asset_is_loaded(self.as_raw()) }
+    }
+
+    /// The image attached to an image asset.
+    pub fn render_image(&self) -> Option<RenderImage> {
+        let mut out = ptr::null_mut();
+        // SAFETY: valid handle and out pointer.
+        let status = unsafe { abi::rive_rs_image_asset_render_image(self.as_raw(), &mut out) };
+        if !status_ok(status) {
+            return None;
+        }
+        Some(RenderImage {
+            raw: NonNull::new(out)?,
+        })
+    }
+
     pub fn set_audio_source(&mut self, audio: &AudioSource) -> Result<(), Error> {
         // SAFETY: valid handles for call duration.
         let status =
             unsafe { abi::rive_rs_audio_asset_set_audio_source(self.as_raw(), audio.as_raw()) };
-        status_result(status)
+        status_result(status)?;
+        audio::note_attached(self, audio);
+        Ok(())
     }
 
     pub fn set_font(&mut self, font: &Font) -> Result<(), Error> {
@@ -2502,6 +3980,34 @@ impl RenderImage {
     pub fn as_raw(&self) -> *mut abi::rive_rs_render_image {
         self.raw.as_ptr()
     }
+
+    pub fn width(&self) -> u32 {
+        // SAFETY: valid render image handle.
+        unsafe { abi::rive_rs_render_image_width(self.as_raw()) }
+    }
+
+    pub fn height(&self) -> u32 {
+        // SAFETY: valid render image handle.
+        unsafe { abi::rive_rs_render_image_height(self.as_raw()) }
+    }
+
+    /// Unpremultiplied RGBA8 pixels, row-major with a stride of `width * 4`.
+    /// `None` when the factory that decoded the image does not keep pixels
+    /// (WebGL2/WebGPU images and the C no-op factory).
+    pub fn pixels(&self) -> Option<&[u8]> {
+        let mut view = abi::rive_rs_bytes_view {
+            ptr: ptr::null(),
+            len: 0,
+        };
+        // SAFETY: valid render image handle; out pointer is writable.
+        let status = unsafe { abi::rive_rs_render_image_pixels(self.as_raw(), &mut view) };
+        if !status_ok(status) || view.ptr.is_null() {
+            return None;
+        }
+        // SAFETY: the provider returns pixels owned by the image, which are
+        // immutable and live as long as this handle.
+        Some(unsafe { slice::from_raw_parts(view.ptr, view.len) })
+    }
 }
 
 impl Clone for RenderImage {
@@ -2519,14 +4025,158 @@ impl Drop for RenderImage {
     }
 }
 
+/// Offscreen surface renderers draw into after `bind_target`; its contents
+/// can be drawn by other artboards as a [`RenderImage`].
+pub struct RenderTarget {
+    raw: NonNull<abi::rive_rs_render_target>,
+}
+
+impl RenderTarget {
+    pub fn new(width: i32, height: i32) -> Result<Self, Error> {
+        let mut out = ptr::null_mut();
+        // SAFETY: out pointer is valid for call duration.
+        let status = unsafe { abi::rive_rs_render_target_new(width, height, &mut out) };
+        status_result(status)?;
+        Ok(Self {
+            raw: non_null(out)?,
+        })
+    }
+
+    pub fn as_raw(&self) -> *mut abi::rive_rs_render_target {
+        self.raw.as_ptr()
+    }
+
+    pub fn width(&self) -> i32 {
+        // SAFETY: valid handle.
+        unsafe { abi::rive_rs_render_target_width(self.as_raw()) }
+    }
+
+    pub fn height(&self) -> i32 {
+        // SAFETY: valid handle.
+        unsafe { abi::rive_rs_render_target_height(self.as_raw()) }
+    }
+
+    /// Image of the target's contents, created through `factory` so it can
+    /// be attached to artboards and view models using that factory. WebGL2
+    /// images share the target's texture and see later frames; other
+    /// factories copy the contents last flushed into the target.
+    pub fn to_image(&self, factory: &Factory) -> Result<RenderImage, Error> {
+        let mut out = ptr::null_mut();
+        // SAFETY: valid handles for call duration; out pointer is writable.
+        let status =
+            unsafe { abi::rive_rs_render_target_image(factory.as_raw(), self.as_raw(), &mut out) };
+        status_result(status)?;
+        Ok(RenderImage {
+            raw: non_null(out)?,
+        })
+    }
+}
+
+impl Clone for RenderTarget {
+    fn clone(&self) -> Self {
+        // SAFETY: intrusive ref-count increment on valid handle.
+        unsafe { abi::rive_rs_render_target_ref(self.as_raw()) };
+        Self { raw: self.raw }
+    }
+}
+
+impl Drop for RenderTarget {
+    fn drop(&mut self) {
+        // SAFETY: intrusive ref-count decrement on valid handle.
+        unsafe { abi::rive_rs_render_target_unref(self.as_raw()) };
+    }
+}
+
 pub struct Font {
     raw: NonNull<abi::rive_rs_font>,
+    face: Option<Arc<FontFace>>,
 }
 
 impl Font {
     pub fn as_raw(&self) -> *mut abi::rive_rs_font {
         self.raw.as_ptr()
     }
+
+    /// Whether the crate could read the font's tables, which the accessors
+    /// below need.
+    pub fn has_metadata(&self) -> bool {
+        self.face.is_some()
+    }
+
+    /// Typographic family, falling back to the legacy family name.
+    pub fn family_name(&self) -> Option<&str> {
+        self.face.as_deref().map(|face| face.family.as_str())
+    }
+
+    /// OpenType weight class, 100 (thin) to 900 (black).
+    pub fn weight(&self) -> Option<u16> {
+        self.face.as_deref().map(|face| face.weight)
+    }
+
+    pub fn style(&self) -> Option<FontStyle> {
+        self.face.as_deref().map(|face| face.style)
+    }
+
+    pub fn units_per_em(&self) -> Option<u16> {
+        self.face.as_deref().map(|face| face.units_per_em)
+    }
+
+    /// Distance from the baseline to the top of the line, in ems.
+    pub fn ascent(&self) -> Option<f32> {
+        let face = self.face.as_deref()?;
+        Some(ems(face, face.ascent.into()))
+    }
+
+    /// Distance from the baseline to the bottom of the line, in ems;
+    /// negative below the baseline.
+    pub fn descent(&self) -> Option<f32> {
+        let face = self.face.as_deref()?;
+        Some(ems(face, face.descent.into()))
+    }
+
+    /// Extra spacing between lines, in ems.
+    pub fn line_gap(&self) -> Option<f32> {
+        let face = self.face.as_deref()?;
+        Some(ems(face, face.line_gap.into()))
+    }
+
+    pub fn has_glyph(&self, c: char) -> Option<bool> {
+        Some(self.face.as_deref()?.glyph(c).is_some())
+    }
+
+    /// Horizontal advance of `c` in ems, or `None` if the font lacks it.
+    pub fn advance(&self, c: char) -> Option<f32> {
+        let face = self.face.as_deref()?;
+        Some(ems(face, face.advance(face.glyph(c)?)?.into()))
+    }
+
+    /// Characters of `text` the font has no glyph for, in first-seen order.
+    /// Control characters are ignored.
+    pub fn missing_glyphs(&self, text: &str) -> Option<Vec<char>> {
+        let face = self.face.as_deref()?;
+        let mut missing = Vec::new();
+        for c in text.chars() {
+            if !c.is_control() && !missing.contains(&c) && face.glyph(c).is_none() {
+                missing.push(c);
+            }
+        }
+        Some(missing)
+    }
+}
+
+fn ems(face: &FontFace, units: f32) -> f32 {
+    units / f32::from(face.units_per_em)
+}
+
+impl Clone for Font {
+    fn clone(&self) -> Self {
+        // SAFETY: intrusive ref-count increment on valid handle.
+        unsafe { abi::rive_rs_font_ref(self.as_raw()) };
+        Self {
+            raw: self.raw,
+            face: self.face.clone(),
+        }
+    }
 }
 
 impl Drop for Font {
@@ -2536,6 +4186,21 @@ impl Drop for Font {
     }
 }
 
+/// Installs the process-wide font fallback chain. Text runs whose font lacks
+/// a glyph take it from the first font in `fonts` that has it; an empty
+/// slice turns fallback off.
+///
+/// The chain lives in the runtime's global fallback hook, not in a
+/// [`Factory`]: it applies to text in every factory and file in the process,
+/// including ones loaded earlier, and replaces the chain other threads (or
+/// tests running in parallel) installed.
+pub fn set_font_fallbacks(fonts: &[Font]) -> Result<(), Error> {
+    let raw: Vec<_> = fonts.iter().map(Font::as_raw).collect();
+    // SAFETY: the handles are valid for the call; the provider retains them.
+    let status = unsafe { abi::rive_rs_set_font_fallbacks(raw.as_ptr(), raw.len()) };
+    status_result(status)
+}
+
 pub fn mat2d_invert(matrix: &Mat2D) -> Option<Mat2D> {
     let det = matrix.xx * matrix.yy - matrix.xy * matrix.yx;
     if det == 0.0 {
@@ -0,0 +1,509 @@
+use std::rc::Rc;
+
+use crate::backend::{
+    BackendBuffer, BackendImage, BackendRenderer, BlendMode, BufferType, FillRule, GradientStop,
+    ImageSampler, PaintStyle, RenderBackend,
+};
+use crate::raster::{
+    self, Contour, IDENTITY, ImagePixels, Mask, PathCommand, Pixmap, Point, transform_point,
+};
+use crate::recording::{
+    DisplayList, DrawCommand, PathVerb, RecordedBuffer, RecordedImage, RecordedPaint, RecordedPath,
+    RecordedShader, RecordingBackend, read_f32s, read_u16s,
+};
+use crate::runtime::{Mat2D, mat2d_invert, mat2d_multiply};
+use crate::{image, png};
+
+// Flattening tolerance in device pixels.
+const TOLERANCE: f32 = 0.25;
+
+/// Factory backend behind `Factory::new_software`. It builds the recording
+/// backend's objects, so artboards from either factory draw straight into a
+/// `SoftwareCanvas`, but decodes images up front. Paint feathering is
+/// recorded but not rasterized: feathered fills and strokes draw with hard
+/// edges.
+pub(crate) struct SoftwareBackend;
+
+impl RenderBackend for SoftwareBackend {
+    type Path = RecordedPath;
+    type Paint = RecordedPaint;
+    type Shader = RecordedShader;
+    type Image = RecordedImage;
+    type Buffer = RecordedBuffer;
+
+    fn make_path(&mut self, fill_rule: FillRule) -> RecordedPath {
+        RecordingBackend.make_path(fill_rule)
+    }
+
+    fn make_paint(&mut self) -> RecordedPaint {
+        RecordingBackend.make_paint()
+    }
+
+    fn make_linear_gradient(
+        &mut self,
+        start: (f32, f32),
+        end: (f32, f32),
+        stops: &[GradientStop],
+    ) -> RecordedShader {
+        RecordingBackend.make_linear_gradient(start, end, stops)
+    }
+
+    fn make_radial_gradient(
+        &mut self,
+        center: (f32, f32),
+        radius: f32,
+        stops: &[GradientStop],
+    ) -> RecordedShader {
+        RecordingBackend.make_radial_gradient(center, radius, stops)
+    }
+
+    fn decode_image(&mut self, bytes: &[u8]) -> Option<RecordedImage> {
+        let (width, height, rgba) = image::decode(bytes)?;
+        Some(RecordedImage::decoded(bytes.into(), width, height, rgba))
+    }
+
+    fn make_image(&mut self, width: u32, height: u32, rgba: &[u8]) -> Option<RecordedImage> {
+        if rgba.len() != width as usize * height as usize * 4 {
+            return None;
+        }
+        // Encoded too, so display lists holding the image still serialize.
+        let bytes = png::encode(width, height, rgba);
+        Some(RecordedImage::decoded(
+            bytes.into(),
+            width,
+            height,
+            rgba.to_vec(),
+        ))
+    }
+
+    fn make_buffer(&mut self, buffer_type: BufferType, flags: u32, size: usize) -> RecordedBuffer {
+        RecordingBackend.make_buffer(buffer_type, flags, size)
+    }
+}
+
+fn path_commands(path: &RecordedPath) -> Vec<PathCommand> {
+    path.verbs
+        .iter()
+        .map(|verb| match *verb {
+            PathVerb::MoveTo { x, y } => PathCommand::MoveTo(Point::new(x, y)),
+            PathVerb::LineTo { x, y } => PathCommand::LineTo(Point::new(x, y)),
+            PathVerb::CubicTo {
+                out_x,
+                out_y,
+                in_x,
+                in_y,
+                x,
+                y,
+            } => PathCommand::CubicTo(
+                Point::new(out_x, out_y),
+                Point::new(in_x, in_y),
+                Point::new(x, y),
+            ),
+            PathVerb::Close => PathCommand::Close,
+        })
+        .collect()
+}
+
+// The decoded pixels of `image`, if they cover its size.
+fn image_pixels(image: &RecordedImage) -> Option<ImagePixels<'_>> {
+    let (width, height) = (image.width() as usize, image.height() as usize);
+    let data = image
+        .pixels()
+        .filter(|data| data.len() == width * height * 4)?;
+    Some(ImagePixels {
+        width,
+        height,
+        data,
+    })
+}
+
+#[derive(Clone)]
+struct DrawState {
+    transform: Mat2D,
+    opacity: f32,
+    clip: Option<Rc<Mask>>,
+}
+
+impl Default for DrawState {
+    fn default() -> Self {
+        Self {
+            transform: IDENTITY,
+            opacity: 1.0,
+            clip: None,
+        }
+    }
+}
+
+/// CPU draw target behind `SoftwareRenderer`.
+pub(crate) struct SoftwareCanvas {
+    pixmap: Pixmap,
+    state: DrawState,
+    stack: Vec<DrawState>,
+}
+
+impl SoftwareCanvas {
+    pub(crate) fn new(width: usize, height: usize) -> Self {
+        Self {
+            pixmap: Pixmap::new(width, height),
+            state: DrawState::default(),
+            stack: Vec::new(),
+        }
+    }
+
+    pub(crate) fn width(&self) -> usize {
+        self.pixmap.width
+    }
+
+    pub(crate) fn height(&self) -> usize {
+        self.pixmap.height
+    }
+
+    pub(crate) fn pixels(&self) -> &[u8] {
+        &self.pixmap.data
+    }
+
+    pub(crate) fn clear(&mut self) {
+        self.pixmap.clear();
+    }
+
+    pub(crate) fn resize(&mut self, width: usize, height: usize) {
+        self.replace_pixmap(Pixmap::new(width, height));
+    }
+
+    /// Swaps in a new draw surface, returning the old one.
+    pub(crate) fn replace_pixmap(&mut self, pixmap: Pixmap) -> Pixmap {
+        // Clip masks are sized to the old target.
+        self.state.clip = None;
+        for state in &mut self.stack {
+            state.clip = None;
+        }
+        std::mem::replace(&mut self.pixmap, pixmap)
+    }
+
+    pub(crate) fn clip_rect(&mut self, left: f32, top: f32, right: f32, bottom: f32) {
+        let commands = [
+            PathCommand::MoveTo(Point::new(left, top)),
+            PathCommand::LineTo(Point::new(right, top)),
+            PathCommand::LineTo(Point::new(right, bottom)),
+            PathCommand::LineTo(Point::new(left, bottom)),
+            PathCommand::Close,
+        ];
+        self.clip_commands(&commands, FillRule::RIVE_RS_FILL_RULE_NON_ZERO);
+    }
+
+    fn clip_commands(&mut self, commands: &[PathCommand], fill_rule: FillRule) {
+        let (width, height) = (self.pixmap.width, self.pixmap.height);
+        let contours = raster::flatten(commands, &self.state.transform, TOLERANCE);
+        let clip =
+            raster::fill_mask(&contours, fill_rule, width, height).unwrap_or_else(Mask::empty);
+        let clip = match &self.state.clip {
+            Some(previous) => clip.intersect(previous),
+            None => clip,
+        };
+        self.state.clip = Some(clip.into());
+    }
+
+    fn fill(
+        &mut self,
+        mask: &Mask,
+        blend_mode: BlendMode,
+        opacity: f32,
+        shade: impl FnMut(f32, f32) -> [f32; 4],
+    ) {
+        let clip = self.state.clip.clone();
+        self.pixmap
+            .fill(mask, clip.as_deref(), opacity, blend_mode, shade);
+    }
+
+    // Feathering is not supported; feathered paints render with hard edges.
+    fn path_mask(&self, path: &RecordedPath, paint: &RecordedPaint) -> Option<Mask> {
+        let (width, height) = (self.pixmap.width, self.pixmap.height);
+        let transform = &self.state.transform;
+        let commands = path_commands(path);
+        if paint.style == PaintStyle::RIVE_RS_PAINT_STYLE_FILL {
+            let contours = raster::flatten(&commands, transform, TOLERANCE);
+            return raster::fill_mask(&contours, path.fill_rule, width, height);
+        }
+
+        let scale = raster::matrix_scale(transform);
+        let visible = paint.thickness > 0.0 && scale > 0.0;
+        if !visible {
+            return None;
+        }
+        // Stroke in local space so non-uniform transforms skew the outline.
+        let contours = raster::flatten(&commands, &IDENTITY, TOLERANCE / scale);
+        let mut outline = raster::stroke(&contours, paint.thickness, paint.join, paint.cap, scale);
+        raster::transform_contours(&mut outline, transform);
+        raster::fill_mask(
+            &outline,
+            FillRule::RIVE_RS_FILL_RULE_NON_ZERO,
+            width,
+            height,
+        )
+    }
+
+    fn rect_mask(&self, width: f32, height: f32) -> Option<Mask> {
+        let contour = Contour {
+            points: [
+                Point::new(0.0, 0.0),
+                Point::new(width, 0.0),
+                Point::new(width, height),
+                Point::new(0.0, height),
+            ]
+            .iter()
+            .map(|&p| transform_point(&self.state.transform, p))
+            .collect(),
+            closed: true,
+        };
+        raster::fill_mask(
+            &[contour],
+            FillRule::RIVE_RS_FILL_RULE_NON_ZERO,
+            self.pixmap.width,
+            self.pixmap.height,
+        )
+    }
+}
+
+impl SoftwareCanvas {
+    /// Draws `list` without rebuilding its objects, so images decode at most
+    /// once per recorded image.
+    pub(crate) fn draw_list(&mut self, list: &DisplayList) {
+        for command in &list.commands {
+            match command {
+                DrawCommand::Save => self.save(),
+                DrawCommand::Restore => self.restore(),
+                DrawCommand::Transform(matrix) => self.transform(matrix),
+                DrawCommand::ModulateOpacity(opacity) => self.modulate_opacity(*opacity),
+                DrawCommand::DrawPath { path, paint } => self.draw_path(path, paint),
+                DrawCommand::ClipPath(path) => self.clip_path(path),
+                DrawCommand::DrawImage {
+                    image,
+                    sampler,
+                    blend_mode,
+                    opacity,
+                } => self.draw_image(image, *sampler, *blend_mode, *opacity),
+                DrawCommand::DrawImageMesh {
+                    image,
+                    sampler,
+                    vertices,
+                    uv_coords,
+                    indices,
+                    blend_mode,
+                    opacity,
+                } => {
+                    let Some(pixels) = image_pixels(image) else {
+                        continue;
+                    };
+                    self.draw_mesh(
+                        &pixels,
+                        *sampler,
+                        vertices,
+                        uv_coords,
+                        indices,
+                        (vertices.len() / 2) as u32,
+                        indices.len() as u32,
+                        *blend_mode,
+                        self.state.opacity * opacity,
+                    );
+                }
+            }
+        }
+    }
+
+    // `opacity` already includes the state's opacity.
+    #[allow(clippy::too_many_arguments)]
+    fn draw_mesh(
+        &mut self,
+        pixels: &ImagePixels<'_>,
+        sampler: ImageSampler,
+        vertices: &[f32],
+        uv_coords: &[f32],
+        indices: &[u16],
+        vertex_count: u32,
+        index_count: u32,
+        blend_mode: BlendMode,
+        opacity: f32,
+    ) {
+        let vertex_count = (vertex_count as usize)
+            .min(vertices.len() / 2)
+            .min(uv_coords.len() / 2);
+        let index_count = (index_count as usize).min(indices.len());
+        let (image_width, image_height) = (pixels.width as f32, pixels.height as f32);
+
+        for triangle in indices[..index_count].chunks_exact(3) {
+            let corners = [
+                triangle[0] as usize,
+                triangle[1] as usize,
+                triangle[2] as usize,
+            ];
+            if corners.iter().any(|&index| index >= vertex_count) {
+                continue;
+            }
+            let device = corners.map(|index| {
+                transform_point(
+                    &self.state.transform,
+                    Point::new(vertices[index * 2], vertices[index * 2 + 1]),
+                )
+            });
+            let uv =
+                corners.map(|index| Point::new(uv_coords[index * 2], uv_coords[index * 2 + 1]));
+            let area = (device[1].x - device[0].x) * (device[2].y - device[0].y)
+                - (device[2].x - device[0].x) * (device[1].y - device[0].y);
+            if area == 0.0 || !area.is_finite() {
+                continue;
+            }
+            let contour = Contour {
+                points: device.to_vec(),
+                closed: true,
+            };
+            let Some(mask) = raster::fill_mask(
+                &[contour],
+                FillRule::RIVE_RS_FILL_RULE_NON_ZERO,
+                self.pixmap.width,
+                self.pixmap.height,
+            ) else {
+                continue;
+            };
+            self.fill(&mask, blend_mode, opacity, |x, y| {
+                let w1 = ((x - device[0].x) * (device[2].y - device[0].y)
+                    - (device[2].x - device[0].x) * (y - device[0].y))
+                    / area;
+                let w2 = ((device[1].x - device[0].x) * (y - device[0].y)
+                    - (x - device[0].x) * (device[1].y - device[0].y))
+                    / area;
+                let w0 = 1.0 - w1 - w2;
+                let u = uv[0].x * w0 + uv[1].x * w1 + uv[2].x * w2;
+                let v = uv[0].y * w0 + uv[1].y * w1 + uv[2].y * w2;
+                pixels.sample(u * image_width, v * image_height, sampler)
+            });
+        }
+    }
+}
+
+impl BackendRenderer for SoftwareCanvas {
+    type Backend = SoftwareBackend;
+
+    fn save(&mut self) {
+        self.stack.push(self.state.clone());
+    }
+
+    fn restore(&mut self) {
+        if let Some(state) = self.stack.pop() {
+            self.state = state;
+        }
+    }
+
+    fn transform(&mut self, matrix: &Mat2D) {
+        self.state.transform = mat2d_multiply(&self.state.transform, matrix);
+    }
+
+    fn modulate_opacity(&mut self, opacity: f32) {
+        self.state.opacity = (self.state.opacity * opacity).clamp(0.0, 1.0);
+    }
+
+    fn draw_path(&mut self, path: &RecordedPath, paint: &RecordedPaint) {
+        let opacity = self.state.opacity;
+        if opacity <= 0.0 {
+            return;
+        }
+        let Some(mask) = self.path_mask(path, paint) else {
+            return;
+        };
+        match &paint.shader {
+            None => {
+                let color = raster::unpack_argb(paint.color);
+                self.fill(&mask, paint.blend_mode, opacity, |_, _| color);
+            }
+            Some(shader) => {
+                let Some(inverse) = mat2d_invert(&self.state.transform) else {
+                    return;
+                };
+                self.fill(&mask, paint.blend_mode, opacity, |x, y| {
+                    let p = transform_point(&inverse, Point::new(x, y));
+                    match shader {
+                        RecordedShader::Linear { start, end, stops } => {
+                            let (dx, dy) = (end.0 - start.0, end.1 - start.1);
+                            let length_squared = dx * dx + dy * dy;
+                            let t = if length_squared > 0.0 {
+                                ((p.x - start.0) * dx + (p.y - start.1) * dy) / length_squared
+                            } else {
+                                0.0
+                            };
+                            raster::gradient_color(stops, t)
+                        }
+                        RecordedShader::Radial {
+                            center,
+                            radius,
+                            stops,
+                        } => {
+                            let distance =
+                                ((p.x - center.0).powi(2) + (p.y - center.1).powi(2)).sqrt();
+                            let t = if *radius > 0.0 {
+                                distance / radius
+                            } else {
+                                1.0
+                            };
+                            raster::gradient_color(stops, t)
+                        }
+                    }
+                });
+            }
+        }
+    }
+
+    fn clip_path(&mut self, path: &RecordedPath) {
+        self.clip_commands(&path_commands(path), path.fill_rule);
+    }
+
+    fn draw_image(
+        &mut self,
+        image: &RecordedImage,
+        sampler: ImageSampler,
+        blend_mode: BlendMode,
+        opacity: f32,
+    ) {
+        let opacity = self.state.opacity * opacity;
+        let Some(pixels) = image_pixels(image) else {
+            return;
+        };
+        let Some(inverse) = mat2d_invert(&self.state.transform) else {
+            return;
+        };
+        let Some(mask) = self.rect_mask(pixels.width as f32, pixels.height as f32) else {
+            return;
+        };
+        self.fill(&mask, blend_mode, opacity, |x, y| {
+            let p = transform_point(&inverse, Point::new(x, y));
+            pixels.sample(p.x, p.y, sampler)
+        });
+    }
+
+    fn draw_image_mesh(
+        &mut self,
+        image: &RecordedImage,
+        sampler: ImageSampler,
+        vertices: &RecordedBuffer,
+        uv_coords: &RecordedBuffer,
+        indices: &RecordedBuffer,
+        vertex_count: u32,
+        index_count: u32,
+        blend_mode: BlendMode,
+        opacity: f32,
+    ) {
+        let opacity = self.state.opacity * opacity;
+        let Some(pixels) = image_pixels(image) else {
+            return;
+        };
+        self.draw_mesh(
+            &pixels,
+            sampler,
+            &read_f32s(vertices.bytes()),
+            &read_f32s(uv_coords.bytes()),
+            &read_u16s(indices.bytes()),
+            vertex_count,
+            index_count,
+            blend_mode,
+            opacity,
+        );
+    }
+}
@@ -0,0 +1,532 @@
+use std::fmt::Write;
+
+use crate::backend::{
+    BlendMode, FillRule, GradientStop, ImageFilter, PaintStyle, StrokeCap, StrokeJoin,
+};
+use crate::image;
+use crate::raster::IDENTITY;
+use crate::recording::{
+    DisplayList, DrawCommand, PathVerb, RecordedImage, RecordedPaint, RecordedPath, RecordedShader,
+};
+use crate::runtime::{Aabb, Mat2D, mat2d_multiply};
+
+#[derive(Clone)]
+struct State {
+    transform: Mat2D,
+    opacity: f32,
+    clips: Vec<usize>,
+}
+
+struct SvgWriter {
+    defs: String,
+    body: String,
+    next_id: usize,
+    // Clip groups currently open in `body`, outermost first.
+    open_clips: Vec<usize>,
+}
+
+impl DisplayList {
+    /// Converts the display list into a standalone SVG document whose
+    /// `viewBox` is `view_box`.
+    ///
+    /// SVG has no clockwise fill rule (drawn as non-zero) and no feathering
+    /// (ignored). Images whose format is not recognised are skipped.
+    pub fn to_svg(&self, view_box: &Aabb) -> String {
+        let mut writer = SvgWriter {
+            defs: String::new(),
+            body: String::new(),
+            next_id: 0,
+            open_clips: Vec::new(),
+        };
+        let mut state = State {
+            transform: IDENTITY,
+            opacity: 1.0,
+            clips: Vec::new(),
+        };
+        let mut stack = Vec::new();
+
+        for command in &self.commands {
+            match command {
+                DrawCommand::Save => stack.push(state.clone()),
+                DrawCommand::Restore => {
+                    if let Some(saved) = stack.pop() {
+                        state = saved;
+                    }
+                }
+                DrawCommand::Transform(matrix) => {
+                    state.transform = mat2d_multiply(&state.transform, matrix);
+                }
+                DrawCommand::ModulateOpacity(opacity) => {
+                    state.opacity = (state.opacity * opacity).clamp(0.0, 1.0);
+                }
+                DrawCommand::ClipPath(path) => {
+                    let id = writer.clip(path, &state.transform);
+                    state.clips.push(id);
+                }
+                DrawCommand::DrawPath { path, paint } => writer.draw_path(&state, path, paint),
+                DrawCommand::DrawImage {
+                    image,
+                    sampler,
+                    blend_mode,
+                    opacity,
+                } => {
+                    let mut attributes = String::new();
+                    push_blend_style(&mut attributes, *blend_mode, sampler.filter);
+                    writer.draw_image(
+                        &state,
+                        image,
+                        &state.transform,
+                        state.opacity * opacity,
+                        &attributes,
+                    );
+                }
+                DrawCommand::DrawImageMesh {
+                    image,
+                    sampler,
+                    vertices,
+                    uv_coords,
+                    indices,
+                    blend_mode,
+                    opacity,
+                } => {
+                    let mut attributes = String::new();
+                    push_blend_style(&mut attributes, *blend_mode, sampler.filter);
+                    writer.draw_image_mesh(
+                        &state,
+                        image,
+                        vertices,
+                        uv_coords,
+                        indices,
+                        state.opacity * opacity,
+                        &attributes,
+                    );
+                }
+            }
+        }
+        writer.sync_clips(&[]);
+
+        let width = view_box.max_x - view_box.min_x;
+        let height = view_box.max_y - view_box.min_y;
+        let mut out = String::new();
+        let _ = writeln!(
+            out,
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" \
+             xmlns:xlink=\"http://www.w3.org/1999/xlink\" \
+             width=\"{}\" height=\"{}\" viewBox=\"{} {} {} {}\">",
+            num(width),
+            num(height),
+            num(view_box.min_x),
+            num(view_box.min_y),
+            num(width),
+            num(height),
+        );
+        if !writer.defs.is_empty() {
+            out.push_str("<defs>\n");
+            out.push_str(&writer.defs);
+            out.push_str("</defs>\n");
+        }
+        out.push_str(&writer.body);
+        out.push_str("</svg>\n");
+        out
+    }
+}
+
+impl SvgWriter {
+    fn id(&mut self) -> usize {
+        self.next_id += 1;
+        self.next_id
+    }
+
+    fn clip(&mut self, path: &RecordedPath, transform: &Mat2D) -> usize {
+        let id = self.id();
+        let _ = writeln!(
+            self.defs,
+            "<clipPath id=\"c{id}\" clipPathUnits=\"userSpaceOnUse\">\
+             <path d=\"{}\" clip-rule=\"{}\" transform=\"{}\"/></clipPath>",
+            path_data(&path.verbs),
+            fill_rule(path.fill_rule),
+            matrix(transform),
+        );
+        id
+    }
+
+    /// Closes and opens `<g clip-path>` groups so that exactly `clips` are
+    /// applied to the next element.
+    fn sync_clips(&mut self, clips: &[usize]) {
+        let shared = self
+            .open_clips
+            .iter()
+            .zip(clips)
+            .take_while(|(open, wanted)| open == wanted)
+            .count();
+        for _ in shared..self.open_clips.len() {
+            self.body.push_str("</g>\n");
+        }
+        self.open_clips.truncate(shared);
+        for &id in &clips[shared..] {
+            let _ = writeln!(self.body, "<g clip-path=\"url(#c{id})\">");
+            self.open_clips.push(id);
+        }
+    }
+
+    fn gradient(&mut self, shader: &RecordedShader) -> usize {
+        let id = self.id();
+        let stops = match shader {
+            RecordedShader::Linear { start, end, stops } => {
+                let _ = write!(
+                    self.defs,
+                    "<linearGradient id=\"g{id}\" gradientUnits=\"userSpaceOnUse\" \
+                     x1=\"{}\" y1=\"{}\" x2=\"{}\" y2=\"{}\">",
+                    num(start.0),
+                    num(start.1),
+                    num(end.0),
+                    num(end.1),
+                );
+                stops
+            }
+            RecordedShader::Radial {
+                center,
+                radius,
+                stops,
+            } => {
+                let _ = write!(
+                    self.defs,
+                    "<radialGradient id=\"g{id}\" gradientUnits=\"userSpaceOnUse\" \
+                     cx=\"{}\" cy=\"{}\" r=\"{}\">",
+                    num(center.0),
+                    num(center.1),
+                    num(*radius),
+                );
+                stops
+            }
+        };
+        push_stops(&mut self.defs, stops);
+        self.defs.push_str(match shader {
+            RecordedShader::Linear { .. } => "</linearGradient>\n",
+            RecordedShader::Radial { .. } => "</radialGradient>\n",
+        });
+        id
+    }
+
+    fn draw_path(&mut self, state: &State, path: &RecordedPath, paint: &RecordedPaint) {
+        let stroke = paint.style == PaintStyle::RIVE_RS_PAINT_STYLE_STROKE;
+        if state.opacity <= 0.0 || path.verbs.is_empty() || (stroke && paint.thickness <= 0.0) {
+            return;
+        }
+        let fill = match &paint.shader {
+            Some(shader) => format!("url(#g{})", self.gradient(shader)),
+            None => hex_color(paint.color),
+        };
+        let alpha = match paint.shader {
+            Some(_) => 1.0,
+            None => (paint.color >> 24) as f32 / 255.0,
+        };
+
+        self.sync_clips(&state.clips);
+        let _ = write!(
+            self.body,
+            "<path d=\"{}\" transform=\"{}\"",
+            path_data(&path.verbs),
+            matrix(&state.transform)
+        );
+        if stroke {
+            let _ = write!(
+                self.body,
+                " fill=\"none\" stroke=\"{fill}\" stroke-width=\"{}\" \
+                 stroke-linecap=\"{}\" stroke-linejoin=\"{}\"",
+                num(paint.thickness),
+                stroke_cap(paint.cap),
+                stroke_join(paint.join),
+            );
+            if paint.join == StrokeJoin::RIVE_RS_STROKE_JOIN_MITER {
+                self.body.push_str(" stroke-miterlimit=\"4\"");
+            }
+            if alpha < 1.0 {
+                let _ = write!(self.body, " stroke-opacity=\"{}\"", num(alpha));
+            }
+        } else {
+            let _ = write!(
+                self.body,
+                " fill=\"{fill}\" fill-rule=\"{}\"",
+                fill_rule(path.fill_rule)
+            );
+            if alpha < 1.0 {
+                let _ = write!(self.body, " fill-opacity=\"{}\"", num(alpha));
+            }
+        }
+        if state.opacity < 1.0 {
+            let _ = write!(self.body, " opacity=\"{}\"", num(state.opacity));
+        }
+        push_blend_style(
+            &mut self.body,
+            paint.blend_mode,
+            ImageFilter::RIVE_RS_IMAGE_FILTER_BILINEAR,
+        );
+        self.body.push_str("/>\n");
+    }
+
+    fn draw_image(
+        &mut self,
+        state: &State,
+        image: &RecordedImage,
+        transform: &Mat2D,
+        opacity: f32,
+        attributes: &str,
+    ) {
+        let Some(header) = image::sniff(&image.bytes) else {
+            return;
+        };
+        if opacity <= 0.0 {
+            return;
+        }
+        self.sync_clips(&state.clips);
+        let _ = write!(
+            self.body,
+            "<image width=\"{}\" height=\"{}\" preserveAspectRatio=\"none\" \
+             transform=\"{}\" xlink:href=\"data:{};base64,{}\"",
+            header.width,
+            header.height,
+            matrix(transform),
+            header.format.mime_type(),
+            base64(&image.bytes),
+        );
+        if opacity < 1.0 {
+            let _ = write!(self.body, " opacity=\"{}\"", num(opacity));
+        }
+        self.body.push_str(attributes);
+        self.body.push_str("/>\n");
+    }
+
+    // SVG has no textured meshes, so each triangle becomes the image mapped
+    // by the affine transform taking its UV triangle onto its vertex
+    // triangle, clipped to that triangle.
+    #[allow(clippy::too_many_arguments)]
+    fn draw_image_mesh(
+        &mut self,
+        state: &State,
+        image: &RecordedImage,
+        vertices: &[f32],
+        uv_coords: &[f32],
+        indices: &[u16],
+        opacity: f32,
+        attributes: &str,
+    ) {
+        let Some(header) = image::sniff(&image.bytes) else {
+            return;
+        };
+        let (width, height) = (header.width as f32, header.height as f32);
+        let vertex_count = vertices.len().min(uv_coords.len()) / 2;
+
+        for triangle in indices.chunks_exact(3) {
+            let corners = [
+                triangle[0] as usize,
+                triangle[1] as usize,
+                triangle[2] as usize,
+            ];
+            if corners.iter().any(|&index| index >= vertex_count) {
+                continue;
+            }
+            let dst = corners.map(|i| (vertices[i * 2], vertices[i * 2 + 1]));
+            let src = corners.map(|i| (uv_coords[i * 2] * width, uv_coords[i * 2 + 1] * height));
+            let Some(mapping) = triangle_mapping(src, dst) else {
+                continue;
+            };
+
+            let id = self.id();
+            let _ = writeln!(
+                self.defs,
+                "<clipPath id=\"c{id}\" clipPathUnits=\"userSpaceOnUse\">\
+                 <path d=\"M{} {}L{} {}L{} {}Z\" transform=\"{}\"/></clipPath>",
+                num(dst[0].0),
+                num(dst[0].1),
+                num(dst[1].0),
+                num(dst[1].1),
+                num(dst[2].0),
+                num(dst[2].1),
+                matrix(&state.transform),
+            );
+            let mut clipped = state.clone();
+            clipped.clips.push(id);
+            let transform = mat2d_multiply(&state.transform, &mapping);
+            self.draw_image(&clipped, image, &transform, opacity, attributes);
+        }
+    }
+}
+
+fn triangle_mapping(src: [(f32, f32); 3], dst: [(f32, f32); 3]) -> Option<Mat2D> {
+    let (s1x, s1y) = (src[1].0 - src[0].0, src[1].1 - src[0].1);
+    let (s2x, s2y) = (src[2].0 - src[0].0, src[2].1 - src[0].1);
+    let (d1x, d1y) = (dst[1].0 - dst[0].0, dst[1].1 - dst[0].1);
+    let (d2x, d2y) = (dst[2].0 - dst[0].0, dst[2].1 - dst[0].1);
+    let det = s1x * s2y - s2x * s1y;
+    if det.abs() < f32::EPSILON {
+        return None;
+    }
+    let inv = 1.0 / det;
+    let xx = (d1x * s2y - d2x * s1y) * inv;
+    let yx = (d2x * s1x - d1x * s2x) * inv;
+    let xy = (d1y * s2y - d2y * s1y) * inv;
+    let yy = (d2y * s1x - d1y * s2x) * inv;
+    Some(Mat2D {
+        xx,
+        xy,
+        yx,
+        yy,
+        tx: dst[0].0 - xx * src[0].0 - yx * src[0].1,
+        ty: dst[0].1 - xy * src[0].0 - yy * src[0].1,
+    })
+}
+
+fn num(value: f32) -> String {
+    if !value.is_finite() || value == 0.0 {
+        return "0".to_string();
+    }
+    let rounded = (value * 1000.0).round() / 1000.0;
+    if rounded == 0.0 {
+        "0".to_string()
+    } else {
+        rounded.to_string()
+    }
+}
+
+fn matrix(m: &Mat2D) -> String {
+    format!(
+        "matrix({} {} {} {} {} {})",
+        num(m.xx),
+        num(m.xy),
+        num(m.yx),
+        num(m.yy),
+        num(m.tx),
+        num(m.ty)
+    )
+}
+
+fn path_data(verbs: &[PathVerb]) -> String {
+    let mut out = String::new();
+    for verb in verbs {
+        let _ = match *verb {
+            PathVerb::MoveTo { x, y } => write!(out, "M{} {}", num(x), num(y)),
+            PathVerb::LineTo { x, y } => write!(out, "L{} {}", num(x), num(y)),
+            PathVerb::CubicTo {
+                out_x,
+                out_y,
+                in_x,
+                in_y,
+                x,
+                y,
+            } => write!(
+                out,
+                "C{} {} {} {} {} {}",
+                num(out_x),
+                num(out_y),
+                num(in_x),
+                num(in_y),
+                num(x),
+                num(y)
+            ),
+            PathVerb::Close => write!(out, "Z"),
+        };
+    }
+    out
+}
+
+fn hex_color(argb: u32) -> String {
+    format!("#{:06x}", argb & 0x00ff_ffff)
+}
+
+fn push_stops(out: &mut String, stops: &[GradientStop]) {
+    for stop in stops {
+        let _ = write!(
+            out,
+            "<stop offset=\"{}\" stop-color=\"{}\"",
+            num(stop.position.clamp(0.0, 1.0)),
+            hex_color(stop.color)
+        );
+        let alpha = (stop.color >> 24) as f32 / 255.0;
+        if alpha < 1.0 {
+            let _ = write!(out, " stop-opacity=\"{}\"", num(alpha));
+        }
+        out.push_str("/>");
+    }
+}
+
+fn push_blend_style(out: &mut String, blend_mode: BlendMode, filter: ImageFilter) {
+    let blend = blend_mode_name(blend_mode);
+    let pixelated = filter == ImageFilter::RIVE_RS_IMAGE_FILTER_NEAREST;
+    if blend.is_none() && !pixelated {
+        return;
+    }
+    out.push_str(" style=\"");
+    if let Some(blend) = blend {
+        let _ = write!(out, "mix-blend-mode:{blend};");
+    }
+    if pixelated {
+        out.push_str("image-rendering:pixelated;");
+    }
+    out.push('"');
+}
+
+fn fill_rule(fill_rule: FillRule) -> &'static str {
+    match fill_rule {
+        FillRule::RIVE_RS_FILL_RULE_EVEN_ODD => "evenodd",
+        FillRule::RIVE_RS_FILL_RULE_NON_ZERO | FillRule::RIVE_RS_FILL_RULE_CLOCKWISE => "nonzero",
+    }
+}
+
+fn stroke_cap(cap: StrokeCap) -> &'static str {
+    match cap {
+        StrokeCap::RIVE_RS_STROKE_CAP_BUTT => "butt",
+        StrokeCap::RIVE_RS_STROKE_CAP_ROUND => "round",
+        StrokeCap::RIVE_RS_STROKE_CAP_SQUARE => "square",
+    }
+}
+
+fn stroke_join(join: StrokeJoin) -> &'static str {
+    match join {
+        StrokeJoin::RIVE_RS_STROKE_JOIN_MITER => "miter",
+        StrokeJoin::RIVE_RS_STROKE_JOIN_ROUND => "round",
+        StrokeJoin::RIVE_RS_STROKE_JOIN_BEVEL => "bevel",
+    }
+}
+
+fn blend_mode_name(blend_mode: BlendMode) -> Option<&'static str> {
+    Some(match blend_mode {
+        BlendMode::RIVE_RS_BLEND_MODE_SRC_OVER => return None,
+        BlendMode::RIVE_RS_BLEND_MODE_SCREEN => "screen",
+        BlendMode::RIVE_RS_BLEND_MODE_OVERLAY => "overlay",
+        BlendMode::RIVE_RS_BLEND_MODE_DARKEN => "darken",
+        BlendMode::RIVE_RS_BLEND_MODE_LIGHTEN => "lighten",
+        BlendMode::RIVE_RS_BLEND_MODE_COLOR_DODGE => "color-dodge",
+        BlendMode::RIVE_RS_BLEND_MODE_COLOR_BURN => "color-burn",
+        BlendMode::RIVE_RS_BLEND_MODE_HARD_LIGHT => "hard-light",
+        BlendMode::RIVE_RS_BLEND_MODE_SOFT_LIGHT => "soft-light",
+        BlendMode::RIVE_RS_BLEND_MODE_DIFFERENCE => "difference",
+        BlendMode::RIVE_RS_BLEND_MODE_EXCLUSION => "exclusion",
+        BlendMode::RIVE_RS_BLEND_MODE_MULTIPLY => "multiply",
+        BlendMode::RIVE_RS_BLEND_MODE_HUE => "hue",
+        BlendMode::RIVE_RS_BLEND_MODE_SATURATION => "saturation",
+        BlendMode::RIVE_RS_BLEND_MODE_COLOR => "color",
+        BlendMode::RIVE_RS_BLEND_MODE_LUMINOSITY => "luminosity",
+    })
+}
+
+fn base64(bytes: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+    let mut out = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let b = [
+            chunk[0],
+            chunk.get(1).copied().unwrap_or(0),
+            chunk.get(2).copied().unwrap_or(0),
+        ];
+        let n = (b[0] as u32) << 16 | (b[1] as u32) << 8 | b[2] as u32;
+        for i in 0..4 {
+            if i <= chunk.len() {
+                out.push(ALPHABET[(n >> (18 - 6 * i)) as usize & 63] as char);
+            } else {
+                out.push('=');
+            }
+        }
+    }
+    out
+}
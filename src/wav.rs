@@ -0,0 +1,129 @@
+// RIFF/WAVE decoder: integer PCM at 8, 16, 24 and 32 bits and IEEE float
+// at 32 and 64 bits, including WAVE_FORMAT_EXTENSIBLE wrappers.
+
+const FORMAT_PCM: u16 = 1;
+const FORMAT_FLOAT: u16 = 3;
+const FORMAT_EXTENSIBLE: u16 = 0xfffe;
+
+/// Decoded audio as interleaved `f32` samples in `[-1, 1]`.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Pcm {
+    pub(crate) sample_rate: u32,
+    pub(crate) channels: u16,
+    pub(crate) samples: Vec<f32>,
+}
+
+impl Pcm {
+    pub(crate) fn frames(&self) -> usize {
+        self.samples.len() / self.channels as usize
+    }
+
+    /// Linearly resamples to `sample_rate`, keeping the channel layout.
+    pub(crate) fn resample(&self, sample_rate: u32) -> Vec<f32> {
+        let channels = self.channels as usize;
+        let frames = self.frames();
+        if sample_rate == self.sample_rate || frames == 0 {
+            return self.samples.clone();
+        }
+        let out_frames =
+            (frames as u64 * sample_rate as u64).div_ceil(self.sample_rate as u64) as usize;
+        let step = self.sample_rate as f64 / sample_rate as f64;
+        let mut out = Vec::with_capacity(out_frames * channels);
+        for frame in 0..out_frames {
+            let position = frame as f64 * step;
+            let index = (position as usize).min(frames - 1);
+            let next = (index + 1).min(frames - 1);
+            let t = (position - index as f64) as f32;
+            for channel in 0..channels {
+                let from = self.samples[index * channels + channel];
+                let to = self.samples[next * channels + channel];
+                out.push(from + (to - from) * t);
+            }
+        }
+        out
+    }
+}
+
+fn u16_at(data: &[u8], offset: usize) -> Option<u16> {
+    let bytes = data.get(offset..offset.checked_add(2)?)?;
+    Some(u16::from_le_bytes([bytes[0], bytes[1]]))
+}
+
+fn u32_at(data: &[u8], offset: usize) -> Option<u32> {
+    let bytes = data.get(offset..offset.checked_add(4)?)?;
+    Some(u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+}
+
+fn sample(format: u16, bits: u16, bytes: &[u8]) -> f32 {
+    match (format, bits) {
+        (FORMAT_PCM, 8) => (bytes[0] as f32 - 128.0) / 128.0,
+        (FORMAT_PCM, 16) => i16::from_le_bytes([bytes[0], bytes[1]]) as f32 / 32768.0,
+        (FORMAT_PCM, 24) => {
+            let value = i32::from_le_bytes([0, bytes[0], bytes[1], bytes[2]]) >> 8;
+            value as f32 / 8_388_608.0
+        }
+        (FORMAT_PCM, _) => {
+            let value = i32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+            value as f32 / 2_147_483_648.0
+        }
+        (_, 32) => f32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]),
+        _ => {
+            let mut value = [0u8; 8];
+            value.copy_from_slice(bytes);
+            f64::from_le_bytes(value) as f32
+        }
+    }
+}
+
+pub(crate) fn decode(bytes: &[u8]) -> Option<Pcm> {
+    if bytes.get(..4)? != b"RIFF" || bytes.get(8..12)? != b"WAVE" {
+        return None;
+    }
+
+    let mut format = None;
+    let mut data = None;
+    let mut offset = 12;
+    while let Some(id) = bytes.get(offset..offset + 4) {
+        let len = u32_at(bytes, offset + 4)? as usize;
+        let body = offset + 8;
+        // Writers sometimes stream a placeholder length for the last chunk.
+        let end = body.checked_add(len)?.min(bytes.len());
+        match id {
+            b"fmt " => format = Some(body),
+            b"data" => data = Some(&bytes[body..end]),
+            _ => {}
+        }
+        offset = end + (len & 1);
+    }
+
+    let fmt = format?;
+    let mut tag = u16_at(bytes, fmt)?;
+    let channels = u16_at(bytes, fmt + 2)?;
+    let sample_rate = u32_at(bytes, fmt + 4)?;
+    let bits = u16_at(bytes, fmt + 14)?;
+    if tag == FORMAT_EXTENSIBLE {
+        // The sub-format GUID starts with the plain format tag.
+        tag = u16_at(bytes, fmt + 24)?;
+    }
+    let supported = match tag {
+        FORMAT_PCM => matches!(bits, 8 | 16 | 24 | 32),
+        FORMAT_FLOAT => matches!(bits, 32 | 64),
+        _ => false,
+    };
+    if !supported || channels == 0 || sample_rate == 0 {
+        return None;
+    }
+
+    let width = bits as usize / 8;
+    let frame = width * channels as usize;
+    let data = data?;
+    let samples = data[..data.len() / frame * frame]
+        .chunks_exact(width)
+        .map(|bytes| sample(tag, bits, bytes))
+        .collect();
+    Some(Pcm {
+        sample_rate,
+        channels,
+        samples,
+    })
+}
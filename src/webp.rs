@@ -0,0 +1,2121 @@
+// WebP decoder covering lossy (VP8, optionally with an ALPH chunk) and
+// lossless (VP8L) still images in simple or extended containers. Animated
+// files are rejected. Reconstruction mirrors libwebp, including its loop
+// filter and fancy chroma upsampling, so output matches `WebPDecodeRGBA`.
+
+const ANIMATION_FLAG: u8 = 0x02;
+
+fn le16(data: &[u8], at: usize) -> Option<u32> {
+    Some(u16::from_le_bytes(data.get(at..at + 2)?.try_into().ok()?) as u32)
+}
+
+fn le24(data: &[u8], at: usize) -> Option<u32> {
+    let b = data.get(at..at + 3)?;
+    Some(b[0] as u32 | (b[1] as u32) << 8 | (b[2] as u32) << 16)
+}
+
+fn le32(data: &[u8], at: usize) -> Option<u32> {
+    Some(u32::from_le_bytes(data.get(at..at + 4)?.try_into().ok()?))
+}
+
+struct Chunks<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> Iterator for Chunks<'a> {
+    type Item = ([u8; 4], &'a [u8]);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let tag: [u8; 4] = self.data.get(..4)?.try_into().ok()?;
+        let size = le32(self.data, 4)? as usize;
+        let body = self.data.get(8..8usize.checked_add(size)?)?;
+        let next = (8 + size + (size & 1)).min(self.data.len());
+        self.data = &self.data[next..];
+        Some((tag, body))
+    }
+}
+
+/// Decodes a still WebP image into unpremultiplied RGBA8.
+pub(crate) fn decode(bytes: &[u8]) -> Option<(u32, u32, Vec<u8>)> {
+    if bytes.get(..4)? != b"RIFF" || bytes.get(8..12)? != b"WEBP" {
+        return None;
+    }
+    let riff_end = (le32(bytes, 4)? as usize)
+        .saturating_add(8)
+        .min(bytes.len());
+    let mut chunks = Chunks {
+        data: bytes.get(12..riff_end)?,
+    };
+    let (tag, body) = chunks.next()?;
+    match &tag {
+        b"VP8 " => decode_lossy(body, None),
+        b"VP8L" => decode_lossless(body),
+        b"VP8X" => {
+            if body.len() < 10 || body[0] & ANIMATION_FLAG != 0 {
+                return None;
+            }
+            let canvas = (le24(body, 4)? + 1, le24(body, 7)? + 1);
+            let mut alpha = None;
+            let image = loop {
+                let (tag, body) = chunks.next()?;
+                match &tag {
+                    b"ALPH" => {
+                        alpha.get_or_insert(body);
+                    }
+                    b"VP8 " => break decode_lossy(body, alpha)?,
+                    b"VP8L" => break decode_lossless(body)?,
+                    b"ANIM" | b"ANMF" => return None,
+                    _ => {}
+                }
+            };
+            ((image.0, image.1) == canvas).then_some(image)
+        }
+        _ => None,
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Lossless (VP8L)
+
+const LOSSLESS_SIGNATURE: u8 = 0x2f;
+const FAST_BITS: u32 = 8;
+const CODE_LENGTH_ORDER: [usize; 19] = [
+    17, 18, 0, 1, 2, 3, 4, 5, 16, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15,
+];
+// Maps the first 120 distance codes to (dy, dx) offsets packed as
+// `dy << 4 | (8 - dx)`.
+const CODE_TO_PLANE: [u8; 120] = [
+    0x18, 0x07, 0x17, 0x19, 0x28, 0x06, 0x27, 0x29, 0x16, 0x1a, 0x26, 0x2a, 0x38, 0x05, 0x37, 0x39,
+    0x15, 0x1b, 0x36, 0x3a, 0x25, 0x2b, 0x48, 0x04, 0x47, 0x49, 0x14, 0x1c, 0x35, 0x3b, 0x46, 0x4a,
+    0x24, 0x2c, 0x58, 0x45, 0x4b, 0x34, 0x3c, 0x03, 0x57, 0x59, 0x13, 0x1d, 0x56, 0x5a, 0x23, 0x2d,
+    0x44, 0x4c, 0x55, 0x5b, 0x33, 0x3d, 0x68, 0x02, 0x67, 0x69, 0x12, 0x1e, 0x66, 0x6a, 0x22, 0x2e,
+    0x54, 0x5c, 0x43, 0x4d, 0x65, 0x6b, 0x32, 0x3e, 0x78, 0x01, 0x77, 0x79, 0x53, 0x5d, 0x11, 0x1f,
+    0x64, 0x6c, 0x42, 0x4e, 0x76, 0x7a, 0x21, 0x2f, 0x75, 0x7b, 0x31, 0x3f, 0x63, 0x6d, 0x52, 0x5e,
+    0x00, 0x74, 0x7c, 0x41, 0x4f, 0x10, 0x20, 0x62, 0x6e, 0x30, 0x73, 0x7d, 0x51, 0x5f, 0x40, 0x72,
+    0x7e, 0x61, 0x6f, 0x50, 0x71, 0x7f, 0x60, 0x70,
+];
+
+struct LosslessReader<'a> {
+    data: &'a [u8],
+    position: usize,
+    buffer: u64,
+    count: u32,
+    eos: bool,
+}
+
+impl<'a> LosslessReader<'a> {
+    fn new(data: &'a [u8]) -> Self {
+        Self {
+            data,
+            position: 0,
+            buffer: 0,
+            count: 0,
+            eos: false,
+        }
+    }
+
+    fn peek(&mut self, n: u32) -> u32 {
+        while self.count <= 56 {
+            let Some(&byte) = self.data.get(self.position) else {
+                break;
+            };
+            self.buffer |= (byte as u64) << self.count;
+            self.position += 1;
+            self.count += 8;
+        }
+        (self.buffer & ((1u64 << n) - 1)) as u32
+    }
+
+    fn consume(&mut self, n: u32) {
+        if n > self.count {
+            self.eos = true;
+            self.buffer = 0;
+            self.count = 0;
+        } else {
+            self.buffer >>= n;
+            self.count -= n;
+        }
+    }
+
+    fn read(&mut self, n: u32) -> u32 {
+        let value = self.peek(n);
+        self.consume(n);
+        value
+    }
+}
+
+struct PrefixCode {
+    single: Option<u16>,
+    // (code length, symbol) for every `FAST_BITS` prefix; length 0 = slow path.
+    fast: Vec<(u8, u16)>,
+    counts: [u16; 16],
+    symbols: Vec<u16>,
+}
+
+impl PrefixCode {
+    fn new(lengths: &[u8]) -> Option<Self> {
+        let mut counts = [0u16; 16];
+        for &length in lengths {
+            counts[length as usize] += 1;
+        }
+        let used = lengths.len() - counts[0] as usize;
+        if used == 0 {
+            return None;
+        }
+        if used == 1 {
+            let symbol = lengths.iter().position(|&length| length != 0)? as u16;
+            return Some(Self {
+                single: Some(symbol),
+                fast: Vec::new(),
+                counts,
+                symbols: Vec::new(),
+            });
+        }
+        let mut left = 1i32;
+        for &count in &counts[1..] {
+            left = (left << 1) - count as i32;
+            if left < 0 {
+                return None;
+            }
+        }
+        if left != 0 {
+            return None;
+        }
+        let mut offsets = [0usize; 16];
+        for length in 1..15 {
+            offsets[length + 1] = offsets[length] + counts[length] as usize;
+        }
+        let mut symbols = vec![0u16; used];
+        for (symbol, &length) in lengths.iter().enumerate() {
+            if length != 0 {
+                symbols[offsets[length as usize]] = symbol as u16;
+                offsets[length as usize] += 1;
+            }
+        }
+        let mut fast = vec![(0u8, 0u16); 1 << FAST_BITS];
+        let mut code = 0u32;
+        let mut index = 0;
+        for length in 1..16u32 {
+            for _ in 0..counts[length as usize] {
+                if length <= FAST_BITS {
+                    let reversed = code.reverse_bits() >> (32 - length);
+                    for entry in fast.iter_mut().skip(reversed as usize).step_by(1 << length) {
+                        *entry = (length as u8, symbols[index]);
+                    }
+                }
+                code += 1;
+                index += 1;
+            }
+            code <<= 1;
+        }
+        Some(Self {
+            single: None,
+            fast,
+            counts,
+            symbols,
+        })
+    }
+
+    fn decode(&self, reader: &mut LosslessReader) -> u16 {
+        if let Some(symbol) = self.single {
+            return symbol;
+        }
+        let (length, symbol) = self.fast[reader.peek(FAST_BITS) as usize];
+        if length > 0 {
+            reader.consume(length as u32);
+            return symbol;
+        }
+        let (mut code, mut first, mut index) = (0i32, 0i32, 0i32);
+        for &count in &self.counts[1..] {
+            code |= reader.read(1) as i32;
+            let count = count as i32;
+            if code - first < count {
+                return self.symbols[(index + code - first) as usize];
+            }
+            index += count;
+            first = (first + count) << 1;
+            code <<= 1;
+        }
+        reader.eos = true;
+        0
+    }
+}
+
+fn read_prefix_code(reader: &mut LosslessReader, alphabet_size: usize) -> Option<PrefixCode> {
+    let mut lengths = vec![0u8; alphabet_size];
+    if reader.read(1) == 1 {
+        let num_symbols = reader.read(1) + 1;
+        let first_bits = if reader.read(1) == 0 { 1 } else { 8 };
+        *lengths.get_mut(reader.read(first_bits) as usize)? = 1;
+        if num_symbols == 2 {
+            *lengths.get_mut(reader.read(8) as usize)? = 1;
+        }
+    } else {
+        let mut code_length_lengths = [0u8; 19];
+        let count = reader.read(4) as usize + 4;
+        for &symbol in &CODE_LENGTH_ORDER[..count] {
+            code_length_lengths[symbol] = reader.read(3) as u8;
+        }
+        let code_length_code = PrefixCode::new(&code_length_lengths)?;
+        let mut max_symbol = if reader.read(1) == 1 {
+            let bits = 2 + 2 * reader.read(3);
+            let max_symbol = 2 + reader.read(bits) as usize;
+            if max_symbol > alphabet_size {
+                return None;
+            }
+            max_symbol
+        } else {
+            alphabet_size
+        };
+        let mut previous = 8;
+        let mut symbol = 0;
+        while symbol < alphabet_size && max_symbol > 0 {
+            max_symbol -= 1;
+            let code = code_length_code.decode(reader) as usize;
+            if code < 16 {
+                lengths[symbol] = code as u8;
+                symbol += 1;
+                if code != 0 {
+                    previous = code as u8;
+                }
+                continue;
+            }
+            let (extra_bits, offset) = [(2, 3), (3, 3), (7, 11)][code - 16];
+            let repeat = reader.read(extra_bits) as usize + offset;
+            if symbol + repeat > alphabet_size {
+                return None;
+            }
+            let value = if code == 16 { previous } else { 0 };
+            lengths[symbol..symbol + repeat].fill(value);
+            symbol += repeat;
+        }
+    }
+    if reader.eos {
+        return None;
+    }
+    PrefixCode::new(&lengths)
+}
+
+struct CodeGroup {
+    green: PrefixCode,
+    red: PrefixCode,
+    blue: PrefixCode,
+    alpha: PrefixCode,
+    distance: PrefixCode,
+}
+
+enum Transform {
+    Predictor { bits: u32, data: Vec<u32> },
+    CrossColor { bits: u32, data: Vec<u32> },
+    SubtractGreen,
+    ColorIndexing { bits: u32, palette: Vec<u32> },
+}
+
+fn subsample(size: usize, bits: u32) -> usize {
+    (size + (1 << bits) - 1) >> bits
+}
+
+fn decode_lossless(data: &[u8]) -> Option<(u32, u32, Vec<u8>)> {
+    if data.first() != Some(&LOSSLESS_SIGNATURE) {
+        return None;
+    }
+    let mut reader = LosslessReader::new(&data[1..]);
+    let width = reader.read(14) as usize + 1;
+    let height = reader.read(14) as usize + 1;
+    let _has_alpha = reader.read(1);
+    if reader.read(3) != 0 || width * height > crate::image::MAX_PIXELS {
+        return None;
+    }
+    let argb = decode_image_stream(&mut reader, width, height, true)?;
+    let mut rgba = Vec::with_capacity(argb.len() * 4);
+    for pixel in argb {
+        let [b, g, r, a] = pixel.to_le_bytes();
+        rgba.extend_from_slice(&[r, g, b, a]);
+    }
+    Some((width as u32, height as u32, rgba))
+}
+
+fn decode_image_stream(
+    reader: &mut LosslessReader,
+    width: usize,
+    height: usize,
+    level0: bool,
+) -> Option<Vec<u32>> {
+    let mut transforms = Vec::new();
+    let mut widths = Vec::new();
+    let mut coded_width = width;
+    let mut seen = 0u8;
+    while level0 && reader.read(1) == 1 {
+        let kind = reader.read(2);
+        if seen & (1 << kind) != 0 {
+            return None;
+        }
+        seen |= 1 << kind;
+        widths.push(coded_width);
+        let transform = match kind {
+            0 | 1 => {
+                let bits = reader.read(3) + 2;
+                let data = decode_image_stream(
+                    reader,
+                    subsample(coded_width, bits),
+                    subsample(height, bits),
+                    false,
+                )?;
+                if kind == 0 {
+                    Transform::Predictor { bits, data }
+                } else {
+                    Transform::CrossColor { bits, data }
+                }
+            }
+            2 => Transform::SubtractGreen,
+            _ => {
+                let num_colors = reader.read(8) as usize + 1;
+                let bits = match num_colors {
+                    17.. => 0,
+                    5.. => 1,
+                    3.. => 2,
+                    _ => 3,
+                };
+                let mut palette = decode_image_stream(reader, num_colors, 1, false)?;
+                for i in 1..palette.len() {
+                    palette[i] = add_pixels(palette[i], palette[i - 1]);
+                }
+                palette.resize(1 << (8 >> bits), 0);
+                coded_width = subsample(coded_width, bits);
+                Transform::ColorIndexing { bits, palette }
+            }
+        };
+        transforms.push(transform);
+    }
+
+    let cache_bits = if reader.read(1) == 1 {
+        let bits = reader.read(4);
+        if !(1..=11).contains(&bits) {
+            return None;
+        }
+        bits
+    } else {
+        0
+    };
+
+    // The entropy image selects a code group per tile; groups are renumbered
+    // densely so unused ones are parsed but not kept.
+    let mut meta = None;
+    let mut group_count = 1;
+    let mut used = vec![true];
+    if level0 && reader.read(1) == 1 {
+        let bits = reader.read(3) + 2;
+        let mut image = decode_image_stream(
+            reader,
+            subsample(coded_width, bits),
+            subsample(height, bits),
+            false,
+        )?;
+        group_count = image
+            .iter()
+            .map(|&p| ((p >> 8) & 0xffff) as usize + 1)
+            .max()?;
+        used = vec![false; group_count];
+        for &p in &image {
+            used[((p >> 8) & 0xffff) as usize] = true;
+        }
+        let mut remap = vec![0u32; group_count];
+        let mut next = 0;
+        for (index, &is_used) in used.iter().enumerate() {
+            if is_used {
+                remap[index] = next;
+                next += 1;
+            }
+        }
+        for p in &mut image {
+            *p = remap[((*p >> 8) & 0xffff) as usize];
+        }
+        meta = Some((bits, image));
+    }
+
+    let cache_size = if cache_bits > 0 { 1 << cache_bits } else { 0 };
+    let mut groups = Vec::new();
+    for &is_used in used.iter().take(group_count) {
+        let group = CodeGroup {
+            green: read_prefix_code(reader, 256 + 24 + cache_size)?,
+            red: read_prefix_code(reader, 256)?,
+            blue: read_prefix_code(reader, 256)?,
+            alpha: read_prefix_code(reader, 256)?,
+            distance: read_prefix_code(reader, 40)?,
+        };
+        if is_used {
+            groups.push(group);
+        }
+    }
+
+    let mut pixels = decode_pixels(
+        reader,
+        coded_width,
+        height,
+        &groups,
+        meta.as_ref(),
+        cache_bits,
+    )?;
+    for (transform, &width) in transforms.iter().zip(&widths).rev() {
+        pixels = apply_transform(transform, pixels, width, height);
+    }
+    Some(pixels)
+}
+
+fn copy_length(symbol: u32, reader: &mut LosslessReader) -> usize {
+    if symbol < 4 {
+        return symbol as usize + 1;
+    }
+    let extra_bits = (symbol - 2) >> 1;
+    let offset = (2 + (symbol & 1)) << extra_bits;
+    (offset + reader.read(extra_bits)) as usize + 1
+}
+
+fn decode_pixels(
+    reader: &mut LosslessReader,
+    width: usize,
+    height: usize,
+    groups: &[CodeGroup],
+    meta: Option<&(u32, Vec<u32>)>,
+    cache_bits: u32,
+) -> Option<Vec<u32>> {
+    let total = width.checked_mul(height)?;
+    let mut pixels: Vec<u32> = Vec::new();
+    let mut cache = vec![0u32; if cache_bits > 0 { 1 << cache_bits } else { 0 }];
+    let mut cached = 0;
+    while pixels.len() < total {
+        let position = pixels.len();
+        let group = match meta {
+            Some((bits, image)) => {
+                let (x, y) = (position % width, position / width);
+                &groups[image[(y >> bits) * subsample(width, *bits) + (x >> bits)] as usize]
+            }
+            None => &groups[0],
+        };
+        let green = group.green.decode(reader) as u32;
+        if green < 256 {
+            let red = group.red.decode(reader) as u32;
+            let blue = group.blue.decode(reader) as u32;
+            let alpha = group.alpha.decode(reader) as u32;
+            pixels.push(alpha << 24 | red << 16 | green << 8 | blue);
+        } else if green < 256 + 24 {
+            let length = copy_length(green - 256, reader);
+            let symbol = group.distance.decode(reader) as u32;
+            let code = copy_length(symbol, reader);
+            let distance = if code > CODE_TO_PLANE.len() {
+                code - CODE_TO_PLANE.len()
+            } else {
+                let plane = CODE_TO_PLANE[code - 1] as isize;
+                let offset = (plane >> 4) * width as isize + 8 - (plane & 0xf);
+                offset.max(1) as usize
+            };
+            if distance > position || length > total - position {
+                return None;
+            }
+            for i in position..position + length {
+                pixels.push(pixels[i - distance]);
+            }
+        } else {
+            for &pixel in &pixels[cached..] {
+                cache[(0x1e35a7bd_u32.wrapping_mul(pixel) >> (32 - cache_bits)) as usize] = pixel;
+            }
+            cached = pixels.len();
+            pixels.push(*cache.get((green - 256 - 24) as usize)?);
+        }
+        if reader.eos {
+            return None;
+        }
+    }
+    Some(pixels)
+}
+
+fn add_pixels(a: u32, b: u32) -> u32 {
+    let alpha_green = (a & 0xff00ff00).wrapping_add(b & 0xff00ff00) & 0xff00ff00;
+    let red_blue = (a & 0x00ff00ff).wrapping_add(b & 0x00ff00ff) & 0x00ff00ff;
+    alpha_green | red_blue
+}
+
+fn average2(a: u32, b: u32) -> u32 {
+    (((a ^ b) & 0xfefefefe) >> 1) + (a & b)
+}
+
+fn channels(pixel: u32) -> [i32; 4] {
+    pixel.to_be_bytes().map(i32::from)
+}
+
+fn from_channels(channels: [i32; 4]) -> u32 {
+    u32::from_be_bytes(channels.map(|c| c.clamp(0, 255) as u8))
+}
+
+fn select(top: u32, left: u32, top_left: u32) -> u32 {
+    let (t, l, tl) = (channels(top), channels(left), channels(top_left));
+    let score: i32 = (0..4)
+        .map(|i| (l[i] - tl[i]).abs() - (t[i] - tl[i]).abs())
+        .sum();
+    if score <= 0 { top } else { left }
+}
+
+fn clamped_add_subtract_full(a: u32, b: u32, c: u32) -> u32 {
+    let (a, b, c) = (channels(a), channels(b), channels(c));
+    from_channels(std::array::from_fn(|i| a[i] + b[i] - c[i]))
+}
+
+fn clamped_add_subtract_half(a: u32, b: u32) -> u32 {
+    let (a, b) = (channels(a), channels(b));
+    from_channels(std::array::from_fn(|i| a[i] + (a[i] - b[i]) / 2))
+}
+
+fn predict(mode: u32, left: u32, top: u32, top_left: u32, top_right: u32) -> u32 {
+    match mode {
+        1 => left,
+        2 => top,
+        3 => top_right,
+        4 => top_left,
+        5 => average2(average2(left, top_right), top),
+        6 => average2(left, top_left),
+        7 => average2(left, top),
+        8 => average2(top_left, top),
+        9 => average2(top, top_right),
+        10 => average2(average2(left, top_left), average2(top, top_right)),
+        11 => select(top, left, top_left),
+        12 => clamped_add_subtract_full(left, top, top_left),
+        13 => clamped_add_subtract_half(average2(left, top), top_left),
+        _ => 0xff000000,
+    }
+}
+
+fn color_delta(multiplier: u8, color: u8) -> i32 {
+    (multiplier as i8 as i32 * color as i8 as i32) >> 5
+}
+
+fn apply_transform(
+    transform: &Transform,
+    mut pixels: Vec<u32>,
+    width: usize,
+    height: usize,
+) -> Vec<u32> {
+    match transform {
+        Transform::Predictor { bits, data } => {
+            let tiles = subsample(width, *bits);
+            pixels[0] = add_pixels(pixels[0], 0xff000000);
+            for x in 1..width {
+                pixels[x] = add_pixels(pixels[x], pixels[x - 1]);
+            }
+            for y in 1..height {
+                let row = y * width;
+                pixels[row] = add_pixels(pixels[row], pixels[row - width]);
+                let modes = &data[(y >> bits) * tiles..];
+                for x in 1..width {
+                    let i = row + x;
+                    let above = i - width;
+                    let prediction = predict(
+                        (modes[x >> bits] >> 8) & 0xf,
+                        pixels[i - 1],
+                        pixels[above],
+                        pixels[above - 1],
+                        pixels[above + 1],
+                    );
+                    pixels[i] = add_pixels(pixels[i], prediction);
+                }
+            }
+            pixels
+        }
+        Transform::CrossColor { bits, data } => {
+            let tiles = subsample(width, *bits);
+            for (i, pixel) in pixels.iter_mut().enumerate() {
+                let (x, y) = (i % width, i / width);
+                let [green_to_red, green_to_blue, red_to_blue, _] =
+                    data[(y >> bits) * tiles + (x >> bits)].to_le_bytes();
+                let [blue, green, red, alpha] = pixel.to_le_bytes();
+                let red = (red as i32 + color_delta(green_to_red, green)) as u8;
+                let blue = (blue as i32
+                    + color_delta(green_to_blue, green)
+                    + color_delta(red_to_blue, red)) as u8;
+                *pixel = u32::from_le_bytes([blue, green, red, alpha]);
+            }
+            pixels
+        }
+        Transform::SubtractGreen => {
+            for pixel in &mut pixels {
+                let green = (*pixel >> 8) & 0xff;
+                *pixel = add_pixels(*pixel, green << 16 | green);
+            }
+            pixels
+        }
+        Transform::ColorIndexing { bits, palette } => {
+            let packed_width = subsample(width, *bits);
+            let index_bits = 8 >> bits;
+            let mask = (1 << index_bits) - 1;
+            let mut out = Vec::with_capacity(width * height);
+            for y in 0..height {
+                let row = &pixels[y * packed_width..(y + 1) * packed_width];
+                for x in 0..width {
+                    let shift = (x & ((1 << bits) - 1)) as u32 * index_bits;
+                    let index = (row[x >> bits] >> 8 >> shift) & mask;
+                    out.push(palette[index as usize]);
+                }
+            }
+            out
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Alpha (ALPH)
+
+fn decode_alpha(data: &[u8], width: usize, height: usize) -> Option<Vec<u8>> {
+    let (&header, body) = data.split_first()?;
+    let (method, filter, preprocessing) = (header & 3, (header >> 2) & 3, (header >> 4) & 3);
+    if method > 1 || preprocessing > 1 || header >> 6 != 0 {
+        return None;
+    }
+    let filtered = if method == 0 {
+        body.get(..width * height)?.to_vec()
+    } else {
+        let mut reader = LosslessReader::new(body);
+        let argb = decode_image_stream(&mut reader, width, height, true)?;
+        argb.iter().map(|p| (p >> 8) as u8).collect()
+    };
+    let mut alpha = vec![0u8; width * height];
+    for y in 0..height {
+        let (done, rest) = alpha.split_at_mut(y * width);
+        let out = &mut rest[..width];
+        let input = &filtered[y * width..(y + 1) * width];
+        let previous = (y > 0).then(|| &done[(y - 1) * width..]);
+        match (filter, previous) {
+            (0, _) => out.copy_from_slice(input),
+            (1, _) | (_, None) => {
+                let mut prediction = previous.map_or(0, |p| p[0]);
+                for (o, &i) in out.iter_mut().zip(input) {
+                    *o = prediction.wrapping_add(i);
+                    prediction = *o;
+                }
+            }
+            (2, Some(previous)) => {
+                for ((o, &i), &p) in out.iter_mut().zip(input).zip(previous) {
+                    *o = p.wrapping_add(i);
+                }
+            }
+            (_, Some(previous)) => {
+                let (mut left, mut top_left) = (previous[0], previous[0]);
+                for ((o, &i), &top) in out.iter_mut().zip(input).zip(previous) {
+                    let gradient = (left as i32 + top as i32 - top_left as i32).clamp(0, 255);
+                    left = i.wrapping_add(gradient as u8);
+                    top_left = top;
+                    *o = left;
+                }
+            }
+        }
+    }
+    Some(alpha)
+}
+
+// ---------------------------------------------------------------------------
+// Lossy (VP8)
+
+const NUM_SEGMENTS: usize = 4;
+const BPS: usize = 32;
+const Y_OFF: usize = BPS + 8;
+const U_OFF: usize = Y_OFF + 16 * BPS + BPS;
+const V_OFF: usize = U_OFF + 16;
+const WORK_SIZE: usize = BPS * 17 + BPS * 9;
+
+const DC_PRED: u8 = 0;
+const TM_PRED: u8 = 1;
+const V_PRED: u8 = 2;
+const H_PRED: u8 = 3;
+// Intra 4x4 modes extend the first four with the directional predictors.
+const B_RD_PRED: u8 = 4;
+const B_VR_PRED: u8 = 5;
+const B_LD_PRED: u8 = 6;
+const B_VL_PRED: u8 = 7;
+const B_HD_PRED: u8 = 8;
+const B_HU_PRED: u8 = 9;
+
+const ZIGZAG4: [usize; 16] = [0, 1, 4, 8, 5, 2, 3, 6, 9, 12, 13, 10, 7, 11, 14, 15];
+const BANDS: [usize; 17] = [0, 1, 2, 3, 6, 4, 5, 6, 6, 6, 6, 6, 6, 6, 6, 7, 0];
+const CAT_PROBS: [&[u8]; 4] = [
+    &[173, 148, 140],
+    &[176, 155, 140, 135],
+    &[180, 157, 141, 134, 130],
+    &[254, 254, 243, 230, 196, 177, 153, 140, 133, 130, 129],
+];
+
+struct BoolReader<'a> {
+    data: &'a [u8],
+    position: usize,
+    value: u32,
+    range: u32,
+    bit_count: u32,
+}
+
+impl<'a> BoolReader<'a> {
+    fn new(data: &'a [u8]) -> Self {
+        let mut reader = Self {
+            data,
+            position: 0,
+            value: 0,
+            range: 255,
+            bit_count: 0,
+        };
+        reader.value = reader.next_byte() << 8 | reader.next_byte();
+        reader
+    }
+
+    fn next_byte(&mut self) -> u32 {
+        let byte = self.data.get(self.position).copied().unwrap_or(0);
+        self.position += 1;
+        byte as u32
+    }
+
+    // Reading a couple of bytes past the end is normal at the tail of a
+    // partition; anything further means the stream was truncated.
+    fn exhausted(&self) -> bool {
+        self.position > self.data.len() + 2
+    }
+
+    fn read(&mut self, probability: u8) -> bool {
+        let split = 1 + (((self.range - 1) * probability as u32) >> 8);
+        let big_split = split << 8;
+        let bit = if self.value >= big_split {
+            self.range -= split;
+            self.value -= big_split;
+            true
+        } else {
+            self.range = split;
+            false
+        };
+        while self.range < 128 {
+            self.value <<= 1;
+            self.range <<= 1;
+            self.bit_count += 1;
+            if self.bit_count == 8 {
+                self.bit_count = 0;
+                self.value |= self.next_byte();
+            }
+        }
+        bit
+    }
+
+    fn flag(&mut self) -> bool {
+        self.read(128)
+    }
+
+    fn literal(&mut self, bits: u32) -> i32 {
+        (0..bits).fold(0, |value, _| value << 1 | self.flag() as i32)
+    }
+
+    fn signed(&mut self, bits: u32) -> i32 {
+        let value = self.literal(bits);
+        if self.flag() { -value } else { value }
+    }
+
+    fn optional_signed(&mut self, bits: u32) -> i32 {
+        if self.flag() { self.signed(bits) } else { 0 }
+    }
+}
+
+#[derive(Clone, Copy, Default)]
+struct Quant {
+    y1: [i32; 2],
+    y2: [i32; 2],
+    uv: [i32; 2],
+}
+
+#[derive(Clone, Copy, Default)]
+struct FilterStrength {
+    limit: i32,
+    inner_level: i32,
+    hev_threshold: i32,
+}
+
+#[derive(Clone, Copy, Default)]
+struct MacroblockFilter {
+    strength: FilterStrength,
+    inner: bool,
+}
+
+struct Frame {
+    width: usize,
+    height: usize,
+    y_stride: usize,
+    uv_stride: usize,
+    y: Vec<u8>,
+    u: Vec<u8>,
+    v: Vec<u8>,
+}
+
+fn decode_lossy(data: &[u8], alpha: Option<&[u8]>) -> Option<(u32, u32, Vec<u8>)> {
+    let frame = decode_frame(data)?;
+    let mut rgba = frame.to_rgba();
+    if let Some(alpha) = alpha {
+        let alpha = decode_alpha(alpha, frame.width, frame.height)?;
+        for (pixel, a) in rgba.chunks_exact_mut(4).zip(alpha) {
+            pixel[3] = a;
+        }
+    }
+    Some((frame.width as u32, frame.height as u32, rgba))
+}
+
+fn decode_frame(data: &[u8]) -> Option<Frame> {
+    let tag = le24(data, 0)?;
+    let (key_frame, profile, first_partition_size) =
+        (tag & 1 == 0, (tag >> 1) & 7, (tag >> 5) as usize);
+    if !key_frame || profile > 3 || data.get(3..6)? != [0x9d, 0x01, 0x2a] {
+        return None;
+    }
+    let width = (le16(data, 6)? & 0x3fff) as usize;
+    let height = (le16(data, 8)? & 0x3fff) as usize;
+    if width == 0 || height == 0 || width * height > crate::image::MAX_PIXELS {
+        return None;
+    }
+    let first_partition = data.get(10..10usize.checked_add(first_partition_size)?)?;
+    let mut header = BoolReader::new(first_partition);
+    let _color_space = header.flag();
+    let _clamping = header.flag();
+
+    let mut segment_quant = [0i32; NUM_SEGMENTS];
+    let mut segment_filter = [0i32; NUM_SEGMENTS];
+    let mut segment_probs = [255u8; 3];
+    let (mut use_segments, mut update_map, mut absolute_delta) = (false, false, false);
+    if header.flag() {
+        use_segments = true;
+        update_map = header.flag();
+        if header.flag() {
+            absolute_delta = header.flag();
+            for quant in &mut segment_quant {
+                *quant = header.optional_signed(7);
+            }
+            for filter in &mut segment_filter {
+                *filter = header.optional_signed(6);
+            }
+        }
+        if update_map {
+            for prob in &mut segment_probs {
+                *prob = if header.flag() {
+                    header.literal(8) as u8
+                } else {
+                    255
+                };
+            }
+        }
+    }
+
+    let simple_filter = header.flag();
+    let filter_level = header.literal(6);
+    let sharpness = header.literal(3);
+    let use_lf_delta = header.flag();
+    let (mut ref_lf_delta, mut mode_lf_delta) = ([0i32; 4], [0i32; 4]);
+    if use_lf_delta && header.flag() {
+        for delta in ref_lf_delta.iter_mut().chain(&mut mode_lf_delta) {
+            if header.flag() {
+                *delta = header.signed(6);
+            }
+        }
+    }
+    let filter_type = if filter_level == 0 {
+        0
+    } else if simple_filter {
+        1
+    } else {
+        2
+    };
+
+    let rest = &data[10 + first_partition_size..];
+    let last_partition = (1 << header.literal(2)) - 1;
+    let mut partition_data = rest.get(3 * last_partition..)?;
+    let mut partitions = Vec::with_capacity(last_partition + 1);
+    for p in 0..last_partition {
+        let size = (le24(rest, 3 * p)? as usize).min(partition_data.len());
+        partitions.push(BoolReader::new(&partition_data[..size]));
+        partition_data = &partition_data[size..];
+    }
+    partitions.push(BoolReader::new(partition_data));
+
+    let base_q = header.literal(7);
+    let [y1_dc, y2_dc, y2_ac, uv_dc, uv_ac] = [(); 5].map(|_| header.optional_signed(4));
+    let mut quant = [Quant::default(); NUM_SEGMENTS];
+    for (segment, q) in quant.iter_mut().enumerate() {
+        let base = if !use_segments {
+            base_q
+        } else if absolute_delta {
+            segment_quant[segment]
+        } else {
+            segment_quant[segment] + base_q
+        };
+        let dc = |delta: i32, max: i32| DC_TABLE[(base + delta).clamp(0, max) as usize] as i32;
+        let ac = |delta: i32| AC_TABLE[(base + delta).clamp(0, 127) as usize] as i32;
+        *q = Quant {
+            y1: [dc(y1_dc, 127), ac(0)],
+            y2: [dc(y2_dc, 127) * 2, ((ac(y2_ac) * 101581) >> 16).max(8)],
+            uv: [dc(uv_dc, 117), ac(uv_ac)],
+        };
+    }
+
+    let mut strengths = [[FilterStrength::default(); 2]; NUM_SEGMENTS];
+    for (segment, strength) in strengths.iter_mut().enumerate() {
+        let mut base = filter_level;
+        if use_segments {
+            base = segment_filter[segment];
+            if !absolute_delta {
+                base += filter_level;
+            }
+        }
+        for (i4x4, strength) in strength.iter_mut().enumerate() {
+            let mut level = base;
+            if use_lf_delta {
+                level += ref_lf_delta[0];
+                if i4x4 == 1 {
+                    level += mode_lf_delta[0];
+                }
+            }
+            let level = level.clamp(0, 63);
+            if level == 0 {
+                continue;
+            }
+            let mut inner_level = level;
+            if sharpness > 0 {
+                inner_level >>= if sharpness > 4 { 2 } else { 1 };
+                inner_level = inner_level.min(9 - sharpness);
+            }
+            let inner_level = inner_level.max(1);
+            *strength = FilterStrength {
+                limit: 2 * level + inner_level,
+                inner_level,
+                hev_threshold: if level >= 40 {
+                    2
+                } else if level >= 15 {
+                    1
+                } else {
+                    0
+                },
+            };
+        }
+    }
+
+    let _refresh_entropy = header.flag();
+    let mut coeff_probs = COEFF_PROBS;
+    for (prob, &update) in coeff_probs.iter_mut().zip(&COEFF_UPDATE_PROBS) {
+        if header.read(update) {
+            *prob = header.literal(8) as u8;
+        }
+    }
+    let skip_prob = header.flag().then(|| header.literal(8) as u8);
+
+    let mut decoder = Decoder {
+        mb_width: width.div_ceil(16),
+        coeff_probs,
+        intra_top: Vec::new(),
+        intra_left: [0; 4],
+        top_nz: Vec::new(),
+        top_nz_dc: Vec::new(),
+        left_nz: 0,
+        left_nz_dc: false,
+        top_samples: Vec::new(),
+        work: [0; WORK_SIZE],
+    };
+    let mb_width = decoder.mb_width;
+    let mb_height = height.div_ceil(16);
+    decoder.intra_top = vec![DC_PRED; 4 * mb_width];
+    decoder.top_nz = vec![0; mb_width];
+    decoder.top_nz_dc = vec![false; mb_width];
+    decoder.top_samples = vec![[0; 32]; mb_width];
+
+    let (y_stride, uv_stride) = (mb_width * 16, mb_width * 8);
+    let mut frame = Frame {
+        width,
+        height,
+        y_stride,
+        uv_stride,
+        y: vec![0; y_stride * mb_height * 16],
+        u: vec![0; uv_stride * mb_height * 8],
+        v: vec![0; uv_stride * mb_height * 8],
+    };
+    let mut filters = vec![MacroblockFilter::default(); mb_width * mb_height];
+
+    for mb_y in 0..mb_height {
+        decoder.intra_left = [DC_PRED; 4];
+        decoder.left_nz = 0;
+        decoder.left_nz_dc = false;
+        decoder.start_row(mb_y);
+        let partition = &mut partitions[mb_y & last_partition];
+        for mb_x in 0..mb_width {
+            let segment = if update_map {
+                if !header.read(segment_probs[0]) {
+                    header.read(segment_probs[1]) as usize
+                } else {
+                    header.read(segment_probs[2]) as usize + 2
+                }
+            } else {
+                0
+            };
+            let skip = skip_prob.is_some_and(|prob| header.read(prob));
+            let modes = decoder.parse_modes(&mut header, mb_x);
+
+            let mut coeffs = [0i16; 384];
+            let (non_zero_y, non_zero_uv) = if skip {
+                decoder.top_nz[mb_x] = 0;
+                decoder.left_nz = 0;
+                if !modes.is_i4x4 {
+                    decoder.top_nz_dc[mb_x] = false;
+                    decoder.left_nz_dc = false;
+                }
+                (0, 0)
+            } else {
+                decoder.parse_residuals(
+                    partition,
+                    mb_x,
+                    modes.is_i4x4,
+                    &quant[segment],
+                    &mut coeffs,
+                )
+            };
+            if filter_type > 0 {
+                filters[mb_y * mb_width + mb_x] = MacroblockFilter {
+                    strength: strengths[segment][modes.is_i4x4 as usize],
+                    inner: modes.is_i4x4 || non_zero_y | non_zero_uv != 0,
+                };
+            }
+            decoder.reconstruct(&mut frame, mb_x, mb_y, mb_height, &modes, &coeffs);
+            if header.exhausted() || partition.exhausted() {
+                return None;
+            }
+        }
+    }
+
+    if filter_type > 0 {
+        for mb_y in 0..mb_height {
+            for mb_x in 0..mb_width {
+                let filter = filters[mb_y * mb_width + mb_x];
+                frame.filter_macroblock(mb_x, mb_y, filter, filter_type == 1);
+            }
+        }
+    }
+    Some(frame)
+}
+
+struct Modes {
+    is_i4x4: bool,
+    // A single 16x16 mode, or one mode per 4x4 block in raster order.
+    luma: [u8; 16],
+    chroma: u8,
+}
+
+struct Decoder {
+    mb_width: usize,
+    coeff_probs: [u8; 1056],
+    intra_top: Vec<u8>,
+    intra_left: [u8; 4],
+    // Non-zero contexts: bits 0-3 luma, 4-5 U, 6-7 V columns or rows.
+    top_nz: Vec<u8>,
+    top_nz_dc: Vec<bool>,
+    left_nz: u8,
+    left_nz_dc: bool,
+    // Unfiltered bottom row of each macroblock: 16 Y, 8 U, 8 V samples.
+    top_samples: Vec<[u8; 32]>,
+    work: [u8; WORK_SIZE],
+}
+
+impl Decoder {
+    fn parse_modes(&mut self, header: &mut BoolReader, mb_x: usize) -> Modes {
+        let top = &mut self.intra_top[4 * mb_x..4 * mb_x + 4];
+        let left = &mut self.intra_left;
+        let mut luma = [DC_PRED; 16];
+        let is_i4x4 = !header.read(145);
+        if !is_i4x4 {
+            let mode = if header.read(156) {
+                if header.read(128) { TM_PRED } else { H_PRED }
+            } else if header.read(163) {
+                V_PRED
+            } else {
+                DC_PRED
+            };
+            luma[0] = mode;
+            top.fill(mode);
+            left.fill(mode);
+        } else {
+            for y in 0..4 {
+                let mut mode = left[y];
+                for x in 0..4 {
+                    let p = &BMODE_PROBS[(top[x] as usize * 10 + mode as usize) * 9..][..9];
+                    mode = if !header.read(p[0]) {
+                        DC_PRED
+                    } else if !header.read(p[1]) {
+                        TM_PRED
+                    } else if !header.read(p[2]) {
+                        V_PRED
+                    } else if !header.read(p[3]) {
+                        if !header.read(p[4]) {
+                            H_PRED
+                        } else if !header.read(p[5]) {
+                            B_RD_PRED
+                        } else {
+                            B_VR_PRED
+                        }
+                    } else if !header.read(p[6]) {
+                        B_LD_PRED
+                    } else if !header.read(p[7]) {
+                        B_VL_PRED
+                    } else if !header.read(p[8]) {
+                        B_HD_PRED
+                    } else {
+                        B_HU_PRED
+                    };
+                    top[x] = mode;
+                    luma[y * 4 + x] = mode;
+                }
+                left[y] = mode;
+            }
+        }
+        let chroma = if !header.read(142) {
+            DC_PRED
+        } else if !header.read(114) {
+            V_PRED
+        } else if header.read(183) {
+            TM_PRED
+        } else {
+            H_PRED
+        };
+        Modes {
+            is_i4x4,
+            luma,
+            chroma,
+        }
+    }
+
+    fn probs(&self, kind: usize, band: usize, context: usize) -> &[u8] {
+        &self.coeff_probs[((kind * 8 + band) * 3 + context) * 11..][..11]
+    }
+
+    // Returns one past the position of the last non-zero coefficient.
+    fn read_coefficients(
+        &self,
+        reader: &mut BoolReader,
+        kind: usize,
+        context: usize,
+        dq: [i32; 2],
+        start: usize,
+        out: &mut [i16],
+    ) -> usize {
+        let mut n = start;
+        let mut p = self.probs(kind, BANDS[n], context);
+        while n < 16 {
+            if !reader.read(p[0]) {
+                return n;
+            }
+            while !reader.read(p[1]) {
+                n += 1;
+                if n == 16 {
+                    return 16;
+                }
+                p = self.probs(kind, BANDS[n], 0);
+            }
+            let (value, next_context) = if !reader.read(p[2]) {
+                (1, 1)
+            } else {
+                (large_value(reader, p), 2)
+            };
+            let value = if reader.flag() { -value } else { value };
+            out[ZIGZAG4[n]] = (value * dq[(n > 0) as usize]) as i16;
+            n += 1;
+            p = self.probs(kind, BANDS[n], next_context);
+        }
+        16
+    }
+
+    fn parse_residuals(
+        &mut self,
+        reader: &mut BoolReader,
+        mb_x: usize,
+        is_i4x4: bool,
+        quant: &Quant,
+        coeffs: &mut [i16; 384],
+    ) -> (u32, u32) {
+        let (first, kind) = if is_i4x4 {
+            (0, 3)
+        } else {
+            let mut dc = [0i16; 16];
+            let context = self.top_nz_dc[mb_x] as usize + self.left_nz_dc as usize;
+            let nz = self.read_coefficients(reader, 1, context, quant.y2, 0, &mut dc);
+            self.top_nz_dc[mb_x] = nz > 0;
+            self.left_nz_dc = nz > 0;
+            inverse_wht(&dc, coeffs);
+            (1, 0)
+        };
+
+        let mut tnz = (self.top_nz[mb_x] & 0x0f) as u32;
+        let mut lnz = (self.left_nz & 0x0f) as u32;
+        let mut non_zero_y = 0u32;
+        for y in 0..4 {
+            let mut l = lnz & 1;
+            let mut nz_codes = 0u32;
+            for x in 0..4 {
+                let context = (l + (tnz & 1)) as usize;
+                let block = &mut coeffs[(y * 4 + x) * 16..][..16];
+                let nz = self.read_coefficients(reader, kind, context, quant.y1, first, block);
+                l = (nz > first) as u32;
+                tnz = (tnz >> 1) | (l << 7);
+                nz_codes = nz_code(nz_codes, nz, block[0] != 0);
+            }
+            tnz >>= 4;
+            lnz = (lnz >> 1) | (l << 7);
+            non_zero_y = (non_zero_y << 8) | nz_codes;
+        }
+        let mut out_top = tnz;
+        let mut out_left = lnz >> 4;
+
+        let mut non_zero_uv = 0u32;
+        for channel in [0, 2] {
+            let mut nz_codes = 0u32;
+            let mut tnz = (self.top_nz[mb_x] >> (4 + channel)) as u32;
+            let mut lnz = (self.left_nz >> (4 + channel)) as u32;
+            for y in 0..2 {
+                let mut l = lnz & 1;
+                for x in 0..2 {
+                    let context = (l + (tnz & 1)) as usize;
+                    let block = &mut coeffs[(16 + channel * 2 + y * 2 + x) * 16..][..16];
+                    let nz = self.read_coefficients(reader, 2, context, quant.uv, 0, block);
+                    l = (nz > 0) as u32;
+                    tnz = (tnz >> 1) | (l << 3);
+                    nz_codes = nz_code(nz_codes, nz, block[0] != 0);
+                }
+                tnz >>= 2;
+                lnz = (lnz >> 1) | (l << 5);
+            }
+            non_zero_uv |= nz_codes << (4 * channel);
+            out_top |= (tnz << 4) << channel;
+            out_left |= (lnz & 0xf0) << channel;
+        }
+        self.top_nz[mb_x] = out_top as u8;
+        self.left_nz = out_left as u8;
+        (non_zero_y, non_zero_uv)
+    }
+
+    fn start_row(&mut self, mb_y: usize) {
+        let work = &mut self.work;
+        for j in 0..16 {
+            work[Y_OFF + j * BPS - 1] = 129;
+        }
+        for j in 0..8 {
+            work[U_OFF + j * BPS - 1] = 129;
+            work[V_OFF + j * BPS - 1] = 129;
+        }
+        if mb_y > 0 {
+            work[Y_OFF - BPS - 1] = 129;
+            work[U_OFF - BPS - 1] = 129;
+            work[V_OFF - BPS - 1] = 129;
+        } else {
+            work[Y_OFF - BPS - 1..Y_OFF - BPS + 20].fill(127);
+            work[U_OFF - BPS - 1..U_OFF - BPS + 8].fill(127);
+            work[V_OFF - BPS - 1..V_OFF - BPS + 8].fill(127);
+        }
+    }
+
+    fn reconstruct(
+        &mut self,
+        frame: &mut Frame,
+        mb_x: usize,
+        mb_y: usize,
+        mb_height: usize,
+        modes: &Modes,
+        coeffs: &[i16; 384],
+    ) {
+        let work = &mut self.work;
+        if mb_x > 0 {
+            for j in 0..17 {
+                let row = Y_OFF + j * BPS - BPS;
+                work.copy_within(row + 12..row + 16, row - 4);
+            }
+            for j in 0..9 {
+                for offset in [U_OFF, V_OFF] {
+                    let row = offset + j * BPS - BPS;
+                    work.copy_within(row + 4..row + 8, row - 4);
+                }
+            }
+        }
+        let top = self.top_samples[mb_x];
+        if mb_y > 0 {
+            work[Y_OFF - BPS..Y_OFF - BPS + 16].copy_from_slice(&top[..16]);
+            work[U_OFF - BPS..U_OFF - BPS + 8].copy_from_slice(&top[16..24]);
+            work[V_OFF - BPS..V_OFF - BPS + 8].copy_from_slice(&top[24..]);
+        }
+
+        if modes.is_i4x4 {
+            let top_right = Y_OFF - BPS + 16;
+            if mb_y > 0 {
+                let samples = if mb_x + 1 >= self.mb_width {
+                    [top[15]; 4]
+                } else {
+                    self.top_samples[mb_x + 1][..4].try_into().unwrap()
+                };
+                work[top_right..top_right + 4].copy_from_slice(&samples);
+            }
+            for row in [4, 8, 12] {
+                work.copy_within(top_right..top_right + 4, top_right + row * BPS);
+            }
+            for n in 0..16 {
+                let offset = Y_OFF + (n % 4) * 4 + (n / 4) * 4 * BPS;
+                predict_luma4(work, offset, modes.luma[n]);
+                inverse_dct(&coeffs[n * 16..][..16], work, offset);
+            }
+        } else {
+            predict_block(work, Y_OFF, 16, checked_mode(mb_x, mb_y, modes.luma[0]));
+            for n in 0..16 {
+                let offset = Y_OFF + (n % 4) * 4 + (n / 4) * 4 * BPS;
+                inverse_dct(&coeffs[n * 16..][..16], work, offset);
+            }
+        }
+        let chroma_mode = checked_mode(mb_x, mb_y, modes.chroma);
+        for (plane, offset) in [(0, U_OFF), (1, V_OFF)] {
+            predict_block(work, offset, 8, chroma_mode);
+            for n in 0..4 {
+                let block = &coeffs[(16 + plane * 4 + n) * 16..][..16];
+                inverse_dct(block, work, offset + (n % 2) * 4 + (n / 2) * 4 * BPS);
+            }
+        }
+
+        if mb_y + 1 < mb_height {
+            let samples = &mut self.top_samples[mb_x];
+            samples[..16].copy_from_slice(&work[Y_OFF + 15 * BPS..][..16]);
+            samples[16..24].copy_from_slice(&work[U_OFF + 7 * BPS..][..8]);
+            samples[24..].copy_from_slice(&work[V_OFF + 7 * BPS..][..8]);
+        }
+        for j in 0..16 {
+            let at = (mb_y * 16 + j) * frame.y_stride + mb_x * 16;
+            frame.y[at..at + 16].copy_from_slice(&work[Y_OFF + j * BPS..][..16]);
+        }
+        for j in 0..8 {
+            let at = (mb_y * 8 + j) * frame.uv_stride + mb_x * 8;
+            frame.u[at..at + 8].copy_from_slice(&work[U_OFF + j * BPS..][..8]);
+            frame.v[at..at + 8].copy_from_slice(&work[V_OFF + j * BPS..][..8]);
+        }
+    }
+}
+
+fn large_value(reader: &mut BoolReader, p: &[u8]) -> i32 {
+    if !reader.read(p[3]) {
+        if !reader.read(p[4]) {
+            2
+        } else {
+            3 + reader.read(p[5]) as i32
+        }
+    } else if !reader.read(p[6]) {
+        if !reader.read(p[7]) {
+            5 + reader.read(159) as i32
+        } else {
+            7 + 2 * reader.read(165) as i32 + reader.read(145) as i32
+        }
+    } else {
+        let bit1 = reader.read(p[8]) as usize;
+        let bit0 = reader.read(p[9 + bit1]) as usize;
+        let category = 2 * bit1 + bit0;
+        let extra = CAT_PROBS[category]
+            .iter()
+            .fold(0, |value, &prob| value * 2 + reader.read(prob) as i32);
+        extra + 3 + (8 << category)
+    }
+}
+
+fn nz_code(codes: u32, nz: usize, dc_non_zero: bool) -> u32 {
+    let code = if nz > 3 {
+        3
+    } else if nz > 1 {
+        2
+    } else {
+        dc_non_zero as u32
+    };
+    (codes << 2) | code
+}
+
+fn inverse_wht(input: &[i16; 16], out: &mut [i16; 384]) {
+    let mut tmp = [0i32; 16];
+    for i in 0..4 {
+        let a0 = input[i] as i32 + input[12 + i] as i32;
+        let a1 = input[4 + i] as i32 + input[8 + i] as i32;
+        let a2 = input[4 + i] as i32 - input[8 + i] as i32;
+        let a3 = input[i] as i32 - input[12 + i] as i32;
+        tmp[i] = a0 + a1;
+        tmp[8 + i] = a0 - a1;
+        tmp[4 + i] = a3 + a2;
+        tmp[12 + i] = a3 - a2;
+    }
+    for i in 0..4 {
+        let dc = tmp[i * 4] + 3;
+        let a0 = dc + tmp[3 + i * 4];
+        let a1 = tmp[1 + i * 4] + tmp[2 + i * 4];
+        let a2 = tmp[1 + i * 4] - tmp[2 + i * 4];
+        let a3 = dc - tmp[3 + i * 4];
+        let base = i * 64;
+        out[base] = ((a0 + a1) >> 3) as i16;
+        out[base + 16] = ((a3 + a2) >> 3) as i16;
+        out[base + 32] = ((a0 - a1) >> 3) as i16;
+        out[base + 48] = ((a3 - a2) >> 3) as i16;
+    }
+}
+
+// Widened so corrupt coefficients cannot overflow; valid data rounds exactly
+// as libwebp does.
+fn mul1(a: i32) -> i32 {
+    ((a as i64 * 20091) >> 16) as i32 + a
+}
+
+fn mul2(a: i32) -> i32 {
+    ((a as i64 * 35468) >> 16) as i32
+}
+
+fn inverse_dct(input: &[i16], work: &mut [u8], offset: usize) {
+    let mut tmp = [0i32; 16];
+    for i in 0..4 {
+        let column = |row: usize| input[row * 4 + i] as i32;
+        let a = column(0) + column(2);
+        let b = column(0) - column(2);
+        let c = mul2(column(1)) - mul1(column(3));
+        let d = mul1(column(1)) + mul2(column(3));
+        tmp[i * 4] = a + d;
+        tmp[i * 4 + 1] = b + c;
+        tmp[i * 4 + 2] = b - c;
+        tmp[i * 4 + 3] = a - d;
+    }
+    for i in 0..4 {
+        let dc = tmp[i] + 4;
+        let a = dc + tmp[8 + i];
+        let b = dc - tmp[8 + i];
+        let c = mul2(tmp[4 + i]) - mul1(tmp[12 + i]);
+        let d = mul1(tmp[4 + i]) + mul2(tmp[12 + i]);
+        let row = offset + i * BPS;
+        for (x, value) in [a + d, b + c, b - c, a - d].into_iter().enumerate() {
+            work[row + x] = (work[row + x] as i32 + (value >> 3)).clamp(0, 255) as u8;
+        }
+    }
+}
+
+// Variants of DC prediction used when the top or left edge is missing.
+const DC_NO_TOP: u8 = 4;
+const DC_NO_LEFT: u8 = 5;
+const DC_NO_TOP_LEFT: u8 = 6;
+
+fn checked_mode(mb_x: usize, mb_y: usize, mode: u8) -> u8 {
+    if mode != DC_PRED {
+        return mode;
+    }
+    match (mb_x == 0, mb_y == 0) {
+        (true, true) => DC_NO_TOP_LEFT,
+        (true, false) => DC_NO_LEFT,
+        (false, true) => DC_NO_TOP,
+        (false, false) => DC_PRED,
+    }
+}
+
+// 16x16 luma or 8x8 chroma prediction.
+fn predict_block(work: &mut [u8], offset: usize, size: usize, mode: u8) {
+    let top = offset - BPS;
+    let shift = size.trailing_zeros();
+    let fill = |work: &mut [u8], value: u8| {
+        for y in 0..size {
+            work[offset + y * BPS..][..size].fill(value);
+        }
+    };
+    let top_sum = || (0..size).map(|x| work[top + x] as u32).sum::<u32>();
+    let left_sum = || {
+        (0..size)
+            .map(|y| work[offset + y * BPS - 1] as u32)
+            .sum::<u32>()
+    };
+    match mode {
+        DC_PRED => {
+            let value = (top_sum() + left_sum() + size as u32) >> (shift + 1);
+            fill(work, value as u8);
+        }
+        DC_NO_TOP => {
+            let value = (left_sum() + (size as u32 >> 1)) >> shift;
+            fill(work, value as u8);
+        }
+        DC_NO_LEFT => {
+            let value = (top_sum() + (size as u32 >> 1)) >> shift;
+            fill(work, value as u8);
+        }
+        DC_NO_TOP_LEFT => fill(work, 0x80),
+        TM_PRED => true_motion(work, offset, size),
+        V_PRED => {
+            for y in 0..size {
+                work.copy_within(top..top + size, offset + y * BPS);
+            }
+        }
+        _ => {
+            for y in 0..size {
+                let row = offset + y * BPS;
+                let left = work[row - 1];
+                work[row..row + size].fill(left);
+            }
+        }
+    }
+}
+
+fn true_motion(work: &mut [u8], offset: usize, size: usize) {
+    let top = offset - BPS;
+    let top_left = work[top - 1] as i32;
+    for y in 0..size {
+        let row = offset + y * BPS;
+        let left = work[row - 1] as i32;
+        for x in 0..size {
+            work[row + x] = (work[top + x] as i32 + left - top_left).clamp(0, 255) as u8;
+        }
+    }
+}
+
+fn avg3(a: u8, b: u8, c: u8) -> u8 {
+    ((a as u32 + 2 * b as u32 + c as u32 + 2) >> 2) as u8
+}
+
+fn avg2(a: u8, b: u8) -> u8 {
+    ((a as u32 + b as u32 + 1) >> 1) as u8
+}
+
+fn predict_luma4(work: &mut [u8], offset: usize, mode: u8) {
+    let top = offset - BPS;
+    let at = |x: usize, y: usize| offset + x + y * BPS;
+    // Edge samples: top row A..H (including top-right), left column I..L,
+    // and the corner X.
+    let [a, b, c, d, e, f, g, h]: [u8; 8] = work[top..top + 8].try_into().unwrap();
+    let [i, j, k, l] = [0, 1, 2, 3].map(|y| work[offset + y * BPS - 1]);
+    let x = work[top - 1];
+    let mut set = |cells: &[(usize, usize)], value: u8| {
+        for &(cx, cy) in cells {
+            work[at(cx, cy)] = value;
+        }
+    };
+    match mode {
+        DC_PRED => {
+            let sum: u32 = [a, b, c, d, i, j, k, l].iter().map(|&v| v as u32).sum();
+            let value = ((sum + 4) >> 3) as u8;
+            for cy in 0..4 {
+                for cx in 0..4 {
+                    set(&[(cx, cy)], value);
+                }
+            }
+        }
+        TM_PRED => true_motion(work, offset, 4),
+        V_PRED => {
+            let values = [avg3(x, a, b), avg3(a, b, c), avg3(b, c, d), avg3(c, d, e)];
+            for cy in 0..4 {
+                for (cx, &value) in values.iter().enumerate() {
+                    set(&[(cx, cy)], value);
+                }
+            }
+        }
+        H_PRED => {
+            let values = [avg3(x, i, j), avg3(i, j, k), avg3(j, k, l), avg3(k, l, l)];
+            for (cy, &value) in values.iter().enumerate() {
+                for cx in 0..4 {
+                    set(&[(cx, cy)], value);
+                }
+            }
+        }
+        B_RD_PRED => {
+            set(&[(0, 3)], avg3(j, k, l));
+            set(&[(1, 3), (0, 2)], avg3(i, j, k));
+            set(&[(2, 3), (1, 2), (0, 1)], avg3(x, i, j));
+            set(&[(3, 3), (2, 2), (1, 1), (0, 0)], avg3(a, x, i));
+            set(&[(3, 2), (2, 1), (1, 0)], avg3(b, a, x));
+            set(&[(3, 1), (2, 0)], avg3(c, b, a));
+            set(&[(3, 0)], avg3(d, c, b));
+        }
+        B_LD_PRED => {
+            set(&[(0, 0)], avg3(a, b, c));
+            set(&[(1, 0), (0, 1)], avg3(b, c, d));
+            set(&[(2, 0), (1, 1), (0, 2)], avg3(c, d, e));
+            set(&[(3, 0), (2, 1), (1, 2), (0, 3)], avg3(d, e, f));
+            set(&[(3, 1), (2, 2), (1, 3)], avg3(e, f, g));
+            set(&[(3, 2), (2, 3)], avg3(f, g, h));
+            set(&[(3, 3)], avg3(g, h, h));
+        }
+        B_VR_PRED => {
+            set(&[(0, 0), (1, 2)], avg2(x, a));
+            set(&[(1, 0), (2, 2)], avg2(a, b));
+            set(&[(2, 0), (3, 2)], avg2(b, c));
+            set(&[(3, 0)], avg2(c, d));
+            set(&[(0, 3)], avg3(k, j, i));
+            set(&[(0, 2)], avg3(j, i, x));
+            set(&[(0, 1), (1, 3)], avg3(i, x, a));
+            set(&[(1, 1), (2, 3)], avg3(x, a, b));
+            set(&[(2, 1), (3, 3)], avg3(a, b, c));
+            set(&[(3, 1)], avg3(b, c, d));
+        }
+        B_VL_PRED => {
+            set(&[(0, 0)], avg2(a, b));
+            set(&[(1, 0), (0, 2)], avg2(b, c));
+            set(&[(2, 0), (1, 2)], avg2(c, d));
+            set(&[(3, 0), (2, 2)], avg2(d, e));
+            set(&[(0, 1)], avg3(a, b, c));
+            set(&[(1, 1), (0, 3)], avg3(b, c, d));
+            set(&[(2, 1), (1, 3)], avg3(c, d, e));
+            set(&[(3, 1), (2, 3)], avg3(d, e, f));
+            set(&[(3, 2)], avg3(e, f, g));
+            set(&[(3, 3)], avg3(f, g, h));
+        }
+        B_HU_PRED => {
+            set(&[(0, 0)], avg2(i, j));
+            set(&[(2, 0), (0, 1)], avg2(j, k));
+            set(&[(2, 1), (0, 2)], avg2(k, l));
+            set(&[(1, 0)], avg3(i, j, k));
+            set(&[(3, 0), (1, 1)], avg3(j, k, l));
+            set(&[(3, 1), (1, 2)], avg3(k, l, l));
+            set(&[(3, 2), (2, 2), (0, 3), (1, 3), (2, 3), (3, 3)], l);
+        }
+        _ => {
+            set(&[(0, 0), (2, 1)], avg2(i, x));
+            set(&[(0, 1), (2, 2)], avg2(j, i));
+            set(&[(0, 2), (2, 3)], avg2(k, j));
+            set(&[(0, 3)], avg2(l, k));
+            set(&[(3, 0)], avg3(a, b, c));
+            set(&[(2, 0)], avg3(x, a, b));
+            set(&[(1, 0), (3, 1)], avg3(i, x, a));
+            set(&[(1, 1), (3, 2)], avg3(j, i, x));
+            set(&[(1, 2), (3, 3)], avg3(k, j, i));
+            set(&[(1, 3)], avg3(l, k, j));
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Loop filter
+
+fn hev(plane: &[u8], p: usize, step: usize, threshold: i32) -> bool {
+    let [p1, p0, q0, q1] = [p - 2 * step, p - step, p, p + step].map(|i| plane[i] as i32);
+    (p1 - p0).abs() > threshold || (q1 - q0).abs() > threshold
+}
+
+fn needs_filter(plane: &[u8], p: usize, step: usize, threshold: i32) -> bool {
+    let [p1, p0, q0, q1] = [p - 2 * step, p - step, p, p + step].map(|i| plane[i] as i32);
+    4 * (p0 - q0).abs() + (p1 - q1).abs() <= threshold
+}
+
+fn needs_filter2(plane: &[u8], p: usize, step: usize, threshold: i32, inner: i32) -> bool {
+    let [p3, p2, p1, p0, q0, q1, q2, q3] = [
+        p - 4 * step,
+        p - 3 * step,
+        p - 2 * step,
+        p - step,
+        p,
+        p + step,
+        p + 2 * step,
+        p + 3 * step,
+    ]
+    .map(|i| plane[i] as i32);
+    4 * (p0 - q0).abs() + (p1 - q1).abs() <= threshold
+        && [p3 - p2, p2 - p1, p1 - p0, q3 - q2, q2 - q1, q1 - q0]
+            .iter()
+            .all(|d| d.abs() <= inner)
+}
+
+fn sclip1(v: i32) -> i32 {
+    v.clamp(-128, 127)
+}
+
+fn sclip2(v: i32) -> i32 {
+    v.clamp(-16, 15)
+}
+
+fn clip1(v: i32) -> u8 {
+    v.clamp(0, 255) as u8
+}
+
+fn filter2(plane: &mut [u8], p: usize, step: usize) {
+    let [p1, p0, q0, q1] = [p - 2 * step, p - step, p, p + step].map(|i| plane[i] as i32);
+    let a = 3 * (q0 - p0) + sclip1(p1 - q1);
+    let a1 = sclip2((a + 4) >> 3);
+    let a2 = sclip2((a + 3) >> 3);
+    plane[p - step] = clip1(p0 + a2);
+    plane[p] = clip1(q0 - a1);
+}
+
+fn filter4(plane: &mut [u8], p: usize, step: usize) {
+    let [p1, p0, q0, q1] = [p - 2 * step, p - step, p, p + step].map(|i| plane[i] as i32);
+    let a = 3 * (q0 - p0);
+    let a1 = sclip2((a + 4) >> 3);
+    let a2 = sclip2((a + 3) >> 3);
+    let a3 = (a1 + 1) >> 1;
+    plane[p - 2 * step] = clip1(p1 + a3);
+    plane[p - step] = clip1(p0 + a2);
+    plane[p] = clip1(q0 - a1);
+    plane[p + step] = clip1(q1 - a3);
+}
+
+fn filter6(plane: &mut [u8], p: usize, step: usize) {
+    let [p2, p1, p0, q0, q1, q2] = [
+        p - 3 * step,
+        p - 2 * step,
+        p - step,
+        p,
+        p + step,
+        p + 2 * step,
+    ]
+    .map(|i| plane[i] as i32);
+    let a = sclip1(3 * (q0 - p0) + sclip1(p1 - q1));
+    let a1 = (27 * a + 63) >> 7;
+    let a2 = (18 * a + 63) >> 7;
+    let a3 = (9 * a + 63) >> 7;
+    plane[p - 3 * step] = clip1(p2 + a3);
+    plane[p - 2 * step] = clip1(p1 + a2);
+    plane[p - step] = clip1(p0 + a1);
+    plane[p] = clip1(q0 - a1);
+    plane[p + step] = clip1(q1 - a2);
+    plane[p + 2 * step] = clip1(q2 - a3);
+}
+
+// Filters `size` positions along an edge; `macroblock_edge` selects the
+// stronger six-tap filter used between macroblocks.
+#[allow(clippy::too_many_arguments)]
+fn filter_loop(
+    plane: &mut [u8],
+    mut p: usize,
+    step: usize,
+    advance: usize,
+    size: usize,
+    strength: FilterStrength,
+    limit: i32,
+    macroblock_edge: bool,
+) {
+    let threshold = 2 * limit + 1;
+    for _ in 0..size {
+        if needs_filter2(plane, p, step, threshold, strength.inner_level) {
+            if hev(plane, p, step, strength.hev_threshold) {
+                filter2(plane, p, step);
+            } else if macroblock_edge {
+                filter6(plane, p, step);
+            } else {
+                filter4(plane, p, step);
+            }
+        }
+        p += advance;
+    }
+}
+
+fn simple_filter_loop(plane: &mut [u8], mut p: usize, step: usize, advance: usize, limit: i32) {
+    let threshold = 2 * limit + 1;
+    for _ in 0..16 {
+        if needs_filter(plane, p, step, threshold) {
+            filter2(plane, p, step);
+        }
+        p += advance;
+    }
+}
+
+impl Frame {
+    fn filter_macroblock(
+        &mut self,
+        mb_x: usize,
+        mb_y: usize,
+        filter: MacroblockFilter,
+        simple: bool,
+    ) {
+        let strength = filter.strength;
+        let limit = strength.limit;
+        if limit == 0 {
+            return;
+        }
+        let (ys, uvs) = (self.y_stride, self.uv_stride);
+        let y = mb_y * 16 * ys + mb_x * 16;
+        let uv = mb_y * 8 * uvs + mb_x * 8;
+        if simple {
+            if mb_x > 0 {
+                simple_filter_loop(&mut self.y, y, 1, ys, limit + 4);
+            }
+            if filter.inner {
+                for k in 1..4 {
+                    simple_filter_loop(&mut self.y, y + 4 * k, 1, ys, limit);
+                }
+            }
+            if mb_y > 0 {
+                simple_filter_loop(&mut self.y, y, ys, 1, limit + 4);
+            }
+            if filter.inner {
+                for k in 1..4 {
+                    simple_filter_loop(&mut self.y, y + 4 * k * ys, ys, 1, limit);
+                }
+            }
+            return;
+        }
+        if mb_x > 0 {
+            filter_loop(&mut self.y, y, 1, ys, 16, strength, limit + 4, true);
+            filter_loop(&mut self.u, uv, 1, uvs, 8, strength, limit + 4, true);
+            filter_loop(&mut self.v, uv, 1, uvs, 8, strength, limit + 4, true);
+        }
+        if filter.inner {
+            for k in 1..4 {
+                filter_loop(&mut self.y, y + 4 * k, 1, ys, 16, strength, limit, false);
+            }
+            filter_loop(&mut self.u, uv + 4, 1, uvs, 8, strength, limit, false);
+            filter_loop(&mut self.v, uv + 4, 1, uvs, 8, strength, limit, false);
+        }
+        if mb_y > 0 {
+            filter_loop(&mut self.y, y, ys, 1, 16, strength, limit + 4, true);
+            filter_loop(&mut self.u, uv, uvs, 1, 8, strength, limit + 4, true);
+            filter_loop(&mut self.v, uv, uvs, 1, 8, strength, limit + 4, true);
+        }
+        if filter.inner {
+            for k in 1..4 {
+                filter_loop(
+                    &mut self.y,
+                    y + 4 * k * ys,
+                    ys,
+                    1,
+                    16,
+                    strength,
+                    limit,
+                    false,
+                );
+            }
+            filter_loop(&mut self.u, uv + 4 * uvs, uvs, 1, 8, strength, limit, false);
+            filter_loop(&mut self.v, uv + 4 * uvs, uvs, 1, 8, strength, limit, false);
+        }
+    }
+
+    // Upsamples chroma with libwebp's "fancy" filter: each output sample
+    // blends the nearer chroma row 3:1 with the farther one.
+    fn to_rgba(&self) -> Vec<u8> {
+        let (width, height) = (self.width, self.height);
+        let uv_height = height.div_ceil(2);
+        let mut rgba = vec![0u8; width * height * 4];
+        for y in 0..height {
+            let (near, far) = if y == 0 {
+                (0, 0)
+            } else if y % 2 == 1 {
+                ((y - 1) / 2, y.div_ceil(2).min(uv_height - 1))
+            } else {
+                (y / 2, y / 2 - 1)
+            };
+            let uv_row = |plane: &'_ [u8], row: usize| -> Vec<u8> {
+                plane[row * self.uv_stride..][..self.uv_stride].to_vec()
+            };
+            let u = upsample_row(&uv_row(&self.u, near), &uv_row(&self.u, far), width);
+            let v = upsample_row(&uv_row(&self.v, near), &uv_row(&self.v, far), width);
+            let luma = &self.y[y * self.y_stride..][..width];
+            let out = &mut rgba[y * width * 4..][..width * 4];
+            for (x, pixel) in out.chunks_exact_mut(4).enumerate() {
+                let (l, u, v) = (luma[x] as i32, u[x] as i32, v[x] as i32);
+                pixel[0] = yuv_clip(mult_hi(l, 19077) + mult_hi(v, 26149) - 14234);
+                pixel[1] =
+                    yuv_clip(mult_hi(l, 19077) - mult_hi(u, 6419) - mult_hi(v, 13320) + 8708);
+                pixel[2] = yuv_clip(mult_hi(l, 19077) + mult_hi(u, 33050) - 17685);
+                pixel[3] = 255;
+            }
+        }
+        rgba
+    }
+}
+
+fn upsample_row(near: &[u8], far: &[u8], width: usize) -> Vec<u8> {
+    let edge = |x: usize| ((3 * near[x] as u32 + far[x] as u32 + 2) >> 2) as u8;
+    let mut out = vec![0u8; width];
+    out[0] = edge(0);
+    for x in 1..=(width - 1) / 2 {
+        let (a, b) = (near[x - 1] as u32, near[x] as u32);
+        let (c, d) = (far[x - 1] as u32, far[x] as u32);
+        let average = a + b + c + d + 8;
+        let diagonal_bc = (average + 2 * (b + c)) >> 3;
+        let diagonal_ad = (average + 2 * (a + d)) >> 3;
+        out[2 * x - 1] = ((diagonal_bc + a) >> 1) as u8;
+        out[2 * x] = ((diagonal_ad + b) >> 1) as u8;
+    }
+    if width.is_multiple_of(2) {
+        out[width - 1] = edge(width / 2 - 1);
+    }
+    out
+}
+
+fn mult_hi(value: i32, coefficient: i32) -> i32 {
+    (value * coefficient) >> 8
+}
+
+fn yuv_clip(value: i32) -> u8 {
+    if value & !16383 == 0 {
+        (value >> 6) as u8
+    } else if value < 0 {
+        0
+    } else {
+        255
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tables from RFC 6386: dequantization factors, default coefficient
+// probabilities with their update probabilities (indexed by block type, band,
+// context and token), and intra 4x4 mode probabilities keyed by the modes
+// above and to the left.
+
+const DC_TABLE: [u8; 128] = [
+    4, 5, 6, 7, 8, 9, 10, 10, 11, 12, 13, 14, 15, 16, 17, 17, 18, 19, 20, 20, 21, 21, 22, 22, 23,
+    23, 24, 25, 25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37, 37, 38, 39, 40, 41, 42, 43, 44,
+    45, 46, 46, 47, 48, 49, 50, 51, 52, 53, 54, 55, 56, 57, 58, 59, 60, 61, 62, 63, 64, 65, 66, 67,
+    68, 69, 70, 71, 72, 73, 74, 75, 76, 76, 77, 78, 79, 80, 81, 82, 83, 84, 85, 86, 87, 88, 89, 91,
+    93, 95, 96, 98, 100, 101, 102, 104, 106, 108, 110, 112, 114, 116, 118, 122, 124, 126, 128, 130,
+    132, 134, 136, 138, 140, 143, 145, 148, 151, 154, 157,
+];
+const AC_TABLE: [u16; 128] = [
+    4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28,
+    29, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47, 48, 49, 50, 51, 52,
+    53, 54, 55, 56, 57, 58, 60, 62, 64, 66, 68, 70, 72, 74, 76, 78, 80, 82, 84, 86, 88, 90, 92, 94,
+    96, 98, 100, 102, 104, 106, 108, 110, 112, 114, 116, 119, 122, 125, 128, 131, 134, 137, 140,
+    143, 146, 149, 152, 155, 158, 161, 164, 167, 170, 173, 177, 181, 185, 189, 193, 197, 201, 205,
+    209, 213, 217, 221, 225, 229, 234, 239, 245, 249, 254, 259, 264, 269, 274, 279, 284,
+];
+const COEFF_PROBS: [u8; 1056] = [
+    128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128,
+    128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 253, 136, 254, 255, 228,
+    219, 128, 128, 128, 128, 128, 189, 129, 242, 255, 227, 213, 255, 219, 128, 128, 128, 106, 126,
+    227, 252, 214, 209, 255, 255, 128, 128, 128, 1, 98, 248, 255, 236, 226, 255, 255, 128, 128,
+    128, 181, 133, 238, 254, 221, 234, 255, 154, 128, 128, 128, 78, 134, 202, 247, 198, 180, 255,
+    219, 128, 128, 128, 1, 185, 249, 255, 243, 255, 128, 128, 128, 128, 128, 184, 150, 247, 255,
+    236, 224, 128, 128, 128, 128, 128, 77, 110, 216, 255, 236, 230, 128, 128, 128, 128, 128, 1,
+    101, 251, 255, 241, 255, 128, 128, 128, 128, 128, 170, 139, 241, 252, 236, 209, 255, 255, 128,
+    128, 128, 37, 116, 196, 243, 228, 255, 255, 255, 128, 128, 128, 1, 204, 254, 255, 245, 255,
+    128, 128, 128, 128, 128, 207, 160, 250, 255, 238, 128, 128, 128, 128, 128, 128, 102, 103, 231,
+    255, 211, 171, 128, 128, 128, 128, 128, 1, 152, 252, 255, 240, 255, 128, 128, 128, 128, 128,
+    177, 135, 243, 255, 234, 225, 128, 128, 128, 128, 128, 80, 129, 211, 255, 194, 224, 128, 128,
+    128, 128, 128, 1, 1, 255, 128, 128, 128, 128, 128, 128, 128, 128, 246, 1, 255, 128, 128, 128,
+    128, 128, 128, 128, 128, 255, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 198, 35, 237,
+    223, 193, 187, 162, 160, 145, 155, 62, 131, 45, 198, 221, 172, 176, 220, 157, 252, 221, 1, 68,
+    47, 146, 208, 149, 167, 221, 162, 255, 223, 128, 1, 149, 241, 255, 221, 224, 255, 255, 128,
+    128, 128, 184, 141, 234, 253, 222, 220, 255, 199, 128, 128, 128, 81, 99, 181, 242, 176, 190,
+    249, 202, 255, 255, 128, 1, 129, 232, 253, 214, 197, 242, 196, 255, 255, 128, 99, 121, 210,
+    250, 201, 198, 255, 202, 128, 128, 128, 23, 91, 163, 242, 170, 187, 247, 210, 255, 255, 128, 1,
+    200, 246, 255, 234, 255, 128, 128, 128, 128, 128, 109, 178, 241, 255, 231, 245, 255, 255, 128,
+    128, 128, 44, 130, 201, 253, 205, 192, 255, 255, 128, 128, 128, 1, 132, 239, 251, 219, 209,
+    255, 165, 128, 128, 128, 94, 136, 225, 251, 218, 190, 255, 255, 128, 128, 128, 22, 100, 174,
+    245, 186, 161, 255, 199, 128, 128, 128, 1, 182, 249, 255, 232, 235, 128, 128, 128, 128, 128,
+    124, 143, 241, 255, 227, 234, 128, 128, 128, 128, 128, 35, 77, 181, 251, 193, 211, 255, 205,
+    128, 128, 128, 1, 157, 247, 255, 236, 231, 255, 255, 128, 128, 128, 121, 141, 235, 255, 225,
+    227, 255, 255, 128, 128, 128, 45, 99, 188, 251, 195, 217, 255, 224, 128, 128, 128, 1, 1, 251,
+    255, 213, 255, 128, 128, 128, 128, 128, 203, 1, 248, 255, 255, 128, 128, 128, 128, 128, 128,
+    137, 1, 177, 255, 224, 255, 128, 128, 128, 128, 128, 253, 9, 248, 251, 207, 208, 255, 192, 128,
+    128, 128, 175, 13, 224, 243, 193, 185, 249, 198, 255, 255, 128, 73, 17, 171, 221, 161, 179,
+    236, 167, 255, 234, 128, 1, 95, 247, 253, 212, 183, 255, 255, 128, 128, 128, 239, 90, 244, 250,
+    211, 209, 255, 255, 128, 128, 128, 155, 77, 195, 248, 188, 195, 255, 255, 128, 128, 128, 1, 24,
+    239, 251, 218, 219, 255, 205, 128, 128, 128, 201, 51, 219, 255, 196, 186, 128, 128, 128, 128,
+    128, 69, 46, 190, 239, 201, 218, 255, 228, 128, 128, 128, 1, 191, 251, 255, 255, 128, 128, 128,
+    128, 128, 128, 223, 165, 249, 255, 213, 255, 128, 128, 128, 128, 128, 141, 124, 248, 255, 255,
+    128, 128, 128, 128, 128, 128, 1, 16, 248, 255, 255, 128, 128, 128, 128, 128, 128, 190, 36, 230,
+    255, 236, 255, 128, 128, 128, 128, 128, 149, 1, 255, 128, 128, 128, 128, 128, 128, 128, 128, 1,
+    226, 255, 128, 128, 128, 128, 128, 128, 128, 128, 247, 192, 255, 128, 128, 128, 128, 128, 128,
+    128, 128, 240, 128, 255, 128, 128, 128, 128, 128, 128, 128, 128, 1, 134, 252, 255, 255, 128,
+    128, 128, 128, 128, 128, 213, 62, 250, 255, 255, 128, 128, 128, 128, 128, 128, 55, 93, 255,
+    128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128,
+    128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128,
+    128, 128, 128, 202, 24, 213, 235, 186, 191, 220, 160, 240, 175, 255, 126, 38, 182, 232, 169,
+    184, 228, 174, 255, 187, 128, 61, 46, 138, 219, 151, 178, 240, 170, 255, 216, 128, 1, 112, 230,
+    250, 199, 191, 247, 159, 255, 255, 128, 166, 109, 228, 252, 211, 215, 255, 174, 128, 128, 128,
+    39, 77, 162, 232, 172, 180, 245, 178, 255, 255, 128, 1, 52, 220, 246, 198, 199, 249, 220, 255,
+    255, 128, 124, 74, 191, 243, 183, 193, 250, 221, 255, 255, 128, 24, 71, 130, 219, 154, 170,
+    243, 182, 255, 255, 128, 1, 182, 225, 249, 219, 240, 255, 224, 128, 128, 128, 149, 150, 226,
+    252, 216, 205, 255, 171, 128, 128, 128, 28, 108, 170, 242, 183, 194, 254, 223, 255, 255, 128,
+    1, 81, 230, 252, 204, 203, 255, 192, 128, 128, 128, 123, 102, 209, 247, 188, 196, 255, 233,
+    128, 128, 128, 20, 95, 153, 243, 164, 173, 255, 203, 128, 128, 128, 1, 222, 248, 255, 216, 213,
+    128, 128, 128, 128, 128, 168, 175, 246, 252, 235, 205, 255, 255, 128, 128, 128, 47, 116, 215,
+    255, 211, 212, 255, 255, 128, 128, 128, 1, 121, 236, 253, 212, 214, 255, 255, 128, 128, 128,
+    141, 84, 213, 252, 201, 202, 255, 219, 128, 128, 128, 42, 80, 160, 240, 162, 185, 255, 205,
+    128, 128, 128, 1, 1, 255, 128, 128, 128, 128, 128, 128, 128, 128, 244, 1, 255, 128, 128, 128,
+    128, 128, 128, 128, 128, 238, 1, 255, 128, 128, 128, 128, 128, 128, 128, 128,
+];
+const COEFF_UPDATE_PROBS: [u8; 1056] = [
+    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
+    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 176, 246, 255, 255, 255,
+    255, 255, 255, 255, 255, 255, 223, 241, 252, 255, 255, 255, 255, 255, 255, 255, 255, 249, 253,
+    253, 255, 255, 255, 255, 255, 255, 255, 255, 255, 244, 252, 255, 255, 255, 255, 255, 255, 255,
+    255, 234, 254, 254, 255, 255, 255, 255, 255, 255, 255, 255, 253, 255, 255, 255, 255, 255, 255,
+    255, 255, 255, 255, 255, 246, 254, 255, 255, 255, 255, 255, 255, 255, 255, 239, 253, 254, 255,
+    255, 255, 255, 255, 255, 255, 255, 254, 255, 254, 255, 255, 255, 255, 255, 255, 255, 255, 255,
+    248, 254, 255, 255, 255, 255, 255, 255, 255, 255, 251, 255, 254, 255, 255, 255, 255, 255, 255,
+    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 253, 254, 255, 255, 255,
+    255, 255, 255, 255, 255, 251, 254, 254, 255, 255, 255, 255, 255, 255, 255, 255, 254, 255, 254,
+    255, 255, 255, 255, 255, 255, 255, 255, 255, 254, 253, 255, 254, 255, 255, 255, 255, 255, 255,
+    250, 255, 254, 255, 254, 255, 255, 255, 255, 255, 255, 254, 255, 255, 255, 255, 255, 255, 255,
+    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
+    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 217, 255,
+    255, 255, 255, 255, 255, 255, 255, 255, 255, 225, 252, 241, 253, 255, 255, 254, 255, 255, 255,
+    255, 234, 250, 241, 250, 253, 255, 253, 254, 255, 255, 255, 255, 254, 255, 255, 255, 255, 255,
+    255, 255, 255, 255, 223, 254, 254, 255, 255, 255, 255, 255, 255, 255, 255, 238, 253, 254, 254,
+    255, 255, 255, 255, 255, 255, 255, 255, 248, 254, 255, 255, 255, 255, 255, 255, 255, 255, 249,
+    254, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
+    255, 255, 255, 253, 255, 255, 255, 255, 255, 255, 255, 255, 255, 247, 254, 255, 255, 255, 255,
+    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 253, 254,
+    255, 255, 255, 255, 255, 255, 255, 255, 252, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
+    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 254, 254, 255, 255, 255, 255, 255,
+    255, 255, 255, 253, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
+    255, 255, 255, 255, 255, 255, 255, 254, 253, 255, 255, 255, 255, 255, 255, 255, 255, 250, 255,
+    255, 255, 255, 255, 255, 255, 255, 255, 255, 254, 255, 255, 255, 255, 255, 255, 255, 255, 255,
+    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
+    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 186, 251, 250, 255,
+    255, 255, 255, 255, 255, 255, 255, 234, 251, 244, 254, 255, 255, 255, 255, 255, 255, 255, 251,
+    251, 243, 253, 254, 255, 254, 255, 255, 255, 255, 255, 253, 254, 255, 255, 255, 255, 255, 255,
+    255, 255, 236, 253, 254, 255, 255, 255, 255, 255, 255, 255, 255, 251, 253, 253, 254, 254, 255,
+    255, 255, 255, 255, 255, 255, 254, 254, 255, 255, 255, 255, 255, 255, 255, 255, 254, 254, 254,
+    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
+    255, 254, 255, 255, 255, 255, 255, 255, 255, 255, 255, 254, 254, 255, 255, 255, 255, 255, 255,
+    255, 255, 255, 254, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
+    255, 255, 255, 255, 255, 255, 254, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
+    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
+    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
+    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
+    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
+    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
+    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 248, 255, 255, 255, 255, 255,
+    255, 255, 255, 255, 255, 250, 254, 252, 254, 255, 255, 255, 255, 255, 255, 255, 248, 254, 249,
+    253, 255, 255, 255, 255, 255, 255, 255, 255, 253, 253, 255, 255, 255, 255, 255, 255, 255, 255,
+    246, 253, 253, 255, 255, 255, 255, 255, 255, 255, 255, 252, 254, 251, 254, 254, 255, 255, 255,
+    255, 255, 255, 255, 254, 252, 255, 255, 255, 255, 255, 255, 255, 255, 248, 254, 253, 255, 255,
+    255, 255, 255, 255, 255, 255, 253, 255, 254, 254, 255, 255, 255, 255, 255, 255, 255, 255, 251,
+    254, 255, 255, 255, 255, 255, 255, 255, 255, 245, 251, 254, 255, 255, 255, 255, 255, 255, 255,
+    255, 253, 253, 254, 255, 255, 255, 255, 255, 255, 255, 255, 255, 251, 253, 255, 255, 255, 255,
+    255, 255, 255, 255, 252, 253, 254, 255, 255, 255, 255, 255, 255, 255, 255, 255, 254, 255, 255,
+    255, 255, 255, 255, 255, 255, 255, 255, 252, 255, 255, 255, 255, 255, 255, 255, 255, 255, 249,
+    255, 254, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 254, 255, 255, 255, 255, 255, 255,
+    255, 255, 255, 255, 253, 255, 255, 255, 255, 255, 255, 255, 255, 250, 255, 255, 255, 255, 255,
+    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
+    255, 255, 255, 255, 255, 255, 255, 255, 254, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
+    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
+];
+const BMODE_PROBS: [u8; 900] = [
+    231, 120, 48, 89, 115, 113, 120, 152, 112, 152, 179, 64, 126, 170, 118, 46, 70, 95, 175, 69,
+    143, 80, 85, 82, 72, 155, 103, 56, 58, 10, 171, 218, 189, 17, 13, 152, 114, 26, 17, 163, 44,
+    195, 21, 10, 173, 121, 24, 80, 195, 26, 62, 44, 64, 85, 144, 71, 10, 38, 171, 213, 144, 34, 26,
+    170, 46, 55, 19, 136, 160, 33, 206, 71, 63, 20, 8, 114, 114, 208, 12, 9, 226, 81, 40, 11, 96,
+    182, 84, 29, 16, 36, 134, 183, 89, 137, 98, 101, 106, 165, 148, 72, 187, 100, 130, 157, 111,
+    32, 75, 80, 66, 102, 167, 99, 74, 62, 40, 234, 128, 41, 53, 9, 178, 241, 141, 26, 8, 107, 74,
+    43, 26, 146, 73, 166, 49, 23, 157, 65, 38, 105, 160, 51, 52, 31, 115, 128, 104, 79, 12, 27,
+    217, 255, 87, 17, 7, 87, 68, 71, 44, 114, 51, 15, 186, 23, 47, 41, 14, 110, 182, 183, 21, 17,
+    194, 66, 45, 25, 102, 197, 189, 23, 18, 22, 88, 88, 147, 150, 42, 46, 45, 196, 205, 43, 97,
+    183, 117, 85, 38, 35, 179, 61, 39, 53, 200, 87, 26, 21, 43, 232, 171, 56, 34, 51, 104, 114,
+    102, 29, 93, 77, 39, 28, 85, 171, 58, 165, 90, 98, 64, 34, 22, 116, 206, 23, 34, 43, 166, 73,
+    107, 54, 32, 26, 51, 1, 81, 43, 31, 68, 25, 106, 22, 64, 171, 36, 225, 114, 34, 19, 21, 102,
+    132, 188, 16, 76, 124, 62, 18, 78, 95, 85, 57, 50, 48, 51, 193, 101, 35, 159, 215, 111, 89, 46,
+    111, 60, 148, 31, 172, 219, 228, 21, 18, 111, 112, 113, 77, 85, 179, 255, 38, 120, 114, 40, 42,
+    1, 196, 245, 209, 10, 25, 109, 88, 43, 29, 140, 166, 213, 37, 43, 154, 61, 63, 30, 155, 67, 45,
+    68, 1, 209, 100, 80, 8, 43, 154, 1, 51, 26, 71, 142, 78, 78, 16, 255, 128, 34, 197, 171, 41,
+    40, 5, 102, 211, 183, 4, 1, 221, 51, 50, 17, 168, 209, 192, 23, 25, 82, 138, 31, 36, 171, 27,
+    166, 38, 44, 229, 67, 87, 58, 169, 82, 115, 26, 59, 179, 63, 59, 90, 180, 59, 166, 93, 73, 154,
+    40, 40, 21, 116, 143, 209, 34, 39, 175, 47, 15, 16, 183, 34, 223, 49, 45, 183, 46, 17, 33, 183,
+    6, 98, 15, 32, 183, 57, 46, 22, 24, 128, 1, 54, 17, 37, 65, 32, 73, 115, 28, 128, 23, 128, 205,
+    40, 3, 9, 115, 51, 192, 18, 6, 223, 87, 37, 9, 115, 59, 77, 64, 21, 47, 104, 55, 44, 218, 9,
+    54, 53, 130, 226, 64, 90, 70, 205, 40, 41, 23, 26, 57, 54, 57, 112, 184, 5, 41, 38, 166, 213,
+    30, 34, 26, 133, 152, 116, 10, 32, 134, 39, 19, 53, 221, 26, 114, 32, 73, 255, 31, 9, 65, 234,
+    2, 15, 1, 118, 73, 75, 32, 12, 51, 192, 255, 160, 43, 51, 88, 31, 35, 67, 102, 85, 55, 186, 85,
+    56, 21, 23, 111, 59, 205, 45, 37, 192, 55, 38, 70, 124, 73, 102, 1, 34, 98, 125, 98, 42, 88,
+    104, 85, 117, 175, 82, 95, 84, 53, 89, 128, 100, 113, 101, 45, 75, 79, 123, 47, 51, 128, 81,
+    171, 1, 57, 17, 5, 71, 102, 57, 53, 41, 49, 38, 33, 13, 121, 57, 73, 26, 1, 85, 41, 10, 67,
+    138, 77, 110, 90, 47, 114, 115, 21, 2, 10, 102, 255, 166, 23, 6, 101, 29, 16, 10, 85, 128, 101,
+    196, 26, 57, 18, 10, 102, 102, 213, 34, 20, 43, 117, 20, 15, 36, 163, 128, 68, 1, 26, 102, 61,
+    71, 37, 34, 53, 31, 243, 192, 69, 60, 71, 38, 73, 119, 28, 222, 37, 68, 45, 128, 34, 1, 47, 11,
+    245, 171, 62, 17, 19, 70, 146, 85, 55, 62, 70, 37, 43, 37, 154, 100, 163, 85, 160, 1, 63, 9,
+    92, 136, 28, 64, 32, 201, 85, 75, 15, 9, 9, 64, 255, 184, 119, 16, 86, 6, 28, 5, 64, 255, 25,
+    248, 1, 56, 8, 17, 132, 137, 255, 55, 116, 128, 58, 15, 20, 82, 135, 57, 26, 121, 40, 164, 50,
+    31, 137, 154, 133, 25, 35, 218, 51, 103, 44, 131, 131, 123, 31, 6, 158, 86, 40, 64, 135, 148,
+    224, 45, 183, 128, 22, 26, 17, 131, 240, 154, 14, 1, 209, 45, 16, 21, 91, 64, 222, 7, 1, 197,
+    56, 21, 39, 155, 60, 138, 23, 102, 213, 83, 12, 13, 54, 192, 255, 68, 47, 28, 85, 26, 85, 85,
+    128, 128, 32, 146, 171, 18, 11, 7, 63, 144, 171, 4, 4, 246, 35, 27, 10, 146, 174, 171, 12, 26,
+    128, 190, 80, 35, 99, 180, 80, 126, 54, 45, 85, 126, 47, 87, 176, 51, 41, 20, 32, 101, 75, 128,
+    139, 118, 146, 116, 128, 85, 56, 41, 15, 176, 236, 85, 37, 9, 62, 71, 30, 17, 119, 118, 255,
+    17, 18, 138, 101, 38, 60, 138, 55, 70, 43, 26, 142, 146, 36, 19, 30, 171, 255, 97, 27, 20, 138,
+    45, 61, 62, 219, 1, 81, 188, 64, 32, 41, 20, 117, 151, 142, 20, 21, 163, 112, 19, 12, 61, 195,
+    128, 48, 4, 24,
+];
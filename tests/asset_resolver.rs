@@ -0,0 +1,207 @@
+use std::fs;
+use std::path::PathBuf;
+
+use rive_rs::AssetResolver;
+
+fn scratch_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("rive-rs-assets-{name}-{}", std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    dir
+}
+
+fn tar_entry(out: &mut Vec<u8>, name: &str, typeflag: u8, contents: &[u8]) {
+    let mut header = [0u8; 512];
+    header[..name.len()].copy_from_slice(name.as_bytes());
+    header[100..107].copy_from_slice(b"0000644");
+    header[124..135].copy_from_slice(format!("{:011o}", contents.len()).as_bytes());
+    header[156] = typeflag;
+    header[257..263].copy_from_slice(b"ustar\0");
+    header[263..265].copy_from_slice(b"00");
+    header[148..156].fill(b' ');
+    let checksum: u32 = header.iter().map(|&byte| byte as u32).sum();
+    header[148..155].copy_from_slice(format!("{checksum:06o}\0").as_bytes());
+    out.extend_from_slice(&header);
+    out.extend_from_slice(contents);
+    out.resize(out.len().div_ceil(512) * 512, 0);
+}
+
+fn tar(entries: &[(&str, &[u8])]) -> Vec<u8> {
+    let mut out = Vec::new();
+    tar_entry(&mut out, "assets/", b'5', &[]);
+    for (name, contents) in entries {
+        tar_entry(&mut out, name, b'0', contents);
+    }
+    out.resize(out.len() + 1024, 0);
+    out
+}
+
+// A single stored DEFLATE block, enough to exercise compressed containers.
+fn stored_deflate(contents: &[u8]) -> Vec<u8> {
+    let len = contents.len() as u16;
+    let mut out = vec![0x01];
+    out.extend_from_slice(&len.to_le_bytes());
+    out.extend_from_slice(&(!len).to_le_bytes());
+    out.extend_from_slice(contents);
+    out
+}
+
+fn gzip(contents: &[u8]) -> Vec<u8> {
+    let mut out = vec![0x1f, 0x8b, 8, 0x08, 0, 0, 0, 0, 0, 0xff];
+    out.extend_from_slice(b"bundle.tar\0");
+    let mut deflated = Vec::new();
+    for chunk in contents.chunks(0xffff) {
+        let mut block = stored_deflate(chunk);
+        block[0] = 0;
+        deflated.extend_from_slice(&block);
+    }
+    deflated.extend_from_slice(&stored_deflate(&[]));
+    out.extend_from_slice(&deflated);
+    // The reader does not verify the trailer.
+    out.extend_from_slice(&[0; 4]);
+    out.extend_from_slice(&(contents.len() as u32).to_le_bytes());
+    out
+}
+
+fn zip(entries: &[(&str, u16, &[u8])]) -> Vec<u8> {
+    let mut out = Vec::new();
+    let mut central = Vec::new();
+    for (name, method, contents) in entries {
+        let data = if *method == 8 {
+            stored_deflate(contents)
+        } else {
+            contents.to_vec()
+        };
+        let local = out.len() as u32;
+        out.extend_from_slice(&0x0403_4b50u32.to_le_bytes());
+        out.extend_from_slice(&[20, 0, 0, 0]);
+        out.extend_from_slice(&method.to_le_bytes());
+        out.extend_from_slice(&[0; 8]);
+        out.extend_from_slice(&(data.len() as u32).to_le_bytes());
+        out.extend_from_slice(&(contents.len() as u32).to_le_bytes());
+        out.extend_from_slice(&(name.len() as u16).to_le_bytes());
+        out.extend_from_slice(&[0, 0]);
+        out.extend_from_slice(name.as_bytes());
+        out.extend_from_slice(&data);
+
+        central.extend_from_slice(&0x0201_4b50u32.to_le_bytes());
+        central.extend_from_slice(&[20, 0, 20, 0, 0, 0]);
+        central.extend_from_slice(&method.to_le_bytes());
+        central.extend_from_slice(&[0; 8]);
+        central.extend_from_slice(&(data.len() as u32).to_le_bytes());
+        central.extend_from_slice(&(contents.len() as u32).to_le_bytes());
+        central.extend_from_slice(&(name.len() as u16).to_le_bytes());
+        central.extend_from_slice(&[0; 12]);
+        central.extend_from_slice(&local.to_le_bytes());
+        central.extend_from_slice(name.as_bytes());
+    }
+    let offset = out.len() as u32;
+    out.extend_from_slice(&central);
+    out.extend_from_slice(&[0x50, 0x4b, 0x05, 0x06, 0, 0, 0, 0]);
+    out.extend_from_slice(&(entries.len() as u16).to_le_bytes());
+    out.extend_from_slice(&(entries.len() as u16).to_le_bytes());
+    out.extend_from_slice(&(central.len() as u32).to_le_bytes());
+    out.extend_from_slice(&offset.to_le_bytes());
+    out.extend_from_slice(&[0, 0]);
+    out
+}
+
+#[test]
+fn finds_files_in_directories_and_bundles() -> Result<(), Box<dyn std::error::Error>> {
+    let dir = scratch_dir("lookup");
+    fs::create_dir_all(&dir)?;
+    fs::write(dir.join("hero-1.png"), b"from disk")?;
+
+    let archive = tar(&[
+        ("assets/hero-1.png", b"from tar"),
+        ("assets/font-2.ttf", b"tar font"),
+    ]);
+    let resolver = AssetResolver::new()
+        .directory(&dir)
+        .bundle(&archive)?
+        .bundle(&gzip(&tar(&[("nested/dir/sound-3.wav", b"gzipped")])))?
+        .bundle(&zip(&[
+            ("zip/dir/", 0, b""),
+            ("zip/stored.bin", 0, b"stored"),
+            ("zip/font-2.ttf", 8, b"zip font"),
+            ("zip/deflated.bin", 8, b"deflated"),
+        ]))?;
+
+    let (source, bytes) = resolver.find("hero-1.png").ok_or("missing hero")?;
+    assert_eq!(bytes, b"from disk");
+    assert_eq!(PathBuf::from(source), dir.join("hero-1.png"));
+    assert_eq!(
+        resolver.find("font-2.ttf"),
+        Some(("assets/font-2.ttf".to_owned(), b"tar font".to_vec()))
+    );
+    assert_eq!(
+        resolver.find("sound-3.wav").map(|found| found.1),
+        Some(b"gzipped".to_vec())
+    );
+    assert_eq!(
+        resolver.find("stored.bin").map(|found| found.1),
+        Some(b"stored".to_vec())
+    );
+    assert_eq!(
+        resolver.find("deflated.bin").map(|found| found.1),
+        Some(b"deflated".to_vec())
+    );
+    assert_eq!(resolver.find("absent.png"), None);
+    assert_eq!(resolver.find("../hero-1.png"), None);
+    assert_eq!(resolver.find("assets/font-2.ttf"), None);
+
+    fs::remove_dir_all(&dir)?;
+    Ok(())
+}
+
+#[test]
+fn skips_names_shared_by_several_bundle_entries() -> Result<(), Box<dyn std::error::Error>> {
+    let resolver = AssetResolver::new()
+        .bundle(&tar(&[
+            ("assets/a/logo.png", b"first"),
+            ("assets/b/logo.png", b"second"),
+            ("assets/font.ttf", b"old"),
+            ("assets/font.ttf", b"new"),
+        ]))?
+        .bundle(&zip(&[("logo.png", 0, b"zip logo")]))?;
+
+    assert_eq!(
+        resolver.find("logo.png"),
+        Some(("logo.png".to_owned(), b"zip logo".to_vec()))
+    );
+    assert_eq!(
+        resolver.find("font.ttf"),
+        Some(("assets/font.ttf".to_owned(), b"new".to_vec()))
+    );
+    Ok(())
+}
+
+#[test]
+fn rejects_bundles_that_inflate_past_their_declared_size() {
+    let mut archive = zip(&[("a.bin", 8, b"abcdefgh")]);
+    // Shrink the uncompressed size in the local and central headers.
+    for offset in [22, archive.len() - 22 - 51 + 24] {
+        archive[offset..offset + 4].copy_from_slice(&1u32.to_le_bytes());
+    }
+    assert!(AssetResolver::new().bundle(&archive).is_err());
+}
+
+#[test]
+fn rejects_malformed_bundles() {
+    let archive = tar(&[("hero.png", b"contents")]);
+    let mut corrupt = archive.clone();
+    corrupt[0] = b'X';
+
+    assert!(AssetResolver::new().bundle(b"not a bundle").is_err());
+    assert!(AssetResolver::new().bundle(&corrupt).is_err());
+    assert!(AssetResolver::new().bundle(&archive[..600]).is_err());
+    assert!(
+        AssetResolver::new()
+            .bundle(&zip(&[("a.bin", 8, b"a")])[..40])
+            .is_err()
+    );
+    assert!(
+        AssetResolver::new()
+            .bundle_file(std::env::temp_dir().join("rive-rs-no-such-bundle.zip"))
+            .is_err()
+    );
+}
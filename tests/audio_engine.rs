@@ -0,0 +1,93 @@
+use rive_rs::AudioEngine;
+
+fn wav(format: u16, bits: u16, channels: u16, sample_rate: u32, data: &[u8]) -> Vec<u8> {
+    let block = channels * bits / 8;
+    let mut out = b"RIFF".to_vec();
+    out.extend_from_slice(&(36 + data.len() as u32).to_le_bytes());
+    out.extend_from_slice(b"WAVEfmt ");
+    out.extend_from_slice(&16u32.to_le_bytes());
+    out.extend_from_slice(&format.to_le_bytes());
+    out.extend_from_slice(&channels.to_le_bytes());
+    out.extend_from_slice(&sample_rate.to_le_bytes());
+    out.extend_from_slice(&(sample_rate * block as u32).to_le_bytes());
+    out.extend_from_slice(&block.to_le_bytes());
+    out.extend_from_slice(&bits.to_le_bytes());
+    out.extend_from_slice(b"data");
+    out.extend_from_slice(&(data.len() as u32).to_le_bytes());
+    out.extend_from_slice(data);
+    out
+}
+
+fn pcm16(samples: &[i16]) -> Vec<u8> {
+    samples
+        .iter()
+        .flat_map(|sample| sample.to_le_bytes())
+        .collect()
+}
+
+#[test]
+fn mixes_voices_with_volume() -> Result<(), Box<dyn std::error::Error>> {
+    let mut engine = AudioEngine::new(8000, 2)?;
+    engine.insert_sound("beep-1.wav", &wav(1, 16, 1, 8000, &pcm16(&[16384, -8192])))?;
+    let float: Vec<u8> = [0.25f32, -0.25]
+        .iter()
+        .flat_map(|sample| sample.to_le_bytes())
+        .collect();
+    engine.insert_sound("pan-2.wav", &wav(3, 32, 2, 8000, &float))?;
+
+    assert!(engine.play("beep-1.wav", 1.0));
+    assert!(engine.play("pan-2.wav", 0.5));
+    assert!(!engine.play("absent-3.wav", 1.0));
+    assert_eq!(engine.active_voices(), 2);
+
+    let mut out = [1.0f32; 6];
+    engine.render(&mut out);
+    assert_eq!(out, [0.625, 0.375, -0.25, -0.25, 0.0, 0.0]);
+    assert_eq!(engine.active_voices(), 0);
+
+    // Sums past full scale clip; the engine volume scales everything.
+    engine.insert_sound("loud-4.wav", &wav(1, 8, 1, 8000, &[255]))?;
+    engine.play("loud-4.wav", 1.0);
+    engine.play("loud-4.wav", 1.0);
+    let mut out = [0.0f32; 2];
+    engine.render(&mut out);
+    assert_eq!(out, [1.0, 1.0]);
+
+    engine.set_volume(0.5);
+    engine.play("beep-1.wav", 1.0);
+    let mut out = [0.0f32; 2];
+    engine.render(&mut out);
+    assert_eq!(out, [0.25, 0.25]);
+    engine.stop_all();
+    assert_eq!(engine.active_voices(), 0);
+    Ok(())
+}
+
+#[test]
+fn resamples_and_downmixes() -> Result<(), Box<dyn std::error::Error>> {
+    let mut engine = AudioEngine::new(8000, 1)?;
+    let stereo = pcm16(&[16384, 0, -16384, 0]);
+    engine.insert_sound("stereo-1.wav", &wav(1, 16, 2, 4000, &stereo))?;
+    engine.play("stereo-1.wav", 1.0);
+
+    let mut out = [0.0f32; 5];
+    engine.render(&mut out);
+    assert_eq!(out, [0.25, 0.0, -0.25, -0.25, 0.0]);
+    assert_eq!(engine.active_voices(), 0);
+    Ok(())
+}
+
+#[test]
+fn rejects_invalid_input() {
+    assert!(AudioEngine::new(0, 2).is_err());
+    assert!(AudioEngine::new(48000, 0).is_err());
+
+    let mut engine = AudioEngine::new(48000, 2).unwrap();
+    assert!(engine.insert_sound("a.wav", b"not a wav").is_err());
+    assert!(
+        engine
+            .insert_sound("b.wav", &wav(2, 16, 1, 8000, &[0, 0]))
+            .is_err()
+    );
+    assert!(!engine.has_sound("a.wav"));
+}
@@ -0,0 +1,32 @@
+use std::fs;
+use std::path::PathBuf;
+
+use rive_rs::DiskCache;
+
+fn scratch_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("rive-rs-cdn-{name}-{}", std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    dir
+}
+
+#[test]
+fn disk_cache_round_trips_by_uuid() -> Result<(), Box<dyn std::error::Error>> {
+    let dir = scratch_dir("cache");
+    let cache = DiskCache::new(&dir);
+    let uuid = "4f1c7a8e-2b0d-4c0e-9f57-6a1d2f3b4c5d";
+
+    assert_eq!(cache.get(uuid), None);
+    cache.put(uuid, b"first")?;
+    cache.put(uuid, b"second")?;
+    assert_eq!(cache.get(uuid), Some(b"second".to_vec()));
+    assert_eq!(cache.path(uuid), Some(dir.join(uuid)));
+    assert_eq!(fs::read_dir(&dir)?.count(), 1);
+
+    for invalid in ["", "..", "../escape", "nested/uuid"] {
+        assert_eq!(cache.path(invalid), None);
+        assert!(cache.put(invalid, b"bytes").is_err());
+    }
+
+    fs::remove_dir_all(&dir)?;
+    Ok(())
+}
@@ -0,0 +1,10 @@
+use std::fs;
+use std::path::PathBuf;
+
+/// Reads `name` from the runtime's unit-test assets.
+pub fn asset_bytes(name: &str) -> Vec<u8> {
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("submodules/rive-runtime/tests/unit_tests/assets")
+        .join(name);
+    fs::read(&path).unwrap_or_else(|err| panic!("failed to read {}: {err}", path.display()))
+}
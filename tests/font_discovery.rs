@@ -0,0 +1,44 @@
+use std::fs;
+use std::path::PathBuf;
+
+use rive_rs::{font_files, system_font_dirs};
+
+fn scratch_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("rive-rs-fonts-{name}-{}", std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    dir
+}
+
+#[test]
+fn finds_font_files_recursively() -> Result<(), Box<dyn std::error::Error>> {
+    let dir = scratch_dir("scan");
+    fs::create_dir_all(dir.join("noto/cjk"))?;
+    for name in [
+        "b.ttf",
+        "a.OTF",
+        "noto/cjk/c.ttc",
+        "noto/readme.txt",
+        "fonts.conf",
+    ] {
+        fs::write(dir.join(name), b"")?;
+    }
+
+    let found = font_files(&dir)?;
+    assert_eq!(
+        found,
+        [
+            dir.join("a.OTF"),
+            dir.join("b.ttf"),
+            dir.join("noto/cjk/c.ttc")
+        ]
+    );
+    assert!(font_files(dir.join("missing")).is_err());
+
+    fs::remove_dir_all(&dir)?;
+    Ok(())
+}
+
+#[test]
+fn system_font_dirs_exist() {
+    assert!(system_font_dirs().iter().all(|dir| dir.is_dir()));
+}
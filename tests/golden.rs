@@ -0,0 +1,88 @@
+#![cfg(feature = "golden")]
+
+use rive_rs::Frame;
+use rive_rs::golden::{Golden, GoldenError, Tolerance, compare};
+use std::fs;
+use std::path::PathBuf;
+
+fn scratch_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("rive-rs-golden-{name}-{}", std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    dir
+}
+
+fn gradient(width: u32, height: u32) -> Frame {
+    let mut rgba = Vec::with_capacity((width * height * 4) as usize);
+    for y in 0..height {
+        for x in 0..width {
+            rgba.extend_from_slice(&[(x * 255 / width) as u8, (y * 255 / height) as u8, 128, 255]);
+        }
+    }
+    Frame {
+        width,
+        height,
+        rgba,
+    }
+}
+
+#[test]
+fn compare_counts_pixels_beyond_tolerance() {
+    let expected = gradient(8, 8);
+    let mut actual = expected.clone();
+    actual.rgba[0] = actual.rgba[0].wrapping_add(2);
+    actual.rgba[4] = actual.rgba[4].wrapping_add(40);
+
+    let tolerance = Tolerance::default();
+    let comparison = compare(&expected, &actual, &tolerance).expect("same size");
+    assert_eq!(comparison.mismatched_pixels, 1);
+    assert_eq!(comparison.max_difference, 40);
+    assert_eq!(&comparison.diff.rgba[4..8], &[255, 0, 0, 255]);
+    assert!(!comparison.passes(&tolerance));
+    assert!(comparison.passes(&Tolerance {
+        channel: 2,
+        max_mismatched_pixels: 1,
+    }));
+
+    assert!(compare(&expected, &gradient(8, 4), &tolerance).is_none());
+}
+
+#[test]
+fn check_records_compares_and_reports_diffs() -> Result<(), Box<dyn std::error::Error>> {
+    let dir = scratch_dir("check");
+    let golden = Golden::new(&dir, 16, 16).update(false);
+    let frame = gradient(16, 16);
+
+    match golden.check("gradient", &frame) {
+        Err(GoldenError::MissingReference { actual, .. }) => assert!(actual.exists()),
+        other => panic!("expected a missing reference, got {other:?}"),
+    }
+
+    golden.clone().update(true).check("gradient", &frame)?;
+    assert!(golden.reference_path("gradient").exists());
+    golden.check("gradient", &frame)?;
+
+    let mut changed = frame.clone();
+    for pixel in changed.rgba.chunks_exact_mut(4).take(16) {
+        pixel[2] = 0;
+    }
+    match golden.check("gradient", &changed) {
+        Err(GoldenError::Mismatch {
+            mismatched_pixels,
+            diff,
+            actual,
+            ..
+        }) => {
+            assert_eq!(mismatched_pixels, 16);
+            assert!(diff.exists() && actual.exists());
+        }
+        other => panic!("expected a mismatch, got {other:?}"),
+    }
+
+    assert!(matches!(
+        golden.check("gradient", &gradient(8, 8)),
+        Err(GoldenError::SizeMismatch { .. })
+    ));
+
+    fs::remove_dir_all(&dir)?;
+    Ok(())
+}
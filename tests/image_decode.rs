@@ -0,0 +1,92 @@
+use std::fs;
+use std::path::PathBuf;
+
+use rive_rs::{BackendImage, RecordingBackend, RenderBackend};
+
+// Reference pixels were decoded with libjpeg-turbo (islow IDCT, fancy
+// upsampling) and libwebp, which the native decoders match exactly.
+const DECODED: [&str; 8] = [
+    "baseline_444.jpg",
+    "baseline_420.jpg",
+    "baseline_422_restart.jpg",
+    "progressive_420.jpg",
+    "grayscale.jpg",
+    "lossy.webp",
+    "lossy_alpha.webp",
+    "lossless.webp",
+];
+
+fn fixture(name: &str) -> Vec<u8> {
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/fixtures/images")
+        .join(name);
+    fs::read(&path).unwrap_or_else(|err| panic!("failed to read {}: {err}", path.display()))
+}
+
+fn decode(bytes: &[u8]) -> Option<(u32, u32, Vec<u8>)> {
+    let image = RecordingBackend.decode_image(bytes)?;
+    let pixels = image.pixels()?.to_vec();
+    Some((image.width(), image.height(), pixels))
+}
+
+#[test]
+fn decodes_fixtures_to_reference_pixels() {
+    for name in DECODED {
+        let (width, height, pixels) =
+            decode(&fixture(name)).unwrap_or_else(|| panic!("{name} failed to decode"));
+        assert_eq!((width, height), (67, 45), "{name}");
+        assert!(
+            pixels == fixture(&format!("{name}.rgba")),
+            "{name} differs from its reference pixels"
+        );
+    }
+}
+
+#[test]
+fn rejects_truncated_images() {
+    for name in DECODED {
+        let bytes = fixture(name);
+        for len in 0..bytes.len() {
+            assert!(
+                decode(&bytes[..len]).is_none(),
+                "{name} truncated to {len} bytes decoded"
+            );
+        }
+    }
+}
+
+#[test]
+fn survives_corrupted_bytes() {
+    for name in DECODED {
+        let bytes = fixture(name);
+        // Every header byte, then a sample of the entropy-coded data.
+        let offsets = (0..bytes.len()).filter(|&at| at < 128 || at % 5 == 0);
+        for at in offsets {
+            for value in [0xff, bytes[at] ^ 0x55] {
+                let mut corrupted = bytes.clone();
+                corrupted[at] = value;
+                // Any outcome but a panic is fine.
+                let _ = decode(&corrupted);
+            }
+        }
+    }
+}
+
+#[test]
+fn survives_extreme_quantization() {
+    // baseline_444.jpg with every quantization entry raised to 0xffff.
+    let (width, height, _) =
+        decode(&fixture("overflow_quant.jpg")).expect("overflow_quant.jpg decodes");
+    assert_eq!((width, height), (67, 45));
+}
+
+#[test]
+fn rejects_jpeg_frames_larger_than_their_data() {
+    // An 8000x8000 YCbCr frame header followed by an empty scan.
+    let mut bytes = vec![0xff, 0xd8];
+    bytes.extend_from_slice(&[0xff, 0xc0, 0x00, 0x11, 0x08, 0x1f, 0x40, 0x1f, 0x40, 0x03]);
+    bytes.extend_from_slice(&[0x01, 0x11, 0x00, 0x02, 0x11, 0x00, 0x03, 0x11, 0x00]);
+    bytes.extend_from_slice(&[0xff, 0xda, 0x00, 0x0c, 0x03, 0x01, 0x00, 0x02, 0x00]);
+    bytes.extend_from_slice(&[0x03, 0x00, 0x00, 0x3f, 0x00, 0xff, 0xd9]);
+    assert!(decode(&bytes).is_none());
+}
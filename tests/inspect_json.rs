@@ -0,0 +1,183 @@
+use rive_rs::{
+    AnimationReport, ArtboardReport, AssetInfo, AssetKind, DataEnumInfo, DataType, EventInfo,
+    EventProperty, EventPropertyValue, FileReport, InputReport, InputValue, PropertyInfo,
+    StateMachineReport, TextRunReport, Vec2, ViewModelReport,
+};
+
+fn report() -> FileReport {
+    FileReport {
+        artboards: vec![ArtboardReport {
+            name: "Main".to_owned(),
+            width: 500.0,
+            height: 250.5,
+            origin: Vec2 { x: 0.5, y: 0.0 },
+            animations: vec![AnimationReport {
+                name: "Idle".to_owned(),
+                fps: 60,
+                duration: 120,
+                work_start: 0,
+                work_end: 60,
+                enable_work_area: true,
+                loop_value: 1,
+                speed: 1.0,
+            }],
+            state_machines: vec![StateMachineReport {
+                name: "State Machine 1".to_owned(),
+                inputs: vec![
+                    InputReport {
+                        name: "hover".to_owned(),
+                        value: InputValue::Bool(false),
+                    },
+                    InputReport {
+                        name: "level".to_owned(),
+                        value: InputValue::Number(f32::NAN),
+                    },
+                    InputReport {
+                        name: "fire".to_owned(),
+                        value: InputValue::Trigger,
+                    },
+                ],
+            }],
+            events: vec![EventInfo {
+                name: "Open".to_owned(),
+                event_type: 131,
+                url: Some("https://rive.app".to_owned()),
+                target: None,
+                properties: vec![EventProperty {
+                    name: "label".to_owned(),
+                    value: EventPropertyValue::String("say \"hi\"\n".to_owned()),
+                }],
+            }],
+            text_runs: vec![TextRunReport {
+                name: "title".to_owned(),
+                text: "tab\there\u{1}".to_owned(),
+            }],
+        }],
+        view_models: vec![ViewModelReport {
+            name: "Card".to_owned(),
+            properties: vec![PropertyInfo {
+                name: "color".to_owned(),
+                data_type: DataType::RIVE_RS_DATA_TYPE_COLOR,
+            }],
+            instances: Vec::new(),
+        }],
+        enums: vec![DataEnumInfo {
+            name: "Mode".to_owned(),
+            values: vec!["light".to_owned(), "dark".to_owned()],
+        }],
+        assets: vec![AssetInfo {
+            kind: AssetKind::Image,
+            name: "logo".to_owned(),
+            unique_filename: "logo-12.png".to_owned(),
+            file_extension: "png".to_owned(),
+            cdn_uuid: String::new(),
+            embedded: true,
+            embedded_size: 1024,
+        }],
+    }
+}
+
+#[test]
+fn writes_reports_as_json() {
+    let expected = r#"{
+  "artboards": [
+    {
+      "name": "Main",
+      "width": 500,
+      "height": 250.5,
+      "origin": {
+        "x": 0.5,
+        "y": 0
+      },
+      "animations": [
+        {
+          "name": "Idle",
+          "fps": 60,
+          "duration": 120,
+          "workStart": 0,
+          "workEnd": 60,
+          "enableWorkArea": true,
+          "loop": "loop",
+          "speed": 1
+        }
+      ],
+      "stateMachines": [
+        {
+          "name": "State Machine 1",
+          "inputs": [
+            {
+              "name": "hover",
+              "type": "bool",
+              "value": false
+            },
+            {
+              "name": "level",
+              "type": "number",
+              "value": null
+            },
+            {
+              "name": "fire",
+              "type": "trigger",
+              "value": null
+            }
+          ]
+        }
+      ],
+      "events": [
+        {
+          "name": "Open",
+          "type": 131,
+          "url": "https://rive.app",
+          "target": null,
+          "properties": [
+            {
+              "name": "label",
+              "value": "say \"hi\"\n"
+            }
+          ]
+        }
+      ],
+      "textRuns": [
+        {
+          "name": "title",
+          "text": "tab\there\u0001"
+        }
+      ]
+    }
+  ],
+  "viewModels": [
+    {
+      "name": "Card",
+      "properties": [
+        {
+          "name": "color",
+          "type": "color"
+        }
+      ],
+      "instances": []
+    }
+  ],
+  "enums": [
+    {
+      "name": "Mode",
+      "values": [
+        "light",
+        "dark"
+      ]
+    }
+  ],
+  "assets": [
+    {
+      "kind": "image",
+      "name": "logo",
+      "uniqueFilename": "logo-12.png",
+      "fileExtension": "png",
+      "cdnUuid": "",
+      "embedded": true,
+      "embeddedSize": 1024
+    }
+  ]
+}
+"#;
+    assert_eq!(report().to_json(), expected);
+}
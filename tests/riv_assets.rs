@@ -0,0 +1,179 @@
+use rive_rs::{AssetEdit, AssetKind, extract_embedded_assets, rewrite_assets};
+
+const NAME: u64 = 4;
+const WIDTH: u64 = 7;
+const FILL_COLOR: u64 = 37;
+const ASSET_NAME: u64 = 203;
+const ASSET_ID: u64 = 204;
+const CONTENTS_BYTES: u64 = 212;
+
+fn varuint(out: &mut Vec<u8>, mut value: u64) {
+    while value >= 0x80 {
+        out.push((value as u8 & 0x7f) | 0x80);
+        value >>= 7;
+    }
+    out.push(value as u8);
+}
+
+enum Value<'a> {
+    Uint(u64),
+    Bytes(&'a [u8]),
+    Float(f32),
+    Color(u32),
+}
+
+fn object(out: &mut Vec<u8>, type_key: u64, properties: &[(u64, Value)]) {
+    varuint(out, type_key);
+    for (key, value) in properties {
+        varuint(out, *key);
+        match value {
+            Value::Uint(value) => varuint(out, *value),
+            Value::Bytes(bytes) => {
+                varuint(out, bytes.len() as u64);
+                out.extend_from_slice(bytes);
+            }
+            Value::Float(value) => out.extend_from_slice(&value.to_le_bytes()),
+            Value::Color(value) => out.extend_from_slice(&value.to_le_bytes()),
+        }
+    }
+    varuint(out, 0);
+}
+
+fn sample_riv() -> Vec<u8> {
+    let mut out = b"RIVE".to_vec();
+    for value in [7, 0, 1] {
+        varuint(&mut out, value);
+    }
+    let toc = [
+        (NAME, 1),
+        (WIDTH, 2),
+        (FILL_COLOR, 3),
+        (ASSET_NAME, 1),
+        (ASSET_ID, 0),
+        (CONTENTS_BYTES, 1),
+    ];
+    for (key, _) in toc {
+        varuint(&mut out, key);
+    }
+    varuint(&mut out, 0);
+    for chunk in toc.chunks(4) {
+        let bits = chunk
+            .iter()
+            .enumerate()
+            .fold(0u32, |bits, (slot, (_, field))| {
+                bits | (field << (slot * 2))
+            });
+        out.extend_from_slice(&bits.to_le_bytes());
+    }
+
+    object(&mut out, 23, &[]);
+    object(
+        &mut out,
+        1,
+        &[(NAME, Value::Bytes(b"Main")), (WIDTH, Value::Float(512.0))],
+    );
+    object(&mut out, 18, &[(FILL_COLOR, Value::Color(0xff00_ff00))]);
+    object(
+        &mut out,
+        105,
+        &[
+            (ASSET_NAME, Value::Bytes(b"hero")),
+            (ASSET_ID, Value::Uint(300)),
+        ],
+    );
+    object(
+        &mut out,
+        106,
+        &[(CONTENTS_BYTES, Value::Bytes(b"png bytes"))],
+    );
+    object(
+        &mut out,
+        141,
+        &[
+            (ASSET_NAME, Value::Bytes(b"inter")),
+            (ASSET_ID, Value::Uint(301)),
+        ],
+    );
+    object(&mut out, 406, &[(ASSET_NAME, Value::Bytes(b"click"))]);
+    object(
+        &mut out,
+        106,
+        &[(CONTENTS_BYTES, Value::Bytes(b"wav bytes"))],
+    );
+    out
+}
+
+#[test]
+fn extracts_embedded_asset_bytes() -> Result<(), Box<dyn std::error::Error>> {
+    let assets = extract_embedded_assets(&sample_riv())?;
+    let summary: Vec<_> = assets
+        .iter()
+        .map(|asset| {
+            (
+                asset.index,
+                asset.kind,
+                asset.name.as_str(),
+                asset.bytes.as_slice(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        summary,
+        [
+            (0, AssetKind::Image, "hero", &b"png bytes"[..]),
+            (2, AssetKind::Audio, "click", &b"wav bytes"[..]),
+        ]
+    );
+    Ok(())
+}
+
+#[test]
+fn rewrites_embedded_asset_bytes() -> Result<(), Box<dyn std::error::Error>> {
+    let riv = sample_riv();
+    assert_eq!(rewrite_assets(&riv, &[])?, riv);
+
+    let rewritten = rewrite_assets(
+        &riv,
+        &[
+            (0, AssetEdit::Embed(b"smaller png".to_vec())),
+            (1, AssetEdit::Embed(b"ttf bytes".to_vec())),
+            (2, AssetEdit::Reference),
+        ],
+    )?;
+    let assets = extract_embedded_assets(&rewritten)?;
+    let summary: Vec<_> = assets
+        .iter()
+        .map(|asset| (asset.index, asset.name.as_str(), asset.bytes.as_slice()))
+        .collect();
+    assert_eq!(
+        summary,
+        [
+            (0, "hero", &b"smaller png"[..]),
+            (1, "inter", &b"ttf bytes"[..])
+        ]
+    );
+
+    let restored = rewrite_assets(
+        &rewritten,
+        &[
+            (0, AssetEdit::Embed(b"png bytes".to_vec())),
+            (1, AssetEdit::Reference),
+            (2, AssetEdit::Embed(b"wav bytes".to_vec())),
+        ],
+    )?;
+    assert_eq!(restored, riv);
+    Ok(())
+}
+
+#[test]
+fn rejects_malformed_files() {
+    let riv = sample_riv();
+    assert!(extract_embedded_assets(b"RIFF").is_err());
+    assert!(extract_embedded_assets(&riv[..riv.len() - 3]).is_err());
+    assert!(rewrite_assets(&riv, &[(3, AssetEdit::Reference)]).is_err());
+
+    // Properties missing from the table of contents cannot be skipped.
+    let mut unknown = riv.clone();
+    object(&mut unknown, 2, &[(500, Value::Uint(1))]);
+    assert!(extract_embedded_assets(&unknown).is_err());
+}
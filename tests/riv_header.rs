@@ -0,0 +1,82 @@
+use rive_rs::{HeaderError, RIV_MAJOR_VERSION, Status, inspect_header, rewrite_assets};
+
+fn varuint(out: &mut Vec<u8>, mut value: u64) {
+    while value >= 0x80 {
+        out.push((value as u8 & 0x7f) | 0x80);
+        value >>= 7;
+    }
+    out.push(value as u8);
+}
+
+fn header(major: u64, minor: u64, file_id: u64, keys: &[u64]) -> Vec<u8> {
+    let mut out = b"RIVE".to_vec();
+    for value in [major, minor, file_id] {
+        varuint(&mut out, value);
+    }
+    for &key in keys {
+        varuint(&mut out, key);
+    }
+    varuint(&mut out, 0);
+    for _ in keys.chunks(4) {
+        out.extend_from_slice(&0u32.to_le_bytes());
+    }
+    out
+}
+
+#[test]
+fn reads_versions_and_file_id() {
+    let bytes = header(RIV_MAJOR_VERSION as u64, 3, 300, &[4, 7, 37, 203, 204]);
+    let header = inspect_header(&bytes).unwrap();
+    assert_eq!(header.major_version, RIV_MAJOR_VERSION);
+    assert_eq!(header.minor_version, 3);
+    assert_eq!(header.file_id, 300);
+    assert!(header.is_supported());
+    assert!(header.supported_major_versions.contains(&RIV_MAJOR_VERSION));
+
+    // Objects after the header are not read.
+    let mut with_objects = bytes.clone();
+    with_objects.extend_from_slice(&[0xff, 0xff]);
+    assert_eq!(inspect_header(&with_objects), Ok(header));
+}
+
+#[test]
+fn rejects_other_major_versions() {
+    for major in [RIV_MAJOR_VERSION - 1, RIV_MAJOR_VERSION + 1] {
+        let bytes = header(major as u64, 0, 1, &[]);
+        let Err(HeaderError::UnsupportedVersion(header)) = inspect_header(&bytes) else {
+            panic!("major version {major} should be unsupported");
+        };
+        assert_eq!(header.major_version, major);
+        assert!(!header.is_supported());
+        assert_eq!(
+            rive_rs::Error::from(HeaderError::UnsupportedVersion(header)).status(),
+            Status::RIVE_RS_STATUS_UNSUPPORTED
+        );
+    }
+}
+
+#[test]
+fn tells_truncated_from_malformed() {
+    let bytes = header(RIV_MAJOR_VERSION as u64, 0, 1, &[4, 7]);
+    for len in 0..bytes.len() {
+        assert_eq!(
+            inspect_header(&bytes[..len]),
+            Err(HeaderError::Truncated),
+            "prefix of {len} bytes"
+        );
+    }
+
+    assert_eq!(inspect_header(b"RIFF"), Err(HeaderError::Malformed));
+    assert_eq!(inspect_header(b"PNG"), Err(HeaderError::Malformed));
+    let mut oversized = b"RIVE".to_vec();
+    varuint(&mut oversized, u64::from(u32::MAX) + 1);
+    assert_eq!(inspect_header(&oversized), Err(HeaderError::Malformed));
+
+    // Asset rewriting reports both as decode errors.
+    for bytes in [&bytes[..5], b"RIFF".as_slice()] {
+        assert_eq!(
+            rewrite_assets(bytes, &[]).unwrap_err().status(),
+            Status::RIVE_RS_STATUS_DECODE_ERROR
+        );
+    }
+}
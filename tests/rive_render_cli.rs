@@ -0,0 +1,94 @@
+#![cfg(all(
+    feature = "runtime-abi-provider",
+    feature = "runtime-abi-provider-tests"
+))]
+
+use std::process::{Command, Output};
+
+fn rive_render(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_rive-render"))
+        .args(args)
+        .output()
+        .expect("failed to run rive-render")
+}
+
+// Asserts a usage error (exit code 2) whose message contains `message`.
+fn assert_rejected(args: &[&str], message: &str) {
+    let output = rive_render(args);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert_eq!(output.status.code(), Some(2), "{args:?}: {stderr}");
+    assert!(stderr.contains(message), "{args:?}: {stderr}");
+}
+
+// A state-machine render of ten frames with `input` added.
+fn with_input(input: &str) -> Vec<&str> {
+    vec![
+        "a.riv",
+        "--state-machine",
+        "main",
+        "--frames",
+        "10",
+        "--input",
+        input,
+    ]
+}
+
+#[test]
+fn prints_usage() {
+    let output = rive_render(&["--help"]);
+    assert!(output.status.success());
+    assert!(String::from_utf8_lossy(&output.stdout).starts_with("usage: rive-render"));
+}
+
+#[test]
+fn rejects_conflicting_selection() {
+    assert_rejected(&[], "missing input file");
+    assert_rejected(&["a.riv", "b.riv"], "unexpected argument `b.riv`");
+    assert_rejected(
+        &["a.riv", "--animation", "idle", "--state-machine", "main"],
+        "mutually exclusive",
+    );
+    assert_rejected(
+        &["a.riv", "--animation", "idle", "--input", "hover=true"],
+        "--input requires --state-machine",
+    );
+    assert_rejected(
+        &["a.riv", "--input", "hover"],
+        "--input requires --state-machine",
+    );
+}
+
+#[test]
+fn parses_scheduled_inputs() {
+    assert_rejected(&with_input("=1"), "missing input name");
+    assert_rejected(&with_input("hover=true@soon"), "invalid frame `soon`");
+    assert_rejected(&with_input("hover=true@-1s"), "invalid time `-1s`");
+    assert_rejected(&with_input("hover=true@10"), "after the last frame (9)");
+    // 0.5s at the default 30 fps is frame 15.
+    assert_rejected(&with_input("jump@0.5s"), "after the last frame (9)");
+
+    // Valid schedules get past parsing and fail on the missing file.
+    for input in ["hover=true", "hover=true@9", "jump@0.3s", "level=2@0"] {
+        let output = rive_render(&with_input(input));
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        assert_eq!(output.status.code(), Some(1), "{input}: {stderr}");
+        assert!(stderr.contains("failed to read a.riv"), "{input}: {stderr}");
+    }
+}
+
+#[test]
+fn rejects_malformed_values() {
+    assert_rejected(&["a.riv", "--fps", "0"], "--fps must be positive");
+    assert_rejected(&["a.riv", "--size", "64"], "expected <width>x<height>");
+    assert_rejected(&["a.riv", "--fit", "stretch"], "unknown fit `stretch`");
+    assert_rejected(
+        &["a.riv", "--background", "#12345"],
+        "invalid color `#12345`",
+    );
+    assert_rejected(
+        &["a.riv", "--view-model", "title"],
+        "expected <path>=<value>",
+    );
+    assert_rejected(&["a.riv", "--frames"], "missing value for `--frames`");
+    assert_rejected(&["a.riv", "--loop"], "unknown option `--loop`");
+}
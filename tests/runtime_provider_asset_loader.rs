@@ -0,0 +1,81 @@
+#![cfg(all(
+    feature = "runtime-abi-provider",
+    feature = "runtime-abi-provider-tests"
+))]
+
+mod common;
+
+use std::cell::Cell;
+use std::rc::Rc;
+
+use rive_rs::{AssetLoader, Factory, FileAsset, LoadOutcome};
+
+use common::asset_bytes;
+
+struct DropFlag(Rc<Cell<bool>>);
+
+impl Drop for DropFlag {
+    fn drop(&mut self) {
+        self.0.set(true);
+    }
+}
+
+struct PanickingLoader {
+    _dropped: DropFlag,
+}
+
+impl AssetLoader for PanickingLoader {
+    fn load(&mut self, _asset: &mut FileAsset, _in_band: &[u8], _factory: &Factory) -> LoadOutcome {
+        panic!("loader failure");
+    }
+}
+
+#[test]
+fn closure_loader_decodes_in_band_assets() -> Result<(), Box<dyn std::error::Error>> {
+    let factory = Factory::new()?;
+    let images = Rc::new(Cell::new(0));
+    let seen = images.clone();
+
+    let file = factory.load_file_with_loader(
+        &asset_bytes("in_band_asset.riv"),
+        move |asset: &mut FileAsset, in_band: &[u8], factory: &Factory| {
+            if asset.is_image() && !in_band.is_empty() && asset.decode(factory, in_band).is_ok() {
+                seen.set(seen.get() + 1);
+                LoadOutcome::Loaded
+            } else {
+                LoadOutcome::Fallback
+            }
+        },
+    )?;
+
+    assert!(file.artboard_count() > 0);
+    assert!(images.get() > 0);
+    Ok(())
+}
+
+#[test]
+fn panicking_loader_falls_back_and_is_released() -> Result<(), Box<dyn std::error::Error>> {
+    let factory = Factory::new()?;
+    let dropped = Rc::new(Cell::new(false));
+
+    let file = factory.load_file_with_loader(
+        &asset_bytes("in_band_asset.riv"),
+        PanickingLoader {
+            _dropped: DropFlag(dropped.clone()),
+        },
+    )?;
+    assert!(file.artboard_count() > 0);
+    drop(file);
+    assert!(dropped.get());
+
+    let dropped = Rc::new(Cell::new(false));
+    let result = factory.load_file_with_loader(
+        b"not a riv file",
+        PanickingLoader {
+            _dropped: DropFlag(dropped.clone()),
+        },
+    );
+    assert!(result.is_err());
+    assert!(dropped.get());
+    Ok(())
+}
@@ -0,0 +1,38 @@
+#![cfg(all(
+    feature = "runtime-abi-provider",
+    feature = "runtime-abi-provider-tests"
+))]
+
+mod common;
+
+use rive_rs::{AssetKind, inspect_assets};
+
+use common::asset_bytes;
+
+#[test]
+fn lists_embedded_and_hosted_assets() -> Result<(), Box<dyn std::error::Error>> {
+    let images = inspect_assets(&asset_bytes("in_band_asset.riv"))?;
+    let image = images
+        .iter()
+        .find(|info| info.kind == AssetKind::Image)
+        .ok_or("no image asset")?;
+    assert!(image.embedded);
+    assert!(image.embedded_size > 0);
+    assert!(!image.file_extension.is_empty());
+    assert!(image.unique_filename.ends_with(&image.file_extension));
+
+    let sounds = inspect_assets(&asset_bytes("sound.riv"))?;
+    assert!(sounds.iter().any(|info| info.kind == AssetKind::Audio));
+
+    let fonts = inspect_assets(&asset_bytes("hosted_font_file.riv"))?;
+    let font = fonts
+        .iter()
+        .find(|info| info.kind == AssetKind::Font)
+        .ok_or("no font asset")?;
+    assert!(!font.embedded);
+    assert_eq!(font.embedded_size, 0);
+    assert!(!font.cdn_uuid.is_empty());
+
+    assert!(inspect_assets(b"not a riv file").is_err());
+    Ok(())
+}
@@ -0,0 +1,125 @@
+#![cfg(all(
+    feature = "runtime-abi-provider",
+    feature = "runtime-abi-provider-tests"
+))]
+
+mod common;
+
+use std::fs;
+use std::path::PathBuf;
+
+use rive_rs::{AssetKind, AssetResolver, AssetStatus, Factory};
+
+use common::asset_bytes;
+
+fn scratch_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("rive-rs-resolver-{name}-{}", std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    dir
+}
+
+#[cfg(feature = "runtime-text")]
+fn tar(entries: &[(String, &[u8])]) -> Vec<u8> {
+    let mut out = Vec::new();
+    for (name, contents) in entries {
+        let mut header = [0u8; 512];
+        header[..name.len()].copy_from_slice(name.as_bytes());
+        header[100..107].copy_from_slice(b"0000644");
+        header[124..135].copy_from_slice(format!("{:011o}", contents.len()).as_bytes());
+        header[156] = b'0';
+        header[148..156].fill(b' ');
+        let checksum: u32 = header.iter().map(|&byte| byte as u32).sum();
+        header[148..155].copy_from_slice(format!("{checksum:06o}\0").as_bytes());
+        out.extend_from_slice(&header);
+        out.extend_from_slice(contents);
+        out.resize(out.len().div_ceil(512) * 512, 0);
+    }
+    out.resize(out.len() + 1024, 0);
+    out
+}
+
+#[test]
+fn reports_embedded_assets() -> Result<(), Box<dyn std::error::Error>> {
+    let factory = Factory::new()?;
+    let (file, report) =
+        AssetResolver::new().load_file(&factory, &asset_bytes("in_band_asset.riv"))?;
+
+    assert!(file.artboard_count() > 0);
+    assert!(!report.assets.is_empty());
+    assert_eq!(report.embedded().count(), report.assets.len());
+    assert!(
+        report
+            .embedded()
+            .any(|record| record.kind == AssetKind::Image)
+    );
+    Ok(())
+}
+
+#[test]
+fn reports_missing_hosted_assets() -> Result<(), Box<dyn std::error::Error>> {
+    let factory = Factory::new()?;
+    let bytes = asset_bytes("hosted_font_file.riv");
+    let (_, report) = AssetResolver::new().load_file(&factory, &bytes)?;
+    let fonts: Vec<_> = report
+        .missing()
+        .filter(|record| record.kind == AssetKind::Font)
+        .collect();
+    assert!(!fonts.is_empty());
+
+    // Matches that fail to decode stay missing.
+    let dir = scratch_dir("undecodable");
+    fs::create_dir_all(&dir)?;
+    for record in &fonts {
+        fs::write(dir.join(&record.unique_filename), b"not a font")?;
+    }
+    let resolver = AssetResolver::new().directory(&dir);
+    let (_, retried) = resolver.load_file(&factory, &bytes)?;
+    assert_eq!(retried.missing().count(), report.missing().count());
+    assert!(
+        retried
+            .assets
+            .iter()
+            .all(|record| !matches!(record.status, AssetStatus::Resolved { .. }))
+    );
+
+    let file = factory.load_file_with_loader(&bytes, resolver)?;
+    assert!(file.artboard_count() > 0);
+    fs::remove_dir_all(&dir)?;
+    Ok(())
+}
+
+#[cfg(feature = "runtime-text")]
+#[test]
+fn reports_ambiguous_bundle_entries() -> Result<(), Box<dyn std::error::Error>> {
+    let factory = Factory::new()?;
+    let bytes = asset_bytes("hosted_font_file.riv");
+    let font = fs::read(
+        PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/fonts/fixture-italic.ttf"),
+    )?;
+    let (_, report) = AssetResolver::new().load_file(&factory, &bytes)?;
+    let name = report
+        .missing()
+        .find(|record| record.kind == AssetKind::Font)
+        .map(|record| record.unique_filename.clone())
+        .ok_or("no hosted font")?;
+
+    let unique = tar(&[(format!("fonts/{name}"), &font)]);
+    let (_, resolved) = AssetResolver::new()
+        .bundle(&unique)?
+        .load_file(&factory, &bytes)?;
+    assert!(resolved.assets.iter().any(|record| record.status
+        == AssetStatus::Resolved {
+            source: format!("fonts/{name}")
+        }));
+
+    let shared = tar(&[(format!("a/{name}"), &font), (format!("b/{name}"), &font)]);
+    let (_, ambiguous) = AssetResolver::new()
+        .bundle(&shared)?
+        .load_file(&factory, &bytes)?;
+    assert!(ambiguous.ambiguous().any(|record| record.status
+        == AssetStatus::Ambiguous {
+            paths: vec![format!("a/{name}"), format!("b/{name}")]
+        }));
+    assert_eq!(ambiguous.resolved().count(), 0);
+    Ok(())
+}
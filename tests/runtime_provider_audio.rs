@@ -0,0 +1,299 @@
+#![cfg(all(
+    feature = "runtime-abi-provider",
+    feature = "runtime-abi-provider-tests"
+))]
+
+mod common;
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use rive_rs::{
+    AudioEngine, Factory, FileAsset, LinearAnimationInstance, LoadOutcome, StateMachineInstance,
+    Status,
+};
+
+use common::asset_bytes;
+
+fn wav16(channels: u16, sample_rate: u32, samples: &[i16]) -> Vec<u8> {
+    let data: Vec<u8> = samples
+        .iter()
+        .flat_map(|sample| sample.to_le_bytes())
+        .collect();
+    let mut out = b"RIFF".to_vec();
+    out.extend_from_slice(&(36 + data.len() as u32).to_le_bytes());
+    out.extend_from_slice(b"WAVEfmt ");
+    out.extend_from_slice(&16u32.to_le_bytes());
+    out.extend_from_slice(&1u16.to_le_bytes());
+    out.extend_from_slice(&channels.to_le_bytes());
+    out.extend_from_slice(&sample_rate.to_le_bytes());
+    out.extend_from_slice(&(sample_rate * channels as u32 * 2).to_le_bytes());
+    out.extend_from_slice(&(channels * 2).to_le_bytes());
+    out.extend_from_slice(&16u16.to_le_bytes());
+    out.extend_from_slice(b"data");
+    out.extend_from_slice(&(data.len() as u32).to_le_bytes());
+    out.extend_from_slice(&data);
+    out
+}
+
+#[test]
+fn decodes_audio_sources_to_pcm() -> Result<(), Box<dyn std::error::Error>> {
+    let factory = Factory::new()?;
+    let bytes = wav16(2, 8000, &[16384, -16384, 0, 8192, -32768, 0, 0, 0]);
+    let source = factory.decode_audio(&bytes)?;
+    assert!(source.has_pcm());
+    assert_eq!(source.channels(), Some(2));
+    assert_eq!(source.sample_rate(), Some(8000));
+    assert_eq!(source.frame_count(), Some(4));
+    assert_eq!(source.duration(), Some(0.0005));
+    assert_eq!(
+        source.decode_pcm()?,
+        [0.5, -0.5, 0.0, 0.25, -1.0, 0.0, 0.0, 0.0]
+    );
+
+    let upsampled = source.decode_pcm_at(16000)?;
+    assert_eq!(upsampled.len(), 16);
+    assert_eq!(&upsampled[..4], [0.5, -0.5, 0.25, -0.125]);
+    assert_eq!(source.decode_pcm_at(4000)?, [0.5, -0.5, -1.0, 0.0]);
+    assert!(source.decode_pcm_at(0).is_err());
+
+    let results = Rc::new(RefCell::new(Vec::new()));
+    let sink = results.clone();
+    factory.load_file_with_loader(
+        &asset_bytes("sound.riv"),
+        move |asset: &mut FileAsset, _: &[u8], _: &Factory| {
+            if !asset.is_audio() {
+                return LoadOutcome::Fallback;
+            }
+            sink.borrow_mut().push(asset.set_audio_source(&source));
+            LoadOutcome::Loaded
+        },
+    )?;
+    let results = results.take();
+    assert!(!results.is_empty());
+    assert!(results.iter().all(Result::is_ok));
+    Ok(())
+}
+
+#[test]
+fn decodes_non_wav_audio_through_the_runtime() -> Result<(), Box<dyn std::error::Error>> {
+    let factory = Factory::new()?;
+    let in_band = Rc::new(RefCell::new(Vec::new()));
+    let sink = in_band.clone();
+    factory.load_file_with_loader(
+        &asset_bytes("sound.riv"),
+        move |asset: &mut FileAsset, bytes: &[u8], _: &Factory| {
+            if asset.is_audio() && !bytes.is_empty() {
+                sink.borrow_mut().push(bytes.to_vec());
+            }
+            LoadOutcome::Fallback
+        },
+    )?;
+    let in_band = in_band.take();
+    assert!(!in_band.is_empty());
+    for bytes in in_band {
+        // Whatever the format, the runtime decodes it; only WAV also
+        // exposes its samples.
+        let source = factory.decode_audio(&bytes)?;
+        assert!(!source.as_raw().is_null());
+        if !bytes.starts_with(b"RIFF") {
+            assert!(!source.has_pcm());
+            assert_eq!(source.channels(), None);
+            assert_eq!(
+                source.decode_pcm().err().map(|err| err.status()),
+                Some(Status::RIVE_RS_STATUS_UNSUPPORTED)
+            );
+        }
+    }
+
+    assert!(factory.decode_audio(b"not audio").is_err());
+    Ok(())
+}
+
+// A second of a 440 Hz tone at 8 kHz, attached in place of the file's sound
+// so the mix does not depend on the embedded format.
+fn tone() -> Vec<u8> {
+    let samples: Vec<i16> = (0..8000)
+        .map(|frame| {
+            let phase = frame as f32 * 440.0 / 8000.0 * std::f32::consts::TAU;
+            (phase.sin() * 16384.0) as i16
+        })
+        .collect();
+    wav16(1, 8000, &samples)
+}
+
+fn load_with_tone(
+    engine: &mut AudioEngine,
+    factory: &Factory,
+) -> Result<(rive_rs::File, Vec<String>), Box<dyn std::error::Error>> {
+    let sounds = Rc::new(RefCell::new(Vec::new()));
+    let sink = sounds.clone();
+    let file = engine.load_file_with_loader(
+        factory,
+        &asset_bytes("sound.riv"),
+        move |asset: &mut FileAsset, _: &[u8], factory: &Factory| {
+            if !asset.is_audio() {
+                return LoadOutcome::Fallback;
+            }
+            let attached = factory
+                .decode_audio(&tone())
+                .and_then(|source| asset.set_audio_source(&source));
+            assert!(attached.is_ok());
+            sink.borrow_mut().push(asset.unique_filename());
+            LoadOutcome::Loaded
+        },
+    )?;
+    Ok((file, sounds.take()))
+}
+
+#[test]
+fn registers_in_band_and_loader_provided_sounds() -> Result<(), Box<dyn std::error::Error>> {
+    let factory = Factory::new()?;
+
+    // In-band sounds are either mixable or reported as unsupported.
+    let mut engine = AudioEngine::new(48000, 2)?;
+    let in_band = Rc::new(RefCell::new(Vec::new()));
+    let sink = in_band.clone();
+    engine.load_file_with_loader(
+        &factory,
+        &asset_bytes("sound.riv"),
+        move |asset: &mut FileAsset, bytes: &[u8], _: &Factory| {
+            if asset.is_audio() && !bytes.is_empty() {
+                sink.borrow_mut()
+                    .push((asset.unique_filename(), bytes.starts_with(b"RIFF")));
+            }
+            LoadOutcome::Fallback
+        },
+    )?;
+    let in_band = in_band.take();
+    assert!(!in_band.is_empty());
+    for (name, is_wav) in &in_band {
+        assert_eq!(engine.has_sound(name), *is_wav);
+        assert_eq!(engine.unsupported_sounds().contains(name), !*is_wav);
+    }
+
+    // Sources a loader attaches are registered whatever the in-band format.
+    let mut engine = AudioEngine::new(48000, 2)?;
+    let (_file, sounds) = load_with_tone(&mut engine, &factory)?;
+    assert!(!sounds.is_empty());
+    assert!(sounds.iter().all(|name| engine.has_sound(name)));
+    assert!(engine.unsupported_sounds().is_empty());
+
+    // Sources without samples of their own cannot be mixed.
+    if let Some((name, false)) = in_band.first() {
+        let source = factory.decode_audio(&asset_bytes_of(&factory, name)?)?;
+        assert_eq!(
+            engine
+                .insert_source(name, &source)
+                .err()
+                .map(|err| err.status()),
+            Some(Status::RIVE_RS_STATUS_UNSUPPORTED)
+        );
+    }
+    Ok(())
+}
+
+fn asset_bytes_of(factory: &Factory, name: &str) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
+    let found = Rc::new(RefCell::new(Vec::new()));
+    let sink = found.clone();
+    let name = name.to_owned();
+    factory.load_file_with_loader(
+        &asset_bytes("sound.riv"),
+        move |asset: &mut FileAsset, bytes: &[u8], _: &Factory| {
+            if asset.unique_filename() == name {
+                sink.borrow_mut().extend_from_slice(bytes);
+            }
+            LoadOutcome::Fallback
+        },
+    )?;
+    Ok(found.take())
+}
+
+#[test]
+fn renders_audio_events_from_state_machines() -> Result<(), Box<dyn std::error::Error>> {
+    let factory = Factory::new()?;
+    let mut engine = AudioEngine::new(48000, 2)?;
+    let (file, sounds) = load_with_tone(&mut engine, &factory)?;
+    assert!(file.has_audio());
+    assert!(sounds.iter().all(|name| engine.has_sound(name)));
+
+    let mut artboard = file.default_artboard()?;
+    let machine = artboard.state_machine_by_index(0)?;
+    let mut instance = StateMachineInstance::new(machine, &mut artboard)?;
+    let mut out = vec![0.0f32; 800 * 2];
+    let mut started = 0;
+    let mut peak = 0.0f32;
+    for _ in 0..120 {
+        instance.advance_and_apply(1.0 / 60.0)?;
+        for index in 0..instance.reported_event_count() {
+            if let Err(err) = instance.reported_event_audio_asset(index) {
+                assert_eq!(err.status(), Status::RIVE_RS_STATUS_NOT_FOUND);
+            }
+        }
+        started += engine.collect(&instance, &artboard);
+        engine.render(&mut out);
+        assert!(out.iter().all(|sample| (-1.0..=1.0).contains(sample)));
+        peak = out.iter().fold(peak, |peak, sample| peak.max(sample.abs()));
+    }
+    assert!(started > 0);
+    assert!(peak > 0.1);
+
+    let count = instance.reported_event_count();
+    assert_eq!(
+        instance
+            .reported_event_audio_asset(count)
+            .err()
+            .map(|err| err.status()),
+        Some(Status::RIVE_RS_STATUS_OUT_OF_RANGE)
+    );
+
+    // A silent artboard mixes to silence.
+    artboard.set_volume(0.0);
+    engine.stop_all();
+    for _ in 0..60 {
+        instance.advance_and_apply(1.0 / 60.0)?;
+        engine.collect(&instance, &artboard);
+        engine.render(&mut out);
+        assert!(out.iter().all(|&sample| sample == 0.0));
+    }
+    Ok(())
+}
+
+#[test]
+fn renders_audio_events_from_animations() -> Result<(), Box<dyn std::error::Error>> {
+    let factory = Factory::new()?;
+    let mut engine = AudioEngine::new(48000, 2)?;
+    let (file, _) = load_with_tone(&mut engine, &factory)?;
+
+    // The state machine's sounds are keyed on the timelines it plays, so
+    // playing those animations directly fires them too.
+    let mut artboard = file.default_artboard()?;
+    let mut out = vec![0.0f32; 800 * 2];
+    let mut started = 0;
+    let mut peak = 0.0f32;
+    for index in 0..artboard.animation_count() {
+        let animation = artboard.animation_by_index(index)?;
+        let frames = animation.duration().max(1);
+        let step = 1.0 / animation.fps().max(1) as f32;
+        let mut instance = LinearAnimationInstance::new(animation, &mut artboard)?;
+        for _ in 0..frames {
+            instance.advance(step)?;
+            instance.apply(&mut artboard, 1.0)?;
+            artboard.advance(step)?;
+            started += engine.collect_animation(&instance, &artboard);
+            engine.render(&mut out);
+            peak = out.iter().fold(peak, |peak, sample| peak.max(sample.abs()));
+        }
+        assert_eq!(
+            instance
+                .reported_audio_asset(instance.reported_audio_count())
+                .err()
+                .map(|err| err.status()),
+            Some(Status::RIVE_RS_STATUS_OUT_OF_RANGE)
+        );
+        engine.stop_all();
+    }
+    assert!(started > 0);
+    assert!(peak > 0.1);
+    Ok(())
+}
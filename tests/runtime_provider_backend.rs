@@ -0,0 +1,217 @@
+#![cfg(all(
+    feature = "runtime-abi-provider",
+    feature = "runtime-abi-provider-tests"
+))]
+
+mod common;
+
+use rive_rs::{
+    BackendBuffer, BackendImage, BackendPaint, BackendPath, BackendRenderer, BackendRendererHandle,
+    BackendShader, BlendMode, BufferType, Factory, FillRule, GradientStop, ImageSampler, Mat2D,
+    PaintStyle, RenderBackend, StrokeCap, StrokeJoin,
+};
+use std::cell::Cell;
+use std::rc::Rc;
+
+use common::asset_bytes;
+
+#[derive(Default)]
+struct Counters {
+    paths: Cell<usize>,
+    paints: Cell<usize>,
+    verbs: Cell<usize>,
+    backend_dropped: Cell<bool>,
+}
+
+struct CountingBackend {
+    counters: Rc<Counters>,
+}
+
+impl Drop for CountingBackend {
+    fn drop(&mut self) {
+        self.counters.backend_dropped.set(true);
+    }
+}
+
+struct CountingPath {
+    counters: Rc<Counters>,
+}
+
+impl BackendPath for CountingPath {
+    fn rewind(&mut self) {}
+    fn set_fill_rule(&mut self, _fill_rule: FillRule) {}
+    fn move_to(&mut self, _x: f32, _y: f32) {
+        self.counters.verbs.set(self.counters.verbs.get() + 1);
+    }
+    fn line_to(&mut self, _x: f32, _y: f32) {
+        self.counters.verbs.set(self.counters.verbs.get() + 1);
+    }
+    fn cubic_to(&mut self, _ox: f32, _oy: f32, _ix: f32, _iy: f32, _x: f32, _y: f32) {
+        self.counters.verbs.set(self.counters.verbs.get() + 1);
+    }
+    fn close(&mut self) {}
+    fn add_path(&mut self, _other: &Self, _transform: &Mat2D) {}
+}
+
+struct NullPaint;
+
+impl BackendPaint for NullPaint {
+    type Shader = NullShader;
+
+    fn set_color(&mut self, _argb: u32) {}
+    fn set_style(&mut self, _style: PaintStyle) {}
+    fn set_thickness(&mut self, _thickness: f32) {}
+    fn set_join(&mut self, _join: StrokeJoin) {}
+    fn set_cap(&mut self, _cap: StrokeCap) {}
+    fn set_blend_mode(&mut self, _blend_mode: BlendMode) {}
+    fn set_shader(&mut self, _shader: Option<&NullShader>) {}
+    fn set_feather(&mut self, _feather: f32) {}
+}
+
+struct NullShader;
+
+impl BackendShader for NullShader {}
+
+struct NullImage;
+
+impl BackendImage for NullImage {
+    fn width(&self) -> u32 {
+        0
+    }
+    fn height(&self) -> u32 {
+        0
+    }
+}
+
+struct VecBuffer(Vec<u8>);
+
+impl BackendBuffer for VecBuffer {
+    fn bytes(&self) -> &[u8] {
+        &self.0
+    }
+    fn bytes_mut(&mut self) -> &mut [u8] {
+        &mut self.0
+    }
+}
+
+impl RenderBackend for CountingBackend {
+    type Path = CountingPath;
+    type Paint = NullPaint;
+    type Shader = NullShader;
+    type Image = NullImage;
+    type Buffer = VecBuffer;
+
+    fn make_path(&mut self, _fill_rule: FillRule) -> CountingPath {
+        self.counters.paths.set(self.counters.paths.get() + 1);
+        CountingPath {
+            counters: self.counters.clone(),
+        }
+    }
+
+    fn make_paint(&mut self) -> NullPaint {
+        self.counters.paints.set(self.counters.paints.get() + 1);
+        NullPaint
+    }
+
+    fn make_linear_gradient(
+        &mut self,
+        _start: (f32, f32),
+        _end: (f32, f32),
+        _stops: &[GradientStop],
+    ) -> NullShader {
+        NullShader
+    }
+
+    fn make_radial_gradient(
+        &mut self,
+        _center: (f32, f32),
+        _radius: f32,
+        _stops: &[GradientStop],
+    ) -> NullShader {
+        NullShader
+    }
+
+    fn decode_image(&mut self, _bytes: &[u8]) -> Option<NullImage> {
+        None
+    }
+
+    fn make_buffer(&mut self, _buffer_type: BufferType, _flags: u32, size: usize) -> VecBuffer {
+        VecBuffer(vec![0; size])
+    }
+}
+
+#[derive(Default)]
+struct CountingRenderer {
+    draws: usize,
+    saves: usize,
+    restores: usize,
+}
+
+impl BackendRenderer for CountingRenderer {
+    type Backend = CountingBackend;
+
+    fn save(&mut self) {
+        self.saves += 1;
+    }
+    fn restore(&mut self) {
+        self.restores += 1;
+    }
+    fn transform(&mut self, _matrix: &Mat2D) {}
+    fn modulate_opacity(&mut self, _opacity: f32) {}
+    fn draw_path(&mut self, _path: &CountingPath, _paint: &NullPaint) {
+        self.draws += 1;
+    }
+    fn clip_path(&mut self, _path: &CountingPath) {}
+    fn draw_image(
+        &mut self,
+        _image: &NullImage,
+        _sampler: ImageSampler,
+        _blend_mode: BlendMode,
+        _opacity: f32,
+    ) {
+    }
+    fn draw_image_mesh(
+        &mut self,
+        _image: &NullImage,
+        _sampler: ImageSampler,
+        _vertices: &VecBuffer,
+        _uv_coords: &VecBuffer,
+        _indices: &VecBuffer,
+        _vertex_count: u32,
+        _index_count: u32,
+        _blend_mode: BlendMode,
+        _opacity: f32,
+    ) {
+    }
+}
+
+#[test]
+fn user_backend_drives_factory_and_renderer() -> Result<(), Box<dyn std::error::Error>> {
+    let counters = Rc::new(Counters::default());
+    let factory = Factory::from_backend(CountingBackend {
+        counters: counters.clone(),
+    })?;
+    let file = factory.load_file(&asset_bytes("smi_test.riv"))?;
+    let mut artboard = file.default_artboard()?;
+    artboard.advance(0.0)?;
+
+    assert!(counters.paths.get() > 0);
+    assert!(counters.paints.get() > 0);
+
+    let mut renderer = BackendRendererHandle::new(CountingRenderer::default())?;
+    artboard.draw_backend(&mut renderer)?;
+    assert!(renderer.get().draws > 0);
+    assert!(counters.verbs.get() > 0);
+
+    // SAFETY: the handle outlives the call and is not otherwise in use.
+    unsafe { artboard.draw_raw(renderer.as_raw()) }?;
+    let renderer = renderer.into_inner();
+    assert_eq!(renderer.saves, renderer.restores);
+
+    drop(artboard);
+    drop(file);
+    drop(factory);
+    assert!(counters.backend_dropped.get());
+
+    Ok(())
+}
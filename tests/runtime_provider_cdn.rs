@@ -0,0 +1,161 @@
+#![cfg(all(
+    feature = "runtime-abi-provider",
+    feature = "runtime-abi-provider-tests"
+))]
+
+mod common;
+
+#[cfg(feature = "runtime-text")]
+use std::cell::Cell;
+use std::cell::RefCell;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+use std::rc::Rc;
+use std::thread;
+
+use rive_rs::{CdnFetcher, DiskCache, Factory, FetchCallback, FetchError, HttpTransport};
+
+use common::asset_bytes;
+
+#[cfg(feature = "runtime-text")]
+fn fixture_font() -> Vec<u8> {
+    let path =
+        PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/fonts/fixture-italic.ttf");
+    fs::read(&path).unwrap_or_else(|err| panic!("failed to read {}: {err}", path.display()))
+}
+
+fn scratch_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("rive-rs-cdn-{name}-{}", std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    dir
+}
+
+// Answers from another thread, like a real HTTP client would.
+struct ThreadedTransport(&'static [u8]);
+
+impl HttpTransport for ThreadedTransport {
+    fn get(&self, _url: &str, done: FetchCallback) {
+        let body = self.0;
+        thread::spawn(move || done(Ok(body.to_vec())));
+    }
+}
+
+struct DroppingTransport;
+
+impl HttpTransport for DroppingTransport {
+    fn get(&self, _url: &str, _done: FetchCallback) {}
+}
+
+#[test]
+fn requests_hosted_assets_after_load() -> Result<(), Box<dyn std::error::Error>> {
+    let factory = Factory::new()?;
+    let bytes = asset_bytes("hosted_font_file.riv");
+    let requested = Rc::new(RefCell::new(Vec::new()));
+    let log = requested.clone();
+    let fetcher = CdnFetcher::new(move |url: &str| {
+        log.borrow_mut().push(url.to_owned());
+        Err(io::Error::new(io::ErrorKind::NotFound, "offline"))
+    });
+
+    let (file, mut hosted) = fetcher.load_file(&factory, &bytes)?;
+    assert!(file.artboard_count() > 0);
+    assert!(!requested.borrow().is_empty());
+    assert!(requested.borrow().iter().all(|url| url.starts_with("http")));
+
+    let outcomes = hosted.poll();
+    assert!(hosted.is_complete());
+    assert_eq!(outcomes.len(), requested.borrow().len());
+    for outcome in &outcomes {
+        assert!(outcome.url.ends_with(&outcome.uuid));
+        assert!(matches!(outcome.result, Err(FetchError::Transport(_))));
+    }
+    Ok(())
+}
+
+#[cfg(feature = "runtime-text")]
+#[test]
+fn attaches_fetched_assets_and_reuses_the_cache() -> Result<(), Box<dyn std::error::Error>> {
+    let factory = Factory::new()?;
+    let bytes = asset_bytes("hosted_font_file.riv");
+    let font = fixture_font();
+    let dir = scratch_dir("success");
+    let calls = Rc::new(Cell::new(0));
+    let counter = calls.clone();
+    let body = font.clone();
+    let fetcher = CdnFetcher::new(move |_: &str| {
+        counter.set(counter.get() + 1);
+        Ok(body.clone())
+    })
+    .with_cache(DiskCache::new(&dir));
+
+    let (file, mut hosted) = fetcher.load_file(&factory, &bytes)?;
+    let outcomes = hosted.poll();
+    assert!(hosted.is_complete());
+    assert!(!outcomes.is_empty());
+    assert_eq!(calls.get(), outcomes.len());
+    for outcome in &outcomes {
+        assert!(
+            outcome.result.is_ok(),
+            "{}: {:?}",
+            outcome.uuid,
+            outcome.result
+        );
+        assert_eq!(
+            fetcher.cache().and_then(|cache| cache.get(&outcome.uuid)),
+            Some(font.clone())
+        );
+    }
+    let fonts = || file.assets().into_iter().filter(|asset| asset.is_font());
+    assert!(fonts().count() > 0);
+    assert!(fonts().all(|asset| asset.is_loaded()));
+
+    // The second load is served from the cache without touching the
+    // transport.
+    let (cached, hosted) = fetcher.load_file(&factory, &bytes)?;
+    assert!(hosted.is_complete());
+    assert_eq!(hosted.pending().count(), 0);
+    assert_eq!(calls.get(), outcomes.len());
+    assert!(
+        cached
+            .assets()
+            .iter()
+            .filter(|asset| asset.is_font())
+            .all(|asset| asset.is_loaded())
+    );
+
+    fs::remove_dir_all(&dir)?;
+    Ok(())
+}
+
+#[test]
+fn undecodable_and_dropped_requests_fail() -> Result<(), Box<dyn std::error::Error>> {
+    let factory = Factory::new()?;
+    let bytes = asset_bytes("hosted_font_file.riv");
+    let dir = scratch_dir("fetch");
+
+    let fetcher =
+        CdnFetcher::new(ThreadedTransport(b"not a font")).with_cache(DiskCache::new(&dir));
+    let (_, mut hosted) = fetcher.load_file(&factory, &bytes)?;
+    let outcomes = hosted.wait();
+    assert!(!outcomes.is_empty());
+    assert!(hosted.pending().next().is_none());
+    for outcome in &outcomes {
+        assert!(matches!(outcome.result, Err(FetchError::Decode(_))));
+        assert_eq!(
+            fetcher.cache().and_then(|cache| cache.get(&outcome.uuid)),
+            None
+        );
+    }
+
+    let (_, mut hosted) = CdnFetcher::new(DroppingTransport).load_file(&factory, &bytes)?;
+    let outcomes = hosted.wait();
+    assert!(!outcomes.is_empty());
+    for outcome in &outcomes {
+        assert!(matches!(
+            &outcome.result,
+            Err(FetchError::Transport(err)) if err.kind() == io::ErrorKind::Interrupted
+        ));
+    }
+    Ok(())
+}
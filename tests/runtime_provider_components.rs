@@ -0,0 +1,85 @@
+#![cfg(all(
+    feature = "runtime-abi-provider",
+    feature = "runtime-abi-provider-tests"
+))]
+
+mod common;
+
+use rive_rs::{Factory, Status};
+
+use common::asset_bytes;
+
+#[test]
+fn walks_artboard_component_tree() -> Result<(), Box<dyn std::error::Error>> {
+    let factory = Factory::new()?;
+    let file = factory.load_file(&asset_bytes("smi_test.riv"))?;
+    let artboard = file.default_artboard()?;
+
+    let components = artboard.components();
+    assert_eq!(components.len(), artboard.object_count());
+    let root = &components[0];
+    assert_eq!(root.type_name, "Artboard");
+    assert_eq!(root.parent, None);
+    assert!(!root.children.is_empty());
+
+    for component in &components {
+        if let Some(parent) = component.parent {
+            assert!(components[parent].children.contains(&component.index));
+        }
+        for &child in &component.children {
+            assert_eq!(components[child].parent, Some(component.index));
+        }
+    }
+    assert_eq!(
+        artboard
+            .component_at(components.len())
+            .err()
+            .map(|err| err.status()),
+        Some(Status::RIVE_RS_STATUS_OUT_OF_RANGE)
+    );
+    Ok(())
+}
+
+#[test]
+fn converts_components_to_handles() -> Result<(), Box<dyn std::error::Error>> {
+    let factory = Factory::new()?;
+    let file = factory.load_file(&asset_bytes("smi_test.riv"))?;
+    let mut artboard = file.default_artboard()?;
+    let components = artboard.components();
+
+    let node = components
+        .iter()
+        .find(|component| component.is_node)
+        .expect("smi_test.riv has nodes");
+    assert!(node.is_transform_component);
+    let mut handle = artboard.node_by_index(node.index)?;
+    handle.set_x(12.0);
+    assert_eq!(handle.x(), 12.0);
+    artboard.transform_component_by_index(node.index)?;
+
+    // The artboard itself is not a transform component.
+    assert_eq!(
+        artboard
+            .transform_component_by_index(0)
+            .err()
+            .map(|err| err.status()),
+        Some(Status::RIVE_RS_STATUS_INVALID_ARGUMENT)
+    );
+    for component in components.iter().filter(|component| !component.is_bone) {
+        assert_eq!(
+            artboard
+                .bone_by_index(component.index)
+                .err()
+                .map(|err| err.status()),
+            Some(Status::RIVE_RS_STATUS_INVALID_ARGUMENT)
+        );
+    }
+    assert_eq!(
+        artboard
+            .root_bone_by_index(components.len())
+            .err()
+            .map(|err| err.status()),
+        Some(Status::RIVE_RS_STATUS_OUT_OF_RANGE)
+    );
+    Ok(())
+}
@@ -0,0 +1,85 @@
+#![cfg(all(
+    feature = "runtime-abi-provider",
+    feature = "runtime-abi-provider-tests"
+))]
+
+mod common;
+
+use rive_rs::{Factory, LayerStateType, StateMachineInstance};
+
+use common::asset_bytes;
+
+#[test]
+fn reads_current_state_per_layer() -> Result<(), Box<dyn std::error::Error>> {
+    let factory = Factory::new()?;
+    let file = factory.load_file(&asset_bytes("smi_test.riv"))?;
+    let mut artboard = file.default_artboard()?;
+    let layers = artboard.state_machine_by_index(0)?.layers()?;
+    let machine = artboard.state_machine_by_index(0)?;
+    let mut instance = StateMachineInstance::new(machine, &mut artboard)?;
+    assert_eq!(instance.layer_count(), layers.len());
+
+    // Before the first advance every layer sits in its entry state.
+    let states = instance.current_states()?;
+    assert_eq!(states.len(), layers.len());
+    for (state, layer) in states.iter().zip(&layers) {
+        assert_eq!(state.layer_name, layer.name);
+        assert_eq!(state.state_type, LayerStateType::RIVE_RS_LAYER_STATE_ENTRY);
+        assert!(state.transition.is_none());
+    }
+
+    instance.advance_and_apply(0.0)?;
+    let entered = instance.current_states()?;
+    instance.advance_and_apply(0.25)?;
+    for ((state, before), layer) in instance.current_states()?.iter().zip(&entered).zip(&layers) {
+        let expected = &layer.states[state.state];
+        assert_eq!(state.state_type, expected.state_type);
+        assert_eq!(state.name, expected.name);
+        // The clock restarts whenever the layer changes state.
+        if state.state == before.state {
+            assert_eq!(state.elapsed_seconds, before.elapsed_seconds + 0.25);
+        } else {
+            assert_eq!(state.elapsed_seconds, 0.0);
+        }
+        if let Some(transition) = &state.transition {
+            assert!((0.0..1.0).contains(&transition.mix));
+            let source = &layer.states[transition.source_state];
+            assert_eq!(
+                source.transitions[transition.transition].target_state,
+                Some(state.state)
+            );
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn counts_time_spent_in_blend_states() -> Result<(), Box<dyn std::error::Error>> {
+    let factory = Factory::new()?;
+    let file = factory.load_file(&asset_bytes("blend_test.riv"))?;
+    let mut blend_layers = 0;
+    for artboard_index in 0..file.artboard_count() {
+        let mut artboard = file.artboard_by_index(artboard_index)?;
+        for machine_index in 0..artboard.state_machine_count() {
+            let machine = artboard.state_machine_by_index(machine_index)?;
+            let mut instance = StateMachineInstance::new(machine, &mut artboard)?;
+            instance.advance_and_apply(0.0)?;
+            let entered = instance.current_states()?;
+            instance.advance_and_apply(0.25)?;
+            instance.advance_and_apply(0.25)?;
+            for (state, before) in instance.current_states()?.iter().zip(&entered) {
+                if before.state_type != LayerStateType::RIVE_RS_LAYER_STATE_BLEND
+                    || state.state != before.state
+                {
+                    continue;
+                }
+                // Blend states have no single animation clock; the time is
+                // counted from when the layer entered the state.
+                assert_eq!(state.elapsed_seconds, before.elapsed_seconds + 0.5);
+                blend_layers += 1;
+            }
+        }
+    }
+    assert!(blend_layers > 0);
+    Ok(())
+}
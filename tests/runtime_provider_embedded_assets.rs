@@ -0,0 +1,58 @@
+#![cfg(all(
+    feature = "runtime-abi-provider",
+    feature = "runtime-abi-provider-tests"
+))]
+
+mod common;
+
+use rive_rs::{AssetEdit, Factory, extract_embedded_assets, inspect_assets, rewrite_assets};
+
+use common::asset_bytes;
+
+#[test]
+fn extracted_assets_match_the_runtime_view() -> Result<(), Box<dyn std::error::Error>> {
+    for name in ["in_band_asset.riv", "sound.riv", "hosted_font_file.riv"] {
+        let riv = asset_bytes(name);
+        let infos = inspect_assets(&riv)?;
+        let embedded = extract_embedded_assets(&riv)?;
+        assert_eq!(
+            embedded.len(),
+            infos.iter().filter(|info| info.embedded).count()
+        );
+        for asset in &embedded {
+            let info = &infos[asset.index];
+            assert_eq!(info.kind, asset.kind);
+            assert_eq!(info.name, asset.name);
+            assert_eq!(info.embedded_size, asset.bytes.len());
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn rewritten_files_load() -> Result<(), Box<dyn std::error::Error>> {
+    let riv = asset_bytes("in_band_asset.riv");
+    let embedded = extract_embedded_assets(&riv)?;
+    assert!(!embedded.is_empty());
+
+    let referenced: Vec<_> = embedded
+        .iter()
+        .map(|asset| (asset.index, AssetEdit::Reference))
+        .collect();
+    let stripped = rewrite_assets(&riv, &referenced)?;
+    assert!(stripped.len() < riv.len());
+    assert!(inspect_assets(&stripped)?.iter().all(|info| !info.embedded));
+    assert!(
+        Factory::new_software()?
+            .load_file(&stripped)?
+            .artboard_count()
+            > 0
+    );
+
+    let restored: Vec<_> = embedded
+        .iter()
+        .map(|asset| (asset.index, AssetEdit::Embed(asset.bytes.clone())))
+        .collect();
+    assert_eq!(rewrite_assets(&stripped, &restored)?, riv);
+    Ok(())
+}
@@ -0,0 +1,99 @@
+#![cfg(all(
+    feature = "runtime-abi-provider",
+    feature = "runtime-abi-provider-tests",
+    feature = "runtime-text"
+))]
+
+mod common;
+
+use std::fs;
+use std::path::PathBuf;
+
+use rive_rs::{
+    Artboard, DisplayList, DrawCommand, Factory, FileAsset, Font, LoadOutcome, PathVerb,
+    RecordingRenderer, set_font_fallbacks,
+};
+
+use common::asset_bytes;
+
+fn fixture_font(factory: &Factory, name: &str) -> Result<Font, rive_rs::Error> {
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/fixtures/fonts")
+        .join(name);
+    let bytes =
+        fs::read(&path).unwrap_or_else(|err| panic!("failed to read {}: {err}", path.display()));
+    factory.decode_font(&bytes)
+}
+
+// Contours with at least five line segments. The fixture fonts draw every
+// glyph as a box, except the fallback font's `D` and `E`, which are hexagons.
+fn hexagons(list: &DisplayList) -> usize {
+    let mut count = 0;
+    for command in &list.commands {
+        let DrawCommand::DrawPath { path, .. } = command else {
+            continue;
+        };
+        for contour in path
+            .verbs
+            .split(|verb| matches!(verb, PathVerb::MoveTo { .. }))
+        {
+            let lines = contour
+                .iter()
+                .filter(|verb| matches!(verb, PathVerb::LineTo { .. }))
+                .count();
+            if lines >= 5 {
+                count += 1;
+            }
+        }
+    }
+    count
+}
+
+// Reshapes the first text run and records the frame.
+fn render_text(artboard: &mut Artboard, text: &str) -> Result<DisplayList, rive_rs::Error> {
+    artboard.set_text_value_run_text_at(0, text)?;
+    artboard.advance(0.0)?;
+    let mut renderer = RecordingRenderer::new()?;
+    artboard.draw_recording(&mut renderer)?;
+    Ok(renderer.take_display_list())
+}
+
+#[test]
+fn shapes_missing_glyphs_with_fallback_fonts() -> Result<(), Box<dyn std::error::Error>> {
+    let factory = Factory::new_recording()?;
+    let primary = fixture_font(&factory, "fixture-italic.ttf")?;
+    let fallback = fixture_font(&factory, "fixture-fallback.ttf")?;
+    assert_eq!(primary.missing_glyphs("ABDE"), Some(vec!['D', 'E']));
+    assert_eq!(fallback.missing_glyphs("DE"), Some(Vec::new()));
+
+    let file = factory.load_file_with_loader(
+        &asset_bytes("hosted_font_file.riv"),
+        move |asset: &mut FileAsset, _: &[u8], _: &Factory| {
+            if !asset.is_font() {
+                return LoadOutcome::Fallback;
+            }
+            assert!(asset.set_font(&primary).is_ok());
+            LoadOutcome::Loaded
+        },
+    )?;
+    let mut artboard = file.default_artboard()?;
+    assert!(artboard.text_value_run_count() > 0);
+
+    set_font_fallbacks(&[])?;
+    let without = render_text(&mut artboard, "ABDE")?;
+    assert!(!without.is_empty());
+    assert_eq!(hexagons(&without), 0);
+
+    // The provider keeps the chain alive after the caller's handles drop.
+    let chain = vec![fallback.clone()];
+    drop(fallback);
+    set_font_fallbacks(&chain)?;
+    drop(chain);
+    let with = render_text(&mut artboard, "ABDE")?;
+    assert_eq!(hexagons(&with), 2);
+    assert_eq!(hexagons(&render_text(&mut artboard, "ABC")?), 0);
+
+    set_font_fallbacks(&[])?;
+    assert_eq!(hexagons(&render_text(&mut artboard, "ABDE")?), 0);
+    Ok(())
+}
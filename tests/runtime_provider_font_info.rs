@@ -0,0 +1,77 @@
+#![cfg(all(
+    feature = "runtime-abi-provider",
+    feature = "runtime-abi-provider-tests"
+))]
+
+#[cfg(feature = "runtime-text")]
+use std::path::PathBuf;
+
+use rive_rs::{Factory, FontStyle, load_fonts, system_font_dirs};
+
+#[cfg(feature = "runtime-text")]
+fn fixture_bytes(name: &str) -> Vec<u8> {
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/fixtures/fonts")
+        .join(name);
+    std::fs::read(&path).unwrap_or_else(|err| panic!("{}: {err}", path.display()))
+}
+
+#[cfg(feature = "runtime-text")]
+#[test]
+fn reads_fixture_font_metadata() -> Result<(), Box<dyn std::error::Error>> {
+    let factory = Factory::new()?;
+    let font = factory.decode_font(&fixture_bytes("fixture-italic.ttf"))?;
+
+    assert!(font.has_metadata());
+    assert_eq!(font.family_name(), Some("Rive Fixture"));
+    assert_eq!(font.weight(), Some(400));
+    assert_eq!(font.style(), Some(FontStyle::Italic));
+    assert_eq!(font.units_per_em(), Some(1000));
+    assert_eq!(font.ascent(), Some(0.8));
+    assert_eq!(font.descent(), Some(-0.2));
+    assert_eq!(font.line_gap(), Some(0.09));
+
+    for c in [' ', 'A', 'B', 'C'] {
+        assert_eq!(font.has_glyph(c), Some(true), "{c:?}");
+    }
+    assert_eq!(font.has_glyph('D'), Some(false));
+    assert_eq!(font.advance('A'), Some(0.6));
+    assert_eq!(font.advance(' '), Some(0.25));
+    assert_eq!(font.advance('a'), None);
+    assert_eq!(font.missing_glyphs("ABBA cab\n"), Some(vec!['c', 'a', 'b']));
+    Ok(())
+}
+
+#[test]
+fn reports_font_metrics_and_coverage() -> Result<(), Box<dyn std::error::Error>> {
+    let factory = Factory::new()?;
+    assert!(factory.decode_font(b"not a font").is_err());
+
+    let mut fonts = Vec::new();
+    for dir in system_font_dirs() {
+        fonts.extend(load_fonts(&factory, dir)?);
+    }
+    for font in fonts.iter().filter(|font| font.has_metadata()) {
+        assert!(font.units_per_em().is_some_and(|units| units > 0));
+        assert!(
+            font.weight()
+                .is_some_and(|weight| (1..=1000).contains(&weight))
+        );
+        assert!(font.ascent().is_some_and(|ascent| ascent > 0.0));
+        assert!(font.descent().is_some_and(|descent| descent <= 0.0));
+        assert!(font.line_gap().is_some_and(|gap| gap >= 0.0));
+        assert!(matches!(
+            font.style(),
+            Some(FontStyle::Normal | FontStyle::Italic | FontStyle::Oblique)
+        ));
+        if font.has_glyph('A') == Some(true) {
+            assert!(font.advance('A').is_some_and(|advance| advance > 0.0));
+            assert_eq!(font.missing_glyphs("AA\n"), Some(Vec::new()));
+        } else {
+            assert_eq!(font.advance('A'), None);
+            assert_eq!(font.missing_glyphs("AA\n"), Some(vec!['A']));
+        }
+        assert_eq!(font.clone().family_name(), font.family_name());
+    }
+    Ok(())
+}
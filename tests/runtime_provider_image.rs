@@ -0,0 +1,58 @@
+#![cfg(all(
+    feature = "runtime-abi-provider",
+    feature = "runtime-abi-provider-tests"
+))]
+
+use rive_rs::{Factory, Frame, Status};
+
+fn gradient_frame() -> Frame {
+    Frame {
+        width: 3,
+        height: 2,
+        rgba: (0..24).map(|i| (i * 10) as u8).collect(),
+    }
+}
+
+#[test]
+fn native_factories_decode_image_pixels() -> Result<(), Box<dyn std::error::Error>> {
+    let frame = gradient_frame();
+    let png = frame.to_png();
+    for factory in [Factory::new_recording()?, Factory::new_software()?] {
+        let image = factory.decode_image(&png)?;
+        assert_eq!((image.width(), image.height()), (3, 2));
+        assert_eq!(image.pixels(), Some(frame.rgba.as_slice()));
+
+        let clone = image.clone();
+        drop(image);
+        assert_eq!(clone.pixels(), Some(frame.rgba.as_slice()));
+    }
+    Ok(())
+}
+
+#[test]
+fn undecodable_bytes_are_rejected() -> Result<(), Box<dyn std::error::Error>> {
+    let factory = Factory::new_recording()?;
+    assert!(factory.decode_image(b"not an image").is_err());
+    assert!(factory.decode_image(&[]).is_err());
+
+    // The recording factory only reads the header up front and decodes
+    // pixels on demand; the software factory decodes eagerly.
+    let png = gradient_frame().to_png();
+    let truncated = &png[..png.len() / 2];
+    assert_eq!(factory.decode_image(truncated)?.pixels(), None);
+    assert!(Factory::new_software()?.decode_image(truncated).is_err());
+    Ok(())
+}
+
+#[test]
+fn no_op_factory_does_not_decode_images() -> Result<(), Box<dyn std::error::Error>> {
+    let factory = Factory::new()?;
+    for bytes in [gradient_frame().to_png(), b"not an image".to_vec()] {
+        let err = factory
+            .decode_image(&bytes)
+            .err()
+            .ok_or("decoded on Factory::new")?;
+        assert_eq!(err.status(), Status::RIVE_RS_STATUS_UNSUPPORTED);
+    }
+    Ok(())
+}
@@ -0,0 +1,67 @@
+#![cfg(all(
+    feature = "runtime-abi-provider",
+    feature = "runtime-abi-provider-tests"
+))]
+
+mod common;
+
+use rive_rs::{Factory, InputValue, SmiInputType, StateMachineInstance, inspect_riv};
+
+use common::asset_bytes;
+
+#[test]
+fn inspects_artboards_and_state_machines() -> Result<(), Box<dyn std::error::Error>> {
+    let bytes = asset_bytes("smi_test.riv");
+    let report = inspect_riv(&bytes)?;
+
+    let file = Factory::new()?.load_file(&bytes)?;
+    assert_eq!(report.artboards.len(), file.artboard_count());
+    let mut artboard = file.default_artboard()?;
+    let main = report
+        .artboards
+        .iter()
+        .find(|report| report.name == artboard.name())
+        .expect("default artboard is listed");
+    assert_eq!(main.width, artboard.width());
+    assert_eq!(main.height, artboard.height());
+    assert_eq!(main.animations.len(), artboard.animation_count());
+    assert_eq!(main.state_machines.len(), artboard.state_machine_count());
+    assert_eq!(main.events.len(), artboard.event_count());
+
+    let machine = artboard.state_machine_by_index(0)?;
+    assert_eq!(main.state_machines[0].name, machine.name());
+    let mut instance = StateMachineInstance::new(machine, &mut artboard)?;
+    let inputs = &main.state_machines[0].inputs;
+    assert_eq!(inputs.len(), instance.input_count());
+    for (index, input) in inputs.iter().enumerate() {
+        let expected = instance.input(index)?;
+        assert_eq!(input.name, expected.name());
+        let input_type = match input.value {
+            InputValue::Bool(_) => SmiInputType::RIVE_RS_SMI_INPUT_BOOL,
+            InputValue::Number(_) => SmiInputType::RIVE_RS_SMI_INPUT_NUMBER,
+            InputValue::Trigger => SmiInputType::RIVE_RS_SMI_INPUT_TRIGGER,
+        };
+        assert_eq!(input_type, expected.input_type());
+    }
+
+    let json = report.to_json();
+    assert!(json.starts_with("{\n  \"artboards\": ["));
+    assert!(json.contains(&format!("\"name\": \"{}\"", main.name)));
+    Ok(())
+}
+
+#[test]
+fn inspects_view_models_enums_and_assets() -> Result<(), Box<dyn std::error::Error>> {
+    let report = inspect_riv(&asset_bytes("viewmodel_runtime_file.riv"))?;
+    assert!(!report.view_models.is_empty());
+    let view_model = &report.view_models[0];
+    assert!(!view_model.name.is_empty());
+    assert!(!view_model.properties.is_empty());
+
+    let report = inspect_riv(&asset_bytes("in_band_asset.riv"))?;
+    assert!(report.assets.iter().any(|asset| asset.embedded));
+    assert!(report.to_json().contains("\"embedded\": true"));
+
+    assert!(inspect_riv(b"not a rive file").is_err());
+    Ok(())
+}
@@ -3,16 +3,11 @@
     feature = "runtime-abi-provider-tests"
 ))]
 
+mod common;
+
 use rive_rs::Factory;
-use std::fs;
-use std::path::PathBuf;
-
-fn asset_bytes(name: &str) -> Vec<u8> {
-    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
-        .join("submodules/rive-runtime/tests/unit_tests/assets")
-        .join(name);
-    fs::read(&path).unwrap_or_else(|err| panic!("failed to read {}: {err}", path.display()))
-}
+
+use common::asset_bytes;
 
 #[test]
 fn ref_unref_lifetime_paths_for_core_handles() -> Result<(), Box<dyn std::error::Error>> {
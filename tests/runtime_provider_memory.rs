@@ -0,0 +1,119 @@
+#![cfg(all(
+    feature = "runtime-abi-provider",
+    feature = "runtime-abi-provider-tests"
+))]
+
+mod common;
+
+use rive_rs::{AssetKind, Factory, FileAsset, LoadOutcome, MemoryStats, RecordingRenderer, Status};
+
+use common::asset_bytes;
+
+#[test]
+fn reports_file_and_factory_memory() -> Result<(), Box<dyn std::error::Error>> {
+    let factory = Factory::new_recording()?;
+    let bytes = asset_bytes("in_band_asset.riv");
+    let file = factory.load_file(&bytes)?;
+
+    let assets = file.assets();
+    assert_eq!(assets.len(), file.asset_count());
+    assert!(
+        assets
+            .iter()
+            .any(|asset| AssetKind::of(asset) == AssetKind::Image && asset.is_loaded())
+    );
+    assert_eq!(
+        file.asset_at(file.asset_count())
+            .err()
+            .map(|err| err.status()),
+        Some(Status::RIVE_RS_STATUS_OUT_OF_RANGE)
+    );
+
+    let stats = file.memory_stats();
+    assert_eq!(stats.files, 1);
+    assert!(stats.images > 0);
+    assert!(stats.image_bytes >= stats.images * 4);
+    assert_eq!(stats.released_images, 0);
+    assert_eq!(stats.retained_image_bytes, 0);
+    assert_eq!(stats.objects, file.object_count());
+    assert!(stats.objects > 0);
+    assert_eq!(stats.object_bytes, file.object_bytes());
+    assert!(stats.object_bytes >= stats.objects * std::mem::size_of::<usize>());
+    assert_eq!(factory.memory_stats(), stats);
+
+    let second = factory.clone().load_file(&bytes)?;
+    let mut doubled = stats;
+    doubled += second.memory_stats();
+    assert_eq!(factory.memory_stats(), doubled);
+    assert_eq!(doubled.files, 2);
+    drop(second);
+    assert_eq!(factory.memory_stats(), stats);
+
+    // Other factories keep their own totals.
+    assert_eq!(Factory::new()?.memory_stats(), MemoryStats::default());
+    Ok(())
+}
+
+#[test]
+fn releases_images_only_when_retained() -> Result<(), Box<dyn std::error::Error>> {
+    let factory = Factory::new_recording()?;
+    let bytes = asset_bytes("in_band_asset.riv");
+    let file = factory.load_file(&bytes)?;
+    let loaded = file.memory_stats();
+    assert!(loaded.images > 0);
+    assert_eq!(file.release_images(), 0);
+    assert_eq!(file.memory_stats(), loaded);
+
+    let retained = factory
+        .load_file_retaining_images(&bytes, |_: &mut FileAsset, _: &[u8], _: &Factory| {
+            LoadOutcome::Fallback
+        })?;
+    let stats = retained.memory_stats();
+    assert!(stats.retained_image_bytes > 0);
+    assert_eq!(
+        MemoryStats {
+            retained_image_bytes: 0,
+            ..stats
+        },
+        loaded
+    );
+    Ok(())
+}
+
+#[test]
+fn decodes_released_images_when_drawn() -> Result<(), Box<dyn std::error::Error>> {
+    let factory = Factory::new_recording()?;
+    let file = factory.load_file_retaining_images(
+        &asset_bytes("in_band_asset.riv"),
+        |_: &mut FileAsset, _: &[u8], _: &Factory| LoadOutcome::Fallback,
+    )?;
+    let loaded = file.memory_stats();
+
+    let released = file.release_images();
+    assert_eq!(released, loaded.images);
+    let stats = file.memory_stats();
+    assert_eq!(stats.images, 0);
+    assert_eq!(stats.image_bytes, 0);
+    assert_eq!(stats.released_images, released);
+    assert_eq!(stats.retained_image_bytes, loaded.retained_image_bytes);
+    assert_eq!(file.release_images(), 0);
+
+    // Drawing decodes the released images the artboard uses.
+    let mut artboard = file.default_artboard()?;
+    let used = file
+        .assets()
+        .iter()
+        .filter(|asset| AssetKind::of(asset) == AssetKind::Image)
+        .filter(|asset| artboard.uses_image_asset(asset))
+        .count();
+    assert!(used > 0);
+    artboard.advance(0.0)?;
+    artboard.draw_recording(&mut RecordingRenderer::new()?)?;
+    let drawn = file.memory_stats();
+    assert_eq!(drawn.images, used);
+    assert_eq!(drawn.released_images, released - used);
+    if used == released {
+        assert_eq!(drawn, loaded);
+    }
+    Ok(())
+}
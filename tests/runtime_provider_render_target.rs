@@ -0,0 +1,96 @@
+#![cfg(all(
+    feature = "runtime-abi-provider",
+    feature = "runtime-abi-provider-tests"
+))]
+
+mod common;
+
+use rive_rs::{
+    Aabb, Alignment, Factory, Fit, RecordingRenderer, RenderTarget, Renderer, SoftwareRenderer,
+    Status, WebGl2Renderer, WebGpuRenderer,
+};
+
+use common::asset_bytes;
+
+#[test]
+fn software_renderer_draws_into_bound_target() -> Result<(), Box<dyn std::error::Error>> {
+    let factory = Factory::new_software()?;
+    let file = factory.load_file(&asset_bytes("smi_test.riv"))?;
+    let mut artboard = file.default_artboard()?;
+    let content = artboard.bounds();
+    let frame = Aabb {
+        min_x: 0.0,
+        min_y: 0.0,
+        max_x: 32.0,
+        max_y: 32.0,
+    };
+
+    let mut renderer = SoftwareRenderer::new(64, 64)?;
+    let target = RenderTarget::new(32, 32)?;
+    renderer.bind_target(Some(&target))?;
+    assert_eq!((renderer.width(), renderer.height()), (32, 32));
+    renderer.clear()?;
+    renderer.save()?;
+    renderer.align(
+        Fit::RIVE_RS_FIT_CONTAIN,
+        Alignment::RIVE_RS_ALIGNMENT_CENTER,
+        &frame,
+        &content,
+        1.0,
+    )?;
+    artboard.draw_software(&mut renderer)?;
+    renderer.restore()?;
+    renderer.flush()?;
+    let drawn = renderer.to_rgba8();
+    renderer.bind_target(None)?;
+
+    assert_eq!((renderer.width(), renderer.height()), (64, 64));
+    assert!(renderer.pixels().iter().all(|&byte| byte == 0));
+
+    for factory in [factory, Factory::new()?] {
+        let image = target.to_image(&factory)?;
+        assert_eq!((image.width(), image.height()), (32, 32));
+        assert_eq!(image.pixels(), Some(drawn.as_slice()));
+    }
+    assert!(drawn.chunks_exact(4).any(|pixel| pixel[3] != 0));
+
+    Ok(())
+}
+
+#[test]
+fn render_target_lifecycle() -> Result<(), Box<dyn std::error::Error>> {
+    assert!(RenderTarget::new(0, 16).is_err());
+
+    let target = RenderTarget::new(4, 2)?;
+    let image = target.clone().to_image(&Factory::new_software()?)?;
+    assert_eq!(image.pixels(), Some(&[0; 4 * 2 * 4][..]));
+
+    // Outside emscripten, the GPU renderers cannot fill a target.
+    let mut webgl2 = WebGl2Renderer::new(64, 64)?;
+    assert_eq!(
+        webgl2
+            .bind_target(Some(&target))
+            .err()
+            .map(|err| err.status()),
+        Some(Status::RIVE_RS_STATUS_UNSUPPORTED)
+    );
+    drop(target);
+    webgl2.clear()?;
+    webgl2.flush()?;
+    webgl2.bind_target(None)?;
+
+    let mut webgpu = WebGpuRenderer::new(64, 64)?;
+    assert_eq!(
+        webgpu
+            .bind_target(Some(&RenderTarget::new(8, 8)?))
+            .err()
+            .map(|err| err.status()),
+        Some(Status::RIVE_RS_STATUS_UNSUPPORTED)
+    );
+    webgpu.bind_target(None)?;
+
+    let mut recording = RecordingRenderer::new()?;
+    assert!(Renderer::bind_target(&mut recording, None).is_err());
+
+    Ok(())
+}
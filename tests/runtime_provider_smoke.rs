@@ -3,17 +3,21 @@
     feature = "runtime-abi-provider-tests"
 ))]
 
+mod common;
+
 use rive_rs::abi;
 use rive_rs::{
-    Aabb, Alignment, DataType, Factory, FileAssetLoaderCallbacks, Fit, LinearAnimationInstance,
-    Mat2D, SmiInputType, StateMachineInstance, Vec2, WebGl2Renderer, WebGpuRenderer,
-    compute_alignment, map_xy,
+    Aabb, Alignment, DataType, DisplayList, DrawCommand, ExportOptions, Factory,
+    FileAssetLoaderCallbacks, Fit, LinearAnimationInstance, Mat2D, RecordingRenderer, Renderer,
+    SmiInputType, SoftwareRenderer, StateMachineInstance, Status, SvgRenderer, Timeline, Vec2,
+    WebGl2Renderer, WebGpuRenderer, compute_alignment, encode_apng, encode_gif, export_frames,
+    map_xy,
 };
 use std::ffi::c_void;
-use std::fs;
-use std::path::PathBuf;
 use std::sync::atomic::{AtomicBool, AtomicI32, AtomicUsize, Ordering};
 
+use common::asset_bytes;
+
 static LOADER_CALLBACK_CALLS: AtomicUsize = AtomicUsize::new(0);
 static ASSET_MUTATOR_CALLS: AtomicUsize = AtomicUsize::new(0);
 static SAW_IMAGE_ASSET: AtomicBool = AtomicBool::new(false);
@@ -78,13 +82,6 @@ unsafe extern "C" fn asset_mutating_loader(
     false
 }
 
-fn asset_bytes(name: &str) -> Vec<u8> {
-    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
-        .join("submodules/rive-runtime/tests/unit_tests/assets")
-        .join(name);
-    fs::read(&path).unwrap_or_else(|err| panic!("failed to read {}: {err}", path.display()))
-}
-
 fn reset_asset_mutator_state() {
     ASSET_MUTATOR_CALLS.store(0, Ordering::Relaxed);
     SAW_IMAGE_ASSET.store(false, Ordering::Relaxed);
@@ -104,6 +101,11 @@ fn reset_asset_mutator_state() {
     );
 }
 
+#[test]
+fn provider_matches_crate_abi_version() {
+    assert_eq!(rive_rs::abi_version(), rive_rs::ABI_VERSION);
+}
+
 #[test]
 fn math_helpers_smoke() -> Result<(), Box<dyn std::error::Error>> {
     let source = Aabb {
@@ -440,3 +442,293 @@ fn web_renderer_api_smoke() -> Result<(), Box<dyn std::error::Error>> {
 
     Ok(())
 }
+
+#[test]
+fn software_renderer_smoke() -> Result<(), Box<dyn std::error::Error>> {
+    let factory = Factory::new_software()?;
+    let file = factory.load_file(&asset_bytes("smi_test.riv"))?;
+    let mut artboard = file.default_artboard()?;
+    let content = artboard.bounds();
+    let frame = Aabb {
+        min_x: 0.0,
+        min_y: 0.0,
+        max_x: 64.0,
+        max_y: 64.0,
+    };
+
+    let mut renderer = SoftwareRenderer::new(64, 64)?;
+    assert!(renderer.restore().is_err());
+    renderer.clear()?;
+    renderer.save()?;
+    renderer.align(
+        Fit::RIVE_RS_FIT_CONTAIN,
+        Alignment::RIVE_RS_ALIGNMENT_CENTER,
+        &frame,
+        &content,
+        1.0,
+    )?;
+    artboard.draw_software(&mut renderer)?;
+    renderer.restore()?;
+    renderer.flush()?;
+
+    assert_eq!(renderer.pixels().len(), 64 * 64 * 4);
+    assert!(renderer.pixels().chunks_exact(4).any(|pixel| pixel[3] != 0));
+    assert!(SoftwareRenderer::new(0, 64).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn recording_renderer_round_trip() -> Result<(), Box<dyn std::error::Error>> {
+    let bytes = asset_bytes("smi_test.riv");
+    let frame = Aabb {
+        min_x: 0.0,
+        min_y: 0.0,
+        max_x: 64.0,
+        max_y: 64.0,
+    };
+
+    let factory = Factory::new_recording()?;
+    let file = factory.load_file(&bytes)?;
+    let mut artboard = file.default_artboard()?;
+    let alignment = compute_alignment(
+        Fit::RIVE_RS_FIT_CONTAIN,
+        Alignment::RIVE_RS_ALIGNMENT_CENTER,
+        &frame,
+        &artboard.bounds(),
+        1.0,
+    )?;
+    let mut recorder = RecordingRenderer::new()?;
+    artboard.draw_recording(&mut recorder)?;
+    let list = recorder.take_display_list();
+    assert!(recorder.display_list().is_empty());
+    assert!(
+        list.commands
+            .iter()
+            .any(|command| matches!(command, DrawCommand::DrawPath { .. }))
+    );
+
+    let decoded = DisplayList::from_bytes(&list.to_bytes())?;
+    assert_eq!(decoded, list);
+    assert!(DisplayList::from_bytes(b"not a display list").is_err());
+
+    let mut replayed = SoftwareRenderer::new(64, 64)?;
+    replayed.transform(&alignment)?;
+    replayed.replay(&decoded)?;
+
+    let software_factory = Factory::new_software()?;
+    let software_file = software_factory.load_file(&bytes)?;
+    let mut software_artboard = software_file.default_artboard()?;
+    let mut direct = SoftwareRenderer::new(64, 64)?;
+    direct.transform(&alignment)?;
+    software_artboard.draw_software(&mut direct)?;
+
+    assert_eq!(replayed.pixels(), direct.pixels());
+
+    Ok(())
+}
+
+#[test]
+fn svg_export_from_recording_factory() -> Result<(), Box<dyn std::error::Error>> {
+    let factory = Factory::new_recording()?;
+    let file = factory.load_file(&asset_bytes("smi_test.riv"))?;
+    let mut artboard = file.default_artboard()?;
+    artboard.advance(0.0)?;
+
+    let svg = artboard.to_svg()?;
+    assert!(svg.starts_with("<svg "));
+    assert!(svg.trim_end().ends_with("</svg>"));
+    assert!(svg.contains("<path "));
+
+    let frame = Aabb {
+        min_x: 0.0,
+        min_y: 0.0,
+        max_x: 256.0,
+        max_y: 128.0,
+    };
+    let content = artboard.bounds();
+    let mut renderer = SvgRenderer::new(256.0, 128.0)?;
+    renderer.align(
+        Fit::RIVE_RS_FIT_CONTAIN,
+        Alignment::RIVE_RS_ALIGNMENT_CENTER,
+        &frame,
+        &content,
+        1.0,
+    )?;
+    artboard.draw_svg(&mut renderer)?;
+    assert!(renderer.to_svg().contains("viewBox=\"0 0 256 128\""));
+    assert!(SvgRenderer::new(0.0, 128.0).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn svg_export_from_no_op_factory_is_unsupported() -> Result<(), Box<dyn std::error::Error>> {
+    let factory = Factory::new()?;
+    assert!(!factory.draws());
+    assert!(Factory::new_recording()?.draws());
+    let file = factory.load_file(&asset_bytes("smi_test.riv"))?;
+    let mut artboard = file.default_artboard()?;
+    artboard.advance(0.0)?;
+
+    let err = artboard.to_svg().unwrap_err();
+    assert_eq!(err.status(), Status::RIVE_RS_STATUS_UNSUPPORTED);
+    let err = artboard
+        .draw_recording(&mut RecordingRenderer::new()?)
+        .unwrap_err();
+    assert_eq!(err.status(), Status::RIVE_RS_STATUS_UNSUPPORTED);
+
+    Ok(())
+}
+
+#[test]
+fn recording_renderer_rejects_unbalanced_restore() -> Result<(), Box<dyn std::error::Error>> {
+    let mut recorder = RecordingRenderer::new()?;
+    let restore = Renderer::restore(&mut recorder)
+        .err()
+        .map(|err| err.status());
+    assert_eq!(restore, Some(Status::RIVE_RS_STATUS_OUT_OF_RANGE));
+    let clip = recorder.restore_clip_rect().err().map(|err| err.status());
+    assert_eq!(clip, Some(Status::RIVE_RS_STATUS_OUT_OF_RANGE));
+    assert!(recorder.display_list().commands.is_empty());
+
+    recorder.save_clip_rect(0.0, 0.0, 32.0, 32.0)?;
+    recorder.restore_clip_rect()?;
+    assert!(Renderer::restore(&mut recorder).is_err());
+    assert!(matches!(
+        recorder.display_list().commands.as_slice(),
+        [
+            DrawCommand::Save,
+            DrawCommand::ClipPath(_),
+            DrawCommand::Restore
+        ]
+    ));
+
+    recorder.save()?;
+    let list = recorder.take_display_list();
+    assert!(matches!(list.commands.as_slice(), [DrawCommand::Save]));
+    assert!(Renderer::restore(&mut recorder).is_err());
+    Ok(())
+}
+
+#[test]
+fn svg_renderer_rejects_unbalanced_restore() -> Result<(), Box<dyn std::error::Error>> {
+    let mut renderer = SvgRenderer::new(64.0, 64.0)?;
+    let restore = Renderer::restore(&mut renderer)
+        .err()
+        .map(|err| err.status());
+    assert_eq!(restore, Some(Status::RIVE_RS_STATUS_OUT_OF_RANGE));
+
+    renderer.save()?;
+    let clip = renderer.restore_clip_rect().err().map(|err| err.status());
+    assert_eq!(clip, Some(Status::RIVE_RS_STATUS_OUT_OF_RANGE));
+    Renderer::restore(&mut renderer)?;
+
+    renderer.save_clip_rect(0.0, 0.0, 32.0, 32.0)?;
+    renderer.restore_clip_rect()?;
+    assert!(Renderer::restore(&mut renderer).is_err());
+    let commands = &renderer.display_list().commands;
+    let saves = commands.iter().filter(|c| matches!(c, DrawCommand::Save));
+    let restores = commands
+        .iter()
+        .filter(|c| matches!(c, DrawCommand::Restore));
+    assert_eq!(saves.count(), restores.count());
+    Ok(())
+}
+
+#[test]
+fn unified_renderer_trait_draws_every_backend() -> Result<(), Box<dyn std::error::Error>> {
+    let factory = Factory::new_recording()?;
+    let file = factory.load_file(&asset_bytes("smi_test.riv"))?;
+    let mut artboard = file.default_artboard()?;
+    artboard.advance(0.0)?;
+
+    let frame = Aabb {
+        min_x: 0.0,
+        min_y: 0.0,
+        max_x: 64.0,
+        max_y: 64.0,
+    };
+    let content = artboard.bounds();
+    let mut software = SoftwareRenderer::new(64, 64)?;
+    let mut recording = RecordingRenderer::new()?;
+    let mut svg = SvgRenderer::new(64.0, 64.0)?;
+    let renderers: [&mut dyn Renderer; 3] = [&mut software, &mut recording, &mut svg];
+    for renderer in renderers {
+        renderer.clear()?;
+        renderer.save()?;
+        renderer.align(
+            Fit::RIVE_RS_FIT_CONTAIN,
+            Alignment::RIVE_RS_ALIGNMENT_CENTER,
+            &frame,
+            &content,
+            1.0,
+        )?;
+        renderer.save_clip_rect(0.0, 0.0, 32.0, 64.0)?;
+        artboard.draw(renderer)?;
+        renderer.restore_clip_rect()?;
+        renderer.restore()?;
+        renderer.flush()?;
+    }
+
+    assert!(software.pixels().chunks_exact(4).any(|pixel| pixel[3] != 0));
+    assert!(
+        recording
+            .display_list()
+            .commands
+            .iter()
+            .any(|command| matches!(command, DrawCommand::ClipPath(_)))
+    );
+    assert!(svg.to_svg().contains("<clipPath"));
+
+    Ok(())
+}
+
+#[test]
+fn export_frames_for_animation_and_state_machine() -> Result<(), Box<dyn std::error::Error>> {
+    let factory = Factory::new_recording()?;
+    let file = factory.load_file(&asset_bytes("smi_test.riv"))?;
+    let mut artboard = file.default_artboard()?;
+
+    let mut options = ExportOptions::new(64, 48);
+    options.fps = 24.0;
+    options.frame_count = 4;
+    options.background = 0xff20_2020;
+
+    let frames = if artboard.animation_count() > 0 {
+        let animation = artboard.animation_by_index(0)?;
+        let mut instance = LinearAnimationInstance::new(animation, &mut artboard)?;
+        export_frames(&mut artboard, Timeline::Animation(&mut instance), &options)?
+    } else {
+        export_frames(&mut artboard, Timeline::Static, &options)?
+    };
+    assert_eq!(frames.len(), 4);
+    for frame in &frames {
+        assert_eq!(frame.rgba.len(), 64 * 48 * 4);
+        assert!(frame.rgba.chunks_exact(4).all(|pixel| pixel[3] == 255));
+    }
+    assert!(frames[0].to_png().starts_with(b"\x89PNG\r\n\x1a\n"));
+
+    let apng = encode_apng(&frames, options.fps)?;
+    assert!(apng.windows(4).any(|window| window == b"acTL"));
+    let gif = encode_gif(&frames, options.fps)?;
+    assert!(gif.starts_with(b"GIF89a"));
+    assert_eq!(gif.last(), Some(&0x3b));
+
+    let state_machine = artboard.state_machine_by_index(0)?;
+    let mut instance = StateMachineInstance::new(state_machine, &mut artboard)?;
+    options.background = 0;
+    options.frame_count = 2;
+    let frames = export_frames(
+        &mut artboard,
+        Timeline::StateMachine(&mut instance),
+        &options,
+    )?;
+    assert_eq!(frames.len(), 2);
+
+    options.fps = 0.0;
+    assert!(export_frames(&mut artboard, Timeline::Static, &options).is_err());
+    assert!(encode_gif(&[], 30.0).is_err());
+
+    Ok(())
+}
@@ -0,0 +1,94 @@
+#![cfg(all(
+    feature = "runtime-abi-provider",
+    feature = "runtime-abi-provider-tests"
+))]
+
+mod common;
+
+use rive_rs::{ConditionType, Factory, LayerStateType, StateMachineInstance, Status};
+
+use common::asset_bytes;
+
+#[test]
+fn lists_layers_states_and_transitions() -> Result<(), Box<dyn std::error::Error>> {
+    let factory = Factory::new()?;
+    let file = factory.load_file(&asset_bytes("smi_test.riv"))?;
+    let mut artboard = file.default_artboard()?;
+    let machine = artboard.state_machine_by_index(0)?;
+    let layers = machine.layers()?;
+    assert_eq!(layers.len(), machine.layer_count());
+    assert!(!layers.is_empty());
+
+    let mut input_names = Vec::new();
+    for layer in &layers {
+        for state_type in [
+            LayerStateType::RIVE_RS_LAYER_STATE_ENTRY,
+            LayerStateType::RIVE_RS_LAYER_STATE_EXIT,
+            LayerStateType::RIVE_RS_LAYER_STATE_ANY,
+        ] {
+            let count = layer
+                .states
+                .iter()
+                .filter(|state| state.state_type == state_type)
+                .count();
+            assert_eq!(count, 1, "{state_type:?} in layer `{}`", layer.name);
+        }
+        for state in &layer.states {
+            if state.state_type == LayerStateType::RIVE_RS_LAYER_STATE_ANIMATION {
+                assert!(!state.name.is_empty());
+            }
+            for transition in &state.transitions {
+                let target = transition.target_state.expect("transition has a target");
+                assert!(target < layer.states.len());
+                for condition in &transition.conditions {
+                    if let Some(input) = condition.input {
+                        input_names.push((input, condition.input_name.clone()));
+                    }
+                    if condition.condition_type != ConditionType::RIVE_RS_CONDITION_NUMBER {
+                        assert_eq!(condition.value, 0.0);
+                    }
+                }
+            }
+        }
+    }
+
+    // The entry state leads somewhere.
+    let entry = layers[0]
+        .states
+        .iter()
+        .find(|state| state.state_type == LayerStateType::RIVE_RS_LAYER_STATE_ENTRY)
+        .expect("layer has an entry state");
+    assert!(!entry.transitions.is_empty());
+
+    // Conditions name the same inputs an instance exposes.
+    let instance_machine = artboard.state_machine_by_index(0)?;
+    let mut instance = StateMachineInstance::new(instance_machine, &mut artboard)?;
+    for (input, name) in input_names {
+        assert_eq!(instance.input(input)?.name(), name);
+    }
+    Ok(())
+}
+
+#[test]
+fn lists_listeners_with_targets() -> Result<(), Box<dyn std::error::Error>> {
+    let factory = Factory::new()?;
+    let file = factory.load_file(&asset_bytes("smi_test.riv"))?;
+    let mut artboard = file.default_artboard()?;
+    let object_count = artboard.object_count();
+    for index in 0..artboard.state_machine_count() {
+        let machine = artboard.state_machine_by_index(index)?;
+        let listeners = machine.listeners()?;
+        assert_eq!(listeners.len(), machine.listener_count());
+        for listener in &listeners {
+            assert!(listener.target_index < object_count);
+        }
+        assert_eq!(
+            machine
+                .listener_at(listeners.len())
+                .err()
+                .map(|err| err.status()),
+            Some(Status::RIVE_RS_STATUS_OUT_OF_RANGE)
+        );
+    }
+    Ok(())
+}